@@ -1,11 +1,27 @@
+use pgt_schema_cache::SchemaCache;
+
 use crate::{
     categories::RuleCategory,
-    rule::{GroupCategory, Rule, RuleGroup, RuleMetadata},
+    rule::{GroupCategory, Rule, RuleGroup, RuleMetadata, SchemaRule},
 };
 
+/// The file that contains the analysed statement.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FileContext<'a> {
+    /// All statements of the file that could be parsed, in order.
+    pub stmts: &'a [pgt_query_ext::NodeEnum],
+    /// The index of the analysed statement in `stmts`.
+    pub stmt_index: usize,
+    /// Whether the file is a migration within the configured migrations directory.
+    pub is_migration: bool,
+}
+
 pub struct RuleContext<'a, R: Rule> {
     stmt: &'a pgt_query_ext::NodeEnum,
+    sql: &'a str,
     options: &'a R::Options,
+    schema_cache: Option<&'a SchemaCache>,
+    file: FileContext<'a>,
 }
 
 impl<'a, R> RuleContext<'a, R>
@@ -13,8 +29,20 @@ where
     R: Rule + Sized + 'static,
 {
     #[allow(clippy::too_many_arguments)]
-    pub fn new(stmt: &'a pgt_query_ext::NodeEnum, options: &'a R::Options) -> Self {
-        Self { stmt, options }
+    pub fn new(
+        stmt: &'a pgt_query_ext::NodeEnum,
+        sql: &'a str,
+        options: &'a R::Options,
+        schema_cache: Option<&'a SchemaCache>,
+        file: FileContext<'a>,
+    ) -> Self {
+        Self {
+            stmt,
+            sql,
+            options,
+            schema_cache,
+            file,
+        }
     }
 
     /// Returns the group that belongs to the current rule
@@ -32,6 +60,35 @@ where
         self.stmt
     }
 
+    /// Returns the text of the analysed statement.
+    ///
+    /// Ranges of diagnostics are relative to its start.
+    pub fn sql(&self) -> &str {
+        self.sql
+    }
+
+    /// Returns the schema cache of the connected database, if there is a connection.
+    ///
+    /// Rules must not rely on it, but can use it to give more precise diagnostics.
+    pub fn schema_cache(&self) -> Option<&SchemaCache> {
+        self.schema_cache
+    }
+
+    /// Returns true if the statement is part of a migration file.
+    pub fn is_migration(&self) -> bool {
+        self.file.is_migration
+    }
+
+    /// Returns all statements of the file, including the analysed one.
+    pub fn file_stmts(&self) -> &[pgt_query_ext::NodeEnum] {
+        self.file.stmts
+    }
+
+    /// Returns the statements of the file that precede the analysed one.
+    pub fn previous_stmts(&self) -> &[pgt_query_ext::NodeEnum] {
+        &self.file.stmts[..self.file.stmt_index.min(self.file.stmts.len())]
+    }
+
     /// Returns the metadata of the rule
     ///
     /// The metadata contains information about the rule, such as the name, version, language, and whether it is recommended.
@@ -71,3 +128,45 @@ where
         self.options
     }
 }
+
+pub struct SchemaRuleContext<'a, R: SchemaRule> {
+    options: &'a R::Options,
+    schema_cache: &'a SchemaCache,
+}
+
+impl<'a, R> SchemaRuleContext<'a, R>
+where
+    R: SchemaRule + Sized + 'static,
+{
+    pub fn new(options: &'a R::Options, schema_cache: &'a SchemaCache) -> Self {
+        Self {
+            options,
+            schema_cache,
+        }
+    }
+
+    /// Returns the group that belongs to the current rule
+    pub fn group(&self) -> &'static str {
+        <R::Group as RuleGroup>::NAME
+    }
+
+    /// Returns the category that belongs to the current rule
+    pub fn category(&self) -> RuleCategory {
+        <<R::Group as RuleGroup>::Category as GroupCategory>::CATEGORY
+    }
+
+    /// Returns the schema cache of the connected database.
+    pub fn schema_cache(&self) -> &SchemaCache {
+        self.schema_cache
+    }
+
+    /// Returns the metadata of the rule
+    pub fn metadata(&self) -> &RuleMetadata {
+        &R::METADATA
+    }
+
+    /// It retrieves the options that belong to a rule, if they exist.
+    pub fn options(&self) -> &R::Options {
+        self.options
+    }
+}
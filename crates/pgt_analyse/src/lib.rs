@@ -16,8 +16,9 @@ pub use crate::categories::{
 pub use crate::filter::{AnalysisFilter, GroupKey, RuleFilter, RuleKey};
 pub use crate::options::{AnalyserOptions, AnalyserRules};
 pub use crate::registry::{
-    MetadataRegistry, RegistryRuleParams, RegistryVisitor, RuleRegistry, RuleRegistryBuilder,
+    MetadataRegistry, RegistryRuleParams, RegistrySchemaRuleParams, RegistryVisitor, RuleRegistry,
+    RuleRegistryBuilder,
 };
 pub use crate::rule::{
-    GroupCategory, Rule, RuleDiagnostic, RuleGroup, RuleMeta, RuleMetadata, RuleSource,
+    GroupCategory, Rule, RuleDiagnostic, RuleGroup, RuleMeta, RuleMetadata, RuleSource, SchemaRule,
 };
@@ -71,6 +71,20 @@ macro_rules! declare_rule {
 #[macro_export]
 macro_rules! declare_lint_group {
     ( $vis:vis $id:ident { name: $name:tt, rules: [ $( $( $rule:ident )::* , )* ] } ) => {
+        $crate::declare_lint_group!(
+            $vis $id { name: $name, rules: [ $( $( $rule )::* , )* ], schema_rules: [] }
+        );
+    };
+    ( $vis:vis $id:ident { name: $name:tt, schema_rules: [ $( $( $schema_rule:ident )::* , )* ] } ) => {
+        $crate::declare_lint_group!(
+            $vis $id { name: $name, rules: [], schema_rules: [ $( $( $schema_rule )::* , )* ] }
+        );
+    };
+    ( $vis:vis $id:ident {
+        name: $name:tt,
+        rules: [ $( $( $rule:ident )::* , )* ],
+        schema_rules: [ $( $( $schema_rule:ident )::* , )* ]
+    } ) => {
         $vis enum $id {}
 
         impl $crate::RuleGroup for $id {
@@ -80,6 +94,7 @@ macro_rules! declare_lint_group {
 
             fn record_rules<V: $crate::RegistryVisitor + ?Sized>(registry: &mut V) {
                 $( registry.record_rule::<$( $rule )::*>(); )*
+                $( registry.record_schema_rule::<$( $schema_rule )::*>(); )*
             }
         }
 
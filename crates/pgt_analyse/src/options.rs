@@ -1,6 +1,6 @@
 use rustc_hash::FxHashMap;
 
-use crate::{Rule, RuleKey};
+use crate::{Rule, RuleKey, SchemaRule};
 use std::any::{Any, TypeId};
 use std::fmt::Debug;
 
@@ -58,4 +58,13 @@ impl AnalyserOptions {
             .get_rule_options::<R::Options>(&RuleKey::rule::<R>())
             .cloned()
     }
+
+    pub fn schema_rule_options<R>(&self) -> Option<R::Options>
+    where
+        R: SchemaRule<Options: Clone> + 'static,
+    {
+        self.rules
+            .get_rule_options::<R::Options>(&RuleKey::rule::<R>())
+            .cloned()
+    }
 }
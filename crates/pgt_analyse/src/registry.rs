@@ -1,10 +1,12 @@
-use std::{borrow, collections::BTreeSet};
+use std::{borrow, collections::BTreeMap};
+
+use pgt_schema_cache::SchemaCache;
 
 use crate::{
     AnalyserOptions,
-    context::RuleContext,
+    context::{FileContext, RuleContext, SchemaRuleContext},
     filter::{AnalysisFilter, GroupKey, RuleKey},
-    rule::{GroupCategory, Rule, RuleDiagnostic, RuleGroup},
+    rule::{GroupCategory, Rule, RuleDiagnostic, RuleGroup, RuleMetadata, SchemaRule},
 };
 
 pub trait RegistryVisitor {
@@ -22,6 +24,11 @@ pub trait RegistryVisitor {
     fn record_rule<R>(&mut self)
     where
         R: Rule + 'static;
+
+    /// Record the schema rule `R` to this visitor
+    fn record_schema_rule<R>(&mut self)
+    where
+        R: SchemaRule + 'static;
 }
 
 /// Key struct for a rule in the metadata map, sorted alphabetically
@@ -58,26 +65,41 @@ impl borrow::Borrow<str> for MetadataKey {
 /// alphabetically
 #[derive(Debug, Default)]
 pub struct MetadataRegistry {
-    inner: BTreeSet<MetadataKey>,
+    inner: BTreeMap<MetadataKey, RuleMetadata>,
 }
 
 impl MetadataRegistry {
     /// Return a unique identifier for a rule group if it's known by this registry
     pub fn find_group(&self, group: &str) -> Option<GroupKey> {
-        let key = self.inner.get(group)?;
+        let (key, _) = self.inner.get_key_value(group)?;
         Some(key.into_group_key())
     }
 
     /// Return a unique identifier for a rule if it's known by this registry
     pub fn find_rule(&self, group: &str, rule: &str) -> Option<RuleKey> {
-        let key = self.inner.get(&(group, rule))?;
+        let (key, _) = self.inner.get_key_value(&(group, rule))?;
         Some(key.into_rule_key())
     }
 
-    pub(crate) fn insert_rule(&mut self, group: &'static str, rule: &'static str) {
-        self.inner.insert(MetadataKey {
-            inner: (group, rule),
-        });
+    /// Return the metadata of a rule if it's known by this registry
+    pub fn find_metadata(&self, group: &str, rule: &str) -> Option<&RuleMetadata> {
+        self.inner.get(&(group, rule))
+    }
+
+    /// Iterate over the group name and metadata of every rule in the registry
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &RuleMetadata)> {
+        self.inner
+            .iter()
+            .map(|(key, metadata)| (key.inner.0, metadata))
+    }
+
+    pub(crate) fn insert_rule(&mut self, group: &'static str, metadata: RuleMetadata) {
+        self.inner.insert(
+            MetadataKey {
+                inner: (group, metadata.name),
+            },
+            metadata,
+        );
     }
 }
 
@@ -86,7 +108,14 @@ impl RegistryVisitor for MetadataRegistry {
     where
         R: Rule + 'static,
     {
-        self.insert_rule(<R::Group as RuleGroup>::NAME, R::METADATA.name);
+        self.insert_rule(<R::Group as RuleGroup>::NAME, R::METADATA);
+    }
+
+    fn record_schema_rule<R>(&mut self)
+    where
+        R: SchemaRule + 'static,
+    {
+        self.insert_rule(<R::Group as RuleGroup>::NAME, R::METADATA);
     }
 }
 
@@ -122,11 +151,26 @@ impl RegistryVisitor for RuleRegistryBuilder<'_> {
 
         self.registry.rules.push(rule);
     }
+
+    /// Add the schema rule `R` to the list of schema rules stored in this registry instance
+    fn record_schema_rule<R>(&mut self)
+    where
+        R: SchemaRule<Options: Default> + 'static,
+    {
+        if !self.filter.match_rule::<R>() {
+            return;
+        }
+
+        let rule = RegistrySchemaRule::new::<R>();
+
+        self.registry.schema_rules.push(rule);
+    }
 }
 
 /// The rule registry holds type-erased instances of all active analysis rules
 pub struct RuleRegistry {
     pub rules: Vec<RegistryRule>,
+    pub schema_rules: Vec<RegistrySchemaRule>,
 }
 
 impl IntoIterator for RuleRegistry {
@@ -150,6 +194,7 @@ impl RuleRegistry {
             filter,
             registry: RuleRegistry {
                 rules: Default::default(),
+                schema_rules: Default::default(),
             },
         }
     }
@@ -157,7 +202,10 @@ impl RuleRegistry {
 
 pub struct RegistryRuleParams<'a> {
     pub root: &'a pgt_query_ext::NodeEnum,
+    pub sql: &'a str,
     pub options: &'a AnalyserOptions,
+    pub schema_cache: Option<&'a SchemaCache>,
+    pub file: FileContext<'a>,
 }
 
 /// Executor for rule as a generic function pointer
@@ -174,7 +222,49 @@ impl RegistryRule {
             R: Rule<Options: Default> + 'static,
         {
             let options = params.options.rule_options::<R>().unwrap_or_default();
-            let ctx = RuleContext::new(params.root, &options);
+            let ctx = RuleContext::new(
+                params.root,
+                params.sql,
+                &options,
+                params.schema_cache,
+                params.file,
+            );
+            R::run(&ctx)
+        }
+
+        Self { run: run::<R> }
+    }
+}
+
+/// Internal representation of a single schema rule in the registry
+#[derive(Copy, Clone)]
+pub struct RegistrySchemaRule {
+    pub run: SchemaRuleExecutor,
+}
+
+pub struct RegistrySchemaRuleParams<'a> {
+    pub options: &'a AnalyserOptions,
+    pub schema_cache: &'a SchemaCache,
+}
+
+/// Executor for schema rule as a generic function pointer
+type SchemaRuleExecutor = fn(&RegistrySchemaRuleParams) -> Vec<RuleDiagnostic>;
+
+impl RegistrySchemaRule {
+    fn new<R>() -> Self
+    where
+        R: SchemaRule<Options: Default> + 'static,
+    {
+        /// Generic implementation of SchemaRuleExecutor for any schema rule type R
+        fn run<R>(params: &RegistrySchemaRuleParams) -> Vec<RuleDiagnostic>
+        where
+            R: SchemaRule<Options: Default> + 'static,
+        {
+            let options = params
+                .options
+                .schema_rule_options::<R>()
+                .unwrap_or_default();
+            let ctx = SchemaRuleContext::new(&options, params.schema_cache);
             R::run(&ctx)
         }
 
@@ -3,13 +3,16 @@ use pgt_console::{MarkupBuf, markup};
 use pgt_diagnostics::advice::CodeSuggestionAdvice;
 use pgt_diagnostics::{
     Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory, MessageAndDescription,
-    Visit,
+    Severity, Visit,
 };
 use pgt_text_size::TextRange;
-use std::cmp::Ordering;
 use std::fmt::Debug;
 
-use crate::{categories::RuleCategory, context::RuleContext, registry::RegistryVisitor};
+use crate::{
+    categories::RuleCategory,
+    context::{RuleContext, SchemaRuleContext},
+    registry::RegistryVisitor,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(
@@ -97,6 +100,15 @@ pub trait Rule: RuleMeta + Sized {
     fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic>;
 }
 
+/// Trait implemented by rules that check the connected database as a whole instead of a single
+/// statement, such as the database advisors of Splinter. They run once per analysis with the
+/// schema cache, and only if there is a database connection.
+pub trait SchemaRule: RuleMeta + Sized {
+    type Options: Default + Clone + Debug;
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic>;
+}
+
 /// Diagnostic object returned by a single analysis rule
 #[derive(Debug, Diagnostic, PartialEq)]
 pub struct RuleDiagnostic {
@@ -111,6 +123,8 @@ pub struct RuleDiagnostic {
     pub(crate) tags: DiagnosticTags,
     #[advice]
     pub(crate) rule_advice: RuleAdvice,
+    /// Overrides the severity configured for the rule
+    pub(crate) severity: Option<Severity>,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -182,6 +196,7 @@ impl RuleDiagnostic {
             message: MessageAndDescription::from(message),
             tags: DiagnosticTags::empty(),
             rule_advice: RuleAdvice::default(),
+            severity: None,
         }
     }
 
@@ -260,6 +275,19 @@ impl RuleDiagnostic {
         self.footer(LogCategory::Warn, msg)
     }
 
+    /// Overrides the severity that is configured for the rule.
+    ///
+    /// Useful if a rule knows that a finding is less severe than usual,
+    /// e.g. because the affected table is small.
+    pub fn severity_override(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    pub fn get_severity_override(&self) -> Option<Severity> {
+        self.severity
+    }
+
     pub fn advices(&self) -> &RuleAdvice {
         &self.rule_advice
     }
@@ -270,58 +298,66 @@ impl RuleDiagnostic {
     }
 }
 
-#[derive(Debug, Clone, Eq)]
+/// Where a rule comes from. Sources are ordered by their tool first, then by the name of the
+/// rule, so two sources are only equal if both match.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum RuleSource {
     /// Rules from [Squawk](https://squawkhq.com)
     Squawk(&'static str),
-}
-
-impl PartialEq for RuleSource {
-    fn eq(&self, other: &Self) -> bool {
-        std::mem::discriminant(self) == std::mem::discriminant(other)
-    }
+    /// Rules from [SQLFluff](https://sqlfluff.com)
+    Sqlfluff(&'static str),
+    /// Database advisors from [Splinter](https://github.com/supabase/splinter)
+    Splinter(&'static str),
+    /// Rules from [pgFormatter](https://github.com/darold/pgFormatter)
+    PgFormatter(&'static str),
 }
 
 impl std::fmt::Display for RuleSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Squawk(_) => write!(f, "Squawk"),
+            Self::Sqlfluff(_) => write!(f, "SQLFluff"),
+            Self::Splinter(_) => write!(f, "Splinter"),
+            Self::PgFormatter(_) => write!(f, "pgFormatter"),
         }
     }
 }
 
-impl PartialOrd for RuleSource {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for RuleSource {
-    fn cmp(&self, other: &Self) -> Ordering {
-        let self_rule = self.as_rule_name();
-        let other_rule = other.as_rule_name();
-        self_rule.cmp(other_rule)
-    }
-}
-
 impl RuleSource {
     pub fn as_rule_name(&self) -> &'static str {
         match self {
-            Self::Squawk(rule_name) => rule_name,
+            Self::Squawk(rule_name)
+            | Self::Sqlfluff(rule_name)
+            | Self::Splinter(rule_name)
+            | Self::PgFormatter(rule_name) => rule_name,
         }
     }
 
     pub fn to_namespaced_rule_name(&self) -> String {
         match self {
             Self::Squawk(rule_name) => format!("squawk/{rule_name}"),
+            Self::Sqlfluff(rule_name) => format!("sqlfluff/{rule_name}"),
+            Self::Splinter(rule_name) => format!("splinter/{rule_name}"),
+            Self::PgFormatter(rule_name) => format!("pgformatter/{rule_name}"),
         }
     }
 
     pub fn to_rule_url(&self) -> String {
         match self {
             Self::Squawk(rule_name) => format!("https://squawkhq.com/docs/{rule_name}"),
+            Self::Sqlfluff(rule_name) => {
+                format!("https://docs.sqlfluff.com/en/stable/reference/rules.html#rule-{rule_name}")
+            }
+            Self::Splinter(rule_name) => {
+                format!(
+                    "https://supabase.com/docs/guides/database/database-linter?lint={rule_name}"
+                )
+            }
+            Self::PgFormatter(rule_name) => {
+                format!("https://github.com/darold/pgFormatter#{rule_name}")
+            }
         }
     }
 
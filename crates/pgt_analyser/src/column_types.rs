@@ -0,0 +1,176 @@
+use biome_deserialize_macros::Deserializable;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType, Node, RangeVar, TypeName};
+use pgt_schema_cache::SchemaCache;
+use serde::{Deserialize, Serialize};
+
+/// Options of the rules that check the types of columns.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct ColumnTypeOptions {
+    /// Tables that are not checked by the rule, either as `table` or as `schema.table`.
+    pub allowed_tables: Vec<String>,
+}
+
+impl ColumnTypeOptions {
+    /// Returns true if the rule should not check columns of the given table.
+    pub(crate) fn is_allowed(&self, table: Option<&RangeVar>) -> bool {
+        let Some(table) = table else {
+            return false;
+        };
+
+        self.allowed_tables
+            .iter()
+            .any(|allowed| match allowed.split_once('.') {
+                Some((schema, name)) => {
+                    let table_schema = if table.schemaname.is_empty() {
+                        "public"
+                    } else {
+                        table.schemaname.as_str()
+                    };
+                    schema == table_schema && name == table.relname
+                }
+                None => *allowed == table.relname,
+            })
+    }
+}
+
+/// A column whose type is set by a statement.
+pub(crate) struct ColumnType<'a> {
+    pub table: Option<&'a RangeVar>,
+    pub type_name: &'a TypeName,
+    pub is_primary_key: bool,
+}
+
+impl ColumnType<'_> {
+    /// The name of the type without its schema, e.g. `int4` for `integer` or `pg_catalog.int4`.
+    pub fn name(&self) -> Option<&str> {
+        self.type_name.names.last().and_then(|n| match &n.node {
+            Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns true if the type has modifiers, e.g. the length of `varchar(255)`.
+    pub fn has_modifiers(&self) -> bool {
+        !self.type_name.typmods.is_empty()
+    }
+}
+
+/// Collects the columns whose type is set by `CREATE TABLE`, `ALTER TABLE ADD COLUMN` or
+/// `ALTER TABLE ALTER COLUMN TYPE`.
+///
+/// Whether an altered column is a primary key can only be known with a schema cache.
+pub(crate) fn column_types<'a>(
+    stmt: &'a pgt_query_ext::NodeEnum,
+    schema_cache: Option<&SchemaCache>,
+) -> Vec<ColumnType<'a>> {
+    let mut columns = Vec::new();
+
+    match stmt {
+        pgt_query_ext::NodeEnum::CreateStmt(stmt) => {
+            // primary keys that are declared as a table constraint
+            let primary_keys = stmt
+                .table_elts
+                .iter()
+                .filter_map(|n| match &n.node {
+                    Some(pgt_query_ext::NodeEnum::Constraint(c))
+                        if c.contype() == ConstrType::ConstrPrimary =>
+                    {
+                        Some(c.keys.iter().filter_map(string_value))
+                    }
+                    _ => None,
+                })
+                .flatten()
+                .collect::<Vec<_>>();
+
+            for elt in &stmt.table_elts {
+                if let Some(pgt_query_ext::NodeEnum::ColumnDef(col)) = &elt.node {
+                    if let Some(type_name) = col.type_name.as_ref() {
+                        columns.push(ColumnType {
+                            table: stmt.relation.as_ref(),
+                            type_name,
+                            is_primary_key: primary_keys.contains(&col.colname.as_str())
+                                || has_primary_key_constraint(&col.constraints),
+                        });
+                    }
+                }
+            }
+        }
+        pgt_query_ext::NodeEnum::AlterTableStmt(stmt) => {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    let Some(pgt_query_ext::NodeEnum::ColumnDef(col)) =
+                        cmd.def.as_ref().and_then(|d| d.node.as_ref())
+                    else {
+                        continue;
+                    };
+
+                    let Some(type_name) = col.type_name.as_ref() else {
+                        continue;
+                    };
+
+                    match cmd.subtype() {
+                        AlterTableType::AtAddColumn => columns.push(ColumnType {
+                            table: stmt.relation.as_ref(),
+                            type_name,
+                            is_primary_key: has_primary_key_constraint(&col.constraints),
+                        }),
+                        AlterTableType::AtAlterColumnType => columns.push(ColumnType {
+                            table: stmt.relation.as_ref(),
+                            type_name,
+                            is_primary_key: is_primary_key_in_schema(
+                                schema_cache,
+                                stmt.relation.as_ref(),
+                                &cmd.name,
+                            ),
+                        }),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    columns
+}
+
+fn string_value(node: &Node) -> Option<&str> {
+    match &node.node {
+        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+        _ => None,
+    }
+}
+
+fn has_primary_key_constraint(constraints: &[Node]) -> bool {
+    constraints.iter().any(|c| {
+        matches!(
+            &c.node,
+            Some(pgt_query_ext::NodeEnum::Constraint(c)) if c.contype() == ConstrType::ConstrPrimary
+        )
+    })
+}
+
+fn is_primary_key_in_schema(
+    schema_cache: Option<&SchemaCache>,
+    table: Option<&RangeVar>,
+    column: &str,
+) -> bool {
+    let (Some(schema_cache), Some(table)) = (schema_cache, table) else {
+        return false;
+    };
+
+    let schema = if table.schemaname.is_empty() {
+        "public"
+    } else {
+        table.schemaname.as_str()
+    };
+
+    schema_cache.columns.iter().any(|c| {
+        c.schema_name == schema
+            && c.table_name == table.relname
+            && c.name == column
+            && c.is_primary_key
+    })
+}
@@ -0,0 +1,323 @@
+use pgt_query_ext::{
+    NodeEnum,
+    protobuf::{Node, RangeVar, Token},
+};
+use pgt_schema_cache::{Dependency, DependencyKind, Function, SchemaCache};
+use serde_json::Value;
+
+use crate::plpgsql::{SqlToken, tokens};
+
+/// Returns the schema and name of `relation`. Unqualified names are resolved against the
+/// `public` schema.
+pub(crate) fn relation_name(relation: &RangeVar) -> (&str, &str) {
+    let schema = if relation.schemaname.is_empty() {
+        "public"
+    } else {
+        relation.schemaname.as_str()
+    };
+
+    (schema, relation.relname.as_str())
+}
+
+/// Returns the schema and name of a qualified name given as a list of `String` nodes, such as
+/// the objects of a `DROP` statement. Unqualified names are resolved against the `public` schema.
+pub(crate) fn qualified_name(names: &[Node]) -> Option<(&str, &str)> {
+    let names = names
+        .iter()
+        .map(|n| match &n.node {
+            Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    match names.as_slice() {
+        [name] => Some(("public", *name)),
+        [schema, name] => Some((*schema, *name)),
+        _ => None,
+    }
+}
+
+/// Returns the views, policies and foreign keys that depend on a table, or on one of its
+/// columns if `column` is given.
+pub(crate) fn dependencies_on<'a>(
+    schema_cache: &'a SchemaCache,
+    schema: &str,
+    table: &str,
+    column: Option<&str>,
+) -> Vec<&'a Dependency> {
+    schema_cache
+        .dependencies
+        .iter()
+        .filter(|d| d.referenced_schema == schema && d.referenced_table == table)
+        .filter(|d| column.is_none() || d.referenced_column.as_deref() == column)
+        .collect()
+}
+
+/// Returns the functions that use a table, or one of its columns if `column` is given.
+///
+/// The statements in the bodies of the functions are parsed. A column is used if it is qualified
+/// with the table or its alias, if it is unqualified in a statement that uses the table, or if a
+/// trigger function of a trigger on the table reads it from `NEW` or `OLD`. Dynamic SQL is not
+/// taken into account.
+pub(crate) fn functions_using<'a>(
+    schema_cache: &'a SchemaCache,
+    schema: &str,
+    table: &str,
+    column: Option<&str>,
+) -> Vec<&'a Function> {
+    let trigger_functions = schema_cache
+        .triggers
+        .iter()
+        .filter(|t| t.schema_name == schema && t.table_name == table)
+        .map(|t| t.proc_name.as_str())
+        .collect::<Vec<_>>();
+
+    user_functions(schema_cache)
+        .filter(|f| {
+            let references = BodyReferences::new(f);
+
+            match column {
+                None => references
+                    .tables
+                    .iter()
+                    .any(|(s, t)| s == schema && t == table),
+                Some(column) => {
+                    references
+                        .columns
+                        .iter()
+                        .any(|(s, t, c)| s == schema && t == table && c == column)
+                        || (trigger_functions.contains(&f.name.as_str())
+                            && references.record_fields.iter().any(|c| c == column))
+                }
+            }
+        })
+        .collect()
+}
+
+/// The tables and columns that the statements in the body of a function use.
+#[derive(Default)]
+struct BodyReferences {
+    /// The schema and name of the tables. Unqualified tables are resolved against the `public`
+    /// schema.
+    tables: Vec<(String, String)>,
+    /// The schema, table and name of the columns. Unqualified columns belong to every table of
+    /// their statement.
+    columns: Vec<(String, String, String)>,
+    /// The fields of `NEW` and `OLD` in PL/pgSQL functions.
+    record_fields: Vec<String>,
+}
+
+impl BodyReferences {
+    fn new(function: &Function) -> Self {
+        let mut references = Self::default();
+
+        match function.language.as_str() {
+            "sql" => {
+                let body = function.body.as_deref().unwrap_or_default();
+                let mut start = 0;
+                for token in tokens(body) {
+                    if token.kind == Token::Ascii59 {
+                        references.add_statement(&body[start..token.range.start().into()]);
+                        start = token.range.end().into();
+                    }
+                }
+                references.add_statement(&body[start..]);
+            }
+            "plpgsql" => {
+                // the definition is the whole `CREATE FUNCTION` statement, which the PL/pgSQL
+                // parser needs to know the parameters and whether `NEW` and `OLD` exist
+                let parsed = function
+                    .definition
+                    .as_deref()
+                    .and_then(|definition| pgt_query_ext::parse_plpgsql(definition).ok());
+                if let Some(parsed) = parsed {
+                    references.add_plpgsql(&parsed);
+                }
+            }
+            _ => {}
+        }
+
+        references
+    }
+
+    /// Adds the expressions of a function parsed by the PL/pgSQL parser and the fields of `NEW`
+    /// and `OLD` it uses.
+    fn add_plpgsql(&mut self, value: &Value) {
+        match value {
+            Value::Object(object) => {
+                if let Some(expr) = object.get("PLpgSQL_expr") {
+                    let query = expr
+                        .get("query")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+                    match expr.get("parseMode").and_then(Value::as_i64).unwrap_or(0) {
+                        // a whole statement
+                        0 => self.add_statement(query),
+                        // an expression, or an assignment such as `new.updated_at := now()`
+                        // whose target is a variable
+                        mode => {
+                            let expression = if mode == 2 {
+                                query
+                            } else {
+                                tokens(query)
+                                    .iter()
+                                    .find(|t| t.text == ":=" || t.text == "=")
+                                    .map_or(query, |t| &query[usize::from(t.range.end())..])
+                            };
+                            self.add_statement(&format!("select {expression}"));
+                        }
+                    }
+                    return;
+                }
+
+                if let Some(field) = object.get("PLpgSQL_recfield") {
+                    if let Some(name) = field.get("fieldname").and_then(Value::as_str) {
+                        self.record_fields.push(name.to_string());
+                    }
+                }
+
+                object.values().for_each(|v| self.add_plpgsql(v));
+            }
+            Value::Array(values) => values.iter().for_each(|v| self.add_plpgsql(v)),
+            _ => {}
+        }
+    }
+
+    fn add_statement(&mut self, sql: &str) {
+        let Ok(stmt) = pgt_query_ext::parse(sql) else {
+            return;
+        };
+        let nodes = pgt_query_ext::ChildrenIterator::new(stmt).collect::<Vec<_>>();
+
+        // the tables of the statement with their aliases
+        let relations = nodes
+            .iter()
+            .filter_map(|node| match node {
+                NodeEnum::RangeVar(relation) => {
+                    let (schema, table) = relation_name(relation);
+                    let alias = relation.alias.as_ref().map(|a| a.aliasname.as_str());
+                    Some((schema, table, alias))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut add_column = |qualifier: Option<&str>, column: &str| {
+            let tables = relations
+                .iter()
+                .filter(|(_, table, alias)| match qualifier {
+                    Some(qualifier) => alias.unwrap_or(table) == qualifier,
+                    None => true,
+                });
+            let mut found = false;
+            for (schema, table, _) in tables {
+                found = true;
+                self.columns
+                    .push((schema.to_string(), table.to_string(), column.to_string()));
+            }
+            if !found && matches!(qualifier, Some("new" | "old")) {
+                self.record_fields.push(column.to_string());
+            }
+        };
+
+        for node in &nodes {
+            match node {
+                NodeEnum::ColumnRef(column_ref) => {
+                    let fields = column_ref
+                        .fields
+                        .iter()
+                        .map(|f| match &f.node {
+                            Some(NodeEnum::String(s)) => Some(s.sval.as_str()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    match fields.as_slice() {
+                        [Some(column)] => add_column(None, column),
+                        [.., Some(qualifier), Some(column)] => add_column(Some(qualifier), column),
+                        _ => {}
+                    }
+                }
+                // the target columns of `INSERT` and `UPDATE` belong to the target table
+                NodeEnum::InsertStmt(stmt) => {
+                    add_targets(stmt.relation.as_ref(), &stmt.cols, &mut add_column);
+                }
+                NodeEnum::UpdateStmt(stmt) => {
+                    add_targets(stmt.relation.as_ref(), &stmt.target_list, &mut add_column);
+                }
+                _ => {}
+            }
+        }
+
+        self.tables.extend(
+            relations
+                .into_iter()
+                .map(|(schema, table, _)| (schema.to_string(), table.to_string())),
+        );
+    }
+}
+
+fn add_targets(
+    relation: Option<&RangeVar>,
+    targets: &[Node],
+    add_column: &mut impl FnMut(Option<&str>, &str),
+) {
+    let Some(relation) = relation else {
+        return;
+    };
+    let qualifier = relation
+        .alias
+        .as_ref()
+        .map_or(relation.relname.as_str(), |a| a.aliasname.as_str());
+
+    for target in targets {
+        if let Some(NodeEnum::ResTarget(target)) = &target.node {
+            add_column(Some(qualifier), &target.name);
+        }
+    }
+}
+
+/// Returns the SQL and PL/pgSQL functions outside of the system schemas.
+pub(crate) fn user_functions(schema_cache: &SchemaCache) -> impl Iterator<Item = &Function> {
+    schema_cache.functions.iter().filter(|f| {
+        matches!(f.language.as_str(), "sql" | "plpgsql")
+            && !matches!(f.schema.as_str(), "pg_catalog" | "information_schema")
+    })
+}
+
+/// Returns true if `body` calls a function named `name`.
+pub(crate) fn calls_function(body: &str, name: &str) -> bool {
+    let tokens = tokens(body);
+
+    tokens
+        .windows(2)
+        .any(|pair| is_name(&pair[0], name) && pair[1].kind == Token::Ascii40)
+}
+
+fn is_name(token: &SqlToken, name: &str) -> bool {
+    token.is_word() && token.text.eq_ignore_ascii_case(name)
+}
+
+/// Describes a dependent object for the list in a diagnostic, e.g. `view public.active_users`.
+pub(crate) fn describe_dependency(dependency: &Dependency) -> String {
+    let table = format!("{}.{}", dependency.schema_name, dependency.table_name);
+
+    match dependency.kind {
+        DependencyKind::View => format!("view {table}"),
+        DependencyKind::MaterializedView => format!("materialized view {table}"),
+        DependencyKind::Policy => format!("policy {} on {table}", dependency.name),
+        DependencyKind::ForeignKey => format!("foreign key {} on {table}", dependency.name),
+    }
+}
+
+/// Describes a function for the list in a diagnostic, e.g. `function public.notify()`.
+pub(crate) fn describe_function(function: &Function) -> String {
+    format!(
+        "function {}.{}({})",
+        function.schema,
+        function.name,
+        function
+            .identity_argument_types
+            .as_deref()
+            .unwrap_or_default()
+    )
+}
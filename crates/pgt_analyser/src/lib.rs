@@ -1,14 +1,22 @@
 use std::{ops::Deref, sync::LazyLock};
 
+pub use column_types::ColumnTypeOptions;
 use pgt_analyse::{
-    AnalyserOptions, AnalysisFilter, MetadataRegistry, RegistryRuleParams, RuleDiagnostic,
-    RuleRegistry,
+    AnalyserOptions, AnalysisFilter, MetadataRegistry, RegistryRuleParams,
+    RegistrySchemaRuleParams, RuleDiagnostic, RuleRegistry, context::FileContext,
 };
+use pgt_schema_cache::SchemaCache;
 pub use registry::visit_registry;
 
+mod column_types;
+mod dependents;
 mod lint;
+mod locking;
 pub mod options;
+mod plpgsql;
 mod registry;
+mod span;
+mod table_size;
 
 pub static METADATA: LazyLock<MetadataRegistry> = LazyLock::new(|| {
     let mut metadata = MetadataRegistry::default();
@@ -32,6 +40,12 @@ pub struct Analyser<'a> {
 
 pub struct AnalyserContext<'a> {
     pub root: &'a pgt_query_ext::NodeEnum,
+    /// The text of the statement `root` was parsed from
+    pub sql: &'a str,
+    /// The schema cache of the connected database, if any
+    pub schema_cache: Option<&'a SchemaCache>,
+    /// The file that contains `root`
+    pub file: FileContext<'a>,
 }
 
 pub struct AnalyserConfig<'a> {
@@ -55,7 +69,10 @@ impl<'a> Analyser<'a> {
     pub fn run(&self, ctx: AnalyserContext) -> Vec<RuleDiagnostic> {
         let params = RegistryRuleParams {
             root: ctx.root,
+            sql: ctx.sql,
             options: self.options,
+            schema_cache: ctx.schema_cache,
+            file: ctx.file,
         };
 
         self.registry
@@ -64,6 +81,20 @@ impl<'a> Analyser<'a> {
             .flat_map(|rule| (rule.run)(&params))
             .collect::<Vec<_>>()
     }
+
+    /// Runs the schema rules once against the whole connected database.
+    pub fn run_schema(&self, schema_cache: &SchemaCache) -> Vec<RuleDiagnostic> {
+        let params = RegistrySchemaRuleParams {
+            options: self.options,
+            schema_cache,
+        };
+
+        self.registry
+            .schema_rules
+            .iter()
+            .flat_map(|rule| (rule.run)(&params))
+            .collect::<Vec<_>>()
+    }
 }
 
 #[cfg(test)]
@@ -110,7 +141,12 @@ mod tests {
             filter,
         });
 
-        let results = analyser.run(crate::AnalyserContext { root: &ast });
+        let results = analyser.run(crate::AnalyserContext {
+            root: &ast,
+            sql: SQL,
+            schema_cache: None,
+            file: Default::default(),
+        });
 
         println!("*******************");
         for result in &results {
@@ -1,4 +1,9 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+pub mod correctness;
+pub mod performance;
 pub mod safety;
-::pgt_analyse::declare_category! { pub Lint { kind : Lint , groups : [self :: safety :: Safety ,] } }
+pub mod schema;
+pub mod security;
+pub mod style;
+::pgt_analyse::declare_category! { pub Lint { kind : Lint , groups : [self :: correctness :: Correctness , self :: performance :: Performance , self :: safety :: Safety , self :: schema :: Schema , self :: security :: Security , self :: style :: Style ,] } }
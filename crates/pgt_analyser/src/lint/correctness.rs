@@ -0,0 +1,15 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use pgt_analyse::declare_lint_group;
+pub mod ban_between_with_timestamps;
+pub mod ban_equals_null;
+pub mod ban_explicit_transaction;
+pub mod ban_implicit_cross_join;
+pub mod ban_not_in_subquery;
+pub mod ban_select_star;
+pub mod ban_undeclared_variables;
+pub mod ban_unreachable_code;
+pub mod ban_unused_variables;
+pub mod require_return;
+pub mod require_strict_select_into;
+declare_lint_group! { pub Correctness { name : "correctness" , rules : [self :: ban_between_with_timestamps :: BanBetweenWithTimestamps , self :: ban_equals_null :: BanEqualsNull , self :: ban_explicit_transaction :: BanExplicitTransaction , self :: ban_implicit_cross_join :: BanImplicitCrossJoin , self :: ban_not_in_subquery :: BanNotInSubquery , self :: ban_select_star :: BanSelectStar , self :: ban_undeclared_variables :: BanUndeclaredVariables , self :: ban_unreachable_code :: BanUnreachableCode , self :: ban_unused_variables :: BanUnusedVariables , self :: require_return :: RequireReturn , self :: require_strict_select_into :: RequireStrictSelectInto ,] } }
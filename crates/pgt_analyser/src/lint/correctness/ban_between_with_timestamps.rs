@@ -0,0 +1,194 @@
+use std::sync::LazyLock;
+
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AExprKind, Node, SqlValueFunctionOp, a_const};
+use pgt_schema_cache::SchemaCache;
+use regex::Regex;
+
+use crate::span::token_range;
+
+declare_lint_rule! {
+    /// `BETWEEN` includes both bounds, which is rarely what you want for timestamps.
+    ///
+    /// `created_at between '2024-01-01' and '2024-01-31'` includes rows created exactly at midnight of January 31st, but none of the rows created later that day.
+    /// Using the start of the next day as the upper bound instead includes rows that belong to the next range.
+    ///
+    /// Use a half-open range with `>=` and `<` instead.
+    ///
+    /// A comparison is recognised as one between timestamps if one of the operands is cast to a timestamp, is a function such as `now()`, or is a literal with a time of day.
+    /// If a database connection is available, columns of type `timestamp` and `timestamptz` are recognised as well.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// select * from orders where created_at between '2024-01-01'::timestamptz and '2024-01-31'::timestamptz;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// select * from orders where created_at >= '2024-01-01'::timestamptz and created_at < '2024-02-01'::timestamptz;
+    /// ```
+    ///
+    pub BanBetweenWithTimestamps {
+        version: "next",
+        name: "banBetweenWithTimestamps",
+        recommended: false,
+    }
+}
+
+impl Rule for BanBetweenWithTimestamps {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // the tables of the statement, used to look up the types of columns
+        let tables = ctx
+            .schema_cache()
+            .map(|_| referenced_tables(ctx.stmt()))
+            .unwrap_or_default();
+
+        for node in pgt_query_ext::ChildrenIterator::new(ctx.stmt().clone()) {
+            if let pgt_query_ext::NodeEnum::AExpr(expr) = &node {
+                // the location of `NOT BETWEEN` is the `NOT` keyword
+                let keyword_len = match expr.kind() {
+                    AExprKind::AexprBetween | AExprKind::AexprBetweenSym => "between".len(),
+                    AExprKind::AexprNotBetween | AExprKind::AexprNotBetweenSym => "not".len(),
+                    _ => continue,
+                };
+
+                let bounds = match expr.rexpr.as_deref().and_then(|n| n.node.as_ref()) {
+                    Some(pgt_query_ext::NodeEnum::List(list)) => list.items.as_slice(),
+                    _ => continue,
+                };
+
+                let is_timestamp_comparison = expr
+                    .lexpr
+                    .as_deref()
+                    .into_iter()
+                    .chain(bounds)
+                    .any(|n| is_timestamp(n, ctx.schema_cache(), &tables));
+
+                if is_timestamp_comparison {
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            token_range(expr.location, keyword_len),
+                            markup! {
+                                "BETWEEN includes both bounds, which is rarely intended for timestamps."
+                            },
+                        )
+                        .detail(None, "Use a half-open range with >= and < instead."),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+const TIMESTAMP_TYPES: &[&str] = &["timestamp", "timestamptz"];
+
+/// Built-in functions that return a timestamp.
+const TIMESTAMP_FUNCTIONS: &[&str] = &[
+    "now",
+    "clock_timestamp",
+    "statement_timestamp",
+    "transaction_timestamp",
+    "date_trunc",
+    "to_timestamp",
+];
+
+static TIMESTAMP_LITERAL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}[ T]\d{1,2}:\d{2}").unwrap());
+
+/// Returns true if the expression evaluates to a timestamp.
+fn is_timestamp(
+    node: &Node,
+    schema_cache: Option<&SchemaCache>,
+    tables: &[(String, String)],
+) -> bool {
+    match &node.node {
+        Some(pgt_query_ext::NodeEnum::TypeCast(c)) => c
+            .type_name
+            .as_ref()
+            .and_then(|t| t.names.last())
+            .is_some_and(|n| {
+                matches!(
+                    &n.node,
+                    Some(pgt_query_ext::NodeEnum::String(s)) if TIMESTAMP_TYPES.contains(&s.sval.as_str())
+                )
+            }),
+        Some(pgt_query_ext::NodeEnum::SqlvalueFunction(f)) => matches!(
+            f.op(),
+            SqlValueFunctionOp::SvfopCurrentTimestamp
+                | SqlValueFunctionOp::SvfopCurrentTimestampN
+                | SqlValueFunctionOp::SvfopLocaltimestamp
+                | SqlValueFunctionOp::SvfopLocaltimestampN
+        ),
+        Some(pgt_query_ext::NodeEnum::FuncCall(f)) => f.funcname.last().is_some_and(|n| {
+            matches!(
+                &n.node,
+                Some(pgt_query_ext::NodeEnum::String(s)) if TIMESTAMP_FUNCTIONS.contains(&s.sval.as_str())
+            )
+        }),
+        // e.g. `now() - interval '1 day'`
+        Some(pgt_query_ext::NodeEnum::AExpr(e)) if e.kind() == AExprKind::AexprOp => {
+            [e.lexpr.as_deref(), e.rexpr.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|n| is_timestamp(n, schema_cache, tables))
+        }
+        Some(pgt_query_ext::NodeEnum::AConst(c)) => match &c.val {
+            Some(a_const::Val::Sval(s)) => TIMESTAMP_LITERAL.is_match(&s.sval),
+            _ => false,
+        },
+        Some(pgt_query_ext::NodeEnum::ColumnRef(c)) => {
+            let (Some(schema_cache), Some(column)) = (
+                schema_cache,
+                c.fields.last().and_then(|n| match &n.node {
+                    Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                    _ => None,
+                }),
+            ) else {
+                return false;
+            };
+
+            schema_cache.columns.iter().any(|col| {
+                col.name == column
+                    && tables
+                        .iter()
+                        .any(|(schema, table)| col.schema_name == *schema && col.table_name == *table)
+                    && col
+                        .type_name
+                        .as_deref()
+                        .is_some_and(|t| TIMESTAMP_TYPES.contains(&t))
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Returns the schema and name of all tables referenced by the statement.
+///
+/// Unqualified names are resolved against the `public` schema.
+fn referenced_tables(stmt: &pgt_query_ext::NodeEnum) -> Vec<(String, String)> {
+    pgt_query_ext::ChildrenIterator::new(stmt.clone())
+        .filter_map(|node| match node {
+            pgt_query_ext::NodeEnum::RangeVar(r) => {
+                let schema = if r.schemaname.is_empty() {
+                    "public".to_string()
+                } else {
+                    r.schemaname
+                };
+                Some((schema, r.relname))
+            }
+            _ => None,
+        })
+        .collect()
+}
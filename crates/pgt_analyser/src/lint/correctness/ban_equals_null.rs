@@ -0,0 +1,88 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AExprKind, Node};
+
+use crate::span::token_range;
+
+declare_lint_rule! {
+    /// Comparisons with `NULL` using `=` or `<>` never evaluate to true.
+    ///
+    /// Any comparison with `NULL` evaluates to `NULL`, so `email = null` does not match any row, not even those where `email` is `NULL`.
+    ///
+    /// Use `IS NULL` and `IS NOT NULL` instead, or `IS DISTINCT FROM` to compare two values that may be `NULL`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// select * from users where email = null;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// select * from users where email is null;
+    /// ```
+    ///
+    pub BanEqualsNull {
+        version: "next",
+        name: "banEqualsNull",
+        recommended: true,
+        sources: &[RuleSource::Sqlfluff("CV05")],
+    }
+}
+
+impl Rule for BanEqualsNull {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for node in pgt_query_ext::ChildrenIterator::new(ctx.stmt().clone()) {
+            if let pgt_query_ext::NodeEnum::AExpr(expr) = &node {
+                if expr.kind() != AExprKind::AexprOp {
+                    continue;
+                }
+
+                let operator = match expr.name.as_slice() {
+                    [name] => match &name.node {
+                        Some(pgt_query_ext::NodeEnum::String(s)) => s.sval.as_str(),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
+
+                let replacement = match operator {
+                    "=" => "IS NULL",
+                    "<>" => "IS NOT NULL",
+                    _ => continue,
+                };
+
+                if !is_null(expr.lexpr.as_deref()) && !is_null(expr.rexpr.as_deref()) {
+                    continue;
+                }
+
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        token_range(expr.location, operator.len()),
+                        markup! {
+                            "Comparing with NULL using "{operator}" is never true."
+                        },
+                    )
+                    .detail(None, format!("Use {replacement} instead.")),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+fn is_null(node: Option<&Node>) -> bool {
+    matches!(
+        node.and_then(|n| n.node.as_ref()),
+        Some(pgt_query_ext::NodeEnum::AConst(c)) if c.isnull
+    )
+}
@@ -0,0 +1,71 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::TransactionStmtKind;
+
+declare_lint_rule! {
+    /// Migrations should not start or end transactions themselves.
+    ///
+    /// Many migration tools already run each migration in a transaction.
+    /// A `COMMIT` within the migration ends that transaction early, so a failure in a later statement leaves the migration partially applied.
+    /// A nested `BEGIN` only raises a warning and is otherwise ignored.
+    ///
+    /// This rule only applies to migration files within the configured `migrations.migrationsDir`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// begin;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table users (id bigint primary key);
+    /// ```
+    ///
+    pub BanExplicitTransaction {
+        version: "next",
+        name: "banExplicitTransaction",
+        recommended: false,
+    }
+}
+
+impl Rule for BanExplicitTransaction {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !ctx.is_migration() {
+            return diagnostics;
+        }
+
+        if let pgt_query_ext::NodeEnum::TransactionStmt(stmt) = &ctx.stmt() {
+            if matches!(
+                stmt.kind(),
+                TransactionStmtKind::TransStmtBegin
+                    | TransactionStmtKind::TransStmtStart
+                    | TransactionStmtKind::TransStmtCommit
+                    | TransactionStmtKind::TransStmtRollback
+            ) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Migrations should not control transactions."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Remove the statement and let the migration tool run the migration in a transaction.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
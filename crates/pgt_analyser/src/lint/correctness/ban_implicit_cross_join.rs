@@ -0,0 +1,201 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{BoolExprType, Node, RangeVar};
+use pgt_text_size::TextRange;
+
+use crate::span::relation_range;
+
+declare_lint_rule! {
+    /// Tables listed in `FROM` without a condition that relates them produce a cross join.
+    ///
+    /// `select * from users, orders` returns every combination of users and orders.
+    /// This is almost always caused by a forgotten join condition, and the result grows with the product of the table sizes.
+    ///
+    /// Use an explicit `JOIN ... ON`, or `CROSS JOIN` if the cross join is intended.
+    ///
+    /// A table is considered to be joined if the `WHERE` clause contains a condition that references it together with another table.
+    /// Conditions with unqualified column names can not be attributed to a table and are assumed to join the tables.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// select * from users, orders where users.id = 1;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// select * from users, orders where orders.user_id = users.id;
+    /// ```
+    ///
+    /// ```sql
+    /// select * from users cross join orders;
+    /// ```
+    ///
+    pub BanImplicitCrossJoin {
+        version: "next",
+        name: "banImplicitCrossJoin",
+        recommended: false,
+        sources: &[RuleSource::Sqlfluff("CV12")],
+    }
+}
+
+impl Rule for BanImplicitCrossJoin {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for node in pgt_query_ext::ChildrenIterator::new(ctx.stmt().clone()) {
+            if let pgt_query_ext::NodeEnum::SelectStmt(stmt) = &node {
+                for range in unjoined_items(&stmt.from_clause, stmt.where_clause.as_deref()) {
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            range,
+                            markup! {
+                                "This table is not joined with the other tables in FROM, which results in a cross join."
+                            },
+                        )
+                        .detail(
+                            None,
+                            "Add a join condition, or use CROSS JOIN if the cross join is intended.",
+                        ),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// An item of a `FROM` list.
+struct FromItem<'a> {
+    /// The names that columns of the item can be qualified with.
+    names: Vec<&'a str>,
+    /// The first table of the item.
+    relation: &'a RangeVar,
+}
+
+/// Returns the range of the first item of every group of joined items in `from_clause` that is
+/// not joined with the first item.
+fn unjoined_items(from_clause: &[Node], where_clause: Option<&Node>) -> Vec<Option<TextRange>> {
+    if from_clause.len() < 2 {
+        return Vec::new();
+    }
+
+    let mut items = Vec::new();
+    for item in from_clause {
+        let mut names = Vec::new();
+        let mut relations = Vec::new();
+        collect_relations(item, &mut names, &mut relations);
+
+        match relations.first().copied() {
+            Some(relation) => items.push(FromItem { names, relation }),
+            // subqueries and functions may reference other items or return a single row
+            None => return Vec::new(),
+        }
+    }
+
+    // `component[i]` is the smallest index of the items that item `i` is joined with
+    let mut component = (0..items.len()).collect::<Vec<_>>();
+
+    for condition in where_clause.map(conjuncts).unwrap_or_default() {
+        let Some(condition) = condition.node.as_ref() else {
+            continue;
+        };
+
+        let mut joined = Vec::new();
+        let mut has_unqualified = false;
+        let mut column_count = 0;
+
+        for node in pgt_query_ext::ChildrenIterator::new(condition.clone()) {
+            if let pgt_query_ext::NodeEnum::ColumnRef(c) = &node {
+                column_count += 1;
+
+                let qualifier = match c.fields.as_slice() {
+                    [.., qualifier, _] => match &qualifier.node {
+                        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                match qualifier.and_then(|q| items.iter().position(|i| i.names.contains(&q))) {
+                    Some(idx) => joined.push(idx),
+                    None => has_unqualified = true,
+                }
+            }
+        }
+
+        if has_unqualified && column_count > 1 {
+            // we can not tell which tables the condition references
+            return Vec::new();
+        }
+
+        if let Some(first) = joined.iter().map(|idx| component[*idx]).min() {
+            for idx in joined {
+                let old = component[idx];
+                for c in component.iter_mut() {
+                    if *c == old {
+                        *c = first;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut reported = Vec::new();
+    items
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| {
+            let c = component[*idx];
+            if c == component[0] || reported.contains(&c) {
+                return false;
+            }
+            reported.push(c);
+            true
+        })
+        .map(|(_, item)| relation_range(item.relation))
+        .collect()
+}
+
+/// Collects the names and tables of a `FROM` item, including the tables of joins.
+fn collect_relations<'a>(
+    node: &'a Node,
+    names: &mut Vec<&'a str>,
+    relations: &mut Vec<&'a RangeVar>,
+) {
+    match &node.node {
+        Some(pgt_query_ext::NodeEnum::RangeVar(r)) => {
+            match r.alias.as_ref() {
+                Some(alias) => names.push(alias.aliasname.as_str()),
+                None => names.push(r.relname.as_str()),
+            }
+            relations.push(r);
+        }
+        Some(pgt_query_ext::NodeEnum::JoinExpr(j)) => {
+            if let Some(alias) = j.alias.as_ref() {
+                names.push(alias.aliasname.as_str());
+            }
+            for side in [j.larg.as_deref(), j.rarg.as_deref()].into_iter().flatten() {
+                collect_relations(side, names, relations);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Splits a condition into the parts that are combined with `AND`.
+fn conjuncts(node: &Node) -> Vec<&Node> {
+    match &node.node {
+        Some(pgt_query_ext::NodeEnum::BoolExpr(e)) if e.boolop() == BoolExprType::AndExpr => {
+            e.args.iter().flat_map(conjuncts).collect()
+        }
+        _ => vec![node],
+    }
+}
@@ -0,0 +1,74 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{BoolExprType, SubLinkType};
+
+use crate::span::token_range;
+
+declare_lint_rule! {
+    /// `NOT IN` with a subquery returns no rows at all if the subquery returns a `NULL`.
+    ///
+    /// `x NOT IN (subquery)` is `NULL` instead of true for every `x` as soon as one of the values returned by the subquery is `NULL`.
+    /// Even if the column is not nullable today, this is easy to break, and the planner can not turn `NOT IN` into an efficient anti-join.
+    ///
+    /// Use `NOT EXISTS` instead.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// select * from users where id not in (select user_id from banned_users);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// select * from users u where not exists (select from banned_users b where b.user_id = u.id);
+    /// ```
+    ///
+    pub BanNotInSubquery {
+        version: "next",
+        name: "banNotInSubquery",
+        recommended: false,
+    }
+}
+
+impl Rule for BanNotInSubquery {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for node in pgt_query_ext::ChildrenIterator::new(ctx.stmt().clone()) {
+            if let pgt_query_ext::NodeEnum::BoolExpr(expr) = &node {
+                if expr.boolop() != BoolExprType::NotExpr {
+                    continue;
+                }
+
+                // `x NOT IN (subquery)` is parsed as `NOT (x = ANY (subquery))` without an operator name
+                let is_not_in_subquery = expr.args.iter().any(|arg| {
+                    matches!(
+                        &arg.node,
+                        Some(pgt_query_ext::NodeEnum::SubLink(s))
+                            if s.sub_link_type() == SubLinkType::AnySublink && s.oper_name.is_empty()
+                    )
+                });
+
+                if is_not_in_subquery {
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            token_range(expr.location, "not".len()),
+                            markup! {
+                                "NOT IN with a subquery returns no rows if the subquery returns a NULL."
+                            },
+                        )
+                        .detail(None, "Use NOT EXISTS instead."),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
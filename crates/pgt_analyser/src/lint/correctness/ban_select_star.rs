@@ -0,0 +1,230 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{ObjectType, SubLinkType};
+use pgt_text_size::TextRange;
+
+use crate::span::token_range;
+
+declare_lint_rule! {
+    /// Avoid `SELECT *` in views and functions.
+    ///
+    /// The `*` of a view is expanded when the view is created, so columns that are added to the underlying table later are not part of the view, and dropping a column fails because the view depends on it.
+    /// In a function, the result changes whenever the underlying table changes, which breaks functions that declare the columns they return.
+    ///
+    /// List the columns explicitly instead.
+    ///
+    /// `SELECT *` in `EXISTS` subqueries is allowed, as is selecting all columns of a table in a function that returns the row type of that table.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create view active_users as select * from users where is_active;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create view active_users as select id, email from users where is_active;
+    /// ```
+    ///
+    pub BanSelectStar {
+        version: "next",
+        name: "banSelectStar",
+        recommended: false,
+        sources: &[RuleSource::Sqlfluff("AM04")],
+    }
+}
+
+impl Rule for BanSelectStar {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (kind, stars) = match &ctx.stmt() {
+            pgt_query_ext::NodeEnum::ViewStmt(stmt) => (
+                "view",
+                stmt.query
+                    .as_ref()
+                    .and_then(|q| q.node.as_ref())
+                    .map(|q| star_locations(q, None))
+                    .unwrap_or_default(),
+            ),
+            pgt_query_ext::NodeEnum::CreateTableAsStmt(stmt)
+                if stmt.objtype() == ObjectType::ObjectMatview =>
+            {
+                (
+                    "materialized view",
+                    stmt.query
+                        .as_ref()
+                        .and_then(|q| q.node.as_ref())
+                        .map(|q| star_locations(q, None))
+                        .unwrap_or_default(),
+                )
+            }
+            pgt_query_ext::NodeEnum::CreateFunctionStmt(stmt) => {
+                let return_type = stmt
+                    .return_type
+                    .as_ref()
+                    .and_then(|t| t.names.last())
+                    .and_then(|n| match &n.node {
+                        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                        _ => None,
+                    });
+
+                let stars = match stmt.sql_body.as_ref().and_then(|b| b.node.as_ref()) {
+                    // `BEGIN ATOMIC ... END` and `RETURN ...` bodies are part of the statement
+                    Some(body) => star_locations(body, return_type),
+                    // the locations within a string body are relative to the body, not the statement
+                    None => sql_function_body(&stmt.options)
+                        .and_then(|body| pgt_query_ext::parse(body).ok())
+                        .map(|body| {
+                            star_locations(&body, return_type)
+                                .into_iter()
+                                .map(|_| None)
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                };
+
+                ("function", stars)
+            }
+            _ => return diagnostics,
+        };
+
+        for range in stars {
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "Avoid SELECT * in the definition of a "{kind}"."
+                    },
+                )
+                .detail(
+                    None,
+                    "List the columns explicitly, so that changes to the underlying tables do not silently change or break the definition.",
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+/// Returns the ranges of all `*` in the select lists of a query.
+///
+/// Selecting all columns of `row_type`, the table whose rows are returned by a function, is allowed.
+fn star_locations(
+    query: &pgt_query_ext::NodeEnum,
+    row_type: Option<&str>,
+) -> Vec<Option<TextRange>> {
+    let nodes = pgt_query_ext::ChildrenIterator::new(query.clone()).collect::<Vec<_>>();
+
+    // the select list of `EXISTS` subqueries does not matter
+    let exists_subqueries = nodes
+        .iter()
+        .filter_map(|node| match node {
+            pgt_query_ext::NodeEnum::SubLink(s)
+                if s.sub_link_type() == SubLinkType::ExistsSublink =>
+            {
+                match s.subselect.as_ref().and_then(|s| s.node.as_ref()) {
+                    Some(pgt_query_ext::NodeEnum::SelectStmt(s)) => Some(s.as_ref()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut ranges = Vec::new();
+
+    for node in &nodes {
+        let pgt_query_ext::NodeEnum::SelectStmt(stmt) = node else {
+            continue;
+        };
+
+        if exists_subqueries.contains(&stmt.as_ref()) {
+            continue;
+        }
+
+        let selects_row_type = match stmt.from_clause.as_slice() {
+            [item] => matches!(
+                &item.node,
+                Some(pgt_query_ext::NodeEnum::RangeVar(r)) if row_type == Some(r.relname.as_str())
+            ),
+            _ => false,
+        };
+
+        if selects_row_type {
+            continue;
+        }
+
+        for target in &stmt.target_list {
+            let Some(pgt_query_ext::NodeEnum::ResTarget(target)) = &target.node else {
+                continue;
+            };
+
+            let Some(pgt_query_ext::NodeEnum::ColumnRef(column)) =
+                target.val.as_ref().and_then(|v| v.node.as_ref())
+            else {
+                continue;
+            };
+
+            if !matches!(
+                column.fields.last().and_then(|f| f.node.as_ref()),
+                Some(pgt_query_ext::NodeEnum::AStar(_))
+            ) {
+                continue;
+            }
+
+            // e.g. `*` or `users.*`
+            let len = column
+                .fields
+                .iter()
+                .map(|f| match &f.node {
+                    Some(pgt_query_ext::NodeEnum::String(s)) => s.sval.len() + 1,
+                    _ => 1,
+                })
+                .sum();
+
+            ranges.push(token_range(column.location, len));
+        }
+    }
+
+    ranges
+}
+
+/// Returns the body of a function written in SQL.
+fn sql_function_body(options: &[pgt_query_ext::protobuf::Node]) -> Option<&str> {
+    let mut language = None;
+    let mut body = None;
+
+    for option in options {
+        if let Some(pgt_query_ext::NodeEnum::DefElem(d)) = &option.node {
+            match (
+                d.defname.as_str(),
+                d.arg.as_ref().and_then(|a| a.node.as_ref()),
+            ) {
+                ("language", Some(pgt_query_ext::NodeEnum::String(s))) => {
+                    language = Some(s.sval.as_str());
+                }
+                ("as", Some(pgt_query_ext::NodeEnum::List(l))) => {
+                    body = l.items.first().and_then(|i| match &i.node {
+                        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                        _ => None,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if language.is_some_and(|l| l.eq_ignore_ascii_case("sql")) {
+        body
+    } else {
+        None
+    }
+}
@@ -0,0 +1,128 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::Token;
+use pgt_text_size::TextRange;
+
+use crate::plpgsql::{BodyToken, parse_function};
+
+declare_lint_rule! {
+    /// PL/pgSQL functions must not assign to undeclared variables.
+    ///
+    /// PostgreSQL only reports the error when the function is created with `check_function_bodies` enabled, so it is easy to miss in migrations that disable it.
+    /// The error usually means that the variable is misspelled or declared in another block.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function count_users() returns bigint language plpgsql as $$
+    /// begin
+    ///     select count(*) into total from users;
+    ///     return total;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function count_users() returns bigint language plpgsql as $$
+    /// declare
+    ///     total bigint;
+    /// begin
+    ///     select count(*) into total from users;
+    ///     return total;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    pub BanUndeclaredVariables {
+        version: "next",
+        name: "banUndeclaredVariables",
+        recommended: true,
+    }
+}
+
+impl Rule for BanUndeclaredVariables {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(function) = parse_function(ctx.stmt(), ctx.sql()) else {
+            return diagnostics;
+        };
+
+        // the PL/pgSQL parser stops at the first undeclared variable
+        let Some(name) = function
+            .parsed
+            .as_ref()
+            .err()
+            .and_then(|message| undeclared_variable(message))
+        else {
+            return diagnostics;
+        };
+
+        diagnostics.push(
+            RuleDiagnostic::new(
+                rule_category!(),
+                variable_range(function.body.tokens(), name),
+                markup! {
+                    "The variable "<Emphasis>{name}</Emphasis>" is not declared."
+                },
+            )
+            .detail(
+                None,
+                "Declare the variable in the DECLARE section of the function, or fix its name.",
+            ),
+        );
+
+        diagnostics
+    }
+}
+
+/// Extracts the name from an error such as `"total" is not a known variable`.
+fn undeclared_variable(message: &str) -> Option<&str> {
+    message
+        .strip_suffix(" is not a known variable")?
+        .strip_prefix('"')?
+        .strip_suffix('"')
+}
+
+/// Keywords after which a new statement starts.
+const STATEMENT_STARTS: &[&str] = &["begin", "then", "else", "loop"];
+
+/// Finds the variable at the start of an assignment or in an `INTO` clause, and falls back to
+/// its first occurrence.
+fn variable_range(tokens: &[BodyToken], name: &str) -> Option<TextRange> {
+    // `record.field` is reported as a whole
+    let first = name.split('.').next()?;
+
+    let is_variable = |idx: usize| tokens[idx].token.is_word() && tokens[idx].token.text == first;
+
+    let is_target = |idx: usize| {
+        let prev = idx.checked_sub(1).map(|prev| &tokens[prev].token);
+        let next = tokens.get(idx + 1).map(|next| &next.token);
+
+        let starts_statement = prev.is_none_or(|prev| {
+            prev.kind == Token::Ascii59 || STATEMENT_STARTS.contains(&prev.text.as_str())
+        });
+
+        let is_assignment = next.is_some_and(|next| {
+            matches!(
+                next.kind,
+                Token::ColonEquals | Token::Ascii61 | Token::Ascii91 | Token::Ascii46
+            )
+        });
+
+        let is_into = prev.is_some_and(|prev| prev.text == "into" || prev.text == "strict");
+
+        (starts_statement && is_assignment) || is_into
+    };
+
+    (0..tokens.len())
+        .find(|idx| is_variable(*idx) && is_target(*idx))
+        .or_else(|| (0..tokens.len()).find(|idx| is_variable(*idx)))
+        .map(|idx| tokens[idx].token.range)
+}
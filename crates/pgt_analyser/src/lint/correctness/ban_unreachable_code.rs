@@ -0,0 +1,95 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use serde_json::Value;
+
+use crate::plpgsql::{
+    FunctionBody, is_jump, lineno, nested_statements, node, parse_function, terminates,
+};
+
+declare_lint_rule! {
+    /// PL/pgSQL functions should not contain code that never runs.
+    ///
+    /// Statements after a `RETURN`, a `RAISE EXCEPTION` or an unconditional `EXIT` or `CONTINUE` are never executed.
+    /// This is usually a mistake, e.g. a `RETURN` that was meant to be inside an `IF`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function archive_user(id bigint) returns void language plpgsql as $$
+    /// begin
+    ///     raise exception 'archiving is disabled';
+    ///     update users set archived = true where users.id = archive_user.id;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function archive_user(id bigint) returns void language plpgsql as $$
+    /// begin
+    ///     update users set archived = true where users.id = archive_user.id;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    pub BanUnreachableCode {
+        version: "next",
+        name: "banUnreachableCode",
+        recommended: true,
+    }
+}
+
+impl Rule for BanUnreachableCode {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(function) = parse_function(ctx.stmt(), ctx.sql()) else {
+            return diagnostics;
+        };
+
+        check_statements(function.statements(), &function.body, &mut diagnostics);
+
+        diagnostics
+    }
+}
+
+/// Reports the first unreachable statement of `stmts` and of all nested statement lists.
+fn check_statements(stmts: &[Value], body: &FunctionBody, diagnostics: &mut Vec<RuleDiagnostic>) {
+    let unreachable = stmts
+        .iter()
+        .position(|stmt| terminates(stmt) || is_jump(stmt))
+        .and_then(|idx| {
+            // the `RETURN` that the parser adds to the end of a function has no line number
+            stmts[idx + 1..]
+                .iter()
+                .filter_map(node)
+                .find_map(|(_, fields)| lineno(fields))
+        });
+
+    if let Some(line) = unreachable {
+        diagnostics.push(
+            RuleDiagnostic::new(
+                rule_category!(),
+                body.line_range(Some(line)),
+                markup! {
+                    "This code is unreachable."
+                },
+            )
+            .detail(
+                None,
+                "It follows a RETURN, RAISE EXCEPTION, EXIT or CONTINUE and never runs. Remove it or fix the control flow.",
+            ),
+        );
+    }
+
+    for (kind, fields) in stmts.iter().filter_map(node) {
+        for nested in nested_statements(kind, fields) {
+            check_statements(nested, body, diagnostics);
+        }
+    }
+}
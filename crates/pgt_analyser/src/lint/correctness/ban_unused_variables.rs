@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{FunctionParameterMode, Token};
+use pgt_text_size::TextRange;
+use serde_json::Value;
+
+use crate::plpgsql::{
+    PlpgsqlFunction, expression, lineno, node, parse_function, tokens, visit_statements,
+};
+
+declare_lint_rule! {
+    /// Variables and parameters of PL/pgSQL functions should be used.
+    ///
+    /// A variable that is declared but never read is usually left over from a refactoring, or a sign that the function reads the wrong variable.
+    /// Assigning a value to a variable does not count as using it.
+    ///
+    /// Loop variables of integer `FOR` loops and the variables that PL/pgSQL declares implicitly, such as `FOUND` or `SQLERRM`, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function count_users() returns bigint language plpgsql as $$
+    /// declare
+    ///     total bigint;
+    ///     unused text;
+    /// begin
+    ///     select count(*) into total from users;
+    ///     return total;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function count_users() returns bigint language plpgsql as $$
+    /// declare
+    ///     total bigint;
+    /// begin
+    ///     select count(*) into total from users;
+    ///     return total;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    pub BanUnusedVariables {
+        version: "next",
+        name: "banUnusedVariables",
+        recommended: false,
+    }
+}
+
+/// Variables that PL/pgSQL declares within exception handlers.
+const IMPLICIT_VARIABLES: &[&str] = &["sqlstate", "sqlerrm"];
+
+impl Rule for BanUnusedVariables {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(function) = parse_function(ctx.stmt(), ctx.sql()) else {
+            return diagnostics;
+        };
+
+        if function.parsed.is_err() {
+            return diagnostics;
+        }
+
+        let used = used_names(&function);
+        let loop_variables = loop_variables(&function);
+
+        for (name, mode) in function.parameters() {
+            // output parameters are assigned, not read
+            let is_input = matches!(
+                mode,
+                FunctionParameterMode::FuncParamIn
+                    | FunctionParameterMode::FuncParamVariadic
+                    | FunctionParameterMode::FuncParamDefault
+            );
+
+            if is_input && !used.contains(name) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        parameter_range(ctx.sql(), name),
+                        markup! {
+                            "The parameter "<Emphasis>{name}</Emphasis>" is never used."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Remove the parameter, unless callers depend on the signature of the function.",
+                    ),
+                );
+            }
+        }
+
+        for (kind, datum) in function.datums().iter().filter_map(node) {
+            if !matches!(kind, "PLpgSQL_var" | "PLpgSQL_rec") {
+                continue;
+            }
+
+            // parameters and variables such as `FOUND` have no line number
+            let Some(line) = lineno(datum) else {
+                continue;
+            };
+
+            let Some(name) = datum.get("refname").and_then(Value::as_str) else {
+                continue;
+            };
+
+            if IMPLICIT_VARIABLES.contains(&name)
+                || name.starts_with("__Case__Variable_")
+                || loop_variables.contains(&(name, line))
+                || used.contains(name)
+            {
+                continue;
+            }
+
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    function.body.find_on_line(Some(line), name),
+                    markup! {
+                        "The variable "<Emphasis>{name}</Emphasis>" is never used."
+                    },
+                )
+                .detail(None, "Remove the declaration of the variable."),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+/// Returns the names of all variables that are read somewhere in the function.
+fn used_names(function: &PlpgsqlFunction) -> HashSet<String> {
+    let mut used = HashSet::new();
+    let mut cursors = vec![];
+
+    for datum in function.datums() {
+        collect_uses(datum, &mut used, &mut cursors);
+    }
+
+    if let Ok(parsed) = &function.parsed {
+        if let Some(action) = parsed.get("action") {
+            collect_uses(action, &mut used, &mut cursors);
+        }
+    }
+
+    // `OPEN`, `FETCH` and `CLOSE` refer to cursors by their number
+    used.extend(cursors.into_iter().filter_map(|dno| {
+        let datum = function.datums().get(dno)?;
+        let (_, fields) = node(datum)?;
+        fields.get("refname")?.as_str().map(str::to_string)
+    }));
+
+    used
+}
+
+fn collect_uses(value: &Value, used: &mut HashSet<String>, cursors: &mut Vec<usize>) {
+    match value {
+        Value::Object(object) => {
+            if let Some(assign) = object.get("PLpgSQL_stmt_assign") {
+                // the first word of `target := value` is the assigned variable
+                if let Some(query) = expression(assign, "expr") {
+                    used.extend(
+                        tokens(query)
+                            .into_iter()
+                            .filter(|t| t.is_word())
+                            .skip(1)
+                            .map(|t| t.text),
+                    );
+                }
+
+                return;
+            }
+
+            if let Some(query) = object
+                .get("PLpgSQL_expr")
+                .and_then(|e| e.get("query"))
+                .and_then(Value::as_str)
+            {
+                used.extend(
+                    tokens(query)
+                        .into_iter()
+                        .filter(|t| t.is_word())
+                        .map(|t| t.text),
+                );
+            }
+
+            if let Some(dno) = object.get("curvar").and_then(Value::as_u64) {
+                cursors.push(usize::try_from(dno).unwrap_or(usize::MAX));
+            }
+
+            for value in object.values() {
+                collect_uses(value, used, cursors);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_uses(value, used, cursors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the variables of integer `FOR` loops, which PL/pgSQL declares implicitly.
+fn loop_variables<'a>(function: &'a PlpgsqlFunction) -> Vec<(&'a str, i64)> {
+    let mut variables = vec![];
+
+    visit_statements(function.statements(), &mut |kind, fields| {
+        if kind != "PLpgSQL_stmt_fori" {
+            return;
+        }
+
+        let var = fields.get("var").and_then(|v| v.get("PLpgSQL_var"));
+
+        if let Some((name, line)) =
+            var.and_then(|v| v.get("refname").and_then(Value::as_str).zip(lineno(v)))
+        {
+            variables.push((name, line));
+        }
+    });
+
+    variables
+}
+
+/// Returns the range of the parameter `name` in the signature of the function.
+fn parameter_range(sql: &str, name: &str) -> Option<TextRange> {
+    tokens(sql)
+        .into_iter()
+        .skip_while(|t| t.kind != Token::Ascii40)
+        .find(|t| t.is_word() && t.text == name)
+        .map(|t| t.range)
+}
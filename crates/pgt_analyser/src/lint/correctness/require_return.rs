@@ -0,0 +1,94 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::plpgsql::{parse_function, terminates};
+
+declare_lint_rule! {
+    /// PL/pgSQL functions that return a value must not reach the end of the function.
+    ///
+    /// If control reaches the final `END` of a function that returns a value, PostgreSQL raises an error at runtime.
+    /// Every path through the function has to end with `RETURN` or raise an exception.
+    ///
+    /// Procedures, set-returning functions and functions with output parameters are ignored, because they may fall off the end.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function sign(n int) returns int language plpgsql as $$
+    /// begin
+    ///     if n > 0 then
+    ///         return 1;
+    ///     elsif n < 0 then
+    ///         return -1;
+    ///     end if;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function sign(n int) returns int language plpgsql as $$
+    /// begin
+    ///     if n > 0 then
+    ///         return 1;
+    ///     elsif n < 0 then
+    ///         return -1;
+    ///     end if;
+    ///     return 0;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    pub RequireReturn {
+        version: "next",
+        name: "requireReturn",
+        recommended: true,
+    }
+}
+
+impl Rule for RequireReturn {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(function) = parse_function(ctx.stmt(), ctx.sql()) else {
+            return diagnostics;
+        };
+
+        if function.parsed.is_err()
+            || !function.returns_value()
+            || function.statements().iter().any(terminates)
+        {
+            return diagnostics;
+        }
+
+        // the final `END` of the function
+        let range = function
+            .body
+            .tokens()
+            .iter()
+            .rev()
+            .find(|t| t.token.text == "end")
+            .map(|t| t.token.range);
+
+        diagnostics.push(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The function can reach its end without a RETURN."
+                },
+            )
+            .detail(
+                None,
+                "Add a RETURN at the end of the function, or raise an exception.",
+            ),
+        );
+
+        diagnostics
+    }
+}
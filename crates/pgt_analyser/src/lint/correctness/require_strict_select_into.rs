@@ -0,0 +1,162 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::SelectStmt;
+use serde_json::Value;
+
+use crate::plpgsql::{expression, lineno, parse_function, visit_statements};
+
+declare_lint_rule! {
+    /// `SELECT INTO` in PL/pgSQL functions should use `STRICT` when exactly one row is expected.
+    ///
+    /// Without `STRICT`, `SELECT INTO` assigns the first row if the query returns several rows, and `NULL` if it returns none.
+    /// Both cases are silently ignored, so a missing row or a missing `WHERE` condition goes unnoticed.
+    /// With `STRICT`, PostgreSQL raises an error unless the query returns exactly one row.
+    ///
+    /// Queries that always return a single row, such as aggregates without `GROUP BY`, and queries with a `LIMIT` are ignored.
+    /// Functions that check `FOUND` or `ROW_COUNT` are also ignored, since they handle a missing row themselves.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function user_email(user_id bigint) returns text language plpgsql as $$
+    /// declare
+    ///     result text;
+    /// begin
+    ///     select email into result from users where id = user_id;
+    ///     return result;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function user_email(user_id bigint) returns text language plpgsql as $$
+    /// declare
+    ///     result text;
+    /// begin
+    ///     select email into strict result from users where id = user_id;
+    ///     return result;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    pub RequireStrictSelectInto {
+        version: "next",
+        name: "requireStrictSelectInto",
+        recommended: false,
+    }
+}
+
+const AGGREGATE_FUNCTIONS: &[&str] = &[
+    "count",
+    "sum",
+    "min",
+    "max",
+    "avg",
+    "array_agg",
+    "string_agg",
+    "json_agg",
+    "jsonb_agg",
+    "json_object_agg",
+    "jsonb_object_agg",
+    "bool_and",
+    "bool_or",
+    "every",
+];
+
+impl Rule for RequireStrictSelectInto {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(function) = parse_function(ctx.stmt(), ctx.sql()) else {
+            return diagnostics;
+        };
+
+        let tokens = function.body.tokens();
+
+        if tokens
+            .iter()
+            .any(|t| t.token.is_word() && matches!(t.token.text.as_str(), "found" | "row_count"))
+        {
+            return diagnostics;
+        }
+
+        visit_statements(function.statements(), &mut |kind, fields| {
+            if kind != "PLpgSQL_stmt_execsql"
+                || !flag(fields, "into")
+                || flag(fields, "strict")
+                || !may_not_return_one_row(fields)
+            {
+                return;
+            }
+
+            let range = lineno(fields).and_then(|line| {
+                tokens
+                    .iter()
+                    .find(|t| t.line >= line && t.token.text == "into")
+                    .map(|t| t.token.range)
+            });
+
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "SELECT INTO without STRICT silently ignores missing and extra rows."
+                    },
+                )
+                .detail(
+                    None,
+                    "Use INTO STRICT to raise an error unless the query returns exactly one row, or check FOUND to handle a missing row.",
+                ),
+            );
+        });
+
+        diagnostics
+    }
+}
+
+fn flag(fields: &Value, name: &str) -> bool {
+    fields.get(name).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Returns true if the statement is a `SELECT` from a table that may return more or less than one row.
+fn may_not_return_one_row(fields: &Value) -> bool {
+    // the `INTO` clause is blanked out in the query of the statement
+    let Some(query) = expression(fields, "sqlstmt") else {
+        return false;
+    };
+
+    let Ok(pgt_query_ext::NodeEnum::SelectStmt(select)) = pgt_query_ext::parse(query) else {
+        return false;
+    };
+
+    !select.from_clause.is_empty()
+        && select.limit_count.is_none()
+        && !is_aggregate_without_grouping(&select)
+}
+
+fn is_aggregate_without_grouping(select: &SelectStmt) -> bool {
+    select.group_clause.is_empty()
+        && select.target_list.iter().all(|target| {
+            let Some(pgt_query_ext::NodeEnum::ResTarget(target)) = &target.node else {
+                return false;
+            };
+
+            matches!(
+                target.val.as_ref().and_then(|v| v.node.as_ref()),
+                Some(pgt_query_ext::NodeEnum::FuncCall(f))
+                    if f.over.is_none()
+                        && f.funcname.last().is_some_and(|n| matches!(
+                            &n.node,
+                            Some(pgt_query_ext::NodeEnum::String(s))
+                                if AGGREGATE_FUNCTIONS.contains(&s.sval.as_str())
+                        ))
+            )
+        })
+}
@@ -0,0 +1,6 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use pgt_analyse::declare_lint_group;
+pub mod ban_large_offset;
+pub mod ban_order_by_random;
+declare_lint_group! { pub Performance { name : "performance" , rules : [self :: ban_large_offset :: BanLargeOffset , self :: ban_order_by_random :: BanOrderByRandom ,] } }
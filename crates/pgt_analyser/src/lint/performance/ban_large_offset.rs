@@ -0,0 +1,84 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::a_const;
+
+use crate::span::token_range;
+
+declare_lint_rule! {
+    /// Paginating with a large `OFFSET` gets slower with every page.
+    ///
+    /// Postgres has to compute and discard all skipped rows, so `OFFSET 100000` reads at least 100,000 rows to return a single page.
+    /// Rows that are inserted or deleted between two requests also shift the pages, so that rows are skipped or returned twice.
+    ///
+    /// Use keyset pagination instead: remember the sort key of the last row and filter on it, e.g. `WHERE id > $1 ORDER BY id LIMIT 50`.
+    ///
+    /// Only constant offsets larger than 1000 are reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// select * from events order by id limit 50 offset 100000;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// select * from events where id > 100000 order by id limit 50;
+    /// ```
+    ///
+    pub BanLargeOffset {
+        version: "next",
+        name: "banLargeOffset",
+        recommended: false,
+    }
+}
+
+/// Offsets up to this value are cheap enough to not be worth reporting.
+const MAX_OFFSET: f64 = 1000.0;
+
+impl Rule for BanLargeOffset {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for node in pgt_query_ext::ChildrenIterator::new(ctx.stmt().clone()) {
+            let pgt_query_ext::NodeEnum::SelectStmt(stmt) = &node else {
+                continue;
+            };
+
+            let Some(pgt_query_ext::NodeEnum::AConst(offset)) =
+                stmt.limit_offset.as_ref().and_then(|o| o.node.as_ref())
+            else {
+                continue;
+            };
+
+            // integers that do not fit into 32 bits are parsed as floats
+            let value = match &offset.val {
+                Some(a_const::Val::Ival(i)) => i.ival.to_string(),
+                Some(a_const::Val::Fval(f)) => f.fval.clone(),
+                _ => continue,
+            };
+
+            if value.parse::<f64>().is_ok_and(|v| v > MAX_OFFSET) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        token_range(offset.location, value.len()),
+                        markup! {
+                            "Large OFFSET values make every page slower to compute."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use keyset pagination and filter on the sort key of the last row instead.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
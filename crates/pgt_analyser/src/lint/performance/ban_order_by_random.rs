@@ -0,0 +1,80 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::span::token_range;
+
+declare_lint_rule! {
+    /// Sorting by `random()` reads and sorts the whole table.
+    ///
+    /// To pick random rows, `ORDER BY random()` has to generate a random value for every row and sort all of them, even if only a single row is returned.
+    ///
+    /// Use `TABLESAMPLE` to pick random rows from large tables instead, or select random keys in the application.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// select * from products order by random() limit 1;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// select * from products tablesample system (1) limit 1;
+    /// ```
+    ///
+    pub BanOrderByRandom {
+        version: "next",
+        name: "banOrderByRandom",
+        recommended: false,
+    }
+}
+
+impl Rule for BanOrderByRandom {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for node in pgt_query_ext::ChildrenIterator::new(ctx.stmt().clone()) {
+            let pgt_query_ext::NodeEnum::SortBy(sort_by) = &node else {
+                continue;
+            };
+
+            let Some(pgt_query_ext::NodeEnum::FuncCall(func)) =
+                sort_by.node.as_ref().and_then(|n| n.node.as_ref())
+            else {
+                continue;
+            };
+
+            let names = func
+                .funcname
+                .iter()
+                .filter_map(|n| match &n.node {
+                    Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            if names.last() == Some(&"random") && func.args.is_empty() {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        // e.g. `random` or `pg_catalog.random`
+                        token_range(func.location, names.join(".").len()),
+                        markup! {
+                            "Sorting by random() reads and sorts every row."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use TABLESAMPLE to pick random rows from large tables instead.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -1,8 +1,29 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use pgt_analyse::declare_lint_group;
+pub mod adding_field_with_volatile_default;
+pub mod adding_primary_key_constraint;
 pub mod adding_required_field;
+pub mod adding_stored_generated_column;
+pub mod ban_cluster;
+pub mod ban_delete_without_where;
 pub mod ban_drop_column;
+pub mod ban_drop_function_in_use;
 pub mod ban_drop_not_null;
+pub mod ban_drop_referenced_column;
+pub mod ban_drop_referenced_table;
 pub mod ban_drop_table;
-declare_lint_group! { pub Safety { name : "safety" , rules : [self :: adding_required_field :: AddingRequiredField , self :: ban_drop_column :: BanDropColumn , self :: ban_drop_not_null :: BanDropNotNull , self :: ban_drop_table :: BanDropTable ,] } }
+pub mod ban_mixing_ddl_and_backfill;
+pub mod ban_rename_referenced_by_trigger;
+pub mod ban_update_without_where;
+pub mod ban_vacuum_full;
+pub mod changing_column_type;
+pub mod changing_table_persistence;
+pub mod constraint_missing_not_valid;
+pub mod disallow_unique_constraint;
+pub mod renaming_column;
+pub mod renaming_table;
+pub mod require_concurrent_index_creation;
+pub mod require_concurrent_index_deletion;
+pub mod require_lock_timeout;
+declare_lint_group! { pub Safety { name : "safety" , rules : [self :: adding_field_with_volatile_default :: AddingFieldWithVolatileDefault , self :: adding_primary_key_constraint :: AddingPrimaryKeyConstraint , self :: adding_required_field :: AddingRequiredField , self :: adding_stored_generated_column :: AddingStoredGeneratedColumn , self :: ban_cluster :: BanCluster , self :: ban_delete_without_where :: BanDeleteWithoutWhere , self :: ban_drop_column :: BanDropColumn , self :: ban_drop_function_in_use :: BanDropFunctionInUse , self :: ban_drop_not_null :: BanDropNotNull , self :: ban_drop_referenced_column :: BanDropReferencedColumn , self :: ban_drop_referenced_table :: BanDropReferencedTable , self :: ban_drop_table :: BanDropTable , self :: ban_mixing_ddl_and_backfill :: BanMixingDdlAndBackfill , self :: ban_rename_referenced_by_trigger :: BanRenameReferencedByTrigger , self :: ban_update_without_where :: BanUpdateWithoutWhere , self :: ban_vacuum_full :: BanVacuumFull , self :: changing_column_type :: ChangingColumnType , self :: changing_table_persistence :: ChangingTablePersistence , self :: constraint_missing_not_valid :: ConstraintMissingNotValid , self :: disallow_unique_constraint :: DisallowUniqueConstraint , self :: renaming_column :: RenamingColumn , self :: renaming_table :: RenamingTable , self :: require_concurrent_index_creation :: RequireConcurrentIndexCreation , self :: require_concurrent_index_deletion :: RequireConcurrentIndexDeletion , self :: require_lock_timeout :: RequireLockTimeout ,] } }
@@ -0,0 +1,187 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType};
+use pgt_schema_cache::{Behavior, SchemaCache};
+
+use crate::table_size::with_table_size;
+
+declare_lint_rule! {
+    /// Adding a column with a volatile default value rewrites the whole table while holding an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// Since Postgres 11, adding a column with a constant or non-volatile default is a metadata-only change.
+    /// A volatile default such as `random()`, `gen_random_uuid()` or `clock_timestamp()` has to be evaluated for every existing row, which requires a rewrite of the table.
+    /// The same applies to `serial` columns, because their default is `nextval()`.
+    ///
+    /// If a database connection is available, the volatility of functions is read from the database.
+    /// Otherwise, functions that are not known to be non-volatile are treated as volatile.
+    ///
+    /// Instead, use the expand and contract pattern: add the column without a default, set the default for new rows, and backfill the existing rows in batches.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column token uuid default gen_random_uuid();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table users add column created_at timestamptz default now();
+    /// ```
+    ///
+    pub AddingFieldWithVolatileDefault {
+        version: "next",
+        name: "addingFieldWithVolatileDefault",
+        recommended: false,
+        sources: &[RuleSource::Squawk("adding-field-with-default")],
+    }
+}
+
+impl Rule for AddingFieldWithVolatileDefault {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    if cmd.subtype() != AlterTableType::AtAddColumn {
+                        continue;
+                    }
+
+                    if let Some(pgt_query_ext::NodeEnum::ColumnDef(col)) =
+                        cmd.def.as_ref().and_then(|d| d.node.as_ref())
+                    {
+                        let is_serial = col
+                            .type_name
+                            .as_ref()
+                            .and_then(|t| t.names.last())
+                            .is_some_and(|n| {
+                                matches!(
+                                    &n.node,
+                                    Some(pgt_query_ext::NodeEnum::String(s))
+                                        if SERIAL_TYPES.contains(&s.sval.as_str())
+                                )
+                            });
+
+                        let has_volatile_default = col.constraints.iter().any(|c| {
+                            matches!(
+                                &c.node,
+                                Some(pgt_query_ext::NodeEnum::Constraint(c))
+                                    if c.contype() == ConstrType::ConstrDefault
+                                        && c.raw_expr.as_deref().is_some_and(|e| {
+                                            is_volatile(e, ctx.schema_cache())
+                                        })
+                            )
+                        });
+
+                        if is_serial || has_volatile_default {
+                            diagnostics.push(with_table_size(
+                                RuleDiagnostic::new(
+                                    rule_category!(),
+                                    None,
+                                    markup! {
+                                        "Adding a column with a volatile default rewrites the table while holding an ACCESS EXCLUSIVE lock."
+                                    },
+                                )
+                                .detail(
+                                    None,
+                                    "Add the column without a default, set the default afterwards, and backfill existing rows in batches.",
+                                ),
+                                ctx.schema_cache(),
+                                stmt.relation.as_ref(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+const SERIAL_TYPES: &[&str] = &[
+    "serial",
+    "serial2",
+    "serial4",
+    "serial8",
+    "smallserial",
+    "bigserial",
+];
+
+/// Built-in functions that are known to be stable or immutable.
+const NON_VOLATILE_FUNCTIONS: &[&str] = &[
+    "now",
+    "transaction_timestamp",
+    "statement_timestamp",
+    "current_setting",
+    "lower",
+    "upper",
+    "length",
+    "concat",
+    "concat_ws",
+    "to_char",
+    "to_date",
+    "to_timestamp",
+    "make_date",
+    "make_time",
+    "make_timestamp",
+    "make_timestamptz",
+    "make_interval",
+    "json_build_object",
+    "json_build_array",
+    "jsonb_build_object",
+    "jsonb_build_array",
+];
+
+/// Returns true if the expression contains a call to a volatile function.
+fn is_volatile(node: &pgt_query_ext::protobuf::Node, schema_cache: Option<&SchemaCache>) -> bool {
+    match &node.node {
+        Some(pgt_query_ext::NodeEnum::FuncCall(f)) => {
+            let (schema, name) = match f.funcname.as_slice() {
+                [name] => (None, name),
+                [schema, name] => (Some(schema), name),
+                _ => return true,
+            };
+
+            let name = match &name.node {
+                Some(pgt_query_ext::NodeEnum::String(s)) => s.sval.to_lowercase(),
+                _ => return true,
+            };
+            let schema = schema.and_then(|s| match &s.node {
+                Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.to_lowercase()),
+                _ => None,
+            });
+
+            let function_is_volatile = match schema_cache.and_then(|cache| {
+                cache.functions.iter().find(|func| {
+                    func.name == name && schema.as_ref().is_none_or(|s| func.schema == *s)
+                })
+            }) {
+                Some(func) => func.behavior == Behavior::Volatile,
+                None => !NON_VOLATILE_FUNCTIONS.contains(&name.as_str()),
+            };
+
+            function_is_volatile || f.args.iter().any(|a| is_volatile(a, schema_cache))
+        }
+        Some(pgt_query_ext::NodeEnum::TypeCast(c)) => c
+            .arg
+            .as_deref()
+            .is_some_and(|a| is_volatile(a, schema_cache)),
+        Some(pgt_query_ext::NodeEnum::AExpr(e)) => [e.lexpr.as_deref(), e.rexpr.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(|a| is_volatile(a, schema_cache)),
+        Some(pgt_query_ext::NodeEnum::BoolExpr(e)) => {
+            e.args.iter().any(|a| is_volatile(a, schema_cache))
+        }
+        Some(pgt_query_ext::NodeEnum::CoalesceExpr(e)) => {
+            e.args.iter().any(|a| is_volatile(a, schema_cache))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,89 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType};
+
+declare_lint_rule! {
+    /// Adding a primary key constraint to an existing table builds its index while blocking reads and writes.
+    ///
+    /// The index backing the primary key is created while the table is locked with an `ACCESS EXCLUSIVE` lock.
+    /// Adding a column with a primary key, such as a `serial primary key`, additionally rewrites the whole table.
+    ///
+    /// Instead, create a unique index concurrently and add the primary key using that index.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table items add primary key (id);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create unique index concurrently items_pk on items (id);
+    /// alter table items add constraint items_pk primary key using index items_pk;
+    /// ```
+    ///
+    pub AddingPrimaryKeyConstraint {
+        version: "next",
+        name: "addingPrimaryKeyConstraint",
+        recommended: false,
+        sources: &[RuleSource::Squawk("adding-serial-primary-key-field")],
+    }
+}
+
+impl Rule for AddingPrimaryKeyConstraint {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    let constraints = match cmd.subtype() {
+                        AlterTableType::AtAddConstraint => {
+                            cmd.def.as_deref().into_iter().collect::<Vec<_>>()
+                        }
+                        AlterTableType::AtAddColumn => {
+                            match cmd.def.as_ref().and_then(|d| d.node.as_ref()) {
+                                Some(pgt_query_ext::NodeEnum::ColumnDef(col)) => {
+                                    col.constraints.iter().collect()
+                                }
+                                _ => vec![],
+                            }
+                        }
+                        _ => vec![],
+                    };
+
+                    let adds_primary_key = constraints.iter().any(|node| {
+                        matches!(
+                            &node.node,
+                            Some(pgt_query_ext::NodeEnum::Constraint(c))
+                                if c.contype() == ConstrType::ConstrPrimary && c.indexname.is_empty()
+                        )
+                    });
+
+                    if adds_primary_key {
+                        diagnostics.push(
+                            RuleDiagnostic::new(
+                                rule_category!(),
+                                None,
+                                markup! {
+                                    "Adding a primary key constraint blocks reads and writes while its index is built."
+                                },
+                            )
+                            .detail(
+                                None,
+                                "Create a unique index concurrently first, and add the primary key with USING INDEX.",
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
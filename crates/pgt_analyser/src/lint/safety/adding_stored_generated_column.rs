@@ -0,0 +1,78 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType};
+
+use crate::table_size::with_table_size;
+
+declare_lint_rule! {
+    /// Adding a stored generated column rewrites the whole table while holding an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// The value of the generated column has to be computed and stored for every existing row.
+    /// Reads and writes to the table are blocked until the rewrite is finished.
+    ///
+    /// Instead, use the expand and contract pattern: add a regular column, keep it up to date with a trigger, and backfill the existing rows in batches.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column full_name text generated always as (first_name || ' ' || last_name) stored;
+    /// ```
+    ///
+    pub AddingStoredGeneratedColumn {
+        version: "next",
+        name: "addingStoredGeneratedColumn",
+        recommended: false,
+    }
+}
+
+impl Rule for AddingStoredGeneratedColumn {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    if cmd.subtype() != AlterTableType::AtAddColumn {
+                        continue;
+                    }
+
+                    if let Some(pgt_query_ext::NodeEnum::ColumnDef(col)) =
+                        cmd.def.as_ref().and_then(|d| d.node.as_ref())
+                    {
+                        let is_generated = col.constraints.iter().any(|c| {
+                            matches!(
+                                &c.node,
+                                Some(pgt_query_ext::NodeEnum::Constraint(c))
+                                    if c.contype() == ConstrType::ConstrGenerated
+                            )
+                        });
+
+                        if is_generated {
+                            diagnostics.push(with_table_size(
+                                RuleDiagnostic::new(
+                                    rule_category!(),
+                                    None,
+                                    markup! {
+                                        "Adding a stored generated column rewrites the table while holding an ACCESS EXCLUSIVE lock."
+                                    },
+                                )
+                                .detail(
+                                    None,
+                                    "Add a regular column, keep it up to date with a trigger, and backfill existing rows in batches.",
+                                ),
+                                ctx.schema_cache(),
+                                stmt.relation.as_ref(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
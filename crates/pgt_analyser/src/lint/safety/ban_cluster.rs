@@ -0,0 +1,54 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::table_size::with_table_size;
+
+declare_lint_rule! {
+    /// `CLUSTER` rewrites the whole table while holding an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// Reads and writes to the table are blocked until the table and all of its indexes have been rewritten.
+    ///
+    /// Use an online tool such as `pg_repack` instead, or create a new, ordered copy of the table and swap the tables.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// cluster users using users_created_at_idx;
+    /// ```
+    ///
+    pub BanCluster {
+        version: "next",
+        name: "banCluster",
+        recommended: false,
+    }
+}
+
+impl Rule for BanCluster {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::ClusterStmt(stmt) = &ctx.stmt() {
+            diagnostics.push(with_table_size(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "CLUSTER rewrites the table while holding an ACCESS EXCLUSIVE lock."
+                    },
+                )
+                .detail(
+                    None,
+                    "Use an online tool such as pg_repack, or create an ordered copy of the table and swap the tables.",
+                ),
+                ctx.schema_cache(),
+                stmt.relation.as_ref(),
+            ));
+        }
+
+        diagnostics
+    }
+}
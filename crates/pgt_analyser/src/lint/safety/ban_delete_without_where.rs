@@ -0,0 +1,60 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::span::relation_range;
+
+declare_lint_rule! {
+    /// A `DELETE` without a `WHERE` clause deletes every row of the table.
+    ///
+    /// This is rarely intended and usually the result of a missing or incomplete condition.
+    ///
+    /// If you really want to delete all rows, use `TRUNCATE`, which is also much faster.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// delete from sessions;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// delete from sessions where expires_at < now();
+    /// ```
+    ///
+    pub BanDeleteWithoutWhere {
+        version: "next",
+        name: "banDeleteWithoutWhere",
+        recommended: true,
+    }
+}
+
+impl Rule for BanDeleteWithoutWhere {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::DeleteStmt(stmt) = &ctx.stmt() {
+            if stmt.where_clause.is_none() {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        stmt.relation.as_ref().and_then(relation_range),
+                        markup! {
+                            "DELETE without a WHERE clause deletes every row of the table."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Add a WHERE clause to limit the deleted rows, or use TRUNCATE to delete all of them.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,151 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{DropBehavior, ObjectType};
+use pgt_schema_cache::Function;
+
+use crate::dependents::{calls_function, describe_function, qualified_name, user_functions};
+
+declare_lint_rule! {
+    /// Dropping a function that other functions or triggers still use breaks them.
+    ///
+    /// Postgres does not track which functions the body of another function calls.
+    /// After the function is dropped, its callers fail once they are called.
+    /// Triggers that execute the function prevent it from being dropped, or are silently dropped with `CASCADE`.
+    ///
+    /// If only some overloads of the function are dropped, the calls are still reported, because the rule cannot tell which overload a call resolves to.
+    ///
+    /// The rule needs a database connection to look up the callers. Functions are found by searching their bodies for calls of the dropped function.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// Given a function `create_order` that calls `next_order_number()`:
+    ///
+    /// ```sql,ignore
+    /// drop function next_order_number();
+    /// ```
+    ///
+    pub BanDropFunctionInUse {
+        version: "next",
+        name: "banDropFunctionInUse",
+        recommended: false,
+    }
+}
+
+impl Rule for BanDropFunctionInUse {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (Some(schema_cache), pgt_query_ext::NodeEnum::DropStmt(stmt)) =
+            (ctx.schema_cache(), &ctx.stmt())
+        else {
+            return diagnostics;
+        };
+
+        if stmt.remove_type() != ObjectType::ObjectFunction {
+            return diagnostics;
+        }
+
+        for object in &stmt.objects {
+            let Some(pgt_query_ext::NodeEnum::ObjectWithArgs(func)) = &object.node else {
+                continue;
+            };
+
+            let Some((schema, name)) = qualified_name(&func.objname) else {
+                continue;
+            };
+
+            // without an argument list, the statement drops the only overload of the function
+            let dropped = schema_cache
+                .functions
+                .iter()
+                .filter(|f| f.schema == schema && f.name == name)
+                .filter(|f| func.args_unspecified || input_arg_count(f) == func.objargs.len())
+                .collect::<Vec<_>>();
+
+            if dropped.is_empty() {
+                continue;
+            }
+
+            let callers = user_functions(schema_cache)
+                .filter(|f| !dropped.iter().any(|d| d.id == f.id))
+                .filter(|f| f.body.as_deref().is_some_and(|b| calls_function(b, name)))
+                .map(describe_function)
+                .collect::<Vec<_>>();
+
+            let triggers = if dropped.iter().any(|f| f.return_type == "trigger") {
+                schema_cache
+                    .triggers
+                    .iter()
+                    .filter(|t| t.proc_name == name)
+                    .map(|t| format!("trigger {} on {}.{}", t.name, t.schema_name, t.table_name))
+                    .collect::<Vec<_>>()
+            } else {
+                vec![]
+            };
+
+            if callers.is_empty() && triggers.is_empty() {
+                continue;
+            }
+
+            let dependents = triggers
+                .iter()
+                .chain(callers.iter())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let mut diagnostic = RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "Dropping the function "<Emphasis>{name}</Emphasis>" breaks objects that use it."
+                },
+            )
+            .footer_list("The function is used by:", &dependents);
+
+            if !triggers.is_empty() {
+                diagnostic = if stmt.behavior() == DropBehavior::DropCascade {
+                    diagnostic
+                        .note("With CASCADE, the triggers are dropped together with the function.")
+                } else {
+                    diagnostic.note("Without CASCADE, the statement fails because of the triggers.")
+                };
+            }
+
+            if !callers.is_empty() {
+                diagnostic = diagnostic.note(
+                    "The calling functions are not dropped and fail once they are called. Update them before dropping the function.",
+                );
+            }
+
+            let overloads = schema_cache
+                .functions
+                .iter()
+                .filter(|f| f.schema == schema && f.name == name)
+                .count();
+
+            if overloads > dropped.len() {
+                diagnostic = diagnostic.note(
+                    "Other overloads of the function remain, so calls whose arguments match them keep working.",
+                );
+            }
+
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics
+    }
+}
+
+/// The number of arguments a `DROP FUNCTION` statement lists for `function`.
+fn input_arg_count(function: &Function) -> usize {
+    function
+        .args
+        .args
+        .iter()
+        .filter(|a| matches!(a.mode.as_str(), "in" | "inout" | "variadic"))
+        .count()
+}
@@ -0,0 +1,109 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, DropBehavior};
+
+use crate::dependents::{
+    dependencies_on, describe_dependency, describe_function, functions_using, relation_name,
+};
+
+declare_lint_rule! {
+    /// Dropping a column that other objects still use breaks them.
+    ///
+    /// Views, materialized views and policies that use the column, and foreign keys that reference it, prevent the column from being dropped.
+    /// With `CASCADE`, they are silently dropped together with the column.
+    /// Functions that use the column are not tracked by Postgres and start to fail once they are called.
+    ///
+    /// The rule needs a database connection to look up the objects that use the column. Functions are found by parsing the statements in their bodies; dynamic SQL is not taken into account.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// Given a view `active_users` that selects `users.email`:
+    ///
+    /// ```sql,ignore
+    /// alter table users drop column email;
+    /// ```
+    ///
+    pub BanDropReferencedColumn {
+        version: "next",
+        name: "banDropReferencedColumn",
+        recommended: false,
+    }
+}
+
+impl Rule for BanDropReferencedColumn {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (Some(schema_cache), pgt_query_ext::NodeEnum::AlterTableStmt(stmt)) =
+            (ctx.schema_cache(), &ctx.stmt())
+        else {
+            return diagnostics;
+        };
+
+        let Some(relation) = stmt.relation.as_ref() else {
+            return diagnostics;
+        };
+
+        let (schema, table) = relation_name(relation);
+
+        for cmd in &stmt.cmds {
+            let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node else {
+                continue;
+            };
+
+            if cmd.subtype() != AlterTableType::AtDropColumn {
+                continue;
+            }
+
+            let column = cmd.name.as_str();
+
+            let dependencies = dependencies_on(schema_cache, schema, table, Some(column));
+            let functions = functions_using(schema_cache, schema, table, Some(column));
+
+            if dependencies.is_empty() && functions.is_empty() {
+                continue;
+            }
+
+            let dependents = dependencies
+                .iter()
+                .map(|d| describe_dependency(d))
+                .chain(functions.iter().map(|f| describe_function(f)))
+                .collect::<Vec<_>>();
+
+            let mut diagnostic = RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "Dropping the column "<Emphasis>{column}</Emphasis>" breaks objects that use it."
+                },
+            )
+            .footer_list("The column is used by:", &dependents);
+
+            if !dependencies.is_empty() {
+                diagnostic = if cmd.behavior() == DropBehavior::DropCascade {
+                    diagnostic.note(
+                        "With CASCADE, the views, policies and foreign keys are dropped together with the column.",
+                    )
+                } else {
+                    diagnostic.note(
+                        "Without CASCADE, the statement fails because of the views, policies and foreign keys.",
+                    )
+                };
+            }
+
+            if !functions.is_empty() {
+                diagnostic = diagnostic.note(
+                    "The functions are not dropped and fail once they are called. Update them before dropping the column.",
+                );
+            }
+
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics
+    }
+}
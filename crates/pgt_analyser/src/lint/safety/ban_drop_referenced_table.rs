@@ -0,0 +1,112 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{DropBehavior, ObjectType};
+
+use crate::dependents::{
+    dependencies_on, describe_dependency, describe_function, functions_using, qualified_name,
+};
+
+declare_lint_rule! {
+    /// Dropping a table that other objects still use breaks them.
+    ///
+    /// Foreign keys that reference the table, and views and policies that use it, prevent the table from being dropped.
+    /// With `CASCADE`, the views and policies are silently dropped, and the foreign keys are removed from the referencing tables.
+    /// Functions that use the table are not tracked by Postgres and start to fail once they are called.
+    ///
+    /// The rule needs a database connection to look up the objects that use the table. Functions are found by parsing the statements in their bodies; dynamic SQL is not taken into account.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// Given a table `orders` with a foreign key to `users`:
+    ///
+    /// ```sql,ignore
+    /// drop table users;
+    /// ```
+    ///
+    pub BanDropReferencedTable {
+        version: "next",
+        name: "banDropReferencedTable",
+        recommended: false,
+    }
+}
+
+impl Rule for BanDropReferencedTable {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (Some(schema_cache), pgt_query_ext::NodeEnum::DropStmt(stmt)) =
+            (ctx.schema_cache(), &ctx.stmt())
+        else {
+            return diagnostics;
+        };
+
+        if stmt.remove_type() != ObjectType::ObjectTable {
+            return diagnostics;
+        }
+
+        let tables = stmt
+            .objects
+            .iter()
+            .filter_map(|o| match &o.node {
+                Some(pgt_query_ext::NodeEnum::List(l)) => qualified_name(&l.items),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for &(schema, table) in &tables {
+            // objects on the tables that are dropped by the same statement go away with them
+            let dependencies = dependencies_on(schema_cache, schema, table, None)
+                .into_iter()
+                .filter(|d| !tables.contains(&(d.schema_name.as_str(), d.table_name.as_str())))
+                .collect::<Vec<_>>();
+            let functions = functions_using(schema_cache, schema, table, None);
+
+            if dependencies.is_empty() && functions.is_empty() {
+                continue;
+            }
+
+            let mut dependents = dependencies
+                .iter()
+                .map(|d| describe_dependency(d))
+                .chain(functions.iter().map(|f| describe_function(f)))
+                .collect::<Vec<_>>();
+            // a view or foreign key depends on every column it uses
+            dependents.dedup();
+
+            let mut diagnostic = RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "Dropping the table "<Emphasis>{table}</Emphasis>" breaks objects that use it."
+                },
+            )
+            .footer_list("The table is used by:", &dependents);
+
+            if !dependencies.is_empty() {
+                diagnostic = if stmt.behavior() == DropBehavior::DropCascade {
+                    diagnostic.note(
+                        "With CASCADE, the views and policies are dropped and the foreign keys are removed together with the table.",
+                    )
+                } else {
+                    diagnostic.note(
+                        "Without CASCADE, the statement fails because of the views, policies and foreign keys.",
+                    )
+                };
+            }
+
+            if !functions.is_empty() {
+                diagnostic = diagnostic.note(
+                    "The functions are not dropped and fail once they are called. Update them before dropping the table.",
+                );
+            }
+
+            diagnostics.push(diagnostic);
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,73 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::locking::takes_blocking_lock;
+
+declare_lint_rule! {
+    /// Schema changes and backfills of whole tables should not be run in the same migration.
+    ///
+    /// Migrations usually run in a single transaction, so the locks taken by DDL are held until the backfill has finished.
+    /// An `UPDATE` without a `WHERE` clause touches every row of the table, which can take a long time on large tables.
+    /// In the meantime, all queries on the locked tables are blocked.
+    ///
+    /// Run the backfill in a separate migration, or in batches outside of migrations.
+    ///
+    /// This rule only applies to migration files within the configured `migrations.migrationsDir`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column active boolean;
+    /// update users set active = true;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table users add column active boolean;
+    /// ```
+    ///
+    pub BanMixingDdlAndBackfill {
+        version: "next",
+        name: "banMixingDdlAndBackfill",
+        recommended: false,
+    }
+}
+
+impl Rule for BanMixingDdlAndBackfill {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !ctx.is_migration() {
+            return diagnostics;
+        }
+
+        if let pgt_query_ext::NodeEnum::UpdateStmt(stmt) = &ctx.stmt() {
+            if stmt.where_clause.is_some() {
+                return diagnostics;
+            }
+
+            if ctx.file_stmts().iter().any(takes_blocking_lock) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "This migration backfills a whole table while it also changes the schema."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "The locks taken by the schema changes are held until the backfill has finished. Move the backfill into a separate migration.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,98 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::ObjectType;
+
+use crate::dependents::{functions_using, relation_name};
+
+declare_lint_rule! {
+    /// Renaming a table or column that trigger functions use breaks the triggers.
+    ///
+    /// Postgres does not track which tables and columns the body of a trigger function uses.
+    /// After the rename, the trigger function still refers to the old name and every statement that fires the trigger fails.
+    ///
+    /// The rule needs a database connection to look up the triggers. For a renamed column, the functions of the triggers on its table are checked for statements and `NEW` or `OLD` fields that use the column.
+    /// For a renamed table, the functions of all triggers are checked for statements that use the table.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// Given a trigger on `users` whose function sets `new.updated_at`:
+    ///
+    /// ```sql,ignore
+    /// alter table users rename column updated_at to modified_at;
+    /// ```
+    ///
+    pub BanRenameReferencedByTrigger {
+        version: "next",
+        name: "banRenameReferencedByTrigger",
+        recommended: false,
+    }
+}
+
+impl Rule for BanRenameReferencedByTrigger {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (Some(schema_cache), pgt_query_ext::NodeEnum::RenameStmt(stmt)) =
+            (ctx.schema_cache(), &ctx.stmt())
+        else {
+            return diagnostics;
+        };
+
+        let Some(relation) = stmt.relation.as_ref() else {
+            return diagnostics;
+        };
+
+        let (schema, table) = relation_name(relation);
+
+        let (object, name, column) = match stmt.rename_type() {
+            ObjectType::ObjectColumn => {
+                ("column", stmt.subname.as_str(), Some(stmt.subname.as_str()))
+            }
+            ObjectType::ObjectTable => ("table", table, None),
+            _ => return diagnostics,
+        };
+
+        let functions = functions_using(schema_cache, schema, table, column);
+
+        let triggers = schema_cache
+            .triggers
+            .iter()
+            .filter(|t| {
+                stmt.rename_type() == ObjectType::ObjectTable
+                    || (t.schema_name == schema && t.table_name == table)
+            })
+            .filter(|t| functions.iter().any(|f| f.name == t.proc_name))
+            .map(|t| {
+                format!(
+                    "trigger {} on {}.{} (function {})",
+                    t.name, t.schema_name, t.table_name, t.proc_name
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if triggers.is_empty() {
+            return diagnostics;
+        }
+
+        diagnostics.push(
+            RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "Renaming the "{object}" "<Emphasis>{name}</Emphasis>" breaks triggers that use it."
+                },
+            )
+            .detail(
+                None,
+                "Update the trigger functions to use the new name in the same migration.",
+            )
+            .footer_list("The old name is used by:", &triggers),
+        );
+
+        diagnostics
+    }
+}
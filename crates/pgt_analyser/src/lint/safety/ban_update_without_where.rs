@@ -0,0 +1,61 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::span::relation_range;
+
+declare_lint_rule! {
+    /// An `UPDATE` without a `WHERE` clause updates every row of the table.
+    ///
+    /// This is rarely intended and usually the result of a missing or incomplete condition.
+    /// Updating all rows of a large table also takes a row lock on every row and creates a new version of each of them.
+    ///
+    /// If you really want to update all rows, make it explicit with `WHERE true`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// update users set is_active = false;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// update users set is_active = false where last_login_at < now() - interval '1 year';
+    /// ```
+    ///
+    pub BanUpdateWithoutWhere {
+        version: "next",
+        name: "banUpdateWithoutWhere",
+        recommended: true,
+    }
+}
+
+impl Rule for BanUpdateWithoutWhere {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::UpdateStmt(stmt) = &ctx.stmt() {
+            if stmt.where_clause.is_none() {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        stmt.relation.as_ref().and_then(relation_range),
+                        markup! {
+                            "UPDATE without a WHERE clause updates every row of the table."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Add a WHERE clause to limit the updated rows, or use WHERE true to update all of them.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,67 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// `VACUUM FULL` rewrites the whole table while holding an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// Reads and writes to the table are blocked until the table and all of its indexes have been rewritten.
+    /// Without a table name, every table in the database is rewritten.
+    ///
+    /// Use a regular `VACUUM` or an online tool such as `pg_repack` instead.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// vacuum full users;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// vacuum analyze users;
+    /// ```
+    ///
+    pub BanVacuumFull {
+        version: "next",
+        name: "banVacuumFull",
+        recommended: false,
+    }
+}
+
+impl Rule for BanVacuumFull {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::VacuumStmt(stmt) = &ctx.stmt() {
+            let is_full = stmt.is_vacuumcmd
+                && stmt.options.iter().any(|o| {
+                    matches!(
+                        &o.node,
+                        Some(pgt_query_ext::NodeEnum::DefElem(d)) if d.defname == "full"
+                    )
+                });
+
+            if is_full {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "VACUUM FULL rewrites the table while holding an ACCESS EXCLUSIVE lock."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use a regular VACUUM, or an online tool such as pg_repack.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
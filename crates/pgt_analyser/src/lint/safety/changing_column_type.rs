@@ -0,0 +1,144 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, TypeName};
+use pgt_schema_cache::SchemaCache;
+
+use crate::table_size::with_table_size;
+
+declare_lint_rule! {
+    /// Changing the type of a column rewrites the whole table while holding an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// Reads and writes to the table are blocked until the rewrite and the rebuild of all its indexes is finished.
+    /// Existing clients may also break if they do not expect the new type.
+    ///
+    /// Some changes are binary coercible and do not require a rewrite, e.g. changing a `varchar` column to `text` or increasing the length limit of a `varchar` column.
+    /// These are only recognised if a database connection is available.
+    ///
+    /// Instead of changing the type in place, use the expand and contract pattern: add a new column with the new type, backfill it in batches, switch the clients over, and drop the old column.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users alter column id type bigint;
+    /// ```
+    ///
+    pub ChangingColumnType {
+        version: "next",
+        name: "changingColumnType",
+        recommended: false,
+        sources: &[RuleSource::Squawk("changing-column-type")],
+    }
+}
+
+impl Rule for ChangingColumnType {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    if cmd.subtype() != AlterTableType::AtAlterColumnType {
+                        continue;
+                    }
+
+                    if let Some(pgt_query_ext::NodeEnum::ColumnDef(col)) =
+                        cmd.def.as_ref().and_then(|d| d.node.as_ref())
+                    {
+                        // a `USING` expression always forces a rewrite
+                        if col.raw_default.is_none() {
+                            if let Some(type_name) = col.type_name.as_ref() {
+                                if is_binary_coercible(
+                                    ctx.schema_cache(),
+                                    stmt.relation.as_ref(),
+                                    &cmd.name,
+                                    type_name,
+                                ) {
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+
+                    diagnostics.push(with_table_size(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            None,
+                            markup! {
+                                "Changing the type of a column rewrites the table while holding an ACCESS EXCLUSIVE lock."
+                            },
+                        )
+                        .detail(
+                            None,
+                            "Add a new column with the new type, backfill it, and drop the old column once all clients have been migrated.",
+                        ),
+                        ctx.schema_cache(),
+                        stmt.relation.as_ref(),
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Returns true if the column can be changed to the new type without a table rewrite.
+///
+/// Requires the current type of the column, so this is always false without a schema cache.
+fn is_binary_coercible(
+    schema_cache: Option<&SchemaCache>,
+    relation: Option<&pgt_query_ext::protobuf::RangeVar>,
+    column: &str,
+    new_type: &TypeName,
+) -> bool {
+    let (Some(schema_cache), Some(relation)) = (schema_cache, relation) else {
+        return false;
+    };
+
+    let schema = if relation.schemaname.is_empty() {
+        "public"
+    } else {
+        relation.schemaname.as_str()
+    };
+
+    let Some(current) = schema_cache
+        .columns
+        .iter()
+        .find(|c| c.schema_name == schema && c.table_name == relation.relname && c.name == column)
+    else {
+        return false;
+    };
+
+    if !new_type.array_bounds.is_empty() {
+        return false;
+    }
+
+    let Some(new_name) = new_type.names.last().and_then(|n| match &n.node {
+        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+        _ => None,
+    }) else {
+        return false;
+    };
+
+    let new_length = new_type.typmods.first().and_then(|n| match &n.node {
+        Some(pgt_query_ext::NodeEnum::AConst(c)) => match &c.val {
+            Some(pgt_query_ext::protobuf::a_const::Val::Ival(i)) => Some(i.ival),
+            _ => None,
+        },
+        _ => None,
+    });
+
+    match (current.type_name.as_deref(), new_name) {
+        (Some("varchar") | Some("text"), "text") => true,
+        (Some("varchar") | Some("text"), "varchar") if new_length.is_none() => true,
+        (Some("varchar"), "varchar") => match (current.varchar_length, new_length) {
+            (Some(current_length), Some(new_length)) => new_length >= current_length,
+            _ => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,68 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::AlterTableType;
+
+use crate::table_size::with_table_size;
+
+declare_lint_rule! {
+    /// Changing a table between logged and unlogged rewrites the whole table while holding an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// `SET LOGGED` additionally writes the full contents of the table to the write-ahead log, which can cause replication lag.
+    ///
+    /// Instead, use the expand and contract pattern: create a new table with the desired persistence, copy the data in batches, and swap the tables.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table events set logged;
+    /// ```
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table events set unlogged;
+    /// ```
+    ///
+    pub ChangingTablePersistence {
+        version: "next",
+        name: "changingTablePersistence",
+        recommended: false,
+    }
+}
+
+impl Rule for ChangingTablePersistence {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    if matches!(
+                        cmd.subtype(),
+                        AlterTableType::AtSetLogged | AlterTableType::AtSetUnLogged
+                    ) {
+                        diagnostics.push(with_table_size(
+                            RuleDiagnostic::new(
+                                rule_category!(),
+                                None,
+                                markup! {
+                                    "Changing the persistence of a table rewrites it while holding an ACCESS EXCLUSIVE lock."
+                                },
+                            )
+                            .detail(
+                                None,
+                                "Create a new table with the desired persistence, copy the data in batches, and swap the tables.",
+                            ),
+                            ctx.schema_cache(),
+                            stmt.relation.as_ref(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,81 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType};
+
+declare_lint_rule! {
+    /// Adding constraints without `NOT VALID` blocks all reads and writes while the existing rows are validated.
+    ///
+    /// When a `CHECK` or `FOREIGN KEY` constraint is added, Postgres scans the whole table to validate the existing rows.
+    /// During the scan, the table is locked with an `ACCESS EXCLUSIVE` lock.
+    ///
+    /// Add the constraint as `NOT VALID` in one transaction, and validate it with `VALIDATE CONSTRAINT` in another one.
+    /// Validating only requires a `SHARE UPDATE EXCLUSIVE` lock, which does not block reads and writes.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table orders add constraint orders_user_fk foreign key (user_id) references users (id);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table orders add constraint orders_user_fk foreign key (user_id) references users (id) not valid;
+    /// alter table orders validate constraint orders_user_fk;
+    /// ```
+    ///
+    pub ConstraintMissingNotValid {
+        version: "next",
+        name: "constraintMissingNotValid",
+        recommended: false,
+        sources: &[RuleSource::Squawk("constraint-missing-not-valid")],
+    }
+}
+
+impl Rule for ConstraintMissingNotValid {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    if cmd.subtype() != AlterTableType::AtAddConstraint {
+                        continue;
+                    }
+
+                    let Some(pgt_query_ext::NodeEnum::Constraint(constraint)) =
+                        cmd.def.as_ref().and_then(|def| def.node.as_ref())
+                    else {
+                        continue;
+                    };
+
+                    if matches!(
+                        constraint.contype(),
+                        ConstrType::ConstrCheck | ConstrType::ConstrForeign
+                    ) && !constraint.skip_validation
+                    {
+                        diagnostics.push(
+                            RuleDiagnostic::new(
+                                rule_category!(),
+                                None,
+                                markup! {
+                                    "Adding a constraint without NOT VALID blocks reads and writes while the table is validated."
+                                },
+                            )
+                            .detail(
+                                None,
+                                "Add the constraint as NOT VALID and validate it in a separate transaction with VALIDATE CONSTRAINT.",
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,89 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType};
+
+declare_lint_rule! {
+    /// Adding a unique constraint to an existing table builds its index while blocking reads and writes.
+    ///
+    /// The index backing the constraint is created while the table is locked with an `ACCESS EXCLUSIVE` lock.
+    /// On large tables, this can take a long time.
+    ///
+    /// Instead, create a unique index concurrently and add the constraint using that index.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add constraint users_email_key unique (email);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create unique index concurrently users_email_idx on users (email);
+    /// alter table users add constraint users_email_key unique using index users_email_idx;
+    /// ```
+    ///
+    pub DisallowUniqueConstraint {
+        version: "next",
+        name: "disallowUniqueConstraint",
+        recommended: false,
+        sources: &[RuleSource::Squawk("disallowed-unique-constraint")],
+    }
+}
+
+impl Rule for DisallowUniqueConstraint {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::AlterTableStmt(stmt) = &ctx.stmt() {
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    let constraints = match cmd.subtype() {
+                        AlterTableType::AtAddConstraint => {
+                            cmd.def.as_deref().into_iter().collect::<Vec<_>>()
+                        }
+                        AlterTableType::AtAddColumn => {
+                            match cmd.def.as_ref().and_then(|d| d.node.as_ref()) {
+                                Some(pgt_query_ext::NodeEnum::ColumnDef(col)) => {
+                                    col.constraints.iter().collect()
+                                }
+                                _ => vec![],
+                            }
+                        }
+                        _ => vec![],
+                    };
+
+                    let adds_unique_constraint = constraints.iter().any(|node| {
+                        matches!(
+                            &node.node,
+                            Some(pgt_query_ext::NodeEnum::Constraint(c))
+                                if c.contype() == ConstrType::ConstrUnique && c.indexname.is_empty()
+                        )
+                    });
+
+                    if adds_unique_constraint {
+                        diagnostics.push(
+                            RuleDiagnostic::new(
+                                rule_category!(),
+                                None,
+                                markup! {
+                                    "Adding a unique constraint blocks reads and writes while its index is built."
+                                },
+                            )
+                            .detail(
+                                None,
+                                "Create a unique index concurrently first, and add the constraint with USING INDEX.",
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
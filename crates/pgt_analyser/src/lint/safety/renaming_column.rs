@@ -0,0 +1,59 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// Renaming columns may break existing queries.
+    ///
+    /// Clients that are still running the old version of your application will fail to read or write the column under its old name.
+    ///
+    /// Add a new column instead, write to both columns, backfill the new column and only drop the old one once no client uses it anymore.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users rename column email to email_address;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table users add column email_address text;
+    /// ```
+    ///
+    pub RenamingColumn {
+        version: "next",
+        name: "renamingColumn",
+        recommended: false,
+        sources: &[RuleSource::Squawk("renaming-column")],
+    }
+}
+
+impl Rule for RenamingColumn {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::RenameStmt(stmt) = &ctx.stmt() {
+            if stmt.rename_type() == pgt_query_ext::protobuf::ObjectType::ObjectColumn {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Renaming a column may break existing clients."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Add a new column and migrate the data instead, and drop the old column once no client uses it anymore.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,59 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// Renaming tables may break existing queries.
+    ///
+    /// Clients that are still running the old version of your application will fail to read or write the table under its old name.
+    ///
+    /// Create a new table instead and migrate the data, or keep a view with the old name until no client uses it anymore.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users rename to customers;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// alter table users add column name text;
+    /// ```
+    ///
+    pub RenamingTable {
+        version: "next",
+        name: "renamingTable",
+        recommended: false,
+        sources: &[RuleSource::Squawk("renaming-table")],
+    }
+}
+
+impl Rule for RenamingTable {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::RenameStmt(stmt) = &ctx.stmt() {
+            if stmt.rename_type() == pgt_query_ext::protobuf::ObjectType::ObjectTable {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Renaming a table may break existing clients."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Create a new table and migrate the data instead, or keep a view with the old name until no client uses it anymore.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,60 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// Creating indexes non-concurrently can lock the table for writes.
+    ///
+    /// While a plain `CREATE INDEX` is running, all inserts, updates and deletes on the table are blocked.
+    /// On large tables, building the index can take a long time.
+    ///
+    /// Use `CREATE INDEX CONCURRENTLY` instead. It takes longer and cannot run inside a transaction block, but does not block writes.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create index users_email_idx on users (email);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create index concurrently users_email_idx on users (email);
+    /// ```
+    ///
+    pub RequireConcurrentIndexCreation {
+        version: "next",
+        name: "requireConcurrentIndexCreation",
+        recommended: false,
+        sources: &[RuleSource::Squawk("require-concurrent-index-creation")],
+    }
+}
+
+impl Rule for RequireConcurrentIndexCreation {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::IndexStmt(stmt) = &ctx.stmt() {
+            if !stmt.concurrent {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Creating an index non-concurrently blocks writes to the table."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use CREATE INDEX CONCURRENTLY to build the index without blocking writes.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,62 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// Dropping indexes non-concurrently can lock the table for reads and writes.
+    ///
+    /// A plain `DROP INDEX` acquires an `ACCESS EXCLUSIVE` lock on the table the index belongs to.
+    /// It has to wait for all running queries on the table to finish, and blocks all new ones in the meantime.
+    ///
+    /// Use `DROP INDEX CONCURRENTLY` instead. It cannot run inside a transaction block, but does not block other queries.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// drop index if exists users_email_idx;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// drop index concurrently if exists users_email_idx;
+    /// ```
+    ///
+    pub RequireConcurrentIndexDeletion {
+        version: "next",
+        name: "requireConcurrentIndexDeletion",
+        recommended: false,
+        sources: &[RuleSource::Squawk("require-concurrent-index-deletion")],
+    }
+}
+
+impl Rule for RequireConcurrentIndexDeletion {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::DropStmt(stmt) = &ctx.stmt() {
+            if stmt.remove_type() == pgt_query_ext::protobuf::ObjectType::ObjectIndex
+                && !stmt.concurrent
+            {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Dropping an index non-concurrently blocks reads and writes to the table."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use DROP INDEX CONCURRENTLY to drop the index without blocking other queries.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,96 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{VariableSetKind, a_const};
+
+use crate::locking::takes_blocking_lock;
+
+declare_lint_rule! {
+    /// Set a `lock_timeout` before running DDL that locks existing tables.
+    ///
+    /// A statement that needs an `ACCESS EXCLUSIVE` lock has to wait for all running transactions on the table to finish.
+    /// While it waits, it blocks all other queries on the table, so a single long-running query can take down the application.
+    ///
+    /// With a `lock_timeout` (or a `statement_timeout`), the migration fails instead and can be retried later.
+    ///
+    /// Only the first statement that takes a lock is reported.
+    /// This rule only applies to migration files within the configured `migrations.migrationsDir`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column bio text;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// set lock_timeout = '2s';
+    /// alter table users add column bio text;
+    /// ```
+    ///
+    pub RequireLockTimeout {
+        version: "next",
+        name: "requireLockTimeout",
+        recommended: false,
+    }
+}
+
+impl Rule for RequireLockTimeout {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !ctx.is_migration() || !takes_blocking_lock(ctx.stmt()) {
+            return diagnostics;
+        }
+
+        let previous = ctx.previous_stmts();
+
+        // only the first statement that takes a lock is reported
+        if previous.iter().any(takes_blocking_lock) || previous.iter().any(sets_timeout) {
+            return diagnostics;
+        }
+
+        diagnostics.push(
+            RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "This statement takes a lock without a lock_timeout."
+                },
+            )
+            .detail(
+                None,
+                "Add SET lock_timeout = '2s' at the start of the migration, so that it fails instead of blocking all queries on the table while it waits for the lock.",
+            ),
+        );
+
+        diagnostics
+    }
+}
+
+/// Returns true if the statement sets a `lock_timeout` or `statement_timeout` other than zero.
+fn sets_timeout(stmt: &pgt_query_ext::NodeEnum) -> bool {
+    let pgt_query_ext::NodeEnum::VariableSetStmt(stmt) = stmt else {
+        return false;
+    };
+
+    if stmt.kind() != VariableSetKind::VarSetValue
+        || !matches!(stmt.name.as_str(), "lock_timeout" | "statement_timeout")
+    {
+        return false;
+    }
+
+    // a timeout of zero disables the timeout
+    !stmt.args.iter().any(|arg| match &arg.node {
+        Some(pgt_query_ext::NodeEnum::AConst(c)) => match &c.val {
+            Some(a_const::Val::Ival(i)) => i.ival == 0,
+            Some(a_const::Val::Sval(s)) => s.sval.trim() == "0",
+            _ => false,
+        },
+        _ => false,
+    })
+}
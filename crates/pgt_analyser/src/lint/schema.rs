@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use pgt_analyse::declare_lint_group;
+pub mod auth_rls_initplan;
+pub mod duplicate_index;
+pub mod extension_in_public;
+pub mod multiple_permissive_policies;
+pub mod security_definer_view;
+pub mod unindexed_foreign_keys;
+declare_lint_group! { pub Schema { name : "schema" , schema_rules : [self :: auth_rls_initplan :: AuthRlsInitplan , self :: duplicate_index :: DuplicateIndex , self :: extension_in_public :: ExtensionInPublic , self :: multiple_permissive_policies :: MultiplePermissivePolicies , self :: security_definer_view :: SecurityDefinerView , self :: unindexed_foreign_keys :: UnindexedForeignKeys ,] } }
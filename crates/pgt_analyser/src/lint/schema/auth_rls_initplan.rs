@@ -0,0 +1,85 @@
+use pgt_analyse::{
+    RuleDiagnostic, RuleSource, SchemaRule, context::SchemaRuleContext, declare_lint_rule,
+};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::Node;
+
+use crate::lint::security::wrap_auth_functions_in_select::has_unwrapped_auth_call;
+
+declare_lint_rule! {
+    /// Existing policies should not call auth functions for every row.
+    ///
+    /// This is the database-wide counterpart of `wrapAuthFunctionsInSelect`.
+    /// It checks the policies that already exist in the connected database, including those that were created without going through the linter.
+    ///
+    /// Postgres evaluates a call to `auth.uid()`, `auth.jwt()`, `auth.role()`, `auth.email()` or `current_setting()` in a policy expression once for every row.
+    /// Wrapped in a sub-select such as `(select auth.uid())`, it is evaluated once per statement.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,ignore
+    /// create policy "owners can read" on posts for select using (auth.uid() = author_id);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql,ignore
+    /// create policy "owners can read" on posts for select using ((select auth.uid()) = author_id);
+    /// ```
+    ///
+    pub AuthRlsInitplan {
+        version: "next",
+        name: "authRlsInitplan",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0003_auth_rls_initplan")],
+    }
+}
+
+impl SchemaRule for AuthRlsInitplan {
+    type Options = ();
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        ctx.schema_cache()
+            .policies
+            .iter()
+            .filter(|p| {
+                [p.security_qualification.as_deref(), p.with_check.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(parse_expression)
+                    .any(|e| has_unwrapped_auth_call(&e))
+            })
+            .map(|p| {
+                let table = format!("{}.{}", p.schema_name, p.table_name);
+
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The policy "<Emphasis>{p.name}</Emphasis>" on "<Emphasis>{table}</Emphasis>" calls an auth function for every row."
+                    },
+                )
+                .detail(
+                    None,
+                    "Wrap the call in a sub-select, e.g. (select auth.uid()), so that it is evaluated once per statement.",
+                )
+            })
+            .collect()
+    }
+}
+
+/// Parses a policy expression as stored in `pg_policies`.
+fn parse_expression(expression: &str) -> Option<Node> {
+    let sql = format!("select {expression}");
+
+    let pgt_query_ext::NodeEnum::SelectStmt(stmt) = pgt_query_ext::parse(&sql).ok()? else {
+        return None;
+    };
+
+    match stmt.target_list.into_iter().next()?.node? {
+        pgt_query_ext::NodeEnum::ResTarget(target) => target.val.map(|v| *v),
+        _ => None,
+    }
+}
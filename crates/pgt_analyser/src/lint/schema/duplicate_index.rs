@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+use pgt_analyse::{
+    RuleDiagnostic, RuleSource, SchemaRule, context::SchemaRuleContext, declare_lint_rule,
+};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// A table should not have several identical indexes.
+    ///
+    /// Indexes on the same columns with the same method and predicate are interchangeable.
+    /// All but one of them only take up space and slow down writes, because every insert and update maintains each of them.
+    ///
+    /// A primary key or unique constraint creates an index, so an additional index on the same columns is a duplicate too.
+    ///
+    /// The rule runs once against the connected database.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,ignore
+    /// create table users (id bigint primary key, email text);
+    /// create index users_id_idx on users (id);
+    /// ```
+    ///
+    pub DuplicateIndex {
+        version: "next",
+        name: "duplicateIndex",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0009_duplicate_index")],
+    }
+}
+
+impl SchemaRule for DuplicateIndex {
+    type Options = ();
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut indexes = BTreeMap::new();
+        for index in &ctx.schema_cache().indexes {
+            indexes
+                .entry((
+                    index.schema_name.as_str(),
+                    index.table_name.as_str(),
+                    index.method.as_str(),
+                    &index.columns,
+                    index.predicate.as_deref(),
+                ))
+                .or_insert_with(Vec::new)
+                .push(index.name.as_str());
+        }
+
+        indexes
+            .into_iter()
+            .filter(|(_, names)| names.len() > 1)
+            .map(|((schema, table, ..), names)| {
+                let table = format!("{schema}.{table}");
+
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The table "<Emphasis>{table}</Emphasis>" has identical indexes."
+                    },
+                )
+                .detail(
+                    None,
+                    "Drop all but one of them. Keep the index that backs a constraint, if any.",
+                )
+                .footer_list("The identical indexes are:", &names)
+            })
+            .collect()
+    }
+}
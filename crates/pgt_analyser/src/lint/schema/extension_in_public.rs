@@ -0,0 +1,59 @@
+use pgt_analyse::{
+    RuleDiagnostic, RuleSource, SchemaRule, context::SchemaRuleContext, declare_lint_rule,
+};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// Extensions should not be installed in the `public` schema.
+    ///
+    /// The functions, types and tables of an extension in `public` are exposed to everyone with access to the schema, and in Supabase to the API.
+    /// They also clutter the schema and may clash with the names of application objects.
+    ///
+    /// The rule runs once against the connected database.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,ignore
+    /// create extension pg_trgm;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql,ignore
+    /// create extension pg_trgm schema extensions;
+    /// ```
+    ///
+    pub ExtensionInPublic {
+        version: "next",
+        name: "extensionInPublic",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0014_extension_in_public")],
+    }
+}
+
+impl SchemaRule for ExtensionInPublic {
+    type Options = ();
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        ctx.schema_cache()
+            .extensions
+            .iter()
+            .filter(|e| e.schema == "public")
+            .map(|e| {
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The extension "<Emphasis>{e.name}</Emphasis>" is installed in the public schema."
+                    },
+                )
+                .detail(
+                    None,
+                    "Move it to a separate schema, e.g. with alter extension ... set schema extensions.",
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use pgt_analyse::{
+    RuleDiagnostic, RuleSource, SchemaRule, context::SchemaRuleContext, declare_lint_rule,
+};
+use pgt_console::markup;
+use pgt_schema_cache::{Policy, PolicyCommand};
+
+declare_lint_rule! {
+    /// A table should not have several permissive policies for the same role and action.
+    ///
+    /// Permissive policies are combined with `OR`, and Postgres evaluates every one of them for each row.
+    /// Several permissive policies for the same role and action make queries slower than a single policy that combines their conditions.
+    ///
+    /// Policies for the `public` role apply to every role. Policies `FOR ALL` apply to every action.
+    ///
+    /// The rule runs once against the connected database and reports each table, role and action separately.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,ignore
+    /// create policy "owners can read" on posts for select to authenticated using ((select auth.uid()) = author_id);
+    /// create policy "published posts are public" on posts for select using (is_published);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql,ignore
+    /// create policy "posts are readable" on posts for select using (is_published or (select auth.uid()) = author_id);
+    /// ```
+    ///
+    pub MultiplePermissivePolicies {
+        version: "next",
+        name: "multiplePermissivePolicies",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0006_multiple_permissive_policies")],
+    }
+}
+
+const ACTIONS: &[(PolicyCommand, &str)] = &[
+    (PolicyCommand::Select, "SELECT"),
+    (PolicyCommand::Insert, "INSERT"),
+    (PolicyCommand::Update, "UPDATE"),
+    (PolicyCommand::Delete, "DELETE"),
+];
+
+impl SchemaRule for MultiplePermissivePolicies {
+    type Options = ();
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let mut tables: BTreeMap<(&str, &str), Vec<&Policy>> = BTreeMap::new();
+        for policy in ctx
+            .schema_cache()
+            .policies
+            .iter()
+            .filter(|p| p.is_permissive)
+        {
+            tables
+                .entry((policy.schema_name.as_str(), policy.table_name.as_str()))
+                .or_default()
+                .push(policy);
+        }
+
+        for ((schema, table), policies) in tables {
+            let roles = policies
+                .iter()
+                .flat_map(|p| p.role_names.iter().map(String::as_str))
+                .collect::<BTreeSet<_>>();
+
+            for role in roles {
+                for (command, action) in ACTIONS {
+                    let names = policies
+                        .iter()
+                        .filter(|p| p.command == *command || p.command == PolicyCommand::All)
+                        .filter(|p| p.role_names.iter().any(|r| r == role || r == "public"))
+                        .map(|p| p.name.as_str())
+                        .collect::<Vec<_>>();
+
+                    if names.len() < 2 {
+                        continue;
+                    }
+
+                    let table = format!("{schema}.{table}");
+
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            None,
+                            markup! {
+                                "The table "<Emphasis>{table}</Emphasis>" has several permissive policies for the role "<Emphasis>{role}</Emphasis>" and the action "<Emphasis>{action}</Emphasis>"."
+                            },
+                        )
+                        .detail(
+                            None,
+                            "Each of the policies is evaluated for every row. Combine them into a single policy.",
+                        )
+                        .footer_list("The policies are:", &names),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
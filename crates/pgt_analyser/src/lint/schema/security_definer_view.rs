@@ -0,0 +1,124 @@
+use biome_deserialize_macros::Deserializable;
+use pgt_analyse::{
+    RuleDiagnostic, RuleSource, SchemaRule, context::SchemaRuleContext, declare_lint_rule,
+};
+use pgt_console::markup;
+use pgt_schema_cache::TableKind;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Views in exposed schemas should use the permissions of the querying user.
+    ///
+    /// By default, a view accesses its tables with the permissions of the owner of the view.
+    /// Row level security policies of the tables are then checked against the owner, who usually bypasses them, instead of the user who queries the view.
+    /// Views in a schema that is exposed via an API can therefore leak rows that the user is not allowed to see.
+    ///
+    /// Since Postgres 15, a view created `with (security_invoker = true)` uses the permissions of the querying user.
+    /// The rule does not report anything on older versions.
+    ///
+    /// The rule runs once against the connected database.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,ignore
+    /// create view public.active_users as select * from users where is_active;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql,ignore
+    /// create view public.active_users with (security_invoker = true) as select * from users where is_active;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `exposedSchemas` to configure which schemas are exposed. Defaults to `["public"]`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "exposedSchemas": ["public", "api"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub SecurityDefinerView {
+        version: "next",
+        name: "securityDefinerView",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0010_security_definer_view")],
+    }
+}
+
+impl SchemaRule for SecurityDefinerView {
+    type Options = SecurityDefinerViewOptions;
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let schema_cache = ctx.schema_cache();
+
+        let supports_security_invoker = schema_cache
+            .versions
+            .first()
+            .and_then(|v| v.version_num)
+            .is_none_or(|v| v >= 150000);
+
+        if !supports_security_invoker {
+            return Vec::new();
+        }
+
+        schema_cache
+            .tables
+            .iter()
+            .filter(|t| t.table_kind == TableKind::View)
+            .filter(|t| ctx.options().exposed_schemas.contains(&t.schema))
+            .filter(|t| !t.options.iter().any(|o| is_security_invoker(o)))
+            .map(|t| {
+                let view = format!("{}.{}", t.schema, t.name);
+
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The view "<Emphasis>{view}</Emphasis>" uses the permissions of its owner."
+                    },
+                )
+                .detail(
+                    None,
+                    "Row level security policies of the underlying tables are not applied to the users that query the view.",
+                )
+                .note(markup! {
+                    "Set the option with alter view "{view}" set (security_invoker = true)."
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns true if a storage parameter of a view enables `security_invoker`.
+fn is_security_invoker(option: &str) -> bool {
+    option.split_once('=').is_some_and(|(name, value)| {
+        name.eq_ignore_ascii_case("security_invoker")
+            && matches!(
+                value.to_ascii_lowercase().as_str(),
+                "true" | "on" | "yes" | "1"
+            )
+    })
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct SecurityDefinerViewOptions {
+    /// Schemas that are exposed via an API. Defaults to `["public"]`.
+    pub exposed_schemas: Vec<String>,
+}
+
+impl Default for SecurityDefinerViewOptions {
+    fn default() -> Self {
+        Self {
+            exposed_schemas: vec!["public".to_string()],
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use pgt_analyse::{
+    RuleDiagnostic, RuleSource, SchemaRule, context::SchemaRuleContext, declare_lint_rule,
+};
+use pgt_console::markup;
+use pgt_schema_cache::{ForeignKey, Index};
+
+declare_lint_rule! {
+    /// Foreign keys should be covered by an index.
+    ///
+    /// Postgres does not create an index for the referencing columns of a foreign key.
+    /// Without one, every update or delete of a row in the referenced table scans the referencing table to check the constraint, and joins along the foreign key cannot use an index.
+    ///
+    /// A foreign key is covered by an index whose leading columns are the columns of the foreign key, in any order.
+    ///
+    /// The rule runs once against the connected database.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,ignore
+    /// create table orders (
+    ///     id bigint primary key,
+    ///     user_id bigint references users (id)
+    /// );
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql,ignore
+    /// create index orders_user_id_idx on orders (user_id);
+    /// ```
+    ///
+    pub UnindexedForeignKeys {
+        version: "next",
+        name: "unindexedForeignKeys",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0001_unindexed_foreign_keys")],
+    }
+}
+
+impl SchemaRule for UnindexedForeignKeys {
+    type Options = ();
+
+    fn run(ctx: &SchemaRuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let schema_cache = ctx.schema_cache();
+
+        schema_cache
+            .foreign_keys
+            .iter()
+            .filter(|fk| {
+                !schema_cache
+                    .indexes
+                    .iter()
+                    .filter(|i| i.schema_name == fk.schema_name && i.table_name == fk.table_name)
+                    .any(|i| covers(i, fk))
+            })
+            .map(|fk| {
+                let table = format!("{}.{}", fk.schema_name, fk.table_name);
+                let columns = fk.columns.join(", ");
+
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The foreign key "<Emphasis>{fk.name}</Emphasis>" on "<Emphasis>{table}</Emphasis>" is not covered by an index."
+                    },
+                )
+                .detail(
+                    None,
+                    "Updates and deletes on the referenced table scan the whole table to check the foreign key.",
+                )
+                .note(markup! {
+                    "Add an index, e.g. create index on "{table}" ("{columns}")."
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns true if the leading columns of `index` are the columns of `foreign_key`.
+fn covers(index: &Index, foreign_key: &ForeignKey) -> bool {
+    let count = foreign_key.columns.len();
+
+    index.columns.len() >= count
+        && index.columns[..count]
+            .iter()
+            .all(|c| foreign_key.columns.iter().any(|f| c.trim_matches('"') == f))
+}
@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use pgt_analyse::declare_lint_group;
+pub mod ban_dynamic_sql_concatenation;
+pub mod ban_unrestricted_write_policy;
+pub mod require_row_level_security;
+pub mod require_search_path_on_security_definer;
+pub mod require_update_policy_with_check;
+pub mod wrap_auth_functions_in_select;
+declare_lint_group! { pub Security { name : "security" , rules : [self :: ban_dynamic_sql_concatenation :: BanDynamicSqlConcatenation , self :: ban_unrestricted_write_policy :: BanUnrestrictedWritePolicy , self :: require_row_level_security :: RequireRowLevelSecurity , self :: require_search_path_on_security_definer :: RequireSearchPathOnSecurityDefiner , self :: require_update_policy_with_check :: RequireUpdatePolicyWithCheck , self :: wrap_auth_functions_in_select :: WrapAuthFunctionsInSelect ,] } }
@@ -0,0 +1,168 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::Token;
+use serde_json::Value;
+
+use crate::plpgsql::{expression, lineno, node, parse_function, tokens, visit_statements};
+
+declare_lint_rule! {
+    /// Dynamic SQL in PL/pgSQL functions must not be built by concatenating variables.
+    ///
+    /// A query that is built with `||` or `concat()` from parameters or variables can be changed by whoever controls their values, which allows SQL injection.
+    /// Use `format()` with `%I` for identifiers and `%L` for literals, or pass values as parameters with `EXECUTE ... USING`.
+    ///
+    /// Variables that are wrapped in `quote_ident()`, `quote_literal()`, `quote_nullable()` or `format()` are considered safe.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function count_rows(table_name text) returns bigint language plpgsql as $$
+    /// declare
+    ///     result bigint;
+    /// begin
+    ///     execute 'select count(*) from ' || table_name into result;
+    ///     return result;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function count_rows(table_name text) returns bigint language plpgsql as $$
+    /// declare
+    ///     result bigint;
+    /// begin
+    ///     execute format('select count(*) from %I', table_name) into result;
+    ///     return result;
+    /// end;
+    /// $$;
+    /// ```
+    ///
+    pub BanDynamicSqlConcatenation {
+        version: "next",
+        name: "banDynamicSqlConcatenation",
+        recommended: true,
+    }
+}
+
+/// Functions that quote their arguments safely.
+const QUOTING_FUNCTIONS: &[&str] = &["quote_ident", "quote_literal", "quote_nullable", "format"];
+
+impl Rule for BanDynamicSqlConcatenation {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let Some(function) = parse_function(ctx.stmt(), ctx.sql()) else {
+            return diagnostics;
+        };
+
+        let variables = function
+            .parameters()
+            .map(|(name, _)| name)
+            .chain(
+                function
+                    .datums()
+                    .iter()
+                    .filter_map(node)
+                    .filter_map(|(_, datum)| datum.get("refname").and_then(Value::as_str)),
+            )
+            .collect::<Vec<_>>();
+
+        visit_statements(function.statements(), &mut |kind, fields| {
+            let query = match kind {
+                "PLpgSQL_stmt_dynexecute" | "PLpgSQL_stmt_dynfors" => expression(fields, "query"),
+                "PLpgSQL_stmt_return_query" | "PLpgSQL_stmt_open" => expression(fields, "dynquery"),
+                _ => None,
+            };
+
+            if !query.is_some_and(|query| concatenates_variables(query, &variables)) {
+                return;
+            }
+
+            let range = lineno(fields).and_then(|line| {
+                function
+                    .body
+                    .tokens()
+                    .iter()
+                    .find(|t| t.line >= line && t.token.text == "execute")
+                    .map(|t| t.token.range)
+            });
+
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "The dynamic query is built by concatenating variables."
+                    },
+                )
+                .detail(
+                    None,
+                    "This allows SQL injection. Use format() with %I for identifiers and %L for literals, or pass values with EXECUTE ... USING.",
+                ),
+            );
+        });
+
+        diagnostics
+    }
+}
+
+/// Returns true if `query` concatenates strings and uses one of `variables` outside of a
+/// quoting function.
+fn concatenates_variables(query: &str, variables: &[&str]) -> bool {
+    let tokens = tokens(query);
+
+    let is_call = |idx: usize, names: &[&str]| {
+        names.contains(&tokens[idx].text.as_str())
+            && tokens
+                .get(idx + 1)
+                .is_some_and(|next| next.kind == Token::Ascii40)
+    };
+
+    let concatenates = (0..tokens.len())
+        .any(|idx| tokens[idx].text == "||" || is_call(idx, &["concat", "concat_ws"]));
+
+    if !concatenates {
+        return false;
+    }
+
+    let mut depth = 0usize;
+    // the depths of the parentheses of calls to quoting functions
+    let mut quoted = vec![];
+
+    for (idx, token) in tokens.iter().enumerate() {
+        match token.kind {
+            Token::Ascii40 => {
+                depth += 1;
+                if idx > 0 && is_call(idx - 1, QUOTING_FUNCTIONS) {
+                    quoted.push(depth);
+                }
+            }
+            Token::Ascii41 => {
+                if quoted.last() == Some(&depth) {
+                    quoted.pop();
+                }
+                depth = depth.saturating_sub(1);
+            }
+            _ => {
+                // `t.name` is a column, not a variable
+                let is_qualified = idx > 0 && tokens[idx - 1].kind == Token::Ascii46;
+
+                if quoted.is_empty()
+                    && token.is_word()
+                    && !is_qualified
+                    && variables.contains(&token.text.as_str())
+                {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,108 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_schema_cache::PolicyCommand;
+
+declare_lint_rule! {
+    /// Policies for commands other than `SELECT` must not be unrestricted.
+    ///
+    /// A policy with `USING (true)` or `WITH CHECK (true)` allows every role it applies to to insert, update or delete any row of the table.
+    /// This is rarely intended for anything but read access.
+    ///
+    /// For `ALTER POLICY`, the command of the policy is only known if a database connection is available.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create policy "anyone can delete" on posts for delete using (true);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create policy "anyone can read" on posts for select using (true);
+    /// ```
+    ///
+    /// ```sql
+    /// create policy "owners can delete" on posts for delete using (auth.uid() = author_id);
+    /// ```
+    ///
+    pub BanUnrestrictedWritePolicy {
+        version: "next",
+        name: "banUnrestrictedWritePolicy",
+        recommended: true,
+    }
+}
+
+impl Rule for BanUnrestrictedWritePolicy {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (is_write_policy, qual, with_check) = match &ctx.stmt() {
+            pgt_query_ext::NodeEnum::CreatePolicyStmt(stmt) => (
+                stmt.cmd_name != "select",
+                stmt.qual.as_deref(),
+                stmt.with_check.as_deref(),
+            ),
+            pgt_query_ext::NodeEnum::AlterPolicyStmt(stmt) => {
+                let command = ctx.schema_cache().and_then(|cache| {
+                    let table = stmt.table.as_ref()?;
+                    let schema = if table.schemaname.is_empty() {
+                        "public"
+                    } else {
+                        table.schemaname.as_str()
+                    };
+
+                    cache
+                        .policies
+                        .iter()
+                        .find(|p| {
+                            p.name == stmt.policy_name
+                                && p.schema_name == schema
+                                && p.table_name == table.relname
+                        })
+                        .map(|p| p.command.clone())
+                });
+
+                (
+                    command.is_some_and(|c| c != PolicyCommand::Select),
+                    stmt.qual.as_deref(),
+                    stmt.with_check.as_deref(),
+                )
+            }
+            _ => return diagnostics,
+        };
+
+        if is_write_policy && (is_true(qual) || is_true(with_check)) {
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The policy allows every row to be modified."
+                    },
+                )
+                .detail(
+                    None,
+                    "Restrict the policy to the rows the user is allowed to modify, e.g. by comparing the owner of a row to auth.uid().",
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+fn is_true(node: Option<&pgt_query_ext::protobuf::Node>) -> bool {
+    matches!(
+        node.and_then(|n| n.node.as_ref()),
+        Some(pgt_query_ext::NodeEnum::AConst(c))
+            if matches!(
+                &c.val,
+                Some(pgt_query_ext::protobuf::a_const::Val::Boolval(b)) if b.boolval
+            )
+    )
+}
@@ -0,0 +1,175 @@
+use biome_deserialize_macros::Deserializable;
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, RangeVar};
+use serde::{Deserialize, Serialize};
+
+use crate::table_size::find_table;
+
+declare_lint_rule! {
+    /// Tables in exposed schemas must have row level security enabled.
+    ///
+    /// Tables in schemas that are exposed via an API, such as the `public` schema in Supabase, can be read and written by anyone with access to the API unless row level security is enabled.
+    ///
+    /// The rule reports tables that are created in an exposed schema, and statements that disable row level security on them.
+    /// If a database connection is available, tables that already have row level security enabled are not reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table public.profiles (id uuid primary key, name text);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table private.audit_log (id bigint primary key, payload jsonb);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `exposedSchemas` to configure which schemas are exposed. Defaults to `["public"]`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "exposedSchemas": ["public", "api"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub RequireRowLevelSecurity {
+        version: "next",
+        name: "requireRowLevelSecurity",
+        recommended: false,
+        sources: &[RuleSource::Splinter("0013_rls_disabled_in_public")],
+    }
+}
+
+impl Rule for RequireRowLevelSecurity {
+    type Options = RequireRowLevelSecurityOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        match &ctx.stmt() {
+            pgt_query_ext::NodeEnum::CreateStmt(stmt) => {
+                let Some(relation) = stmt.relation.as_ref() else {
+                    return diagnostics;
+                };
+
+                // temporary tables are only visible to the current session
+                if relation.relpersistence == "t" || !ctx.options().is_exposed(relation) {
+                    return diagnostics;
+                }
+
+                let rls_enabled =
+                    find_table(ctx.schema_cache(), Some(relation)).is_some_and(|t| t.rls_enabled);
+
+                if !rls_enabled {
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            None,
+                            markup! {
+                                "The table is created in an exposed schema without row level security."
+                            },
+                        )
+                        .detail(
+                            None,
+                            "Enable row level security on the table and add policies for it.",
+                        ),
+                    );
+                }
+            }
+            pgt_query_ext::NodeEnum::AlterTableStmt(stmt) => {
+                let Some(relation) = stmt.relation.as_ref() else {
+                    return diagnostics;
+                };
+
+                if !ctx.options().is_exposed(relation) {
+                    return diagnostics;
+                }
+
+                let disables_rls = stmt.cmds.iter().any(|cmd| {
+                    matches!(
+                        &cmd.node,
+                        Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd))
+                            if cmd.subtype() == AlterTableType::AtDisableRowSecurity
+                    )
+                });
+
+                if disables_rls {
+                    let mut diagnostic = RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Row level security is disabled on a table in an exposed schema."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Everyone with access to the API can read and write all rows of the table.",
+                    );
+
+                    if let Some(table) = find_table(ctx.schema_cache(), Some(relation)) {
+                        let policies = ctx
+                            .schema_cache()
+                            .map(|cache| {
+                                cache
+                                    .policies
+                                    .iter()
+                                    .filter(|p| {
+                                        p.schema_name == table.schema && p.table_name == table.name
+                                    })
+                                    .count()
+                            })
+                            .unwrap_or_default();
+
+                        if policies > 0 {
+                            let policies = policies.to_string();
+                            diagnostic = diagnostic.note(markup! {
+                                "The "{policies.as_str()}" existing policies of the table will no longer be enforced."
+                            });
+                        }
+                    }
+
+                    diagnostics.push(diagnostic);
+                }
+            }
+            _ => {}
+        }
+
+        diagnostics
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct RequireRowLevelSecurityOptions {
+    /// Schemas that are exposed via an API. Defaults to `["public"]`.
+    pub exposed_schemas: Vec<String>,
+}
+
+impl Default for RequireRowLevelSecurityOptions {
+    fn default() -> Self {
+        Self {
+            exposed_schemas: vec!["public".to_string()],
+        }
+    }
+}
+
+impl RequireRowLevelSecurityOptions {
+    fn is_exposed(&self, relation: &RangeVar) -> bool {
+        let schema = if relation.schemaname.is_empty() {
+            "public"
+        } else {
+            relation.schemaname.as_str()
+        };
+
+        self.exposed_schemas.iter().any(|s| s == schema)
+    }
+}
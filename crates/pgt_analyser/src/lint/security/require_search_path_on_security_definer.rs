@@ -0,0 +1,134 @@
+use pgt_analyse::{Rule, RuleDiagnostic, RuleSource, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::Node;
+
+declare_lint_rule! {
+    /// `SECURITY DEFINER` functions must set a `search_path`.
+    ///
+    /// A `SECURITY DEFINER` function runs with the privileges of its owner.
+    /// Without a fixed `search_path`, a caller can create objects in a schema that comes first in their own `search_path` and make the function use them instead of the intended ones, which lets them run arbitrary code with the privileges of the owner.
+    ///
+    /// For `ALTER FUNCTION ... SECURITY DEFINER`, functions that already set a `search_path` are only recognised if a database connection is available.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create function public.delete_user(id uuid) returns void language sql security definer as $$ delete from users where users.id = delete_user.id $$;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create function public.delete_user(id uuid) returns void language sql security definer set search_path = '' as $$ delete from public.users where users.id = delete_user.id $$;
+    /// ```
+    ///
+    pub RequireSearchPathOnSecurityDefiner {
+        version: "next",
+        name: "requireSearchPathOnSecurityDefiner",
+        recommended: true,
+        sources: &[RuleSource::Splinter("0011_function_search_path_mutable")],
+    }
+}
+
+impl Rule for RequireSearchPathOnSecurityDefiner {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let is_unsafe = match &ctx.stmt() {
+            pgt_query_ext::NodeEnum::CreateFunctionStmt(stmt) => {
+                is_security_definer(&stmt.options) && !sets_search_path(&stmt.options)
+            }
+            pgt_query_ext::NodeEnum::AlterFunctionStmt(stmt) => {
+                is_security_definer(&stmt.actions)
+                    && !sets_search_path(&stmt.actions)
+                    && !has_search_path_in_schema(ctx, stmt.func.as_ref())
+            }
+            _ => false,
+        };
+
+        if is_unsafe {
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The SECURITY DEFINER function does not set a search_path."
+                    },
+                )
+                .detail(
+                    None,
+                    "Add SET search_path = '' and use schema-qualified names in the function body.",
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+fn is_security_definer(options: &[Node]) -> bool {
+    options.iter().any(|o| {
+        matches!(
+            &o.node,
+            Some(pgt_query_ext::NodeEnum::DefElem(d))
+                if d.defname == "security"
+                    && matches!(
+                        d.arg.as_ref().and_then(|a| a.node.as_ref()),
+                        Some(pgt_query_ext::NodeEnum::Boolean(b)) if b.boolval
+                    )
+        )
+    })
+}
+
+fn sets_search_path(options: &[Node]) -> bool {
+    options.iter().any(|o| {
+        matches!(
+            &o.node,
+            Some(pgt_query_ext::NodeEnum::DefElem(d))
+                if d.defname == "set"
+                    && matches!(
+                        d.arg.as_ref().and_then(|a| a.node.as_ref()),
+                        Some(pgt_query_ext::NodeEnum::VariableSetStmt(s)) if s.name == "search_path"
+                    )
+        )
+    })
+}
+
+/// Returns true if the altered function already sets a `search_path` in the connected database.
+fn has_search_path_in_schema(
+    ctx: &RuleContext<RequireSearchPathOnSecurityDefiner>,
+    func: Option<&pgt_query_ext::protobuf::ObjectWithArgs>,
+) -> bool {
+    let (Some(schema_cache), Some(func)) = (ctx.schema_cache(), func) else {
+        return false;
+    };
+
+    let names = func
+        .objname
+        .iter()
+        .filter_map(|n| match &n.node {
+            Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let (schema, name) = match names.as_slice() {
+        [name] => ("public", *name),
+        [schema, name] => (*schema, *name),
+        _ => return false,
+    };
+
+    schema_cache
+        .functions
+        .iter()
+        .filter(|f| f.schema == schema && f.name == name)
+        .any(|f| {
+            f.definition
+                .as_deref()
+                .is_some_and(|d| d.to_lowercase().contains("set search_path"))
+        })
+}
@@ -0,0 +1,57 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+declare_lint_rule! {
+    /// `UPDATE` policies should have a `WITH CHECK` expression.
+    ///
+    /// The `USING` expression of an `UPDATE` policy only restricts which rows can be updated, and is also used to check the new row if there is no `WITH CHECK` expression.
+    /// Stating the `WITH CHECK` expression explicitly makes it obvious which values a row may be updated to, e.g. that a user cannot transfer the ownership of a row to someone else.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create policy "owners can update" on posts for update using (auth.uid() = author_id);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create policy "owners can update" on posts for update using (auth.uid() = author_id) with check (auth.uid() = author_id);
+    /// ```
+    ///
+    pub RequireUpdatePolicyWithCheck {
+        version: "next",
+        name: "requireUpdatePolicyWithCheck",
+        recommended: true,
+    }
+}
+
+impl Rule for RequireUpdatePolicyWithCheck {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if let pgt_query_ext::NodeEnum::CreatePolicyStmt(stmt) = &ctx.stmt() {
+            if stmt.cmd_name == "update" && stmt.with_check.is_none() {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "The UPDATE policy has no WITH CHECK expression."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Add a WITH CHECK expression to state which values a row may be updated to.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,116 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::Node;
+
+declare_lint_rule! {
+    /// Calls to `auth` functions and `current_setting()` in policies should be wrapped in a `SELECT`.
+    ///
+    /// Postgres evaluates a function call in a policy expression once for every row.
+    /// If the call is wrapped in a sub-select such as `(select auth.uid())`, the planner runs it once per statement as an initplan and caches the result, which can make queries on large tables a lot faster.
+    ///
+    /// This is safe for `auth.uid()`, `auth.jwt()`, `auth.role()`, `auth.email()` and `current_setting()`, because their result does not depend on the row.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create policy "owners can read" on posts for select using (auth.uid() = author_id);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create policy "owners can read" on posts for select using ((select auth.uid()) = author_id);
+    /// ```
+    ///
+    pub WrapAuthFunctionsInSelect {
+        version: "next",
+        name: "wrapAuthFunctionsInSelect",
+        recommended: false,
+    }
+}
+
+impl Rule for WrapAuthFunctionsInSelect {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let (qual, with_check) = match &ctx.stmt() {
+            pgt_query_ext::NodeEnum::CreatePolicyStmt(stmt) => {
+                (stmt.qual.as_deref(), stmt.with_check.as_deref())
+            }
+            pgt_query_ext::NodeEnum::AlterPolicyStmt(stmt) => {
+                (stmt.qual.as_deref(), stmt.with_check.as_deref())
+            }
+            _ => return diagnostics,
+        };
+
+        if [qual, with_check]
+            .into_iter()
+            .flatten()
+            .any(has_unwrapped_auth_call)
+        {
+            diagnostics.push(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    None,
+                    markup! {
+                        "The policy calls an auth function for every row."
+                    },
+                )
+                .detail(
+                    None,
+                    "Wrap the call in a sub-select, e.g. (select auth.uid()), so that it is evaluated once per statement.",
+                ),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+const AUTH_FUNCTIONS: &[&str] = &["uid", "jwt", "role", "email"];
+
+/// Returns true if the expression calls an auth function outside of a sub-select.
+pub(crate) fn has_unwrapped_auth_call(node: &Node) -> bool {
+    match &node.node {
+        // calls within a sub-select are evaluated once as an initplan
+        Some(pgt_query_ext::NodeEnum::SubLink(_)) => false,
+        Some(pgt_query_ext::NodeEnum::FuncCall(f)) => {
+            let names = f
+                .funcname
+                .iter()
+                .filter_map(|n| match &n.node {
+                    Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            let is_auth_call = match names.as_slice() {
+                ["auth", name] => AUTH_FUNCTIONS.contains(name),
+                ["current_setting"] | ["pg_catalog", "current_setting"] => true,
+                _ => false,
+            };
+
+            is_auth_call || f.args.iter().any(has_unwrapped_auth_call)
+        }
+        Some(pgt_query_ext::NodeEnum::AExpr(e)) => [e.lexpr.as_deref(), e.rexpr.as_deref()]
+            .into_iter()
+            .flatten()
+            .any(has_unwrapped_auth_call),
+        Some(pgt_query_ext::NodeEnum::BoolExpr(e)) => e.args.iter().any(has_unwrapped_auth_call),
+        Some(pgt_query_ext::NodeEnum::TypeCast(c)) => {
+            c.arg.as_deref().is_some_and(has_unwrapped_auth_call)
+        }
+        Some(pgt_query_ext::NodeEnum::NullTest(t)) => {
+            t.arg.as_deref().is_some_and(has_unwrapped_auth_call)
+        }
+        Some(pgt_query_ext::NodeEnum::CoalesceExpr(e)) => {
+            e.args.iter().any(has_unwrapped_auth_call)
+        }
+        Some(pgt_query_ext::NodeEnum::List(l)) => l.items.iter().any(has_unwrapped_auth_call),
+        _ => false,
+    }
+}
@@ -0,0 +1,13 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use pgt_analyse::declare_lint_group;
+pub mod ban_json_prefer_jsonb;
+pub mod ban_money_type;
+pub mod naming_convention;
+pub mod prefer_bigint_over_int;
+pub mod prefer_identity_over_serial;
+pub mod prefer_text;
+pub mod prefer_timestamptz;
+pub mod require_create_if_not_exists;
+pub mod require_drop_if_exists;
+declare_lint_group! { pub Style { name : "style" , rules : [self :: ban_json_prefer_jsonb :: BanJsonPreferJsonb , self :: ban_money_type :: BanMoneyType , self :: naming_convention :: NamingConvention , self :: prefer_bigint_over_int :: PreferBigintOverInt , self :: prefer_identity_over_serial :: PreferIdentityOverSerial , self :: prefer_text :: PreferText , self :: prefer_timestamptz :: PreferTimestamptz , self :: require_create_if_not_exists :: RequireCreateIfNotExists , self :: require_drop_if_exists :: RequireDropIfExists ,] } }
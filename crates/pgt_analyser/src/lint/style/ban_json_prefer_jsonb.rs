@@ -0,0 +1,74 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::column_types::{ColumnTypeOptions, column_types};
+
+declare_lint_rule! {
+    /// Prefer `jsonb` over `json`.
+    ///
+    /// `json` stores an exact copy of the input text, which has to be parsed again on every access.
+    /// `jsonb` is stored in a decomposed binary format, is faster to process, and supports indexing.
+    ///
+    /// Only use `json` if the exact formatting or key order of the input has to be preserved.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table events (payload json);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table events (payload jsonb);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `allowedTables` to skip columns of some tables, either as `table` or as `schema.table`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "allowedTables": ["legacy_users"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub BanJsonPreferJsonb {
+        version: "next",
+        name: "banJsonPreferJsonb",
+        recommended: false,
+    }
+}
+
+impl Rule for BanJsonPreferJsonb {
+    type Options = ColumnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for column in column_types(ctx.stmt(), ctx.schema_cache()) {
+            if ctx.options().is_allowed(column.table) {
+                continue;
+            }
+
+            if column.name() == Some("json") {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Prefer jsonb over json."
+                        },
+                    )
+                    .detail(None, "jsonb is faster to process and supports indexing."),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
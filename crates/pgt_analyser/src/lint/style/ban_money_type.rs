@@ -0,0 +1,77 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::column_types::{ColumnTypeOptions, column_types};
+
+declare_lint_rule! {
+    /// Do not use the `money` type.
+    ///
+    /// `money` has a fixed fractional precision that depends on the `lc_monetary` setting of the database, and does not store a currency.
+    /// Its output format also depends on the locale.
+    ///
+    /// Use `numeric` instead, and store the currency in a separate column if needed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table orders (total money);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table orders (total numeric(12, 2), currency text);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `allowedTables` to skip columns of some tables, either as `table` or as `schema.table`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "allowedTables": ["legacy_users"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub BanMoneyType {
+        version: "next",
+        name: "banMoneyType",
+        recommended: false,
+    }
+}
+
+impl Rule for BanMoneyType {
+    type Options = ColumnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for column in column_types(ctx.stmt(), ctx.schema_cache()) {
+            if ctx.options().is_allowed(column.table) {
+                continue;
+            }
+
+            if column.name() == Some("money") {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Do not use the money type."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Its precision and format depend on the locale. Use numeric instead.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,602 @@
+use biome_deserialize_macros::Deserializable;
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::{AlterTableType, ConstrType, KeywordKind, Node, ObjectType};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Names of database objects should follow a consistent naming convention.
+    ///
+    /// The rule checks the names of tables, columns, indexes, constraints, functions, triggers and policies that are created or renamed.
+    /// By default, all names except policy names must be in `snake_case`.
+    ///
+    /// Regardless of the configured convention, names are reported if they reach the limit of 63 bytes, because Postgres silently truncates longer names, and if they can only be used when quoted, e.g. because they contain uppercase letters or are reserved keywords.
+    /// Policy names are only referenced in DDL, so they are allowed to need quotes.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table "UserAccounts" (id bigint primary key);
+    /// ```
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column "select" text;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table user_accounts (id bigint primary key, display_name text);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Each kind of object can be configured separately with `tables`, `columns`, `indexes`, `constraints`, `foreignKeys`, `functions`, `triggers` and `policies`.
+    /// Foreign keys use the convention for `constraints` unless `foreignKeys` is configured.
+    ///
+    /// A convention consists of:
+    ///
+    /// - `case`: one of `snakeCase`, `camelCase` or `pascalCase`.
+    /// - `plurality`: either `singular` or `plural`. Only the last word of the name is checked, using a simple heuristic for English words.
+    /// - `pattern`: a regular expression that the name must match. `<table>` is replaced with the name of the table, and `<columns>` with the names of the columns joined by `_`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "tables": { "case": "snakeCase", "plurality": "plural" },
+    ///         "indexes": { "case": "snakeCase", "pattern": "^idx_<table>_<columns>$" },
+    ///         "foreignKeys": { "case": "snakeCase", "pattern": "^fk_" }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NamingConvention {
+        version: "next",
+        name: "namingConvention",
+        recommended: false,
+    }
+}
+
+/// Postgres truncates identifiers that are longer than this.
+///
+/// The truncation already happens in the parser, so names that reach the limit may have been longer.
+const MAX_IDENTIFIER_BYTES: usize = 63;
+
+impl Rule for NamingConvention {
+    type Options = NamingConventionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for name in collect_names(ctx.stmt()) {
+            let convention = ctx.options().convention(name.kind);
+            let kind = name.kind.label();
+            let value = name.name;
+
+            if value.len() >= MAX_IDENTIFIER_BYTES {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "The "{kind}" name "<Emphasis>{value}</Emphasis>" reaches the limit of 63 bytes."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Postgres truncates longer names to 63 bytes, so the object may get a different name than the one specified.",
+                    ),
+                );
+            }
+
+            let allows_quotes = name.kind == Kind::Policy
+                || convention
+                    .case
+                    .is_some_and(|c| matches!(c, Case::CamelCase | Case::PascalCase));
+
+            if !allows_quotes && needs_quoting(value) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "The "{kind}" name "<Emphasis>{value}</Emphasis>" can only be used when quoted."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use lowercase letters, digits and underscores, and avoid reserved keywords, so that the name can be used without quotes.",
+                    ),
+                );
+
+                // the other checks would only report the same problem again
+                continue;
+            }
+
+            if let Some(case) = convention.case {
+                if !case.matches(value) {
+                    let case = case.label();
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            None,
+                            markup! {
+                                "The "{kind}" name "<Emphasis>{value}</Emphasis>" is not in "{case}"."
+                            },
+                        )
+                        .detail(None, "Rename it to follow the configured naming convention."),
+                    );
+                }
+            }
+
+            if let Some(plurality) = convention.plurality {
+                if is_plural(last_word(value)) != (plurality == Plurality::Plural) {
+                    let plurality = plurality.label();
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            None,
+                            markup! {
+                                "The "{kind}" name "<Emphasis>{value}</Emphasis>" should be "{plurality}"."
+                            },
+                        )
+                        .detail(None, "Rename it to follow the configured naming convention."),
+                    );
+                }
+            }
+
+            if let Some(pattern) = convention.pattern.as_deref() {
+                // the pattern cannot be checked if the statement does not contain the table or columns
+                if (pattern.contains("<table>") && name.table.is_none())
+                    || (pattern.contains("<columns>") && name.columns.is_none())
+                {
+                    continue;
+                }
+
+                let pattern = pattern
+                    .replace("<table>", &regex::escape(name.table.unwrap_or_default()))
+                    .replace(
+                        "<columns>",
+                        &regex::escape(&name.columns.unwrap_or_default().join("_")),
+                    );
+
+                // invalid patterns are ignored, there is no way to report configuration errors here
+                if Regex::new(&pattern).is_ok_and(|re| !re.is_match(value)) {
+                    let pattern = pattern.as_str();
+                    diagnostics.push(
+                        RuleDiagnostic::new(
+                            rule_category!(),
+                            None,
+                            markup! {
+                                "The "{kind}" name "<Emphasis>{value}</Emphasis>" does not match the pattern "<Emphasis>{pattern}</Emphasis>"."
+                            },
+                        )
+                        .detail(None, "Rename it to follow the configured naming convention."),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NamingConventionOptions {
+    /// The convention for table names.
+    pub tables: NameConvention,
+    /// The convention for column names.
+    pub columns: NameConvention,
+    /// The convention for index names.
+    pub indexes: NameConvention,
+    /// The convention for constraint names.
+    pub constraints: NameConvention,
+    /// The convention for foreign key names. Defaults to the convention for constraints.
+    pub foreign_keys: Option<NameConvention>,
+    /// The convention for function names.
+    pub functions: NameConvention,
+    /// The convention for trigger names.
+    pub triggers: NameConvention,
+    /// The convention for policy names.
+    pub policies: NameConvention,
+}
+
+impl Default for NamingConventionOptions {
+    fn default() -> Self {
+        Self {
+            tables: NameConvention::default(),
+            columns: NameConvention::default(),
+            indexes: NameConvention::default(),
+            constraints: NameConvention::default(),
+            foreign_keys: None,
+            functions: NameConvention::default(),
+            triggers: NameConvention::default(),
+            // policy names are usually sentences such as "users can read their own posts"
+            policies: NameConvention {
+                case: None,
+                plurality: None,
+                pattern: None,
+            },
+        }
+    }
+}
+
+impl NamingConventionOptions {
+    fn convention(&self, kind: Kind) -> &NameConvention {
+        match kind {
+            Kind::Table => &self.tables,
+            Kind::Column => &self.columns,
+            Kind::Index => &self.indexes,
+            Kind::Constraint => &self.constraints,
+            Kind::ForeignKey => self.foreign_keys.as_ref().unwrap_or(&self.constraints),
+            Kind::Function => &self.functions,
+            Kind::Trigger => &self.triggers,
+            Kind::Policy => &self.policies,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct NameConvention {
+    /// The case the name must be in.
+    pub case: Option<Case>,
+    /// Whether the last word of the name must be singular or plural.
+    pub plurality: Option<Plurality>,
+    /// A regular expression the name must match. `<table>` is replaced with the name of the
+    /// table, and `<columns>` with the names of the columns joined by `_`.
+    pub pattern: Option<String>,
+}
+
+impl Default for NameConvention {
+    fn default() -> Self {
+        Self {
+            case: Some(Case::SnakeCase),
+            plurality: None,
+            pattern: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::enum_variant_names)]
+pub enum Case {
+    SnakeCase,
+    CamelCase,
+    PascalCase,
+}
+
+impl Case {
+    fn label(self) -> &'static str {
+        match self {
+            Case::SnakeCase => "snake_case",
+            Case::CamelCase => "camelCase",
+            Case::PascalCase => "PascalCase",
+        }
+    }
+
+    fn matches(self, name: &str) -> bool {
+        let Some(first) = name.chars().next() else {
+            return true;
+        };
+
+        match self {
+            Case::SnakeCase => name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'),
+            Case::CamelCase => {
+                first.is_ascii_lowercase() && name.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            Case::PascalCase => {
+                first.is_ascii_uppercase() && name.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum Plurality {
+    Singular,
+    Plural,
+}
+
+impl Plurality {
+    fn label(self) -> &'static str {
+        match self {
+            Plurality::Singular => "singular",
+            Plurality::Plural => "plural",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Table,
+    Column,
+    Index,
+    Constraint,
+    ForeignKey,
+    Function,
+    Trigger,
+    Policy,
+}
+
+impl Kind {
+    fn label(self) -> &'static str {
+        match self {
+            Kind::Table => "table",
+            Kind::Column => "column",
+            Kind::Index => "index",
+            Kind::Constraint => "constraint",
+            Kind::ForeignKey => "foreign key",
+            Kind::Function => "function",
+            Kind::Trigger => "trigger",
+            Kind::Policy => "policy",
+        }
+    }
+}
+
+/// A name that is given to an object by a statement.
+struct ObjectName<'a> {
+    kind: Kind,
+    name: &'a str,
+    /// The table the object belongs to, used for `<table>` in patterns.
+    table: Option<&'a str>,
+    /// The columns the object is defined on, used for `<columns>` in patterns.
+    columns: Option<Vec<&'a str>>,
+}
+
+fn collect_names(stmt: &pgt_query_ext::NodeEnum) -> Vec<ObjectName<'_>> {
+    let mut names = Vec::new();
+
+    match stmt {
+        pgt_query_ext::NodeEnum::CreateStmt(stmt) => {
+            let Some(relation) = stmt.relation.as_ref() else {
+                return names;
+            };
+            let table = relation.relname.as_str();
+
+            names.push(ObjectName {
+                kind: Kind::Table,
+                name: table,
+                table: Some(table),
+                columns: None,
+            });
+
+            for elt in &stmt.table_elts {
+                match &elt.node {
+                    Some(pgt_query_ext::NodeEnum::ColumnDef(col)) => {
+                        push_column_names(&mut names, table, col)
+                    }
+                    Some(pgt_query_ext::NodeEnum::Constraint(c)) => {
+                        push_constraint_name(&mut names, table, c, None)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        pgt_query_ext::NodeEnum::AlterTableStmt(stmt) => {
+            let Some(relation) = stmt.relation.as_ref() else {
+                return names;
+            };
+            let table = relation.relname.as_str();
+
+            for cmd in &stmt.cmds {
+                if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                    match (
+                        cmd.subtype(),
+                        cmd.def.as_ref().and_then(|d| d.node.as_ref()),
+                    ) {
+                        (
+                            AlterTableType::AtAddColumn,
+                            Some(pgt_query_ext::NodeEnum::ColumnDef(col)),
+                        ) => push_column_names(&mut names, table, col),
+                        (
+                            AlterTableType::AtAddConstraint,
+                            Some(pgt_query_ext::NodeEnum::Constraint(c)),
+                        ) => push_constraint_name(&mut names, table, c, None),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        pgt_query_ext::NodeEnum::IndexStmt(stmt) => {
+            // unnamed indexes are named by Postgres
+            if !stmt.idxname.is_empty() {
+                names.push(ObjectName {
+                    kind: Kind::Index,
+                    name: &stmt.idxname,
+                    table: stmt.relation.as_ref().map(|r| r.relname.as_str()),
+                    columns: Some(
+                        stmt.index_params
+                            .iter()
+                            .filter_map(|p| match &p.node {
+                                Some(pgt_query_ext::NodeEnum::IndexElem(e))
+                                    if !e.name.is_empty() =>
+                                {
+                                    Some(e.name.as_str())
+                                }
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                });
+            }
+        }
+        pgt_query_ext::NodeEnum::CreateFunctionStmt(stmt) => {
+            if let Some(name) = stmt.funcname.last().and_then(string_value) {
+                names.push(ObjectName {
+                    kind: Kind::Function,
+                    name,
+                    table: None,
+                    columns: None,
+                });
+            }
+        }
+        pgt_query_ext::NodeEnum::CreateTrigStmt(stmt) => {
+            names.push(ObjectName {
+                kind: Kind::Trigger,
+                name: &stmt.trigname,
+                table: stmt.relation.as_ref().map(|r| r.relname.as_str()),
+                columns: None,
+            });
+        }
+        pgt_query_ext::NodeEnum::CreatePolicyStmt(stmt) => {
+            names.push(ObjectName {
+                kind: Kind::Policy,
+                name: &stmt.policy_name,
+                table: stmt.table.as_ref().map(|r| r.relname.as_str()),
+                columns: None,
+            });
+        }
+        pgt_query_ext::NodeEnum::RenameStmt(stmt) => {
+            let kind = match stmt.rename_type() {
+                ObjectType::ObjectTable => Some(Kind::Table),
+                ObjectType::ObjectColumn => Some(Kind::Column),
+                ObjectType::ObjectIndex => Some(Kind::Index),
+                ObjectType::ObjectTabconstraint => Some(Kind::Constraint),
+                ObjectType::ObjectFunction => Some(Kind::Function),
+                ObjectType::ObjectTrigger => Some(Kind::Trigger),
+                ObjectType::ObjectPolicy => Some(Kind::Policy),
+                _ => None,
+            };
+
+            if let Some(kind) = kind {
+                let table = match kind {
+                    Kind::Table => Some(stmt.newname.as_str()),
+                    // the relation of a renamed index or function is not a table
+                    Kind::Index | Kind::Function => None,
+                    _ => stmt.relation.as_ref().map(|r| r.relname.as_str()),
+                };
+
+                names.push(ObjectName {
+                    kind,
+                    name: &stmt.newname,
+                    table,
+                    columns: None,
+                });
+            }
+        }
+        _ => {}
+    }
+
+    names
+}
+
+fn push_column_names<'a>(
+    names: &mut Vec<ObjectName<'a>>,
+    table: &'a str,
+    col: &'a pgt_query_ext::protobuf::ColumnDef,
+) {
+    names.push(ObjectName {
+        kind: Kind::Column,
+        name: &col.colname,
+        table: Some(table),
+        columns: None,
+    });
+
+    for c in &col.constraints {
+        if let Some(pgt_query_ext::NodeEnum::Constraint(c)) = &c.node {
+            push_constraint_name(names, table, c, Some(vec![col.colname.as_str()]));
+        }
+    }
+}
+
+fn push_constraint_name<'a>(
+    names: &mut Vec<ObjectName<'a>>,
+    table: &'a str,
+    constraint: &'a pgt_query_ext::protobuf::Constraint,
+    column: Option<Vec<&'a str>>,
+) {
+    // unnamed constraints are named by Postgres
+    if constraint.conname.is_empty() {
+        return;
+    }
+
+    let is_foreign_key = constraint.contype() == ConstrType::ConstrForeign;
+
+    let keys = if is_foreign_key {
+        &constraint.fk_attrs
+    } else {
+        &constraint.keys
+    };
+
+    let columns = if keys.is_empty() {
+        column
+    } else {
+        Some(keys.iter().filter_map(string_value).collect())
+    };
+
+    names.push(ObjectName {
+        kind: if is_foreign_key {
+            Kind::ForeignKey
+        } else {
+            Kind::Constraint
+        },
+        name: &constraint.conname,
+        table: Some(table),
+        columns,
+    });
+}
+
+fn string_value(node: &Node) -> Option<&str> {
+    match &node.node {
+        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+        _ => None,
+    }
+}
+
+/// Returns true if the name has to be quoted to be used in SQL.
+fn needs_quoting(name: &str) -> bool {
+    let is_plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '$');
+
+    !is_plain || is_reserved_keyword(name)
+}
+
+fn is_reserved_keyword(name: &str) -> bool {
+    pgt_query_ext::scan(name).is_ok_and(|result| {
+        matches!(
+            result.tokens.as_slice(),
+            [token] if token.keyword_kind() == KeywordKind::ReservedKeyword
+        )
+    })
+}
+
+/// Returns the last word of a name in snake, camel or pascal case.
+fn last_word(name: &str) -> &str {
+    let word = name.rsplit('_').next().unwrap_or(name);
+
+    match word.rfind(|c: char| c.is_ascii_uppercase()) {
+        Some(idx) => &word[idx..],
+        None => word,
+    }
+}
+
+const IRREGULAR_PLURALS: &[&str] = &["people", "children", "men", "women", "data", "media"];
+
+/// A simple heuristic for English words, good enough for most table names.
+fn is_plural(word: &str) -> bool {
+    let word = word.to_ascii_lowercase();
+
+    IRREGULAR_PLURALS.contains(&word.as_str())
+        || (word.ends_with('s')
+            && !word.ends_with("ss")
+            && !word.ends_with("us")
+            && !word.ends_with("is"))
+}
@@ -0,0 +1,87 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::column_types::{ColumnTypeOptions, column_types};
+
+declare_lint_rule! {
+    /// Prefer `bigint` over `integer` and `smallint` for primary keys.
+    ///
+    /// Integer primary keys tend to run out of values on busy tables, and changing the type of a primary key later requires a rewrite of the table and of all referencing tables.
+    /// The additional storage of `bigint` is usually negligible.
+    ///
+    /// Columns that are altered with `ALTER COLUMN TYPE` are only known to be primary keys if a database connection is available.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table users (id integer primary key);
+    /// ```
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table users (id serial, primary key (id));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table users (id bigint primary key);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `allowedTables` to skip columns of some tables, either as `table` or as `schema.table`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "allowedTables": ["legacy_users"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub PreferBigintOverInt {
+        version: "next",
+        name: "preferBigintOverInt",
+        recommended: false,
+    }
+}
+
+impl Rule for PreferBigintOverInt {
+    type Options = ColumnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for column in column_types(ctx.stmt(), ctx.schema_cache()) {
+            if ctx.options().is_allowed(column.table) {
+                continue;
+            }
+
+            if column.is_primary_key && column.name().is_some_and(|n| INT_TYPES.contains(&n)) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Prefer bigint over integer and smallint for primary keys."
+                        },
+                    )
+                    .detail(None, "Integer primary keys can run out of values. Use bigint or bigserial instead."),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+const INT_TYPES: &[&str] = &[
+    "int2",
+    "int4",
+    "smallserial",
+    "serial",
+    "serial2",
+    "serial4",
+];
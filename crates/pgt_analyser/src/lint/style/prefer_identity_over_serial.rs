@@ -0,0 +1,81 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::column_types::{ColumnTypeOptions, column_types};
+
+declare_lint_rule! {
+    /// Prefer identity columns over `serial` types.
+    ///
+    /// Identity columns are part of the SQL standard, and the underlying sequence is owned by the column, so permissions and dropping the table work as expected.
+    /// With `serial`, the sequence has to be managed separately, e.g. when granting access to the table.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table users (id bigserial primary key);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table users (id bigint generated always as identity primary key);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `allowedTables` to skip columns of some tables, either as `table` or as `schema.table`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "allowedTables": ["legacy_users"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub PreferIdentityOverSerial {
+        version: "next",
+        name: "preferIdentityOverSerial",
+        recommended: false,
+    }
+}
+
+impl Rule for PreferIdentityOverSerial {
+    type Options = ColumnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for column in column_types(ctx.stmt(), ctx.schema_cache()) {
+            if ctx.options().is_allowed(column.table) {
+                continue;
+            }
+
+            if column.name().is_some_and(|n| SERIAL_TYPES.contains(&n)) {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Prefer identity columns over serial types."
+                        },
+                    )
+                    .detail(None, "Use GENERATED ALWAYS AS IDENTITY instead."),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
+
+const SERIAL_TYPES: &[&str] = &[
+    "smallserial",
+    "serial",
+    "bigserial",
+    "serial2",
+    "serial4",
+    "serial8",
+];
@@ -0,0 +1,83 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::column_types::{ColumnTypeOptions, column_types};
+
+declare_lint_rule! {
+    /// Prefer `text` over `varchar(n)` and `char(n)`.
+    ///
+    /// `varchar(n)` and `char(n)` are not faster than `text` in Postgres, and changing their length later may require a table rewrite.
+    /// `char(n)` additionally pads values with spaces, which leads to surprising comparisons.
+    ///
+    /// Use `text`, and add a `CHECK` constraint on the length if it has to be limited.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table users (name varchar(255));
+    /// ```
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table users add column code char(2);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table users (name text check (length(name) <= 255));
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `allowedTables` to skip columns of some tables, either as `table` or as `schema.table`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "allowedTables": ["legacy_users"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub PreferText {
+        version: "next",
+        name: "preferText",
+        recommended: false,
+    }
+}
+
+impl Rule for PreferText {
+    type Options = ColumnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for column in column_types(ctx.stmt(), ctx.schema_cache()) {
+            if ctx.options().is_allowed(column.table) {
+                continue;
+            }
+
+            if (column.name() == Some("varchar") && column.has_modifiers())
+                || column.name() == Some("bpchar")
+            {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Prefer text over varchar(n) and char(n)."
+                        },
+                    )
+                    .detail(
+                        None,
+                        "Use text with a CHECK constraint on the length instead.",
+                    ),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
@@ -0,0 +1,76 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+
+use crate::column_types::{ColumnTypeOptions, column_types};
+
+declare_lint_rule! {
+    /// Prefer `timestamptz` over `timestamp`.
+    ///
+    /// `timestamp` stores a date and time without time zone, so the stored value depends on the time zone of the client that wrote it.
+    /// `timestamptz` stores a point in time and converts it to the time zone of the session when it is read.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table events (created_at timestamp);
+    /// ```
+    ///
+    /// ```sql,expect_diagnostic
+    /// alter table events alter column created_at type timestamp without time zone;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table events (created_at timestamptz);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use `allowedTables` to skip columns of some tables, either as `table` or as `schema.table`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "allowedTables": ["legacy_users"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub PreferTimestamptz {
+        version: "next",
+        name: "preferTimestamptz",
+        recommended: false,
+    }
+}
+
+impl Rule for PreferTimestamptz {
+    type Options = ColumnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for column in column_types(ctx.stmt(), ctx.schema_cache()) {
+            if ctx.options().is_allowed(column.table) {
+                continue;
+            }
+
+            if column.name() == Some("timestamp") {
+                diagnostics.push(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        None,
+                        markup! {
+                            "Prefer timestamptz over timestamp."
+                        },
+                    )
+                    .detail(None, "timestamp does not store a time zone, so its values are ambiguous. Use timestamptz instead."),
+                );
+            }
+        }
+
+        diagnostics
+    }
+}
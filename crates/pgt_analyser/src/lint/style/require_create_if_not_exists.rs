@@ -0,0 +1,194 @@
+use biome_deserialize_macros::Deserializable;
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::ObjectType;
+use serde::{Deserialize, Serialize};
+
+declare_lint_rule! {
+    /// Migrations should create objects with `IF NOT EXISTS` or `OR REPLACE`.
+    ///
+    /// A migration that fails halfway cannot always be rolled back, e.g. if it contains statements that cannot run in a transaction.
+    /// If every `CREATE` statement is idempotent, such a migration can simply be run again.
+    ///
+    /// Views, functions and triggers do not support `IF NOT EXISTS` and should use `OR REPLACE` instead.
+    ///
+    /// This rule only applies to migration files within the configured `migrations.migrationsDir`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// create table users (id bigint primary key);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// create table if not exists users (id bigint primary key);
+    /// ```
+    ///
+    /// ```sql
+    /// create or replace view active_users as select id from users where active;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// `objectTypes` configures the kinds of objects that are checked.
+    /// It defaults to all of `table`, `index`, `schema`, `sequence`, `extension`, `view`, `materializedView`, `function` and `trigger`.
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "options": {
+    ///         "objectTypes": ["table", "index"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub RequireCreateIfNotExists {
+        version: "next",
+        name: "requireCreateIfNotExists",
+        recommended: false,
+    }
+}
+
+impl Rule for RequireCreateIfNotExists {
+    type Options = RequireCreateIfNotExistsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !ctx.is_migration() {
+            return diagnostics;
+        }
+
+        let Some((object_type, is_idempotent)) = created_object(ctx.stmt()) else {
+            return diagnostics;
+        };
+
+        if is_idempotent || !ctx.options().object_types.contains(&object_type) {
+            return diagnostics;
+        }
+
+        let kind = object_type.label();
+        let clause = if object_type.supports_or_replace() {
+            "OR REPLACE"
+        } else {
+            "IF NOT EXISTS"
+        };
+
+        diagnostics.push(
+            RuleDiagnostic::new(
+                rule_category!(),
+                None,
+                markup! {
+                    "CREATE "{kind}" without "{clause}" fails if the object already exists."
+                },
+            )
+            .detail(
+                None,
+                "Make the statement idempotent so that the migration can be run again if it fails halfway.",
+            ),
+        );
+
+        diagnostics
+    }
+}
+
+/// Returns the type of the created object and whether the statement is idempotent.
+fn created_object(stmt: &pgt_query_ext::NodeEnum) -> Option<(CreateObjectType, bool)> {
+    match stmt {
+        pgt_query_ext::NodeEnum::CreateStmt(stmt) => {
+            Some((CreateObjectType::Table, stmt.if_not_exists))
+        }
+        pgt_query_ext::NodeEnum::IndexStmt(stmt) => {
+            Some((CreateObjectType::Index, stmt.if_not_exists))
+        }
+        pgt_query_ext::NodeEnum::CreateSchemaStmt(stmt) => {
+            Some((CreateObjectType::Schema, stmt.if_not_exists))
+        }
+        pgt_query_ext::NodeEnum::CreateSeqStmt(stmt) => {
+            Some((CreateObjectType::Sequence, stmt.if_not_exists))
+        }
+        pgt_query_ext::NodeEnum::CreateExtensionStmt(stmt) => {
+            Some((CreateObjectType::Extension, stmt.if_not_exists))
+        }
+        pgt_query_ext::NodeEnum::ViewStmt(stmt) => Some((CreateObjectType::View, stmt.replace)),
+        pgt_query_ext::NodeEnum::CreateTableAsStmt(stmt)
+            if stmt.objtype() == ObjectType::ObjectMatview =>
+        {
+            Some((CreateObjectType::MaterializedView, stmt.if_not_exists))
+        }
+        pgt_query_ext::NodeEnum::CreateFunctionStmt(stmt) => {
+            Some((CreateObjectType::Function, stmt.replace))
+        }
+        pgt_query_ext::NodeEnum::CreateTrigStmt(stmt) => {
+            Some((CreateObjectType::Trigger, stmt.replace))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct RequireCreateIfNotExistsOptions {
+    /// The kinds of objects that must be created idempotently. Defaults to all.
+    pub object_types: Vec<CreateObjectType>,
+}
+
+impl Default for RequireCreateIfNotExistsOptions {
+    fn default() -> Self {
+        Self {
+            object_types: vec![
+                CreateObjectType::Table,
+                CreateObjectType::Index,
+                CreateObjectType::Schema,
+                CreateObjectType::Sequence,
+                CreateObjectType::Extension,
+                CreateObjectType::View,
+                CreateObjectType::MaterializedView,
+                CreateObjectType::Function,
+                CreateObjectType::Trigger,
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CreateObjectType {
+    Table,
+    Index,
+    Schema,
+    Sequence,
+    Extension,
+    View,
+    MaterializedView,
+    Function,
+    Trigger,
+}
+
+impl CreateObjectType {
+    fn label(self) -> &'static str {
+        match self {
+            CreateObjectType::Table => "TABLE",
+            CreateObjectType::Index => "INDEX",
+            CreateObjectType::Schema => "SCHEMA",
+            CreateObjectType::Sequence => "SEQUENCE",
+            CreateObjectType::Extension => "EXTENSION",
+            CreateObjectType::View => "VIEW",
+            CreateObjectType::MaterializedView => "MATERIALIZED VIEW",
+            CreateObjectType::Function => "FUNCTION",
+            CreateObjectType::Trigger => "TRIGGER",
+        }
+    }
+
+    fn supports_or_replace(self) -> bool {
+        matches!(
+            self,
+            CreateObjectType::View | CreateObjectType::Function | CreateObjectType::Trigger
+        )
+    }
+}
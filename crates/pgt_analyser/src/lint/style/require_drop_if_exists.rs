@@ -0,0 +1,88 @@
+use pgt_analyse::{Rule, RuleDiagnostic, context::RuleContext, declare_lint_rule};
+use pgt_console::markup;
+use pgt_query_ext::protobuf::AlterTableType;
+
+declare_lint_rule! {
+    /// Migrations should drop objects with `IF EXISTS`.
+    ///
+    /// A migration that fails halfway cannot always be rolled back, e.g. if it contains statements that cannot run in a transaction.
+    /// If every `DROP` statement is idempotent, such a migration can simply be run again.
+    ///
+    /// This also applies to columns and constraints that are dropped with `ALTER TABLE`.
+    ///
+    /// This rule only applies to migration files within the configured `migrations.migrationsDir`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```sql,expect_diagnostic
+    /// drop index users_email_idx;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```sql
+    /// drop index if exists users_email_idx;
+    /// ```
+    ///
+    /// ```sql
+    /// alter table users drop column if exists bio;
+    /// ```
+    ///
+    pub RequireDropIfExists {
+        version: "next",
+        name: "requireDropIfExists",
+        recommended: false,
+    }
+}
+
+impl Rule for RequireDropIfExists {
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<RuleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !ctx.is_migration() {
+            return diagnostics;
+        }
+
+        match &ctx.stmt() {
+            pgt_query_ext::NodeEnum::DropStmt(stmt) => {
+                if !stmt.missing_ok {
+                    diagnostics.push(missing_if_exists());
+                }
+            }
+            pgt_query_ext::NodeEnum::AlterTableStmt(stmt) => {
+                for cmd in &stmt.cmds {
+                    if let Some(pgt_query_ext::NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                        if matches!(
+                            cmd.subtype(),
+                            AlterTableType::AtDropColumn | AlterTableType::AtDropConstraint
+                        ) && !cmd.missing_ok
+                        {
+                            diagnostics.push(missing_if_exists());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        diagnostics
+    }
+}
+
+fn missing_if_exists() -> RuleDiagnostic {
+    RuleDiagnostic::new(
+        rule_category!(),
+        None,
+        markup! {
+            "DROP without IF EXISTS fails if the object does not exist."
+        },
+    )
+    .detail(
+        None,
+        "Add IF EXISTS so that the migration can be run again if it fails halfway.",
+    )
+}
@@ -0,0 +1,29 @@
+use pgt_query_ext::protobuf::ObjectType;
+
+/// Returns true if the statement acquires a lock on an existing table that blocks reads or
+/// writes, e.g. `ALTER TABLE` or a `CREATE INDEX` that is not built concurrently.
+pub(crate) fn takes_blocking_lock(stmt: &pgt_query_ext::NodeEnum) -> bool {
+    match stmt {
+        pgt_query_ext::NodeEnum::AlterTableStmt(_)
+        | pgt_query_ext::NodeEnum::RenameStmt(_)
+        | pgt_query_ext::NodeEnum::CreateTrigStmt(_)
+        | pgt_query_ext::NodeEnum::CreatePolicyStmt(_)
+        | pgt_query_ext::NodeEnum::AlterPolicyStmt(_)
+        | pgt_query_ext::NodeEnum::TruncateStmt(_)
+        | pgt_query_ext::NodeEnum::ClusterStmt(_)
+        | pgt_query_ext::NodeEnum::ReindexStmt(_) => true,
+        pgt_query_ext::NodeEnum::IndexStmt(stmt) => !stmt.concurrent,
+        pgt_query_ext::NodeEnum::DropStmt(stmt) => {
+            !stmt.concurrent
+                && matches!(
+                    stmt.remove_type(),
+                    ObjectType::ObjectTable
+                        | ObjectType::ObjectIndex
+                        | ObjectType::ObjectMatview
+                        | ObjectType::ObjectTrigger
+                        | ObjectType::ObjectPolicy
+                )
+        }
+        _ => false,
+    }
+}
@@ -1,10 +1,91 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use crate::lint;
+pub type AddingFieldWithVolatileDefault = <lint::safety::adding_field_with_volatile_default::AddingFieldWithVolatileDefault as pgt_analyse::Rule>::Options;
+pub type AddingPrimaryKeyConstraint = <lint::safety::adding_primary_key_constraint::AddingPrimaryKeyConstraint as pgt_analyse::Rule>::Options;
 pub type AddingRequiredField =
     <lint::safety::adding_required_field::AddingRequiredField as pgt_analyse::Rule>::Options;
+pub type AddingStoredGeneratedColumn = <lint::safety::adding_stored_generated_column::AddingStoredGeneratedColumn as pgt_analyse::Rule>::Options;
+pub type AuthRlsInitplan =
+    <lint::schema::auth_rls_initplan::AuthRlsInitplan as pgt_analyse::SchemaRule>::Options;
+pub type BanBetweenWithTimestamps = <lint::correctness::ban_between_with_timestamps::BanBetweenWithTimestamps as pgt_analyse::Rule>::Options;
+pub type BanCluster = <lint::safety::ban_cluster::BanCluster as pgt_analyse::Rule>::Options;
+pub type BanDeleteWithoutWhere =
+    <lint::safety::ban_delete_without_where::BanDeleteWithoutWhere as pgt_analyse::Rule>::Options;
 pub type BanDropColumn =
     <lint::safety::ban_drop_column::BanDropColumn as pgt_analyse::Rule>::Options;
+pub type BanDropFunctionInUse =
+    <lint::safety::ban_drop_function_in_use::BanDropFunctionInUse as pgt_analyse::Rule>::Options;
 pub type BanDropNotNull =
     <lint::safety::ban_drop_not_null::BanDropNotNull as pgt_analyse::Rule>::Options;
+pub type BanDropReferencedColumn = <lint::safety::ban_drop_referenced_column::BanDropReferencedColumn as pgt_analyse::Rule>::Options;
+pub type BanDropReferencedTable =
+    <lint::safety::ban_drop_referenced_table::BanDropReferencedTable as pgt_analyse::Rule>::Options;
 pub type BanDropTable = <lint::safety::ban_drop_table::BanDropTable as pgt_analyse::Rule>::Options;
+pub type BanDynamicSqlConcatenation = <lint::security::ban_dynamic_sql_concatenation::BanDynamicSqlConcatenation as pgt_analyse::Rule>::Options;
+pub type BanEqualsNull =
+    <lint::correctness::ban_equals_null::BanEqualsNull as pgt_analyse::Rule>::Options;
+pub type BanExplicitTransaction = <lint::correctness::ban_explicit_transaction::BanExplicitTransaction as pgt_analyse::Rule>::Options;
+pub type BanImplicitCrossJoin = <lint::correctness::ban_implicit_cross_join::BanImplicitCrossJoin as pgt_analyse::Rule>::Options;
+pub type BanJsonPreferJsonb =
+    <lint::style::ban_json_prefer_jsonb::BanJsonPreferJsonb as pgt_analyse::Rule>::Options;
+pub type BanLargeOffset =
+    <lint::performance::ban_large_offset::BanLargeOffset as pgt_analyse::Rule>::Options;
+pub type BanMixingDdlAndBackfill = <lint::safety::ban_mixing_ddl_and_backfill::BanMixingDdlAndBackfill as pgt_analyse::Rule>::Options;
+pub type BanMoneyType = <lint::style::ban_money_type::BanMoneyType as pgt_analyse::Rule>::Options;
+pub type BanNotInSubquery =
+    <lint::correctness::ban_not_in_subquery::BanNotInSubquery as pgt_analyse::Rule>::Options;
+pub type BanOrderByRandom =
+    <lint::performance::ban_order_by_random::BanOrderByRandom as pgt_analyse::Rule>::Options;
+pub type BanRenameReferencedByTrigger = <lint::safety::ban_rename_referenced_by_trigger::BanRenameReferencedByTrigger as pgt_analyse::Rule>::Options;
+pub type BanSelectStar =
+    <lint::correctness::ban_select_star::BanSelectStar as pgt_analyse::Rule>::Options;
+pub type BanUndeclaredVariables = <lint::correctness::ban_undeclared_variables::BanUndeclaredVariables as pgt_analyse::Rule>::Options;
+pub type BanUnreachableCode =
+    <lint::correctness::ban_unreachable_code::BanUnreachableCode as pgt_analyse::Rule>::Options;
+pub type BanUnrestrictedWritePolicy = <lint::security::ban_unrestricted_write_policy::BanUnrestrictedWritePolicy as pgt_analyse::Rule>::Options;
+pub type BanUnusedVariables =
+    <lint::correctness::ban_unused_variables::BanUnusedVariables as pgt_analyse::Rule>::Options;
+pub type BanUpdateWithoutWhere =
+    <lint::safety::ban_update_without_where::BanUpdateWithoutWhere as pgt_analyse::Rule>::Options;
+pub type BanVacuumFull =
+    <lint::safety::ban_vacuum_full::BanVacuumFull as pgt_analyse::Rule>::Options;
+pub type ChangingColumnType =
+    <lint::safety::changing_column_type::ChangingColumnType as pgt_analyse::Rule>::Options;
+pub type ChangingTablePersistence = <lint::safety::changing_table_persistence::ChangingTablePersistence as pgt_analyse::Rule>::Options;
+pub type ConstraintMissingNotValid = <lint::safety::constraint_missing_not_valid::ConstraintMissingNotValid as pgt_analyse::Rule>::Options;
+pub type DisallowUniqueConstraint = <lint::safety::disallow_unique_constraint::DisallowUniqueConstraint as pgt_analyse::Rule>::Options;
+pub type DuplicateIndex =
+    <lint::schema::duplicate_index::DuplicateIndex as pgt_analyse::SchemaRule>::Options;
+pub type ExtensionInPublic =
+    <lint::schema::extension_in_public::ExtensionInPublic as pgt_analyse::SchemaRule>::Options;
+pub type MultiplePermissivePolicies = <lint::schema::multiple_permissive_policies::MultiplePermissivePolicies as pgt_analyse::SchemaRule>::Options;
+pub type NamingConvention =
+    <lint::style::naming_convention::NamingConvention as pgt_analyse::Rule>::Options;
+pub type PreferBigintOverInt =
+    <lint::style::prefer_bigint_over_int::PreferBigintOverInt as pgt_analyse::Rule>::Options;
+pub type PreferIdentityOverSerial = <lint::style::prefer_identity_over_serial::PreferIdentityOverSerial as pgt_analyse::Rule>::Options;
+pub type PreferText = <lint::style::prefer_text::PreferText as pgt_analyse::Rule>::Options;
+pub type PreferTimestamptz =
+    <lint::style::prefer_timestamptz::PreferTimestamptz as pgt_analyse::Rule>::Options;
+pub type RenamingColumn =
+    <lint::safety::renaming_column::RenamingColumn as pgt_analyse::Rule>::Options;
+pub type RenamingTable =
+    <lint::safety::renaming_table::RenamingTable as pgt_analyse::Rule>::Options;
+pub type RequireConcurrentIndexCreation = <lint::safety::require_concurrent_index_creation::RequireConcurrentIndexCreation as pgt_analyse::Rule>::Options;
+pub type RequireConcurrentIndexDeletion = <lint::safety::require_concurrent_index_deletion::RequireConcurrentIndexDeletion as pgt_analyse::Rule>::Options;
+pub type RequireCreateIfNotExists = <lint::style::require_create_if_not_exists::RequireCreateIfNotExists as pgt_analyse::Rule>::Options;
+pub type RequireDropIfExists =
+    <lint::style::require_drop_if_exists::RequireDropIfExists as pgt_analyse::Rule>::Options;
+pub type RequireLockTimeout =
+    <lint::safety::require_lock_timeout::RequireLockTimeout as pgt_analyse::Rule>::Options;
+pub type RequireReturn =
+    <lint::correctness::require_return::RequireReturn as pgt_analyse::Rule>::Options;
+pub type RequireRowLevelSecurity = <lint::security::require_row_level_security::RequireRowLevelSecurity as pgt_analyse::Rule>::Options;
+pub type RequireSearchPathOnSecurityDefiner = <lint::security::require_search_path_on_security_definer::RequireSearchPathOnSecurityDefiner as pgt_analyse::Rule>::Options;
+pub type RequireStrictSelectInto = <lint::correctness::require_strict_select_into::RequireStrictSelectInto as pgt_analyse::Rule>::Options;
+pub type RequireUpdatePolicyWithCheck = <lint::security::require_update_policy_with_check::RequireUpdatePolicyWithCheck as pgt_analyse::Rule>::Options;
+pub type SecurityDefinerView =
+    <lint::schema::security_definer_view::SecurityDefinerView as pgt_analyse::SchemaRule>::Options;
+pub type UnindexedForeignKeys = <lint::schema::unindexed_foreign_keys::UnindexedForeignKeys as pgt_analyse::SchemaRule>::Options;
+pub type WrapAuthFunctionsInSelect = <lint::security::wrap_auth_functions_in_select::WrapAuthFunctionsInSelect as pgt_analyse::Rule>::Options;
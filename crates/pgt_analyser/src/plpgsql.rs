@@ -0,0 +1,430 @@
+use pgt_query_ext::protobuf::{
+    CreateFunctionStmt, FunctionParameterMode, KeywordKind, Node, Token,
+};
+use pgt_text_size::{TextRange, TextSize};
+use serde_json::Value;
+
+/// The `elog_level` of `RAISE EXCEPTION`. Levels from here on abort the function.
+const ERROR_LEVEL: i64 = 21;
+
+const LOOP_STATEMENTS: &[&str] = &[
+    "PLpgSQL_stmt_loop",
+    "PLpgSQL_stmt_while",
+    "PLpgSQL_stmt_fori",
+    "PLpgSQL_stmt_fors",
+    "PLpgSQL_stmt_forc",
+    "PLpgSQL_stmt_foreach_a",
+    "PLpgSQL_stmt_dynfors",
+];
+
+/// A `CREATE FUNCTION` statement for a PL/pgSQL function.
+pub(crate) struct PlpgsqlFunction<'a> {
+    pub stmt: &'a CreateFunctionStmt,
+    pub body: FunctionBody,
+    /// The `PLpgSQL_function` returned by the PL/pgSQL parser, or its error message.
+    pub parsed: Result<Value, String>,
+}
+
+/// Parses the body of `stmt` with the PL/pgSQL parser.
+///
+/// Returns `None` if `stmt` does not create a PL/pgSQL function. `sql` is the text of `stmt`.
+pub(crate) fn parse_function<'a>(
+    stmt: &'a pgt_query_ext::NodeEnum,
+    sql: &str,
+) -> Option<PlpgsqlFunction<'a>> {
+    let pgt_query_ext::NodeEnum::CreateFunctionStmt(create_fn) = stmt else {
+        return None;
+    };
+
+    if !option_value(&create_fn.options, "language")?.eq_ignore_ascii_case("plpgsql") {
+        return None;
+    }
+
+    let body = FunctionBody::new(sql, &option_value(&create_fn.options, "as")?);
+
+    let parsed = match pgt_query_ext::parse_plpgsql(sql) {
+        Ok(parsed) => parsed
+            .as_array()
+            .and_then(|functions| functions.first())
+            .and_then(|f| f.get("PLpgSQL_function"))
+            .cloned()
+            .ok_or_else(|| "the PL/pgSQL parser returned no function".to_string()),
+        Err(pgt_query_ext::Error::Parse(message)) => Err(message),
+        Err(err) => Err(err.to_string()),
+    };
+
+    Some(PlpgsqlFunction {
+        stmt: create_fn,
+        body,
+        parsed,
+    })
+}
+
+impl PlpgsqlFunction<'_> {
+    /// Returns true if the function must end with a `RETURN <expression>`.
+    pub fn returns_value(&self) -> bool {
+        let Some(return_type) = self.stmt.return_type.as_ref() else {
+            return false;
+        };
+
+        let type_name = return_type.names.last().and_then(|n| match &n.node {
+            Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        });
+
+        !self.stmt.is_procedure
+            && !return_type.setof
+            && !matches!(type_name, Some("void" | "event_trigger"))
+            && !self.parameters().any(|(_, mode)| {
+                matches!(
+                    mode,
+                    FunctionParameterMode::FuncParamOut
+                        | FunctionParameterMode::FuncParamInout
+                        | FunctionParameterMode::FuncParamTable
+                )
+            })
+    }
+
+    /// Returns the named parameters of the function.
+    pub fn parameters(&self) -> impl Iterator<Item = (&str, FunctionParameterMode)> {
+        self.stmt
+            .parameters
+            .iter()
+            .filter_map(|param| match &param.node {
+                Some(pgt_query_ext::NodeEnum::FunctionParameter(p)) if !p.name.is_empty() => {
+                    Some((p.name.as_str(), p.mode()))
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns the `PLpgSQL_var`, `PLpgSQL_rec` and `PLpgSQL_row` datums of the function, indexed by their number.
+    pub fn datums(&self) -> &[Value] {
+        self.parsed
+            .as_ref()
+            .ok()
+            .and_then(|f| f.get("datums"))
+            .and_then(Value::as_array)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the statements of the outermost block.
+    pub fn statements(&self) -> &[Value] {
+        self.parsed
+            .as_ref()
+            .ok()
+            .and_then(|f| f.get("action"))
+            .and_then(node)
+            .map_or(&[], |(_, block)| statements(block, "body"))
+    }
+}
+
+/// A token of a function body.
+pub(crate) struct BodyToken {
+    pub token: SqlToken,
+    /// The line within the body, counting from 1 like the PL/pgSQL parser.
+    pub line: i64,
+}
+
+/// The body of a PL/pgSQL function, split into tokens.
+pub(crate) struct FunctionBody {
+    tokens: Vec<BodyToken>,
+}
+
+impl FunctionBody {
+    fn new(sql: &str, body: &str) -> Self {
+        // the body cannot be found if it contains escaped quotes, in which case we cannot
+        // point at anything within it
+        let Some(offset) = sql.find(body) else {
+            return Self { tokens: vec![] };
+        };
+
+        let offset = TextSize::from(u32::try_from(offset).unwrap_or_default());
+
+        let tokens = tokens(body)
+            .into_iter()
+            .map(|token| {
+                let start = usize::from(token.range.start());
+                let line = 1 + body[..start].matches('\n').count() as i64;
+
+                BodyToken {
+                    token: SqlToken {
+                        range: token.range + offset,
+                        ..token
+                    },
+                    line,
+                }
+            })
+            .collect();
+
+        Self { tokens }
+    }
+
+    pub fn tokens(&self) -> &[BodyToken] {
+        &self.tokens
+    }
+
+    /// Returns the range of the tokens on `line`.
+    pub fn line_range(&self, line: Option<i64>) -> Option<TextRange> {
+        let line = line?;
+
+        let mut on_line = self.tokens.iter().filter(|t| t.line == line);
+        let first = on_line.next()?;
+        let last = on_line.next_back().unwrap_or(first);
+
+        Some(first.token.range.cover(last.token.range))
+    }
+
+    /// Returns the range of the first token on `line` with the normalised `text`,
+    /// or the range of the whole line if there is none.
+    pub fn find_on_line(&self, line: Option<i64>, text: &str) -> Option<TextRange> {
+        self.tokens
+            .iter()
+            .find(|t| Some(t.line) == line && t.token.text == text)
+            .map(|t| t.token.range)
+            .or_else(|| self.line_range(line))
+    }
+}
+
+/// A token of a piece of SQL.
+#[derive(Debug, Clone)]
+pub(crate) struct SqlToken {
+    pub kind: Token,
+    pub is_keyword: bool,
+    /// Keywords and unquoted identifiers are lowercased, and quoted identifiers are unquoted.
+    pub text: String,
+    pub range: TextRange,
+}
+
+impl SqlToken {
+    /// Returns true for identifiers and keywords, which can both name a variable.
+    pub fn is_word(&self) -> bool {
+        self.kind == Token::Ident || self.is_keyword
+    }
+}
+
+/// Splits `sql` into tokens, omitting comments.
+pub(crate) fn tokens(sql: &str) -> Vec<SqlToken> {
+    let Ok(result) = pgt_query_ext::scan(sql) else {
+        return vec![];
+    };
+
+    result
+        .tokens
+        .iter()
+        .filter(|t| !matches!(t.token(), Token::SqlComment | Token::CComment))
+        .filter_map(|t| {
+            let start = u32::try_from(t.start).ok()?;
+            let end = u32::try_from(t.end).ok()?;
+            let range = TextRange::new(start.into(), end.into());
+            let raw = &sql[range];
+
+            let text = if t.token() == Token::Ident && raw.starts_with('"') {
+                raw.trim_matches('"').replace("\"\"", "\"")
+            } else if t.token() == Token::Ident || t.keyword_kind() != KeywordKind::NoKeyword {
+                raw.to_lowercase()
+            } else {
+                raw.to_string()
+            };
+
+            Some(SqlToken {
+                kind: t.token(),
+                is_keyword: t.keyword_kind() != KeywordKind::NoKeyword,
+                text,
+                range,
+            })
+        })
+        .collect()
+}
+
+/// Returns the name and the fields of a node of the PL/pgSQL parser, e.g. `{"PLpgSQL_stmt_if": {...}}`.
+pub(crate) fn node(value: &Value) -> Option<(&str, &Value)> {
+    value
+        .as_object()
+        .and_then(|o| o.iter().next())
+        .map(|(name, fields)| (name.as_str(), fields))
+}
+
+/// Returns the line number of a statement.
+///
+/// Statements that were added by the parser, such as the implicit `RETURN` at the end of a
+/// function, have none.
+pub(crate) fn lineno(fields: &Value) -> Option<i64> {
+    fields.get("lineno").and_then(Value::as_i64)
+}
+
+fn label(fields: &Value) -> Option<&str> {
+    fields.get("label").and_then(Value::as_str)
+}
+
+fn statements<'a>(fields: &'a Value, name: &str) -> &'a [Value] {
+    fields
+        .get(name)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+/// Returns the statement lists nested in a statement, e.g. the branches of an `IF`.
+pub(crate) fn nested_statements<'a>(kind: &str, fields: &'a Value) -> Vec<&'a [Value]> {
+    let nodes = |name: &str| {
+        fields
+            .get(name)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(node)
+            .map(|(_, fields)| fields)
+            .collect::<Vec<_>>()
+    };
+
+    match kind {
+        "PLpgSQL_stmt_block" => {
+            let handlers = fields
+                .get("exceptions")
+                .and_then(node)
+                .map(|(_, exceptions)| {
+                    exceptions
+                        .get("exc_list")
+                        .and_then(Value::as_array)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(node)
+                        .map(|(_, handler)| statements(handler, "action"))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            std::iter::once(statements(fields, "body"))
+                .chain(handlers)
+                .collect()
+        }
+        "PLpgSQL_stmt_if" => std::iter::once(statements(fields, "then_body"))
+            .chain(
+                nodes("elsif_list")
+                    .into_iter()
+                    .map(|e| statements(e, "stmts")),
+            )
+            .chain(std::iter::once(statements(fields, "else_body")))
+            .collect(),
+        "PLpgSQL_stmt_case" => nodes("case_when_list")
+            .into_iter()
+            .map(|w| statements(w, "stmts"))
+            .chain(std::iter::once(statements(fields, "else_stmts")))
+            .collect(),
+        _ if LOOP_STATEMENTS.contains(&kind) => vec![statements(fields, "body")],
+        _ => vec![],
+    }
+}
+
+/// Calls `f` for every statement within `stmts`, including nested ones.
+pub(crate) fn visit_statements<'a>(stmts: &'a [Value], f: &mut impl FnMut(&'a str, &'a Value)) {
+    for (kind, fields) in stmts.iter().filter_map(node) {
+        f(kind, fields);
+
+        for nested in nested_statements(kind, fields) {
+            visit_statements(nested, f);
+        }
+    }
+}
+
+/// Returns true if control never reaches the statement after `stmt`.
+pub(crate) fn terminates(stmt: &Value) -> bool {
+    let Some((kind, fields)) = node(stmt) else {
+        return false;
+    };
+
+    match kind {
+        "PLpgSQL_stmt_return" => lineno(fields).is_some(),
+        "PLpgSQL_stmt_raise" => fields
+            .get("elog_level")
+            .and_then(Value::as_i64)
+            .is_some_and(|level| level >= ERROR_LEVEL),
+        // the body and all exception handlers of a block, or all branches of an `IF`,
+        // including the `ELSE` which is empty if there is none
+        "PLpgSQL_stmt_block" | "PLpgSQL_stmt_if" => nested_statements(kind, fields)
+            .iter()
+            .all(|stmts| stmts.iter().any(terminates)),
+        "PLpgSQL_stmt_case" => {
+            let mut branches = nested_statements(kind, fields);
+
+            // a `CASE` without `ELSE` raises an exception if no branch matches
+            if !fields
+                .get("have_else")
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+            {
+                branches.pop();
+            }
+
+            branches.iter().all(|stmts| stmts.iter().any(terminates))
+        }
+        // a plain `LOOP` runs until it is left with `EXIT`
+        "PLpgSQL_stmt_loop" => !exits_loop(statements(fields, "body"), &[], false),
+        _ => false,
+    }
+}
+
+/// Returns true if `stmt` is an unconditional `EXIT` or `CONTINUE`.
+pub(crate) fn is_jump(stmt: &Value) -> bool {
+    node(stmt)
+        .is_some_and(|(kind, fields)| kind == "PLpgSQL_stmt_exit" && fields.get("cond").is_none())
+}
+
+/// Returns true if an `EXIT` within `stmts` leaves the surrounding loop.
+///
+/// `inner_labels` are the labels of the blocks and loops between the loop and `stmts`.
+fn exits_loop(stmts: &[Value], inner_labels: &[&str], in_inner_loop: bool) -> bool {
+    stmts.iter().filter_map(node).any(|(kind, fields)| {
+        if kind == "PLpgSQL_stmt_exit" {
+            if !fields
+                .get("is_exit")
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+            {
+                return false;
+            }
+
+            return match label(fields) {
+                Some(target) => !inner_labels.contains(&target),
+                None => !in_inner_loop,
+            };
+        }
+
+        let mut labels = inner_labels.to_vec();
+        labels.extend(label(fields));
+
+        let in_inner_loop = in_inner_loop || LOOP_STATEMENTS.contains(&kind);
+
+        nested_statements(kind, fields)
+            .into_iter()
+            .any(|nested| exits_loop(nested, &labels, in_inner_loop))
+    })
+}
+
+/// Returns the text of the expression in the field `name`.
+pub(crate) fn expression<'a>(fields: &'a Value, name: &str) -> Option<&'a str> {
+    fields
+        .get(name)?
+        .get("PLpgSQL_expr")?
+        .get("query")?
+        .as_str()
+}
+
+/// Returns the value of a `DefElem` option of type string, e.g. `language` or `as`.
+fn option_value(options: &[Node], name: &str) -> Option<String> {
+    options.iter().find_map(|o| match &o.node {
+        Some(pgt_query_ext::NodeEnum::DefElem(d)) if d.defname == name => {
+            match d.arg.as_ref().and_then(|a| a.node.as_ref()) {
+                Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.clone()),
+                Some(pgt_query_ext::NodeEnum::List(l)) => {
+                    l.items.iter().find_map(|item| match &item.node {
+                        Some(pgt_query_ext::NodeEnum::String(s)) => Some(s.sval.clone()),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,27 @@
+use pgt_query_ext::protobuf::RangeVar;
+use pgt_text_size::{TextRange, TextSize};
+
+/// Returns the range of a token of `len` bytes that starts at `location`.
+///
+/// The parser only records where a node starts, so the caller has to know the length of the
+/// token that should be highlighted, e.g. from the keyword or name the node starts with.
+/// The range is relative to the statement, and `None` if the parser did not record a location.
+pub(crate) fn token_range(location: i32, len: usize) -> Option<TextRange> {
+    let start = u32::try_from(location).ok()?;
+    let len = u32::try_from(len).ok()?;
+
+    Some(TextRange::at(TextSize::from(start), TextSize::from(len)))
+}
+
+/// Returns the range of the name of a table, e.g. `public.users`.
+///
+/// The length is derived from the name, so the range is slightly off for quoted names.
+pub(crate) fn relation_range(relation: &RangeVar) -> Option<TextRange> {
+    let len = if relation.schemaname.is_empty() {
+        relation.relname.len()
+    } else {
+        relation.schemaname.len() + 1 + relation.relname.len()
+    };
+
+    token_range(relation.location, len)
+}
@@ -0,0 +1,59 @@
+use pgt_analyse::RuleDiagnostic;
+use pgt_console::markup;
+use pgt_diagnostics::Severity;
+use pgt_query_ext::protobuf::RangeVar;
+use pgt_schema_cache::{SchemaCache, Table};
+
+/// Tables below this size are rewritten quickly enough that holding an
+/// `ACCESS EXCLUSIVE` lock for the duration of the rewrite is usually acceptable.
+pub(crate) const SMALL_TABLE_BYTES: i64 = 10 * 1024 * 1024;
+
+/// Looks up the table referenced by `relation` in the schema cache.
+///
+/// Unqualified names are resolved against the `public` schema.
+pub(crate) fn find_table<'a>(
+    schema_cache: Option<&'a SchemaCache>,
+    relation: Option<&RangeVar>,
+) -> Option<&'a Table> {
+    let schema_cache = schema_cache?;
+    let relation = relation?;
+
+    let schema = if relation.schemaname.is_empty() {
+        "public"
+    } else {
+        relation.schemaname.as_str()
+    };
+
+    schema_cache
+        .tables
+        .iter()
+        .find(|t| t.schema == schema && t.name == relation.relname)
+}
+
+/// Adjusts a diagnostic about a table rewrite to the size of the affected table.
+///
+/// If the table is known and small, the severity is lowered to `Information`.
+/// Otherwise, the size of the table is added as a note.
+pub(crate) fn with_table_size(
+    diagnostic: RuleDiagnostic,
+    schema_cache: Option<&SchemaCache>,
+    relation: Option<&RangeVar>,
+) -> RuleDiagnostic {
+    let Some(table) = find_table(schema_cache, relation) else {
+        return diagnostic;
+    };
+
+    let size = table.size.as_str();
+
+    if table.bytes < SMALL_TABLE_BYTES {
+        diagnostic
+            .note(markup! {
+                "The table is only "{size}" large, so the rewrite should be quick."
+            })
+            .severity_override(Severity::Information)
+    } else {
+        diagnostic.note(markup! {
+            "The table is "{size}" large. Rewriting it will block reads and writes for a long time."
+        })
+    }
+}
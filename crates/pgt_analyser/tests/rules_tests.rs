@@ -1,10 +1,20 @@
 use core::slice;
-use std::{fmt::Write, fs::read_to_string, path::Path};
+use std::{
+    fmt::Write,
+    fs::read_to_string,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::Path,
+};
 
-use pgt_analyse::{AnalyserOptions, AnalysisFilter, RuleDiagnostic, RuleFilter};
-use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext};
+use pgt_analyse::{
+    AnalyserOptions, AnalysisFilter, RuleDiagnostic, RuleFilter, context::FileContext,
+};
+use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext, METADATA};
+use pgt_configuration::{Rules, push_to_analyser_rules};
 use pgt_console::StdDisplay;
 use pgt_diagnostics::PrintDiagnostic;
+use pgt_schema_cache::SchemaCache;
+use sqlx::{Executor, PgPool, postgres::PgConnectOptions};
 
 pgt_test_macros::gen_tests! {
   "tests/specs/**/*.sql",
@@ -25,14 +35,56 @@ fn rule_test(full_path: &'static str, _: &str, _: &str) {
     let query =
         read_to_string(full_path).unwrap_or_else(|_| panic!("Failed to read file: {} ", full_path));
 
-    let ast = pgt_query_ext::parse(&query).expect("failed to parse SQL");
-    let options = AnalyserOptions::default();
+    // specs of schema rules are applied to a test database as a whole, other specs can set it up
+    // in a `-- setup` section before the statements under test
+    let (setup, sql) = if group == "schema" {
+        (Some(query.as_str()), "")
+    } else {
+        split_setup(&query)
+    };
+    let schema_cache = setup.map(|setup| load_schema_cache(full_path, setup));
+
+    let ranges = pgt_statement_splitter::split(sql)
+        .expect("failed to split SQL")
+        .ranges;
+
+    let stmts = ranges
+        .iter()
+        .map(|range| pgt_query_ext::parse(&sql[*range]).expect("failed to parse SQL"))
+        .collect::<Vec<_>>();
+
+    let options = rule_options(input_file, &group, &rule);
     let analyser = Analyser::new(AnalyserConfig {
         options: &options,
         filter,
     });
 
-    let results = analyser.run(AnalyserContext { root: &ast });
+    // specs are analysed like migration files, so that rules for migrations can be tested
+    let is_migration = !query.lines().any(|line| line.contains("not_a_migration"));
+    let mut results = stmts
+        .iter()
+        .zip(&ranges)
+        .enumerate()
+        .flat_map(|(stmt_index, (ast, range))| {
+            analyser.run(AnalyserContext {
+                root: ast,
+                sql: &sql[*range],
+                schema_cache: schema_cache.as_ref(),
+                file: FileContext {
+                    stmts: &stmts,
+                    stmt_index,
+                    is_migration,
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if group == "schema" {
+        let mut schema_results = analyser.run_schema(schema_cache.as_ref().unwrap());
+        // the objects in the schema cache are not ordered
+        schema_results.sort_by_cached_key(|d| StdDisplay(PrintDiagnostic::simple(d)).to_string());
+        results.extend(schema_results);
+    }
 
     let mut snapshot = String::new();
     write_snapshot(&mut snapshot, query.as_str(), results.as_slice());
@@ -48,6 +100,82 @@ fn rule_test(full_path: &'static str, _: &str, _: &str) {
     expectation.assert(results.as_slice());
 }
 
+/// Splits a spec into the section between `-- setup` and `-- end_setup`, if any, and the
+/// statements under test
+fn split_setup(query: &str) -> (Option<&str>, &str) {
+    let Some(start) = query.find("-- setup\n") else {
+        return (None, query);
+    };
+    let end = query[start..]
+        .find("-- end_setup")
+        .map(|end| start + end)
+        .expect("Close the setup with `-- end_setup`");
+    let rest = &query[end + "-- end_setup".len()..];
+
+    (Some(&query[start..end]), rest)
+}
+
+/// Applies the setup to a new database and loads its schema cache. The database is created
+/// next to the one in `DATABASE_URL` and dropped afterwards.
+fn load_schema_cache(full_path: &str, setup: &str) -> SchemaCache {
+    dotenv::dotenv().ok();
+    let url = std::env::var("DATABASE_URL").expect("Specs with a setup need a DATABASE_URL");
+    let options: PgConnectOptions = url.parse().expect("Invalid DATABASE_URL");
+
+    let mut hasher = DefaultHasher::new();
+    full_path.hash(&mut hasher);
+    let database = format!("pgt_spec_{}_{:x}", std::process::id(), hasher.finish());
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to create runtime");
+
+    runtime.block_on(async {
+        let admin = PgPool::connect_with(options.clone())
+            .await
+            .expect("Failed to connect to the database");
+        admin
+            .execute(format!("create database {database}").as_str())
+            .await
+            .expect("Failed to create test database");
+
+        let result = async {
+            let pool = PgPool::connect_with(options.database(&database)).await?;
+            pool.execute(setup).await?;
+            let schema_cache = SchemaCache::load(&pool).await;
+            pool.close().await;
+            schema_cache
+        }
+        .await;
+
+        admin
+            .execute(format!("drop database {database} with (force)").as_str())
+            .await
+            .expect("Failed to drop test database");
+
+        result.expect("Failed to set up test database")
+    })
+}
+
+/// Reads the options of the rule from `<spec>.options.json` next to the spec, if any
+fn rule_options(input_file: &Path, group: &str, rule: &str) -> AnalyserOptions {
+    let mut options = AnalyserOptions::default();
+
+    let Ok(content) = read_to_string(input_file.with_extension("options.json")) else {
+        return options;
+    };
+    let rule_options: serde_json::Value =
+        serde_json::from_str(&content).expect("Invalid options file");
+    let rules: Rules = serde_json::from_value(serde_json::json!({
+        group: { rule: { "level": "error", "options": rule_options } }
+    }))
+    .expect("Invalid options for the rule");
+    push_to_analyser_rules(&rules, &METADATA, &mut options.rules);
+
+    options
+}
+
 fn parse_test_path(path: &Path) -> (String, String, String) {
     let mut comps: Vec<&str> = path
         .components()
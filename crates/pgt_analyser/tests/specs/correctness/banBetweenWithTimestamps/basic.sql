@@ -0,0 +1,2 @@
+-- expect_only_lint/correctness/banBetweenWithTimestamps
+select * from orders where created_at between now() - interval '7 days' and now();
\ No newline at end of file
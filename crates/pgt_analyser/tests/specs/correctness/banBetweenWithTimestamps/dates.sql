@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+select * from orders where created_on between '2024-01-01'::date and '2024-01-31'::date;
+select * from orders where created_at between '2024-01-01' and '2024-01-31';
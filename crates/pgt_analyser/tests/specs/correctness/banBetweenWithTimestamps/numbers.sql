@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+select * from orders where amount between 10 and 20;
\ No newline at end of file
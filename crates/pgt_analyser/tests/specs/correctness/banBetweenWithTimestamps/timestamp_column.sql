@@ -0,0 +1,5 @@
+-- setup
+create table orders (id bigint primary key, created_at timestamptz not null);
+-- end_setup
+-- expect_only_lint/correctness/banBetweenWithTimestamps
+select * from orders where created_at between '2024-01-01' and '2024-01-31';
@@ -0,0 +1,3 @@
+select * from orders where created_at between '2024-01-01'::timestamp and '2024-02-01'::timestamp;
+select * from orders where created_at between '2024-01-01 00:00' and '2024-01-31 23:59';
+select * from orders where created_at not between current_timestamp - interval '1 hour' and current_timestamp;
@@ -0,0 +1,2 @@
+-- expect_only_lint/correctness/banEqualsNull
+select * from users where email = null;
\ No newline at end of file
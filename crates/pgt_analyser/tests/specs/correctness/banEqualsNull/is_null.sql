@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+update users set email = null where email is not null;
\ No newline at end of file
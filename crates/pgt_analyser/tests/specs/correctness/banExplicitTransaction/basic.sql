@@ -0,0 +1,3 @@
+-- expect_only_lint/correctness/banExplicitTransaction
+create table users (id bigint primary key);
+commit;
\ No newline at end of file
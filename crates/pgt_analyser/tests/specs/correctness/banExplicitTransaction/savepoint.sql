@@ -0,0 +1,4 @@
+-- expect_no_diagnostics
+savepoint before_users;
+create table users (id bigint primary key);
+release savepoint before_users;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/correctness/banImplicitCrossJoin
+select * from users u, orders o where u.id = 1;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- expect_no_diagnostics
+select * from users;
+select * from a, b, c where a.id = b.a_id and c.b_id = b.id;
+select * from a join b on a.id = b.a_id, c where c.id = b.c_id;
+select * from users, orders where user_id = id;
+select * from users u, lateral (select * from orders o where o.user_id = u.id) x;
+select * from users u, unnest(u.tags) t;
+select * from users cross join orders;
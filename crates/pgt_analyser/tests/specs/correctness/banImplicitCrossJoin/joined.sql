@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+select * from users u, orders o where o.user_id = u.id;
\ No newline at end of file
@@ -0,0 +1,5 @@
+select * from users, orders;
+select * from a, b, c where a.id = b.a_id;
+select * from a, b, c, d where a.id = b.a_id and c.id = d.c_id;
+select * from a join b on a.id = b.a_id, c;
+select * from (select * from a, b) x;
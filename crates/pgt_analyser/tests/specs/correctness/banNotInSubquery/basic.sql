@@ -0,0 +1,2 @@
+-- expect_only_lint/correctness/banNotInSubquery
+select * from users where id not in (select user_id from banned_users);
\ No newline at end of file
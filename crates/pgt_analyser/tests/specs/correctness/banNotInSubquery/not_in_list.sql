@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+select * from users where id not in (1, 2, 3);
\ No newline at end of file
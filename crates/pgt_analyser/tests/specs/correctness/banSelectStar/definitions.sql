@@ -0,0 +1,2 @@
+create view user_orders as select u.*, o.id from users u join orders o on o.user_id = u.id;
+create materialized view all_users as select * from users;
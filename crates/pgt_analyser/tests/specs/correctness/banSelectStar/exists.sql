@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create view users_with_orders as select id, email from users u where exists (select * from orders o where o.user_id = u.id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/correctness/banSelectStar
+create function user_emails() returns table (id bigint, email text) language sql as $$ select * from users $$;
\ No newline at end of file
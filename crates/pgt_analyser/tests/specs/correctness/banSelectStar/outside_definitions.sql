@@ -0,0 +1,4 @@
+-- expect_no_diagnostics
+select * from users;
+create table active_users as select * from users;
+create view user_count as select count(*) from users;
@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+create function all_users() returns setof users language sql as $$ select * from users $$;
+create function all_orders() returns setof orders language plpgsql as $$ begin return query select * from orders; end $$;
@@ -0,0 +1,2 @@
+-- expect_only_lint/correctness/banSelectStar
+create view active_users as select * from users where is_active;
\ No newline at end of file
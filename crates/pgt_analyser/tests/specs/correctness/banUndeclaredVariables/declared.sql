@@ -0,0 +1,7 @@
+-- expect_no_diagnostics
+create function set_updated_at() returns trigger language plpgsql as $$
+begin
+    new.updated_at := now();
+    return new;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- expect_only_lint/correctness/banUndeclaredVariables
+create function next_count(current int) returns int language plpgsql as $$
+declare
+    result int;
+begin
+    reslt := current + 1;
+    return result;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- expect_only_lint/correctness/banUndeclaredVariables
+create function set_updated_at() returns trigger language plpgsql as $$
+begin
+    newrow.updated_at := now();
+    return new;
+end;
+$$;
\ No newline at end of file
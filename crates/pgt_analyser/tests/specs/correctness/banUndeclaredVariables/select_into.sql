@@ -0,0 +1,7 @@
+-- expect_only_lint/correctness/banUndeclaredVariables
+create function count_users() returns bigint language plpgsql as $$
+begin
+    select count(*) into total from users;
+    return total;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- expect_only_lint/correctness/banUnreachableCode
+create function drain_queue() returns void language plpgsql as $$
+begin
+    loop
+        delete from queue where id = (select min(id) from queue);
+        exit;
+        perform pg_sleep(1);
+    end loop;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- expect_only_lint/correctness/banUnreachableCode
+create function archive_user(id bigint) returns void language plpgsql as $$
+begin
+    raise exception 'archiving is disabled';
+    update users set archived = true where users.id = archive_user.id;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- expect_only_lint/correctness/banUnreachableCode
+create function active_users() returns bigint language plpgsql as $$
+declare
+    total bigint;
+begin
+    select count(*) into total from users;
+    return total;
+    raise notice 'counted % users', total;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,14 @@
+-- expect_no_diagnostics
+create function drain_queue() returns void language plpgsql as $$
+begin
+    loop
+        delete from queue where id = (select min(id) from queue);
+        exit when not found;
+        perform pg_sleep(1);
+    end loop;
+    if (select count(*) from queue) > 0 then
+        return;
+    end if;
+    raise notice 'queue is empty';
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- expect_only_lint/correctness/banUnusedVariables
+create function reset_counter() returns void language plpgsql as $$
+declare
+    counter int;
+begin
+    counter := 1;
+end;
+$$;
\ No newline at end of file
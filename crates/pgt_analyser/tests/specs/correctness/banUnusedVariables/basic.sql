@@ -0,0 +1,10 @@
+-- expect_only_lint/correctness/banUnusedVariables
+create function count_users() returns bigint language plpgsql as $$
+declare
+    total bigint;
+    unused text;
+begin
+    select count(*) into total from users;
+    return total;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,15 @@
+-- expect_no_diagnostics
+create function notify_all(channel text) returns void language plpgsql as $$
+declare
+    c cursor for select id from users;
+    user_id bigint;
+begin
+    for i in 1..3 loop
+        perform pg_notify(channel, 'ping');
+    end loop;
+    open c;
+    fetch c into user_id;
+    close c;
+    perform pg_notify(channel, user_id::text);
+end;
+$$;
\ No newline at end of file
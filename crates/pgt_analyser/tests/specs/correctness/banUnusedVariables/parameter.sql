@@ -0,0 +1,6 @@
+-- expect_only_lint/correctness/banUnusedVariables
+create function greet(name text, greeting text) returns text language plpgsql as $$
+begin
+    return 'hello ' || name;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,12 @@
+-- expect_no_diagnostics
+create function sign(n int) returns int language plpgsql as $$
+begin
+    if n > 0 then
+        return 1;
+    elsif n < 0 then
+        return -1;
+    else
+        return 0;
+    end if;
+end;
+$$;
\ No newline at end of file
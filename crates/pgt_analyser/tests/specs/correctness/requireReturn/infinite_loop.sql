@@ -0,0 +1,13 @@
+-- expect_no_diagnostics
+create function next_id() returns bigint language plpgsql as $$
+declare
+    candidate bigint;
+begin
+    loop
+        candidate := floor(random() * 1000000);
+        if not exists (select from users where id = candidate) then
+            return candidate;
+        end if;
+    end loop;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- expect_only_lint/correctness/requireReturn
+create function sign(n int) returns int language plpgsql as $$
+begin
+    if n > 0 then
+        return 1;
+    elsif n < 0 then
+        return -1;
+    end if;
+end;
+$$;
\ No newline at end of file
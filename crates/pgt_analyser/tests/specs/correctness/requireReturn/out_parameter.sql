@@ -0,0 +1,6 @@
+-- expect_no_diagnostics
+create function user_stats(user_id bigint, out post_count bigint) language plpgsql as $$
+begin
+    select count(*) into post_count from posts where author_id = user_id;
+end;
+$$;
\ No newline at end of file
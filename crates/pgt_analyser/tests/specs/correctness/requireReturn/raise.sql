@@ -0,0 +1,9 @@
+-- expect_no_diagnostics
+create function user_email(user_id bigint) returns text language plpgsql as $$
+begin
+    return (select email from users where id = user_id);
+exception
+    when others then
+        raise exception 'user % not found', user_id;
+end;
+$$;
\ No newline at end of file
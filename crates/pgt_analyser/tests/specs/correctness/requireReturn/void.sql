@@ -0,0 +1,6 @@
+-- expect_no_diagnostics
+create function touch_user(user_id bigint) returns void language plpgsql as $$
+begin
+    update users set updated_at = now() where id = user_id;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- expect_no_diagnostics
+create function count_users() returns bigint language plpgsql as $$
+declare
+    total bigint;
+begin
+    select count(*) into total from users;
+    return total;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,12 @@
+-- expect_no_diagnostics
+create function user_email(user_id bigint) returns text language plpgsql as $$
+declare
+    result text;
+begin
+    select email into result from users where id = user_id;
+    if not found then
+        raise exception 'user % not found', user_id;
+    end if;
+    return result;
+end;
+$$;
\ No newline at end of file
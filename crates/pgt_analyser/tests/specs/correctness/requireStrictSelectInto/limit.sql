@@ -0,0 +1,9 @@
+-- expect_no_diagnostics
+create function latest_email() returns text language plpgsql as $$
+declare
+    result text;
+begin
+    select email into result from users order by created_at desc limit 1;
+    return result;
+end;
+$$;
\ No newline at end of file
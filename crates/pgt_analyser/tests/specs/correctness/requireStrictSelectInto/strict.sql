@@ -0,0 +1,9 @@
+-- expect_no_diagnostics
+create function user_email(user_id bigint) returns text language plpgsql as $$
+declare
+    result text;
+begin
+    select email into strict result from users where id = user_id;
+    return result;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/performance/banLargeOffset
+select * from events order by id limit 50 offset 100000;
\ No newline at end of file
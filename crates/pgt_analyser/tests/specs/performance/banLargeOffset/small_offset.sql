@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+select * from events order by id limit 50 offset 100;
\ No newline at end of file
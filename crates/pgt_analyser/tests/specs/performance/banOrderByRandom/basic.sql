@@ -0,0 +1,2 @@
+-- expect_only_lint/performance/banOrderByRandom
+select * from products order by random() limit 1;
\ No newline at end of file
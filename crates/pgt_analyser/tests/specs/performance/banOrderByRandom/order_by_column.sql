@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+select * from products order by created_at desc limit 1;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingFieldWithVolatileDefault
+alter table users add column token uuid default gen_random_uuid();
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users add column active boolean default true;
\ No newline at end of file
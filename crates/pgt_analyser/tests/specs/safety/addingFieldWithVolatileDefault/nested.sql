@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingFieldWithVolatileDefault
+alter table users add column score int default (random() * 100)::int;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingFieldWithVolatileDefault
+alter table users add column seq bigserial;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/addingPrimaryKeyConstraint
+alter table items
+add primary key (id);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/addingPrimaryKeyConstraint
+alter table items
+add column id serial primary key;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+alter table items
+add constraint items_pk primary key using index items_pk;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/addingStoredGeneratedColumn
+alter table users add column full_name text generated always as (first_name || ' ' || last_name) stored;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users add column full_name text;
\ No newline at end of file
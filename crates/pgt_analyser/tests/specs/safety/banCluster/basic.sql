@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/banCluster
+cluster users using users_created_at_idx;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/banDeleteWithoutWhere
+delete from sessions;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+delete from sessions where expires_at < now();
\ No newline at end of file
@@ -0,0 +1,14 @@
+-- setup
+create sequence orders_seq;
+create table orders (id bigint);
+create function next_order_number() returns bigint language sql as $$ select nextval('orders_seq') $$;
+create function next_order_number(seq text) returns bigint language sql as $$ select nextval(seq::regclass) $$;
+create function create_order() returns void language plpgsql as $$
+begin
+  insert into orders values (next_order_number());
+end
+$$;
+-- end_setup
+drop function next_order_number();
+drop function public.next_order_number(text);
+drop function if exists next_order_number() cascade;
@@ -0,0 +1,14 @@
+-- setup
+create table orders (id bigint, next_order_number bigint);
+create function next_order_number() returns bigint language sql as $$ select 1::bigint $$;
+create function create_order() returns void language plpgsql as $$
+begin
+  insert into orders (next_order_number) values (1);
+end
+$$;
+-- end_setup
+-- expect_no_diagnostics
+drop function next_order_number();
+drop function create_order();
+drop function next_order_number(int, int);
+drop function private.next_order_number();
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users drop column email;
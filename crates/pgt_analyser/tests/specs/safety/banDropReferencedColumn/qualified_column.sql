@@ -0,0 +1,9 @@
+-- setup
+create table users (id bigint primary key, name text, email text);
+create table accounts (id bigint primary key, owner_id bigint, name text);
+create function name_accounts() returns void language sql as $$
+  update accounts set name = u.email from users u where u.id = accounts.owner_id;
+$$;
+-- end_setup
+-- expect_only_lint/safety/banDropReferencedColumn
+alter table users drop column email;
@@ -0,0 +1,16 @@
+-- setup
+create table users (id bigint primary key, email text, name text, created_at timestamptz);
+create table accounts (id bigint primary key, name text);
+create schema private;
+create table private.users (email text);
+create view active_users as select id, email from users;
+create function greet(user_id bigint) returns void language plpgsql as $$
+begin
+  perform name from users where id = user_id;
+end
+$$;
+-- end_setup
+-- expect_no_diagnostics
+alter table users drop column created_at;
+alter table private.users drop column email;
+alter table accounts drop column name;
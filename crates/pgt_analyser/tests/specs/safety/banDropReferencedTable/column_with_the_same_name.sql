@@ -0,0 +1,7 @@
+-- setup
+create table users (id bigint primary key);
+create table stats (users bigint);
+create function total_users() returns bigint language sql as $$ select sum(users) from stats $$;
+-- end_setup
+-- expect_no_diagnostics
+drop table users;
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+drop table users;
@@ -0,0 +1,15 @@
+-- setup
+create table users (id bigint unique, tenant_id bigint, primary key (tenant_id, id));
+create table orders (
+  id bigint primary key,
+  tenant_id bigint,
+  user_id bigint,
+  constraint orders_user_fkey foreign key (tenant_id, user_id) references users (tenant_id, id)
+);
+create table accounts (id bigint primary key);
+create schema private;
+create table private.users (id bigint);
+-- end_setup
+drop table users;
+drop table if exists public.users cascade;
+drop table users, accounts;
@@ -0,0 +1,7 @@
+-- setup
+create table users (id bigint primary key);
+create table stats (users bigint);
+create function user_count() returns bigint language sql as $$ select count(*) from public.users $$;
+-- end_setup
+-- expect_only_lint/safety/banDropReferencedTable
+drop table users;
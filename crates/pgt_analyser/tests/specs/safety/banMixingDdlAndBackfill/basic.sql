@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/banMixingDdlAndBackfill
+alter table users add column active boolean;
+update users set active = true;
\ No newline at end of file
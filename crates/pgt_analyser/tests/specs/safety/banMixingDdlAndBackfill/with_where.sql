@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+alter table users add column active boolean;
+update users set active = true where id < 1000;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+alter table users rename column updated_at to modified_at;
@@ -0,0 +1,17 @@
+-- setup
+create table users (id bigint primary key, email text, updated_at timestamptz);
+create table accounts (id bigint primary key, updated_at timestamptz);
+create table audit (id bigint);
+create function touch() returns trigger language plpgsql as $$
+begin
+  new.updated_at := now();
+  insert into audit values (new.id);
+  return new;
+end
+$$;
+create trigger touch_users before update on users for each row execute function touch();
+-- end_setup
+-- expect_no_diagnostics
+alter table users rename column email to email_address;
+alter table accounts rename column updated_at to modified_at;
+alter table users rename to members;
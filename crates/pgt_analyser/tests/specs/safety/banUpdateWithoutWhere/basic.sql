@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/banUpdateWithoutWhere
+update users set is_active = false;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+update users set is_active = false where true;
\ No newline at end of file
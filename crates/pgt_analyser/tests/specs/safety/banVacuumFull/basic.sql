@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/banVacuumFull
+vacuum full users;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+vacuum analyze users;
\ No newline at end of file
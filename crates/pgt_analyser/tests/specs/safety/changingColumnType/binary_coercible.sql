@@ -0,0 +1,8 @@
+-- setup
+create table users (id bigint primary key, email varchar(50));
+-- end_setup
+-- expect_no_diagnostics
+alter table users alter column email type text;
+alter table users alter column email type varchar;
+alter table users alter column email type varchar(100);
+alter table public.users alter column email type varchar(50);
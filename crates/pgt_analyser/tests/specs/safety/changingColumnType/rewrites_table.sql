@@ -0,0 +1,7 @@
+-- setup
+create table users (id bigint primary key, email varchar(50));
+-- end_setup
+alter table users alter column email type varchar(20);
+alter table users alter column email type int using email::int;
+alter table users alter column email type text[];
+alter table other alter column email type text;
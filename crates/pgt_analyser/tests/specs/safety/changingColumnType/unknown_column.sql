@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/changingColumnType
+alter table users alter column email type text;
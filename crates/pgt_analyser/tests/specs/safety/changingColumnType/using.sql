@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/changingColumnType
+alter table users alter column age type int using age::int;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/safety/changingTablePersistence
+alter table events set logged;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/constraintMissingNotValid
+alter table orders
+add constraint positive_total check (total > 0);
\ No newline at end of file
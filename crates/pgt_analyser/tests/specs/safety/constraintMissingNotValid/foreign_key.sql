@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/constraintMissingNotValid
+alter table orders
+add constraint orders_user_fk foreign key (user_id) references users (id);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/disallowUniqueConstraint
+alter table users
+add column email text unique;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/disallowUniqueConstraint
+alter table users
+add constraint users_email_key unique (email);
\ No newline at end of file
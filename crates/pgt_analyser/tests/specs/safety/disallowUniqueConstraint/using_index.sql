@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+alter table users
+add constraint users_email_key unique using index users_email_idx;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/renamingColumn
+alter table users
+rename column email to email_address;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/renamingTable
+alter table users
+rename to customers;
\ No newline at end of file
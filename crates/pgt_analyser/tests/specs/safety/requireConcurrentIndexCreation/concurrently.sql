@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create index concurrently users_email_idx on users (email);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+drop index concurrently if exists users_email_idx;
\ No newline at end of file
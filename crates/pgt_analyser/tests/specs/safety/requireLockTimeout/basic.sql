@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/requireLockTimeout
+alter table users add column bio text;
+create index users_bio_idx on users (bio);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- expect_only_lint/safety/requireLockTimeout
+set lock_timeout = 0;
+alter table users add column bio text;
@@ -0,0 +1,4 @@
+-- expect_no_diagnostics
+create table posts (id bigint primary key);
+create index concurrently users_bio_idx on users (bio);
+drop function if exists do_something;
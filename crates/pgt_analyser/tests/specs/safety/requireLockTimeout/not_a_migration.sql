@@ -0,0 +1,4 @@
+-- not_a_migration
+-- expect_no_diagnostics
+alter table users add column bio text;
+create index users_bio_idx on users (bio);
@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+set local lock_timeout to 2000;
+alter table users add column bio text;
@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+set statement_timeout = '5min';
+alter table users add column bio text;
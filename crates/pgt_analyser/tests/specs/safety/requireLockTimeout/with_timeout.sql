@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+set lock_timeout = '2s';
+alter table users add column bio text;
\ No newline at end of file
@@ -0,0 +1,5 @@
+create schema auth;
+create function auth.uid() returns uuid language sql stable as $$ select null::uuid $$;
+create table posts (id bigint primary key, author_id uuid, tenant_id uuid, is_published boolean);
+create policy owners on posts for select using (auth.uid() = author_id);
+create policy tenants on posts for insert with check (current_setting('app.tenant')::uuid = tenant_id);
@@ -0,0 +1,6 @@
+-- expect_no_diagnostics
+create schema auth;
+create function auth.uid() returns uuid language sql stable as $$ select null::uuid $$;
+create table posts (id bigint primary key, author_id uuid, tenant_id uuid, is_published boolean);
+create policy owners on posts for update using ((select auth.uid()) = author_id) with check ((select auth.uid()) = author_id);
+create policy published on posts for select using (is_published);
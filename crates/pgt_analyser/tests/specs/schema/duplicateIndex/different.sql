@@ -0,0 +1,5 @@
+-- expect_no_diagnostics
+create table users (id bigint primary key, email text, is_active boolean);
+create index users_id_email_idx on users (id, email);
+create index users_email_id_idx on users (email, id);
+create index users_active_id_idx on users (id) where is_active;
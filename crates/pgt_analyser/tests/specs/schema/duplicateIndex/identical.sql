@@ -0,0 +1,4 @@
+-- expect_only_lint/schema/duplicateIndex
+create table users (id bigint primary key, email text);
+create index users_id_idx on users (id);
+create index users_id_idx1 on users (id);
@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+create schema extensions;
+create extension pgcrypto schema extensions;
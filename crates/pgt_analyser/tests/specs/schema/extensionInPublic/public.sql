@@ -0,0 +1,4 @@
+-- expect_only_lint/schema/extensionInPublic
+create schema extensions;
+create extension pg_trgm schema public;
+create extension pgcrypto schema extensions;
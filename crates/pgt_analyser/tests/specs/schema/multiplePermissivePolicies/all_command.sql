@@ -0,0 +1,21 @@
+do $$
+begin
+  create role test_login login;
+exception
+  when duplicate_object or unique_violation then
+    null;
+end
+$$;
+do $$
+begin
+  create role test_nologin;
+exception
+  when duplicate_object or unique_violation then
+    null;
+end
+$$;
+create table posts (id bigint primary key, author_id bigint, is_published boolean);
+-- one diagnostic for each action the `ALL` policy overlaps with
+create policy owners on posts for all to test_login using (true);
+create policy readers on posts for select to test_login using (true);
+create policy writers on posts for insert to test_login with check (true);
@@ -0,0 +1,19 @@
+do $$
+begin
+  create role test_login login;
+exception
+  when duplicate_object or unique_violation then
+    null;
+end
+$$;
+do $$
+begin
+  create role test_nologin;
+exception
+  when duplicate_object or unique_violation then
+    null;
+end
+$$;
+create table posts (id bigint primary key, author_id bigint, is_published boolean);
+create policy owners on posts for select to test_login using (true);
+create policy published on posts for select to public using (is_published);
@@ -0,0 +1,22 @@
+-- expect_no_diagnostics
+do $$
+begin
+  create role test_login login;
+exception
+  when duplicate_object or unique_violation then
+    null;
+end
+$$;
+do $$
+begin
+  create role test_nologin;
+exception
+  when duplicate_object or unique_violation then
+    null;
+end
+$$;
+create table posts (id bigint primary key, author_id bigint, is_published boolean);
+create policy owners on posts for select to test_login using (true);
+create policy anonymous on posts for select to test_nologin using (is_published);
+create policy writers on posts for insert to test_login with check (true);
+create policy tenant on posts as restrictive for select to test_login using (true);
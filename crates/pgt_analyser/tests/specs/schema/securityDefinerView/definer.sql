@@ -0,0 +1,3 @@
+create table users (id bigint primary key, is_active boolean);
+create view active_users as select id from users where is_active;
+create view inactive_users with (security_invoker = false) as select id from users where not is_active;
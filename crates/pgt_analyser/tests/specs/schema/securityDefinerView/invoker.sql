@@ -0,0 +1,6 @@
+-- expect_no_diagnostics
+create schema private;
+create table users (id bigint primary key, is_active boolean);
+create view active_users with (security_invoker = true) as select id from users where is_active;
+create view inactive_users with (security_invoker = on) as select id from users where not is_active;
+create view private.all_users as select id from users;
@@ -0,0 +1,6 @@
+-- expect_no_diagnostics
+create table users (tenant_id bigint, id bigint, primary key (tenant_id, id));
+create table orders (id bigint primary key, tenant_id bigint, user_id bigint, foreign key (tenant_id, user_id) references users);
+create index orders_tenant_id_user_id_idx on orders (tenant_id, user_id);
+create table invoices (id bigint primary key, tenant_id bigint, user_id bigint, foreign key (tenant_id, user_id) references users);
+create index invoices_user_id_tenant_id_idx on invoices (user_id, tenant_id, id);
@@ -0,0 +1,6 @@
+create table users (tenant_id bigint, id bigint, primary key (tenant_id, id));
+create table orders (id bigint primary key, tenant_id bigint, user_id bigint, foreign key (tenant_id, user_id) references users);
+create table invoices (id bigint primary key, tenant_id bigint, user_id bigint, foreign key (tenant_id, user_id) references users);
+create index invoices_tenant_id_idx on invoices (tenant_id);
+create table payments (id bigint, tenant_id bigint, user_id bigint, foreign key (tenant_id, user_id) references users);
+create index payments_idx on payments (id, tenant_id, user_id);
@@ -0,0 +1,6 @@
+-- expect_only_lint/security/banDynamicSqlConcatenation
+create function delete_user(user_name text) returns void language plpgsql as $$
+begin
+    execute concat('delete from users where name = ''', user_name, '''');
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- expect_only_lint/security/banDynamicSqlConcatenation
+create function count_rows(table_name text) returns bigint language plpgsql as $$
+declare
+    result bigint;
+begin
+    execute 'select count(*) from ' || table_name into result;
+    return result;
+end;
+$$;
\ No newline at end of file
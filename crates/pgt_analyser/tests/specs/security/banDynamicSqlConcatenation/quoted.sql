@@ -0,0 +1,6 @@
+-- expect_no_diagnostics
+create function search_users(column_name text, term text) returns setof users language plpgsql as $$
+begin
+    return query execute 'select * from users where ' || quote_ident(column_name) || ' = ' || quote_literal(term);
+end;
+$$;
\ No newline at end of file
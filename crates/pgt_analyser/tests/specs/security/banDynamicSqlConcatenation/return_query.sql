@@ -0,0 +1,6 @@
+-- expect_only_lint/security/banDynamicSqlConcatenation
+create function search_users(column_name text, term text) returns setof users language plpgsql as $$
+begin
+    return query execute 'select * from users where ' || quote_ident(column_name) || ' = ' || term;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- expect_no_diagnostics
+create function count_rows(table_name text, min_id bigint) returns bigint language plpgsql as $$
+declare
+    result bigint;
+begin
+    execute format('select count(*) from %I where id >= $1', table_name) into result using min_id;
+    return result;
+end;
+$$;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/banUnrestrictedWritePolicy
+create policy "anyone" on posts using (true);
\ No newline at end of file
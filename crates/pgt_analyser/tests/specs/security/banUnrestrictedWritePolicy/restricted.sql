@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create policy "owners can delete" on posts for delete using (auth.uid() = author_id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create policy "anyone can read" on posts for select using (true);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/banUnrestrictedWritePolicy
+create policy "anyone can delete" on posts for delete using (true);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/banUnrestrictedWritePolicy
+create policy "anyone can insert" on posts for insert with check (true);
\ No newline at end of file
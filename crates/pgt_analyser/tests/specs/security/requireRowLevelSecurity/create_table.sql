@@ -0,0 +1,2 @@
+-- expect_only_lint/security/requireRowLevelSecurity
+create table public.profiles (id uuid primary key, name text);
\ No newline at end of file
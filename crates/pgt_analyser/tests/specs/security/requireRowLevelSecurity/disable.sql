@@ -0,0 +1,3 @@
+-- expect_only_lint/security/requireRowLevelSecurity
+-- diagnostics: 1
+alter table profiles disable row level security;
\ No newline at end of file
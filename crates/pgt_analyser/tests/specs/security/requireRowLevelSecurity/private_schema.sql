@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table private.audit_log (id bigint primary key);
\ No newline at end of file
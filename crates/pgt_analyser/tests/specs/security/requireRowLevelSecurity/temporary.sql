@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create temporary table scratch (id bigint);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/requireRowLevelSecurity
+create table profiles (id uuid primary key);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/requireSearchPathOnSecurityDefiner
+alter function public.delete_user(uuid) security definer;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/requireSearchPathOnSecurityDefiner
+create function public.delete_user(id uuid) returns void language sql security definer as $$ delete from users where users.id = delete_user.id $$;
\ No newline at end of file
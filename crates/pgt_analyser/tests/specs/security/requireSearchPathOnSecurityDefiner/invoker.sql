@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create function public.count_users() returns bigint language sql as $$ select count(*) from public.users $$;
\ No newline at end of file
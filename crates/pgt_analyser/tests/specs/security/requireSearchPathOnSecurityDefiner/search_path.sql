@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create function public.delete_user(id uuid) returns void language sql security definer set search_path = '' as $$ delete from public.users where users.id = delete_user.id $$;
\ No newline at end of file
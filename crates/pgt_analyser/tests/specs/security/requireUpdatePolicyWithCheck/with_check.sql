@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create policy "owners can update" on posts for update using (auth.uid() = author_id) with check (auth.uid() = author_id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/security/wrapAuthFunctionsInSelect
+create policy "owners can read" on posts for select using (auth.uid() = author_id);
\ No newline at end of file
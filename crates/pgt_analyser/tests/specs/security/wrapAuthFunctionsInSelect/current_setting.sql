@@ -0,0 +1,2 @@
+-- expect_only_lint/security/wrapAuthFunctionsInSelect
+create policy "tenant" on posts using (tenant_id = current_setting('app.tenant_id')::uuid and (select auth.uid()) is not null);
\ No newline at end of file
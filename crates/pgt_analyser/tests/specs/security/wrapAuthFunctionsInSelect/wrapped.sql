@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create policy "owners can read" on posts for select using ((select auth.uid()) = author_id);
\ No newline at end of file
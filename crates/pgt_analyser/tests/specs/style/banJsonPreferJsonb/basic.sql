@@ -0,0 +1,2 @@
+-- expect_only_lint/style/banJsonPreferJsonb
+create table events (payload json);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table events (payload jsonb);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/banMoneyType
+create table orders (total money);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table orders (total numeric(12, 2));
\ No newline at end of file
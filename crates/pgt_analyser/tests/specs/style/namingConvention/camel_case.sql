@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table users ("firstName" text);
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/namingConvention
+create index idx_user_accounts_email_address_user_accounts_email_address_user_accounts_email_address_ on user_accounts (email_address);
\ No newline at end of file
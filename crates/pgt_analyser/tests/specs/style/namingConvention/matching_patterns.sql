@@ -0,0 +1,5 @@
+-- expect_no_diagnostics
+create index idx_users_email on users (email);
+alter table posts add constraint fk_posts_author foreign key (author_id) references users (id);
+-- the columns of a renamed index are unknown
+alter index users_email_idx rename to email_idx;
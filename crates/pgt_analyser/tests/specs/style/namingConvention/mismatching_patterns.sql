@@ -0,0 +1,2 @@
+create index users_email_idx on users (email);
+alter table posts add constraint posts_author_fkey foreign key (author_id) references users (id);
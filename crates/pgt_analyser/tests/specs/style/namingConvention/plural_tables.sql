@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+create table user_accounts (id bigint primary key);
+create table people (id bigint primary key);
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create policy "users can read their own posts" on posts for select using (true);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/namingConvention
+alter table posts add constraint "Posts_Author_FK" foreign key (author_id) references users (id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/namingConvention
+create table "UserAccounts" (id bigint primary key);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/namingConvention
+create table users ("user" text);
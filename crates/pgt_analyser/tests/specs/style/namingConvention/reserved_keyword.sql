@@ -0,0 +1,2 @@
+-- expect_only_lint/style/namingConvention
+alter table users add column "select" text;
\ No newline at end of file
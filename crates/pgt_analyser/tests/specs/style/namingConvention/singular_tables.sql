@@ -0,0 +1,2 @@
+create table user_account (id bigint primary key);
+create table status (id bigint primary key);
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table user_accounts (id bigint primary key, display_name text);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table users (id bigint primary key, age integer);
\ No newline at end of file
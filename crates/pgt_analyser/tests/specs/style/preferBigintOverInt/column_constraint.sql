@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferBigintOverInt
+create table users (id integer primary key);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferBigintOverInt
+create table users (id serial, name text, primary key (id));
\ No newline at end of file
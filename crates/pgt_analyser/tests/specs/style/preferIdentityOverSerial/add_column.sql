@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferIdentityOverSerial
+alter table users add column seq serial;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferIdentityOverSerial
+create table users (id bigserial primary key);
\ No newline at end of file
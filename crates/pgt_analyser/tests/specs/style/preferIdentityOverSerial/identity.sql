@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table users (id bigint generated always as identity primary key);
\ No newline at end of file
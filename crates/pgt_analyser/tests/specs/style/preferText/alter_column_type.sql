@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferText
+alter table users alter column name type varchar(100);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferText
+alter table users add column code char(2);
\ No newline at end of file
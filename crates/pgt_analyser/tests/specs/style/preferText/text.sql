@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table users (name text, nickname varchar);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferText
+create table users (name varchar(255));
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/preferTimestamptz
+create table events (created_at timestamp);
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_no_diagnostics
+create table events (created_at timestamptz, updated_at timestamp with time zone);
\ No newline at end of file
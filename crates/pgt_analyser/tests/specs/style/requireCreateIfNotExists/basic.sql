@@ -0,0 +1,2 @@
+-- expect_only_lint/style/requireCreateIfNotExists
+create table users (id bigint primary key);
\ No newline at end of file
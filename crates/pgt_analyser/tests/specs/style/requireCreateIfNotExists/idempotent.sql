@@ -0,0 +1,5 @@
+-- expect_no_diagnostics
+create table if not exists users (id bigint primary key);
+create index if not exists users_email_idx on users (email);
+create or replace view active_users as select id from users where active;
+create or replace function one() returns int language sql as 'select 1';
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/requireCreateIfNotExists
+create view active_users as select id from users where active;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- expect_only_lint/style/requireDropIfExists
+alter table users drop constraint users_email_key;
\ No newline at end of file
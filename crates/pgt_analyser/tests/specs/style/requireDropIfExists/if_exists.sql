@@ -0,0 +1,3 @@
+-- expect_no_diagnostics
+drop index if exists users_email_idx;
+alter table users drop column if exists bio, drop constraint if exists users_email_key;
\ No newline at end of file
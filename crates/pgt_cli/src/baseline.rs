@@ -0,0 +1,420 @@
+use crate::CliDiagnostic;
+use crate::diagnostics::InvalidBaseline;
+use path_absolutize::Absolutize;
+use pgt_diagnostics::{Error, PrintDescription, Resource};
+use pgt_fs::{FileSystem, FileSystemExt, Fnv128};
+use pgt_text_size::TextRange;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The version of the baseline file, bumped when fingerprints are computed differently
+const BASELINE_VERSION: u32 = 1;
+
+/// The content of a baseline file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<BaselineEntry>,
+}
+
+/// A group of identical diagnostics recorded in the baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct BaselineEntry {
+    /// The category of the diagnostics, e.g. `lint/safety/banDropColumn`
+    pub(crate) category: String,
+    /// The file of the diagnostics relative to the working directory. It's empty for
+    /// diagnostics that belong to the whole database, such as the ones of schema rules.
+    pub(crate) path: String,
+    /// Hash of the category, the path and the statement the diagnostics were emitted for
+    pub(crate) fingerprint: String,
+    /// How many diagnostics share this fingerprint
+    pub(crate) count: u32,
+}
+
+/// Diagnostics recorded in a baseline file.
+///
+/// Diagnostics are identified by their category, their file and the statement they were
+/// emitted for, with all whitespace collapsed. Unlike line numbers, this survives edits
+/// elsewhere in the file and reformatting of the statement itself.
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    /// Entries by fingerprint. When checking against a baseline, `count` holds the number of
+    /// diagnostics that were not matched yet.
+    entries: BTreeMap<String, BaselineEntry>,
+    /// Whether diagnostics are recorded rather than matched
+    recording: bool,
+    /// Whether the schema rules ran, which requires a database connection
+    database_checked: bool,
+    working_directory: Option<PathBuf>,
+}
+
+impl Baseline {
+    /// Creates an empty baseline that records every diagnostic it sees
+    pub(crate) fn recording(working_directory: Option<PathBuf>) -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            recording: true,
+            database_checked: false,
+            working_directory,
+        }
+    }
+
+    /// Reads the baseline file at `path` to match diagnostics against it
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        path: &Path,
+        working_directory: Option<PathBuf>,
+    ) -> Result<Self, CliDiagnostic> {
+        let mut content = String::new();
+        fs.open(path)?.read_to_string(&mut content)?;
+
+        let file: BaselineFile = serde_json::from_str(&content)
+            .map_err(|error| InvalidBaseline::new(path, error.to_string()))?;
+
+        if file.version != BASELINE_VERSION {
+            return Err(InvalidBaseline::new(
+                path,
+                format!(
+                    "Expected version {BASELINE_VERSION}, found version {}. Recreate the baseline with --write-baseline.",
+                    file.version
+                ),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            entries: file
+                .entries
+                .into_iter()
+                .map(|entry| (entry.fingerprint.clone(), entry))
+                .collect(),
+            recording: false,
+            database_checked: false,
+            working_directory,
+        })
+    }
+
+    /// Writes the recorded entries to `path`, sorted so that the file diffs well
+    pub(crate) fn write(&self, fs: &dyn FileSystem, path: &Path) -> Result<(), CliDiagnostic> {
+        let mut entries: Vec<_> = self.entries.values().cloned().collect();
+        entries.sort_by(|a, b| {
+            (&a.path, &a.category, &a.fingerprint).cmp(&(&b.path, &b.category, &b.fingerprint))
+        });
+
+        let content = serde_json::to_string_pretty(&BaselineFile {
+            version: BASELINE_VERSION,
+            entries,
+        })
+        .map_err(|error| InvalidBaseline::new(path, error.to_string()))?;
+
+        fs.create(path)?.set_content(content.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Removes the diagnostics of the file at `path` that are part of the baseline, and
+    /// returns the remaining ones
+    pub(crate) fn filter_file(
+        &mut self,
+        path: &str,
+        content: &str,
+        diagnostics: Vec<Error>,
+    ) -> Vec<Error> {
+        let path = self.relative_path(path);
+        let statements = pgt_statement_splitter::split(content)
+            .map(|result| result.ranges)
+            .unwrap_or_default();
+
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let statement = statement_text(content, &statements, diagnostic.location().span);
+                !self.matches(diagnostic, &path, statement)
+            })
+            .collect()
+    }
+
+    /// Removes the diagnostics of the whole database that are part of the baseline, and
+    /// returns the remaining ones
+    pub(crate) fn filter_database(&mut self, diagnostics: Vec<Error>) -> Vec<Error> {
+        self.database_checked = true;
+
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                // these diagnostics are not bound to a statement, but their message names
+                // the database object they were emitted for
+                let description = PrintDescription(diagnostic).to_string();
+                !self.matches(diagnostic, "", &description)
+            })
+            .collect()
+    }
+
+    /// Removes the diagnostics of the names and the order of the migrations that are part of the
+    /// baseline, and returns the remaining ones
+    pub(crate) fn filter_migrations(&mut self, diagnostics: Vec<Error>) -> Vec<Error> {
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let path = match diagnostic.location().resource {
+                    Some(Resource::File(path)) => self.relative_path(path),
+                    _ => String::new(),
+                };
+                // these diagnostics are about the whole file, so their message takes the place
+                // of the statement
+                let description = PrintDescription(diagnostic).to_string();
+                !self.matches(diagnostic, &path, &description)
+            })
+            .collect()
+    }
+
+    /// Returns the entries that didn't match any diagnostic.
+    ///
+    /// Only entries of files in `checked_paths` are considered, so that running on a subset
+    /// of the files doesn't report the rest of the baseline.
+    pub(crate) fn into_stale_entries<'a>(
+        self,
+        checked_paths: impl IntoIterator<Item = &'a Path>,
+    ) -> Vec<BaselineEntry> {
+        let checked_paths: Vec<_> = checked_paths
+            .into_iter()
+            .map(|path| self.relative_path(&path.to_string_lossy()))
+            .collect();
+
+        self.entries
+            .into_values()
+            .filter(|entry| entry.count > 0)
+            .filter(|entry| {
+                if entry.path.is_empty() {
+                    self.database_checked
+                } else {
+                    checked_paths.contains(&entry.path)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the diagnostic is part of the baseline, recording it first if the
+    /// baseline is being written
+    fn matches(&mut self, diagnostic: &Error, path: &str, statement: &str) -> bool {
+        let Some(category) = diagnostic.category() else {
+            return false;
+        };
+        let fingerprint = fingerprint(category.name(), path, statement);
+
+        if self.recording {
+            self.entries
+                .entry(fingerprint.clone())
+                .or_insert_with(|| BaselineEntry {
+                    category: category.name().to_string(),
+                    path: path.to_string(),
+                    fingerprint,
+                    count: 0,
+                })
+                .count += 1;
+            return true;
+        }
+
+        match self.entries.get_mut(&fingerprint) {
+            Some(entry) if entry.count > 0 => {
+                entry.count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Baselines are shared between machines, so paths are stored relative to the working
+    /// directory and with forward slashes
+    fn relative_path(&self, path: &str) -> String {
+        let relativized = Path::new(path).absolutize().ok().and_then(|resolved| {
+            resolved
+                .strip_prefix(self.working_directory.as_deref()?)
+                .ok()
+                .map(Path::to_path_buf)
+        });
+
+        match relativized {
+            Some(relative) => relative.to_string_lossy().replace('\\', "/"),
+            None => path.replace('\\', "/"),
+        }
+    }
+}
+
+/// Returns the text of the statement that contains `span`, falling back to the text of the
+/// span itself if the file couldn't be split
+fn statement_text<'a>(
+    content: &'a str,
+    statements: &[TextRange],
+    span: Option<TextRange>,
+) -> &'a str {
+    let Some(span) = span else {
+        return "";
+    };
+
+    statements
+        .iter()
+        .find(|range| range.contains_range(span))
+        .or_else(|| statements.iter().find(|range| range.contains(span.start())))
+        .and_then(|range| content.get(std::ops::Range::<usize>::from(*range)))
+        .or_else(|| content.get(std::ops::Range::<usize>::from(span)))
+        .unwrap_or_default()
+}
+
+/// Computes a fingerprint that is stable across whitespace edits and releases.
+fn fingerprint(category: &str, path: &str, statement: &str) -> String {
+    let mut hasher = Fnv128::default();
+    hasher.write(category.as_bytes());
+    hasher.write(path.as_bytes());
+    hasher.write(
+        statement
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .as_bytes(),
+    );
+
+    format!("{:032x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Baseline, fingerprint};
+    use pgt_diagnostics::{Diagnostic, Error};
+    use pgt_fs::MemoryFileSystem;
+    use pgt_text_size::TextRange;
+    use std::path::Path;
+
+    #[derive(Debug, Diagnostic)]
+    #[diagnostic(
+        category = "lint/safety/banDropColumn",
+        message = "Dropping a column may break existing clients."
+    )]
+    struct DropColumnDiagnostic {
+        #[location(span)]
+        span: TextRange,
+    }
+
+    /// Returns a diagnostic spanning the first occurrence of `needle`
+    fn diagnostic_at(content: &str, needle: &str) -> Error {
+        let start = content.find(needle).unwrap();
+        Error::from(DropColumnDiagnostic {
+            span: TextRange::new(
+                (start as u32).into(),
+                ((start + needle.len()) as u32).into(),
+            ),
+        })
+    }
+
+    #[test]
+    fn fingerprint_ignores_whitespace() {
+        assert_eq!(
+            fingerprint(
+                "lint/safety/banDropColumn",
+                "a.sql",
+                "alter table t drop column c;"
+            ),
+            fingerprint(
+                "lint/safety/banDropColumn",
+                "a.sql",
+                "  alter table t\n\tdrop column   c;\n"
+            )
+        );
+    }
+
+    #[test]
+    fn fingerprint_depends_on_rule_path_and_statement() {
+        let fingerprint_a = fingerprint(
+            "lint/safety/banDropColumn",
+            "a.sql",
+            "alter table t drop column c;",
+        );
+
+        assert_ne!(
+            fingerprint_a,
+            fingerprint(
+                "lint/safety/banDropTable",
+                "a.sql",
+                "alter table t drop column c;"
+            )
+        );
+        assert_ne!(
+            fingerprint_a,
+            fingerprint(
+                "lint/safety/banDropColumn",
+                "b.sql",
+                "alter table t drop column c;"
+            )
+        );
+        assert_ne!(
+            fingerprint_a,
+            fingerprint(
+                "lint/safety/banDropColumn",
+                "a.sql",
+                "alter table t drop column d;"
+            )
+        );
+    }
+
+    #[test]
+    fn fingerprint_is_stable() {
+        // changing this breaks existing baseline files and requires a new BASELINE_VERSION
+        assert_eq!(
+            fingerprint(
+                "lint/safety/banDropColumn",
+                "a.sql",
+                "alter table t drop column c;"
+            ),
+            "ac9384c78b0f9bdbe3ba92aa5cd72978"
+        );
+    }
+
+    #[test]
+    fn matches_diagnostics_after_reformatting() {
+        let fs = MemoryFileSystem::default();
+        let baseline_path = Path::new("baseline.json");
+
+        let content = "select 1;\nalter table t drop column c;\nalter table t drop column d;";
+        let mut recording = Baseline::recording(None);
+        let remaining = recording.filter_file(
+            "a.sql",
+            content,
+            vec![
+                diagnostic_at(content, "drop column c"),
+                diagnostic_at(content, "drop column d"),
+            ],
+        );
+        assert!(remaining.is_empty());
+        recording.filter_file(
+            "b.sql",
+            content,
+            vec![diagnostic_at(content, "drop column c")],
+        );
+        recording.write(&fs, baseline_path).unwrap();
+
+        let mut baseline = Baseline::load(&fs, baseline_path, None).unwrap();
+
+        // the first statement moved and was reformatted, the second one was fixed
+        let content = "-- comment\nselect 1;\n\nalter table t\n  drop column c;\nalter table t drop column e;";
+        let remaining = baseline.filter_file(
+            "a.sql",
+            content,
+            vec![
+                diagnostic_at(content, "drop column c"),
+                diagnostic_at(content, "drop column e"),
+            ],
+        );
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(
+            remaining[0].location().span,
+            diagnostic_at(content, "drop column e").location().span
+        );
+
+        // b.sql was not checked, so its entry isn't stale
+        let stale = baseline.into_stale_entries([Path::new("a.sql")]);
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].path, "a.sql");
+        assert_eq!(stale[0].category, "lint/safety/banDropColumn");
+    }
+}
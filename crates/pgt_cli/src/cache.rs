@@ -0,0 +1,240 @@
+use crate::VERSION;
+use pgt_diagnostics::serde::Diagnostic;
+use pgt_fs::{FileSystem, Fnv128, OpenOptions, ensure_cache_dir};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::debug;
+
+/// The most entries a project keeps. Entries of files that weren't checked by the last run are
+/// dropped beyond it.
+const MAX_ENTRIES: usize = 10_000;
+
+/// The content of the cache file of a project
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// The hash of everything the entries depend on, besides the files themselves
+    seed: String,
+    /// The entry of each checked file, keyed by its path
+    files: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    /// The hash of the content of the file
+    key: String,
+    diagnostics: Vec<Diagnostic>,
+    /// Whether the entry was used or created by this run
+    #[serde(skip)]
+    used: bool,
+}
+
+/// The on-disk cache of the diagnostics of each checked file.
+///
+/// Every project, i.e. working directory, has one cache file, with one entry per file. Entries
+/// are keyed by a hash of the path and the content of the file, and of everything else its
+/// diagnostics depend on, i.e. the version of the CLI, the resolved configuration and the schema
+/// of the database. When any of the latter changes, all the entries of the project are dropped.
+/// `clean` removes all of them.
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    file: PathBuf,
+    /// The hash of everything the entries depend on, besides the file itself
+    seed: Fnv128,
+    entries: Mutex<BTreeMap<String, CacheEntry>>,
+}
+
+impl ResultCache {
+    /// The directory of the cache in the cache directory of the user. It's created if it
+    /// doesn't exist, since the cache only writes files.
+    pub(crate) fn default_directory() -> PathBuf {
+        let directory = ensure_cache_dir().join("results");
+        if let Err(error) = std::fs::create_dir_all(&directory) {
+            debug!("Failed to create the result cache directory: {error}");
+        }
+        directory
+    }
+
+    /// Loads the entries of the project from `directory`
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        directory: &Path,
+        configuration: &str,
+        schema_fingerprint: Option<&str>,
+        working_directory: Option<&Path>,
+    ) -> Self {
+        let working_directory = working_directory
+            .map(|path| path.to_string_lossy())
+            .unwrap_or_default();
+
+        let mut seed = Fnv128::default();
+        seed.write(VERSION.as_bytes());
+        seed.write(configuration.as_bytes());
+        seed.write(schema_fingerprint.unwrap_or_default().as_bytes());
+        seed.write(working_directory.as_bytes());
+
+        let mut project = Fnv128::default();
+        project.write(working_directory.as_bytes());
+        let file = directory.join(format!("{:032x}.json", project.finish()));
+
+        let entries = fs
+            .read_file_from_path(&file)
+            .ok()
+            .and_then(|content| {
+                serde_json::from_str::<CacheFile>(&content)
+                    .inspect_err(|error| debug!("Ignoring the invalid result cache: {error}"))
+                    .ok()
+            })
+            .filter(|cache| cache.seed == format!("{:032x}", seed.finish()))
+            .map(|cache| cache.files)
+            .unwrap_or_default();
+
+        Self {
+            file,
+            seed,
+            entries: Mutex::new(entries),
+        }
+    }
+
+    /// Returns the key of the entry of a file
+    pub(crate) fn key(&self, path: &Path, content: &str) -> String {
+        let mut hasher = self.seed.clone();
+        hasher.write(path.to_string_lossy().as_bytes());
+        hasher.write(content.as_bytes());
+        format!("{:032x}", hasher.finish())
+    }
+
+    /// Returns the cached diagnostics of the file, if its entry has the given key
+    pub(crate) fn get(&self, path: &Path, key: &str) -> Option<Vec<Diagnostic>> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries
+            .get_mut(path.to_string_lossy().as_ref())
+            .filter(|entry| entry.key == key)?;
+        entry.used = true;
+        Some(entry.diagnostics.clone())
+    }
+
+    /// Stores the diagnostics of a file, replacing its previous entry
+    pub(crate) fn insert(&self, path: &Path, key: String, diagnostics: Vec<Diagnostic>) {
+        self.entries.lock().unwrap().insert(
+            path.to_string_lossy().to_string(),
+            CacheEntry {
+                key,
+                diagnostics,
+                used: true,
+            },
+        );
+    }
+
+    /// Writes the entries to the cache file of the project. The entries of files that don't
+    /// exist anymore are dropped. Failing to write the file only means the files are checked
+    /// again next time, so errors are only logged.
+    pub(crate) fn persist(self, fs: &dyn FileSystem) {
+        let mut files = self.entries.into_inner().unwrap();
+        files.retain(|path, _| fs.path_exists(Path::new(path)));
+        if files.len() > MAX_ENTRIES {
+            files.retain(|_, entry| entry.used);
+        }
+
+        let cache = CacheFile {
+            seed: format!("{:032x}", self.seed.finish()),
+            files,
+        };
+        let result = serde_json::to_vec(&cache)
+            .map_err(std::io::Error::other)
+            .and_then(|content| {
+                fs.open_with_options(
+                    &self.file,
+                    OpenOptions::default()
+                        .write(true)
+                        .create(true)
+                        .truncate(true),
+                )?
+                .set_content(&content)
+            });
+
+        if let Err(error) = result {
+            debug!("Failed to write the result cache: {error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResultCache;
+    use pgt_fs::{FileSystem, MemoryFileSystem};
+    use std::path::Path;
+
+    fn cache(fs: &dyn FileSystem, configuration: &str, schema: Option<&str>) -> ResultCache {
+        ResultCache::load(
+            fs,
+            Path::new("cache"),
+            configuration,
+            schema,
+            Some(Path::new("/project")),
+        )
+    }
+
+    #[test]
+    fn keys_depend_on_file_configuration_and_schema() {
+        let fs = MemoryFileSystem::default();
+        let key = cache(&fs, "{}", None).key(Path::new("a.sql"), "select 1;");
+
+        assert_eq!(
+            key,
+            cache(&fs, "{}", None).key(Path::new("a.sql"), "select 1;")
+        );
+        assert_ne!(
+            key,
+            cache(&fs, "{}", None).key(Path::new("b.sql"), "select 1;")
+        );
+        assert_ne!(
+            key,
+            cache(&fs, "{}", None).key(Path::new("a.sql"), "select 2;")
+        );
+        assert_ne!(
+            key,
+            cache(&fs, r#"{"linter":{}}"#, None).key(Path::new("a.sql"), "select 1;")
+        );
+        assert_ne!(
+            key,
+            cache(&fs, "{}", Some("1234")).key(Path::new("a.sql"), "select 1;")
+        );
+    }
+
+    #[test]
+    fn stores_and_reads_entries() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert("a.sql".into(), "select 1;");
+        fs.insert("b.sql".into(), "select 2;");
+
+        let results = cache(&fs, "{}", None);
+        let a = results.key(Path::new("a.sql"), "select 1;");
+        let b = results.key(Path::new("b.sql"), "select 2;");
+        assert!(results.get(Path::new("a.sql"), &a).is_none());
+        results.insert(Path::new("a.sql"), a.clone(), Vec::new());
+        results.insert(Path::new("b.sql"), b.clone(), Vec::new());
+        results.persist(&fs);
+
+        let results = cache(&fs, "{}", None);
+        assert_eq!(
+            results
+                .get(Path::new("a.sql"), &a)
+                .map(|diagnostics| diagnostics.len()),
+            Some(0)
+        );
+        let changed = results.key(Path::new("a.sql"), "select 3;");
+        assert!(results.get(Path::new("a.sql"), &changed).is_none());
+        assert!(
+            cache(&fs, "{}", Some("1234"))
+                .get(Path::new("a.sql"), &a)
+                .is_none()
+        );
+
+        // the entries of removed files are dropped
+        fs.remove(Path::new("b.sql"));
+        results.persist(&fs);
+        assert!(cache(&fs, "{}", None).get(Path::new("b.sql"), &b).is_none());
+    }
+}
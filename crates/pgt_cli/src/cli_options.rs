@@ -51,7 +51,7 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty|github|junit|summary|gitlab"),
+        argument("json|json-pretty|github|junit|summary|gitlab|sarif"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
@@ -128,6 +128,12 @@ pub enum CliReporter {
     Junit,
     /// Reports linter diagnostics using the [GitLab Code Quality report](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool).
     GitLab,
+    /// Diagnostics and summary are printed as a single JSON object
+    Json,
+    /// Diagnostics and summary are printed as a single, pretty-printed JSON object
+    JsonPretty,
+    /// Reports diagnostics and rule metadata in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
 }
 
 impl CliReporter {
@@ -144,6 +150,9 @@ impl FromStr for CliReporter {
             "github" => Ok(Self::GitHub),
             "junit" => Ok(Self::Junit),
             "gitlab" => Ok(Self::GitLab),
+            "json" => Ok(Self::Json),
+            "json-pretty" => Ok(Self::JsonPretty),
+            "sarif" => Ok(Self::Sarif),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -158,6 +167,9 @@ impl Display for CliReporter {
             CliReporter::GitHub => f.write_str("github"),
             CliReporter::Junit => f.write_str("junit"),
             CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Json => f.write_str("json"),
+            CliReporter::JsonPretty => f.write_str("json-pretty"),
+            CliReporter::Sarif => f.write_str("sarif"),
         }
     }
 }
@@ -1,11 +1,12 @@
 use crate::cli_options::CliOptions;
-use crate::{CliDiagnostic, Execution, TraversalMode};
+use crate::{BaselineMode, CliDiagnostic, Execution, TraversalMode};
 use biome_deserialize::Merge;
 use pgt_configuration::PartialConfiguration;
 use pgt_console::Console;
 use pgt_fs::FileSystem;
 use pgt_workspace::{DynRef, Workspace, WorkspaceError, configuration::LoadedConfiguration};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 use super::{CommandRunner, get_files_to_process_with_cli_options};
 
@@ -16,6 +17,12 @@ pub(crate) struct CheckCommandPayload {
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) baseline: Option<PathBuf>,
+    pub(crate) write_baseline: Option<PathBuf>,
+    pub(crate) watch: bool,
+    pub(crate) no_cache: bool,
+    /// The resolved configuration as JSON, which cached results are keyed by
+    pub(crate) configuration_for_cache: Option<String>,
 }
 
 impl CommandRunner for CheckCommandPayload {
@@ -37,6 +44,10 @@ impl CommandRunner for CheckCommandPayload {
             fs_configuration.merge_with(configuration);
         }
 
+        if !self.no_cache {
+            self.configuration_for_cache = serde_json::to_string(&fs_configuration).ok();
+        }
+
         Ok(fs_configuration)
     }
 
@@ -70,7 +81,38 @@ impl CommandRunner for CheckCommandPayload {
         Ok(Execution::new(TraversalMode::Check {
             stdin: self.get_stdin(console)?,
             vcs_targeted: (self.staged, self.changed).into(),
+            baseline: self
+                .baseline
+                .clone()
+                .map(BaselineMode::Compare)
+                .or_else(|| self.write_baseline.clone().map(BaselineMode::Write)),
+            watch: self.watch,
+            result_cache: self.configuration_for_cache.clone(),
         })
         .set_report(cli_options))
     }
+
+    fn check_incompatible_arguments(&self) -> Result<(), CliDiagnostic> {
+        if self.baseline.is_some() && self.write_baseline.is_some() {
+            return Err(CliDiagnostic::incompatible_arguments(
+                "--baseline",
+                "--write-baseline",
+            ));
+        }
+
+        if self.watch {
+            let incompatible = [
+                (self.stdin_file_path.is_some(), "--stdin-file-path"),
+                (self.staged, "--staged"),
+                (self.changed, "--changed"),
+                (self.baseline.is_some(), "--baseline"),
+                (self.write_baseline.is_some(), "--write-baseline"),
+            ];
+            if let Some((_, argument)) = incompatible.iter().find(|(used, _)| *used) {
+                return Err(CliDiagnostic::incompatible_arguments("--watch", *argument));
+            }
+        }
+
+        Ok(())
+    }
 }
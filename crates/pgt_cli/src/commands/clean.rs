@@ -4,12 +4,17 @@ use pgt_flags::pgt_env;
 use std::fs::{create_dir, remove_dir_all};
 use std::path::PathBuf;
 
-/// Runs the clean command
-pub fn clean(_cli_session: CliSession) -> Result<(), CliDiagnostic> {
+/// Runs the clean command, which removes the logs and the result cache
+pub fn clean(cli_session: CliSession) -> Result<(), CliDiagnostic> {
     let logs_path = pgt_env()
         .pgt_log_path
         .value()
         .map_or(default_pgt_log_path(), PathBuf::from);
     remove_dir_all(logs_path.clone()).and_then(|_| create_dir(logs_path))?;
+
+    let cache_path = cli_session.cache_directory;
+    if cache_path.exists() {
+        remove_dir_all(cache_path)?;
+    }
     Ok(())
 }
@@ -0,0 +1,189 @@
+use crate::cli_options::CliOptions;
+use crate::diagnostics::InvalidSnapshot;
+use crate::{CliDiagnostic, CliSession, setup_cli_subscriber};
+use biome_deserialize::Merge;
+use pgt_configuration::PartialConfiguration;
+use pgt_console::{Console, ConsoleExt, markup};
+use pgt_diagnostics::{PrintDiagnostic, category};
+use pgt_workspace::PartialConfigurationExt;
+use pgt_workspace::configuration::load_configuration;
+use pgt_workspace::features::schema_diff::{
+    DiffSchemaParams, SchemaChange, SchemaChangeKind, SchemaSnapshot, SchemaSource,
+};
+use pgt_workspace::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub(crate) struct DiffCommandPayload {
+    pub(crate) from: Option<String>,
+    pub(crate) to: Option<String>,
+    pub(crate) schemas: Vec<String>,
+    pub(crate) ddl: bool,
+    pub(crate) write_snapshot: Option<PathBuf>,
+    pub(crate) json: bool,
+}
+
+/// Handler of the `diff` command. Compares two schemas, and prints the objects that differ.
+pub(crate) fn diff(
+    session: CliSession,
+    cli_options: &CliOptions,
+    configuration: Option<PartialConfiguration>,
+    payload: DiffCommandPayload,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+    let fs = &session.app.fs;
+    let console = &mut *session.app.console;
+    let workspace = &*session.app.workspace;
+
+    let from_label = payload.from.unwrap_or_else(|| "migrations".to_string());
+    let to_label = payload.to.unwrap_or_else(|| "database".to_string());
+    let from = parse_source(&from_label)?;
+    let to = parse_source(&to_label)?;
+
+    let loaded_configuration = load_configuration(fs, cli_options.as_configuration_path_hint())?;
+    let configuration_path = loaded_configuration.directory_path.clone();
+    let mut fs_configuration = loaded_configuration.configuration;
+    if let Some(configuration) = configuration {
+        // overwrite fs config with cli args
+        fs_configuration.merge_with(configuration);
+    }
+
+    let vcs_base_path = configuration_path.or(fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        fs_configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
+    workspace.register_project_folder(RegisterProjectFolderParams {
+        path: fs.working_directory(),
+        set_as_current_workspace: true,
+    })?;
+    workspace.update_settings(UpdateSettingsParams {
+        workspace_directory: fs.working_directory(),
+        configuration: fs_configuration,
+        vcs_base_path,
+        gitignore_matches,
+    })?;
+
+    let result = workspace.diff_schema(DiffSchemaParams {
+        from,
+        to,
+        schemas: payload.schemas,
+        include_snapshot: payload.write_snapshot.is_some(),
+    })?;
+    let failed = !result.diagnostics.is_empty() || !result.changes.is_empty();
+
+    if let (Some(path), Some(snapshot)) = (&payload.write_snapshot, &result.snapshot) {
+        let serialized = serde_json::to_string_pretty(snapshot).map_err(std::io::Error::from)?;
+        fs::write(path, serialized)?;
+    }
+
+    if payload.json {
+        let serialized = serde_json::to_string_pretty(&result).map_err(std::io::Error::from)?;
+        console.log(markup!({ serialized }));
+    } else {
+        for diagnostic in &result.diagnostics {
+            if cli_options.verbose {
+                console.error(markup! {{PrintDiagnostic::verbose(diagnostic)}});
+            } else {
+                console.error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+            }
+        }
+        for change in &result.changes {
+            print_change(console, change);
+        }
+
+        if payload.ddl && !result.changes.is_empty() {
+            console.log(markup! { "" });
+            console.log(markup! {
+                <Dim>"-- Statements that turn "{from_label}" into "{to_label}</Dim>
+            });
+            for statement in result.changes.iter().flat_map(|change| &change.ddl) {
+                console.log(markup! { {statement} });
+            }
+        }
+
+        if !result.diagnostics.is_empty() {
+            console.error(markup! {
+                <Error>"The migrations couldn't be applied, so the schemas were not compared."</Error>
+            });
+        } else if result.changes.is_empty() {
+            console.log(markup! {
+                <Info>"No differences between "{from_label}" and "{to_label}"."</Info>
+            });
+        } else {
+            let count = result.changes.len();
+            console.error(markup! {
+                <Error>"Found "{count}" difference(s) between "{from_label}" and "{to_label}"."</Error>
+            });
+        }
+    }
+
+    if failed {
+        Err(CliDiagnostic::check_error(category!("diff")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses the value of `--from` and `--to`. Snapshot files are read right away.
+fn parse_source(value: &str) -> Result<SchemaSource, CliDiagnostic> {
+    match value {
+        "migrations" => Ok(SchemaSource::Migrations),
+        "database" => Ok(SchemaSource::Database),
+        url if url.starts_with("postgres://") || url.starts_with("postgresql://") => {
+            Ok(SchemaSource::Url(url.to_string()))
+        }
+        path => {
+            let path = Path::new(path);
+            let content = fs::read_to_string(path)
+                .map_err(|error| InvalidSnapshot::new(path, error.to_string()))?;
+            let snapshot: SchemaSnapshot = serde_json::from_str(&content)
+                .map_err(|error| InvalidSnapshot::new(path, error.to_string()))?;
+            Ok(SchemaSource::Snapshot(snapshot))
+        }
+    }
+}
+
+/// Prints the kind and name of the changed object, with what differs
+fn print_change(console: &mut dyn Console, change: &SchemaChange) {
+    let object = change.object.to_string();
+    let name = &change.name;
+    match change.kind {
+        SchemaChangeKind::Missing => console.log(markup! {
+            <Error>"- "</Error>{object}" "<Emphasis>{name}</Emphasis>
+        }),
+        SchemaChangeKind::Extra => console.log(markup! {
+            <Success>"+ "</Success>{object}" "<Emphasis>{name}</Emphasis>
+        }),
+        SchemaChangeKind::Changed => console.log(markup! {
+            <Warn>"~ "</Warn>{object}" "<Emphasis>{name}</Emphasis>
+        }),
+    }
+    for detail in &change.details {
+        console.log(markup! {
+            "    "<Dim>{detail}</Dim>
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_workspace::features::schema_diff::SchemaSource;
+
+    use super::parse_source;
+
+    #[test]
+    fn parses_sources() {
+        assert!(matches!(
+            parse_source("migrations"),
+            Ok(SchemaSource::Migrations)
+        ));
+        assert!(matches!(
+            parse_source("database"),
+            Ok(SchemaSource::Database)
+        ));
+        assert!(matches!(
+            parse_source("postgres://postgres@localhost/prod"),
+            Ok(SchemaSource::Url(url)) if url == "postgres://postgres@localhost/prod"
+        ));
+        assert!(parse_source("does-not-exist.json").is_err());
+    }
+}
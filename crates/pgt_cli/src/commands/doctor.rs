@@ -0,0 +1,275 @@
+use crate::cli_options::CliOptions;
+use crate::service::{open_socket, socket_name};
+use crate::{CliDiagnostic, CliSession, VERSION, setup_cli_subscriber};
+use biome_deserialize::Merge;
+use pgt_configuration::PartialConfiguration;
+use pgt_console::{Console, ConsoleExt, markup};
+use pgt_fs::{FileSystem, PgTPath, walk_files};
+use pgt_workspace::configuration::load_configuration;
+use pgt_workspace::workspace::{
+    DatabaseInfo, GetDatabaseInfoParams, IgnoreReason, IsPathIgnoredParams,
+    RegisterProjectFolderParams, UpdateSettingsParams,
+};
+use pgt_workspace::{PartialConfigurationExt, Workspace};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tokio::runtime::Runtime;
+
+/// Ignored paths that are listed without `--verbose`
+const MAX_IGNORED_PATHS: usize = 20;
+
+/// Handler of the `doctor` command. Prints the resolved configuration, the state of the daemon
+/// and the database, the ignored files and the latest logs, e.g. to attach to a bug report.
+pub(crate) fn doctor(
+    session: CliSession,
+    cli_options: &CliOptions,
+    configuration: Option<PartialConfiguration>,
+    log_path: PathBuf,
+    log_lines: usize,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+    let fs = &session.app.fs;
+    let console = &mut *session.app.console;
+    let workspace = &*session.app.workspace;
+
+    console.log(markup! {
+        <Emphasis>"Postgres Tools"</Emphasis>"\n"
+        "  Version:  "{VERSION}"\n"
+        "  Platform: "{std::env::consts::OS}" "{std::env::consts::ARCH}"\n"
+    });
+
+    let loaded_configuration = load_configuration(fs, cli_options.as_configuration_path_hint())?;
+    let configuration_path = loaded_configuration.directory_path.clone();
+    console.log(markup! { <Emphasis>"Configuration"</Emphasis> });
+    match &loaded_configuration.file_path {
+        Some(path) => {
+            let path = path.display().to_string();
+            console.log(markup! { "  File:     "{path} });
+        }
+        None => console.log(markup! { "  File:     "<Dim>"none, using the defaults"</Dim> }),
+    }
+    for path in &loaded_configuration.extended_files {
+        let path = path.display().to_string();
+        console.log(markup! { "  Extends:  "{path} });
+    }
+    let mut fs_configuration = loaded_configuration.configuration;
+    if let Some(configuration) = configuration {
+        // overwrite fs config with cli args
+        fs_configuration.merge_with(configuration);
+    }
+    // the report ends up in bug reports, so it mustn't leak the password
+    let mut redacted = fs_configuration.clone();
+    if let Some(password) = redacted.db.as_mut().and_then(|db| db.password.as_mut()) {
+        if !password.is_empty() {
+            *password = "<redacted>".to_string();
+        }
+    }
+    let resolved = serde_json::to_string_pretty(&redacted).map_err(std::io::Error::from)?;
+    console.log(markup! { "  Resolved:\n"{indent(&resolved)}"\n" });
+
+    let socket = socket_name();
+    let running = Runtime::new()?.block_on(open_socket())?.is_some();
+    console.log(markup! { <Emphasis>"Daemon"</Emphasis> });
+    if running {
+        console.log(markup! { "  Running:  yes" });
+    } else {
+        console.log(markup! { "  Running:  "<Dim>"no"</Dim> });
+    }
+    console.log(markup! { "  Socket:   "{socket}"\n" });
+
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let vcs_base_path = configuration_path.or(fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        fs_configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
+    workspace.register_project_folder(RegisterProjectFolderParams {
+        path: fs.working_directory(),
+        set_as_current_workspace: true,
+    })?;
+    workspace.update_settings(UpdateSettingsParams {
+        workspace_directory: fs.working_directory(),
+        configuration: fs_configuration,
+        vcs_base_path,
+        gitignore_matches,
+    })?;
+
+    let database = workspace.get_database_info(GetDatabaseInfoParams {})?;
+    print_database(console, &database);
+
+    let files = collect_files(&**fs, workspace, &working_directory)?;
+    print_files(console, &files, &working_directory, cli_options.verbose);
+
+    print_logs(&**fs, console, &log_path, log_lines);
+
+    Ok(())
+}
+
+fn print_database(console: &mut dyn Console, database: &DatabaseInfo) {
+    console.log(markup! { <Emphasis>"Database"</Emphasis> });
+    if database.disabled {
+        console.log(markup! { "  Connection: "<Dim>"disabled"</Dim>"\n" });
+        return;
+    }
+    if let Some(error) = &database.error {
+        console.log(markup! { "  Connection: "<Error>"failed"</Error>" "{error}"\n" });
+        return;
+    }
+
+    console.log(markup! { "  Connection: "<Success>"ok"</Success> });
+    if let Some(version) = &database.version {
+        console.log(markup! { "  Version:    "{version} });
+    }
+    if let Some(counts) = &database.schema_cache {
+        let summary = format!(
+            "{} schemas, {} tables, {} columns, {} functions, {} types, {} policies, {} triggers, {} roles, {} indexes, {} foreign keys, {} extensions",
+            counts.schemas,
+            counts.tables,
+            counts.columns,
+            counts.functions,
+            counts.types,
+            counts.policies,
+            counts.triggers,
+            counts.roles,
+            counts.indexes,
+            counts.foreign_keys,
+            counts.extensions
+        );
+        console.log(markup! { "  Schema:     "{summary} });
+    }
+    console.log(markup! { "" });
+}
+
+#[derive(Default)]
+struct FilesReport {
+    /// The number of SQL files that are checked
+    checked: usize,
+    /// The ignored SQL files and directories. The contents of ignored directories are not
+    /// listed.
+    ignored: Vec<(PathBuf, IgnoreReason)>,
+}
+
+/// Walks the directory and asks the workspace which SQL files it ignores, and why. Hidden
+/// directories, such as `.git`, are skipped.
+fn collect_files(
+    fs: &dyn FileSystem,
+    workspace: &dyn Workspace,
+    dir: &Path,
+) -> Result<FilesReport, CliDiagnostic> {
+    let ignored = Mutex::new(Vec::new());
+    let error = Mutex::new(None);
+    let visit = |path: &Path| {
+        let is_dir = fs.path_is_dir(path);
+        if is_dir
+            && path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+        {
+            return false;
+        }
+        if !is_dir
+            && !path
+                .extension()
+                .is_some_and(|ext| ext == "sql" || ext == "pg")
+        {
+            return false;
+        }
+
+        match workspace.get_ignore_reason(IsPathIgnoredParams {
+            pgt_path: PgTPath::new(path),
+        }) {
+            Ok(Some(reason)) => {
+                ignored.lock().unwrap().push((path.to_path_buf(), reason));
+                false
+            }
+            Ok(None) => true,
+            Err(err) => {
+                error.lock().unwrap().get_or_insert(err);
+                false
+            }
+        }
+    };
+    let checked = walk_files(fs, dir, &visit);
+
+    if let Some(error) = error.into_inner().unwrap() {
+        return Err(error.into());
+    }
+    let mut ignored = ignored.into_inner().unwrap();
+    ignored.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(FilesReport {
+        checked: checked.len(),
+        ignored,
+    })
+}
+
+fn print_files(
+    console: &mut dyn Console,
+    report: &FilesReport,
+    working_directory: &Path,
+    verbose: bool,
+) {
+    let checked = report.checked;
+    let ignored = report.ignored.len();
+    console.log(markup! { <Emphasis>"Files"</Emphasis> });
+    console.log(markup! {
+        "  "{checked}" SQL file(s) are checked, "{ignored}" file(s) or directories are ignored"
+    });
+
+    let shown = if verbose {
+        report.ignored.len()
+    } else {
+        MAX_IGNORED_PATHS
+    };
+    for (path, reason) in report.ignored.iter().take(shown) {
+        let path = path
+            .strip_prefix(working_directory)
+            .unwrap_or(path)
+            .display()
+            .to_string();
+        let reason = match reason {
+            IgnoreReason::NotIncluded => "not matched by files.include",
+            IgnoreReason::Ignored => "matched by files.ignore",
+            IgnoreReason::VcsIgnored => "ignored by the VCS ignore file",
+            IgnoreReason::BeforeMigrationsAfter => "migration before migrations.after",
+        };
+        console.log(markup! { "  "{path}"  "<Dim>{reason}</Dim> });
+    }
+    if report.ignored.len() > shown {
+        let hidden = report.ignored.len() - shown;
+        console.log(markup! {
+            "  "<Dim>"... and "{hidden}" more, pass --verbose to list all of them"</Dim>
+        });
+    }
+    console.log(markup! { "" });
+}
+
+/// Prints the last lines of the most recent log file of the daemon and the language server. Log
+/// files are rotated hourly and end with the hour they were created at, e.g.
+/// `server.log.2025-01-31-09`, so the most recent one has the greatest suffix.
+fn print_logs(fs: &dyn FileSystem, console: &mut dyn Console, log_path: &Path, log_lines: usize) {
+    console.log(markup! { <Emphasis>"Logs"</Emphasis> });
+
+    let latest = walk_files(fs, log_path, &|_| true)
+        .into_iter()
+        .max_by(|a, b| a.extension().cmp(&b.extension()).then_with(|| a.cmp(b)));
+    let Some(latest) = latest else {
+        let log_path = log_path.display().to_string();
+        console.log(markup! { "  "<Dim>"No logs in "{log_path}</Dim> });
+        return;
+    };
+
+    let path = latest.display().to_string();
+    console.log(markup! { "  File: "{path} });
+    let content = fs
+        .read_file_from_path(&latest.to_path_buf())
+        .unwrap_or_default();
+    let lines: Vec<&str> = content.lines().collect();
+    let tail = lines[lines.len().saturating_sub(log_lines)..].join("\n");
+    console.log(markup! { {indent(&tail)} });
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
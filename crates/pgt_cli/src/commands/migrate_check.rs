@@ -0,0 +1,158 @@
+use crate::cli_options::CliOptions;
+use crate::{CliDiagnostic, CliSession, setup_cli_subscriber};
+use biome_deserialize::Merge;
+use pgt_configuration::PartialConfiguration;
+use pgt_console::{Console, ConsoleExt, markup};
+use pgt_diagnostics::{PrintDiagnostic, category};
+use pgt_workspace::PartialConfigurationExt;
+use pgt_workspace::configuration::load_configuration;
+use pgt_workspace::features::migrate_check::{MigrateCheckParams, MigrationReport};
+use pgt_workspace::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
+use std::fs;
+
+/// Statements are printed on a single line, cut after this many characters
+const MAX_STATEMENT_WIDTH: usize = 60;
+
+/// Handler of the `migrate-check` command. Applies the migrations to a scratch database, and
+/// prints how long each statement took and the locks it acquired.
+pub(crate) fn migrate_check(
+    session: CliSession,
+    cli_options: &CliOptions,
+    configuration: Option<PartialConfiguration>,
+    json: bool,
+) -> Result<(), CliDiagnostic> {
+    setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
+    let fs = &session.app.fs;
+    let console = &mut *session.app.console;
+    let workspace = &*session.app.workspace;
+
+    let loaded_configuration = load_configuration(fs, cli_options.as_configuration_path_hint())?;
+    let configuration_path = loaded_configuration.directory_path.clone();
+    let mut fs_configuration = loaded_configuration.configuration;
+    if let Some(configuration) = configuration {
+        // overwrite fs config with cli args
+        fs_configuration.merge_with(configuration);
+    }
+
+    let vcs_base_path = configuration_path.or(fs.working_directory());
+    let (vcs_base_path, gitignore_matches) =
+        fs_configuration.retrieve_gitignore_matches(fs, vcs_base_path.as_deref())?;
+    workspace.register_project_folder(RegisterProjectFolderParams {
+        path: fs.working_directory(),
+        set_as_current_workspace: true,
+    })?;
+    workspace.update_settings(UpdateSettingsParams {
+        workspace_directory: fs.working_directory(),
+        configuration: fs_configuration,
+        vcs_base_path,
+        gitignore_matches,
+    })?;
+
+    let result = workspace.migrate_check(MigrateCheckParams {})?;
+    let failed = !result.diagnostics.is_empty();
+
+    if json {
+        let serialized = serde_json::to_string_pretty(&result).map_err(std::io::Error::from)?;
+        console.log(markup!({ serialized }));
+    } else {
+        for migration in &result.migrations {
+            print_migration(console, migration);
+        }
+        for diagnostic in &result.diagnostics {
+            if cli_options.verbose {
+                console.error(markup! {{PrintDiagnostic::verbose(diagnostic)}});
+            } else {
+                console.error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+            }
+        }
+
+        let applied = result
+            .migrations
+            .iter()
+            .filter(|migration| migration.applied)
+            .count();
+        let duration = result
+            .migrations
+            .iter()
+            .flat_map(|migration| &migration.statements)
+            .map(|statement| statement.duration)
+            .sum::<std::time::Duration>();
+        if failed {
+            console.error(markup! {
+                <Error>"Applied "{applied}" migration(s) before one failed, in "{duration}"."</Error>
+            });
+        } else {
+            console.log(markup! {
+                <Info>"Applied "{applied}" migration(s) in "{duration}"."</Info>
+            });
+        }
+    }
+
+    if failed {
+        Err(CliDiagnostic::check_error(category!("migrateCheck")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Prints each statement of the migration with its duration and the locks it acquired
+fn print_migration(console: &mut dyn Console, migration: &MigrationReport) {
+    let path = migration.path.display().to_string();
+    let content = fs::read_to_string(&migration.path).unwrap_or_default();
+
+    console.log(markup! {
+        <Emphasis>{path}</Emphasis>
+    });
+    for statement in &migration.statements {
+        let sql = content
+            .get(std::ops::Range::<usize>::from(statement.range))
+            .map(summarize_statement)
+            .unwrap_or_default();
+        console.log(markup! {
+            "  "{statement.duration}"  "{sql}
+        });
+        for lock in &statement.locks {
+            let target = lock.relation.as_deref().unwrap_or(&lock.locktype);
+            if lock.mode == "AccessExclusiveLock" {
+                console.log(markup! {
+                    "      "<Warn>{lock.mode}</Warn>" on "{target}
+                });
+            } else {
+                console.log(markup! {
+                    "      "<Dim>{lock.mode}" on "{target}</Dim>
+                });
+            }
+        }
+    }
+    console.log(markup! { "" });
+}
+
+/// Collapses the statement to a single line, and cuts it if it's too long
+fn summarize_statement(sql: &str) -> String {
+    let line = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() > MAX_STATEMENT_WIDTH {
+        let cut: String = line.chars().take(MAX_STATEMENT_WIDTH - 3).collect();
+        format!("{}...", cut.trim_end())
+    } else {
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::summarize_statement;
+
+    #[test]
+    fn summarizes_statements_on_one_line() {
+        assert_eq!(
+            summarize_statement("create table users (\n  id int\n);"),
+            "create table users ( id int );"
+        );
+        assert_eq!(
+            summarize_statement(
+                "alter table users add column email text not null default 'unknown@example.com';"
+            ),
+            "alter table users add column email text not null default..."
+        );
+    }
+}
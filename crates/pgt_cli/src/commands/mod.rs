@@ -1,6 +1,6 @@
 use crate::changed::{get_changed_files, get_staged_files};
 use crate::cli_options::{CliOptions, CliReporter, ColorsArg, cli_options};
-use crate::execute::Stdin;
+use crate::execute::{Stdin, execute_watch_mode};
 use crate::logging::LoggingKind;
 use crate::{
     CliDiagnostic, CliSession, Execution, LoggingLevel, VERSION, execute_mode, setup_cli_subscriber,
@@ -18,7 +18,11 @@ use std::path::PathBuf;
 pub(crate) mod check;
 pub(crate) mod clean;
 pub(crate) mod daemon;
+pub(crate) mod diff;
+pub(crate) mod doctor;
 pub(crate) mod init;
+pub(crate) mod migrate_check;
+pub(crate) mod rules;
 pub(crate) mod version;
 
 #[derive(Debug, Clone, Bpaf)]
@@ -62,11 +66,135 @@ pub enum PgtCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Only report diagnostics that are not recorded in the given baseline file. Entries of
+        /// the baseline that no longer match any diagnostic are reported as warnings.
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<PathBuf>,
+
+        /// Record all current diagnostics in the given baseline file, overwriting it. Use it to
+        /// adopt the linter on existing code, and `--baseline` to check against the file.
+        #[bpaf(long("write-baseline"), argument("PATH"))]
+        write_baseline: Option<PathBuf>,
+
+        /// Keep running, and check the changed files again whenever they are saved. The
+        /// configuration file is reloaded when it changes, and the schema cache is refreshed and
+        /// all the files are checked again when a migration changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Check every file, instead of reusing the results of the files that didn't change
+        /// since the last run.
+        #[bpaf(long("no-cache"), switch)]
+        no_cache: bool,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
     },
 
+    /// Applies the migrations to a scratch database, and reports the failing statement, how long each statement took and the locks it acquired.
+    ///
+    /// The scratch database is created next to the database configured in `db`, and dropped
+    /// afterwards. Each migration runs in its own transaction, unless it contains statements that
+    /// can't run in one.
+    #[bpaf(command("migrate-check"))]
+    MigrateCheck {
+        #[bpaf(external(partial_configuration), hide_usage, optional)]
+        configuration: Option<PartialConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Print the report as JSON.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+    },
+
+    /// Compares two schemas and reports the tables, columns, types, functions, policies and triggers that differ.
+    ///
+    /// By default, the schema the migrations result in is compared with the database configured
+    /// in `db`, to find changes that were applied by hand.
+    #[bpaf(command("diff"))]
+    Diff {
+        #[bpaf(external(partial_configuration), hide_usage, optional)]
+        configuration: Option<PartialConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The schema that is compared: `migrations`, `database`, a connection string or the
+        /// path of a snapshot file. Defaults to `migrations`.
+        #[bpaf(long("from"), argument("SOURCE"))]
+        from: Option<String>,
+
+        /// The schema it's compared with, in the same format as `--from`. Defaults to
+        /// `database`.
+        #[bpaf(long("to"), argument("SOURCE"))]
+        to: Option<String>,
+
+        /// Only compare the objects of this Postgres schema. Can be passed multiple times. All
+        /// schemas but the system ones are compared by default.
+        #[bpaf(long("schema"), argument("NAME"), many)]
+        schemas: Vec<String>,
+
+        /// Print the statements that turn `--from` into `--to`.
+        #[bpaf(long("ddl"), switch)]
+        ddl: bool,
+
+        /// Write a snapshot of `--to` to the given file, to compare with it later.
+        #[bpaf(long("write-snapshot"), argument("PATH"))]
+        write_snapshot: Option<PathBuf>,
+
+        /// Print the differences as JSON.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+    },
+
+    /// Prints a report of the environment: the resolved configuration, the daemon, the database, the ignored files and the latest logs.
+    ///
+    /// Attach it to bug reports, or use it to find out why a file isn't checked.
+    #[bpaf(command("doctor"))]
+    Doctor {
+        #[bpaf(external(partial_configuration), hide_usage, optional)]
+        configuration: Option<PartialConfiguration>,
+
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// The folder where the logs of the daemon and the language server are stored.
+        #[bpaf(
+            env("PGT_LOG_PATH"),
+            long("log-path"),
+            argument("PATH"),
+            hide_usage,
+            fallback(pgt_fs::ensure_cache_dir().join("pgt-logs")),
+        )]
+        log_path: PathBuf,
+
+        /// The number of lines of the latest log file to print.
+        #[bpaf(long("log-lines"), argument("NUMBER"), fallback(20), display_fallback)]
+        log_lines: usize,
+    },
+
+    /// Prints the documentation of a lint rule, including its examples, sources and options.
+    #[bpaf(command("explain-rule"))]
+    ExplainRule {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Print the documentation as JSON.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+
+        /// The name of the rule, e.g. `banDropColumn` or `safety/banDropColumn`
+        #[bpaf(positional("RULE"))]
+        rule: String,
+    },
+
+    /// Lists the lint rules.
+    #[bpaf(command)]
+    Rules(#[bpaf(external(rules_command))] RulesCommand),
+
     /// Starts the daemon server process.
     #[bpaf(command)]
     Start {
@@ -101,8 +229,22 @@ pub enum PgtCommand {
     Stop,
 
     /// Bootstraps a new project. Creates a configuration file with some defaults.
+    ///
+    /// The migrations directory of Supabase, Prisma, Drizzle and Flyway projects, the
+    /// connection string in `DATABASE_URL` or `.env` and git repositories are detected, and
+    /// each is confirmed before it's used. The password of the database is never written to the
+    /// configuration file.
     #[bpaf(command)]
-    Init,
+    Init {
+        /// Use everything that was detected without asking. Questions are also skipped when
+        /// stdin isn't a terminal.
+        #[bpaf(long("yes"), short('y'), switch)]
+        yes: bool,
+        /// The connection string of the database, e.g.
+        /// `postgres://postgres@localhost:5432/postgres`. Read from `.env` if it's not set.
+        #[bpaf(env("DATABASE_URL"), long("database-url"), argument("URL"))]
+        database_url: Option<String>,
+    },
 
     /// Acts as a server for the Language Server Protocol over stdin/stdout.
     #[bpaf(command("lsp-proxy"))]
@@ -136,7 +278,7 @@ pub enum PgtCommand {
     },
 
     #[bpaf(command)]
-    /// Cleans the logs emitted by the daemon.
+    /// Cleans the logs emitted by the daemon, and the results cached by `check`.
     Clean,
 
     #[bpaf(command("__run_server"), hide)]
@@ -191,16 +333,39 @@ pub enum PgtCommand {
     PrintSocket,
 }
 
+#[derive(Debug, Clone, Bpaf)]
+pub enum RulesCommand {
+    /// Lists all lint rules and whether they are enabled by the configuration.
+    #[bpaf(command)]
+    List {
+        #[bpaf(external, hide_usage)]
+        cli_options: CliOptions,
+
+        /// Print the list as JSON.
+        #[bpaf(long("json"), switch)]
+        json: bool,
+
+        /// Show the state of the rules for this file, taking the `include` and `ignore`
+        /// settings of `files` and `linter` into account.
+        #[bpaf(positional("PATH"), optional)]
+        path: Option<PathBuf>,
+    },
+}
+
 impl PgtCommand {
     const fn cli_options(&self) -> Option<&CliOptions> {
         match self {
-            PgtCommand::Version(cli_options) | PgtCommand::Check { cli_options, .. } => {
-                Some(cli_options)
-            }
+            PgtCommand::Version(cli_options)
+            | PgtCommand::Check { cli_options, .. }
+            | PgtCommand::MigrateCheck { cli_options, .. }
+            | PgtCommand::Diff { cli_options, .. }
+            | PgtCommand::Doctor { cli_options, .. }
+            | PgtCommand::ExplainRule { cli_options, .. }
+            | PgtCommand::Rules(RulesCommand::List { cli_options, .. }) => Some(cli_options),
             PgtCommand::LspProxy { .. }
             | PgtCommand::Start { .. }
             | PgtCommand::Stop
-            | PgtCommand::Init
+            | PgtCommand::Init { .. }
             | PgtCommand::RunServer { .. }
             | PgtCommand::Clean
             | PgtCommand::PrintSocket => None,
@@ -276,7 +441,11 @@ pub(crate) trait CommandRunner: Sized {
         let workspace = &*session.app.workspace;
         self.check_incompatible_arguments()?;
         let (execution, paths) = self.configure_workspace(fs, console, workspace, cli_options)?;
-        execute_mode(execution, session, cli_options, paths)
+        if execution.is_watch() {
+            execute_watch_mode(self, execution, session, cli_options, paths)
+        } else {
+            execute_mode(execution, session, cli_options, paths)
+        }
     }
 
     /// This function prepares the workspace with the following:
@@ -0,0 +1,436 @@
+use crate::cli_options::CliOptions;
+use crate::diagnostics::UnknownRule;
+use crate::{CliDiagnostic, CliSession};
+use pgt_analyse::{RuleFilter, RuleMetadata};
+use pgt_configuration::analyser::linter::Rules;
+use pgt_console::{ConsoleExt, markup};
+use pgt_diagnostics::{Category, Severity};
+use pgt_workspace::configuration::load_configuration;
+use pgt_workspace::settings::Settings;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Handler of the `explain-rule` command. Prints the documentation of a single rule.
+pub(crate) fn explain_rule(
+    session: CliSession,
+    rule: &str,
+    json: bool,
+) -> Result<(), CliDiagnostic> {
+    let (group, metadata) = find_rule(rule).ok_or_else(|| UnknownRule::new(rule))?;
+    let explanation = RuleExplanation::new(group, metadata);
+    let console = &mut *session.app.console;
+
+    if json {
+        let serialized =
+            serde_json::to_string_pretty(&explanation).map_err(std::io::Error::from)?;
+        console.log(markup!({ serialized }));
+        return Ok(());
+    }
+
+    console.log(markup! {
+        <Emphasis>{explanation.category}</Emphasis>"\n"
+    });
+    if let Some(reason) = explanation.deprecated {
+        console.log(markup! {
+            "  "<Warn>"Deprecated"</Warn>"        "{reason}
+        });
+    }
+    let default_severity = explanation.default_severity.to_string();
+    console.log(markup! {
+        "  Recommended       "{yes_no(explanation.recommended)}"\n"
+        "  Default severity  "{default_severity}"\n"
+        "  Since             "{explanation.version}
+    });
+    for source in &explanation.sources {
+        console.log(markup! {
+            "  Source            "{source.name}" "<Dim>"("<Hyperlink href={&source.url}>{&source.url}</Hyperlink>")"</Dim>
+        });
+    }
+    if let Some(link) = explanation.link {
+        console.log(markup! {
+            "  Documentation     "<Hyperlink href={link}>{link}</Hyperlink>
+        });
+    }
+
+    console.log(markup! { "" });
+    print_docs(console, &explanation.docs);
+    console.log(markup! { "" });
+
+    console.log(markup! { <Emphasis>"Options schema"</Emphasis>"\n" });
+    match explanation
+        .options_schema
+        .as_ref()
+        .and_then(|schema| schema.get("properties"))
+        .and_then(Value::as_object)
+    {
+        Some(properties) => {
+            for (name, property) in properties {
+                let description = property
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                console.log(markup! {
+                    "  "<Emphasis>{name}</Emphasis>"  "{description}
+                });
+                if let Some(default) = property.get("default") {
+                    let default = default.to_string();
+                    console.log(markup! {
+                        "    "<Dim>"Default: "{default}</Dim>
+                    });
+                }
+            }
+            console.log(markup! {
+                "\n"<Dim>"Use "<Emphasis>"--json"</Emphasis>" to print the full JSON schema."</Dim>
+            });
+        }
+        None => console.log(markup! { "  This rule has no options." }),
+    }
+
+    Ok(())
+}
+
+/// Handler of the `rules list` command. Prints all rules and whether they are enabled by the
+/// resolved configuration.
+pub(crate) fn list(
+    session: CliSession,
+    cli_options: &CliOptions,
+    path: Option<PathBuf>,
+    json: bool,
+) -> Result<(), CliDiagnostic> {
+    let fs = &session.app.fs;
+    let loaded_configuration = load_configuration(fs, cli_options.as_configuration_path_hint())?;
+    let mut settings = Settings::default();
+    settings.merge_with_configuration(
+        loaded_configuration.configuration,
+        fs.working_directory(),
+        None,
+        &[],
+    )?;
+
+    let linted = path
+        .as_deref()
+        .is_none_or(|path| is_linted(&settings, path));
+    let rules = settings.as_linter_rules().unwrap_or_default();
+    let enabled_rules = if settings.linter.enabled && linted {
+        rules.as_enabled_rules()
+    } else {
+        Default::default()
+    };
+
+    let list = RuleList {
+        path: path.as_deref().map(|path| path.display().to_string()),
+        linted: settings.linter.enabled && linted,
+        rules: pgt_analyser::METADATA
+            .iter()
+            .map(|(group, metadata)| {
+                let category = format!("lint/{group}/{}", metadata.name);
+                let enabled = enabled_rules.contains(&RuleFilter::Rule(group, metadata.name));
+                let severity = enabled
+                    .then(|| category.parse::<&'static Category>().ok())
+                    .flatten()
+                    .and_then(|category| rules.get_severity_from_code(category));
+
+                RuleState {
+                    category,
+                    recommended: metadata.recommended,
+                    enabled,
+                    severity,
+                }
+            })
+            .collect(),
+    };
+
+    let console = &mut *session.app.console;
+    if json {
+        let serialized = serde_json::to_string_pretty(&list).map_err(std::io::Error::from)?;
+        console.log(markup!({ serialized }));
+        return Ok(());
+    }
+
+    if !settings.linter.enabled {
+        console.log(markup! {
+            <Warn>"The linter is disabled by the configuration."</Warn>"\n"
+        });
+    } else if !linted {
+        console.log(markup! {
+            <Warn>"The path "<Emphasis>{list.path.as_deref().unwrap_or_default()}</Emphasis>" is not linted because of the "<Emphasis>"include"</Emphasis>" and "<Emphasis>"ignore"</Emphasis>" settings."</Warn>"\n"
+        });
+    }
+
+    for rule in &list.rules {
+        let recommended = if rule.recommended { "recommended" } else { "" };
+        let recommended = format!("{recommended:13}");
+        if rule.enabled {
+            let severity = rule
+                .severity
+                .map(|severity| severity.to_string())
+                .unwrap_or_default();
+            let severity = format!("{severity:10}");
+            console.log(markup! {
+                <Success>"enabled   "</Success>{severity}<Dim>{recommended}</Dim>{rule.category}
+            });
+        } else {
+            console.log(markup! {
+                <Dim>"disabled            "{recommended}{rule.category}</Dim>
+            });
+        }
+    }
+
+    let enabled = list.rules.iter().filter(|rule| rule.enabled).count();
+    console.log(markup! {
+        "\n"{enabled}" of "{list.rules.len()}" rules are enabled. Rules of the "<Emphasis>"schema"</Emphasis>" group only run with a database connection."
+    });
+
+    Ok(())
+}
+
+/// The documentation of a rule as printed by `explain-rule`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleExplanation {
+    category: String,
+    group: &'static str,
+    name: &'static str,
+    version: &'static str,
+    recommended: bool,
+    deprecated: Option<&'static str>,
+    default_severity: Severity,
+    sources: Vec<RuleSourceLink>,
+    link: Option<&'static str>,
+    docs: String,
+    /// The JSON schema of the `options` of the rule, or `null` if it has none
+    options_schema: Option<Value>,
+}
+
+impl RuleExplanation {
+    fn new(group: &'static str, metadata: &RuleMetadata) -> Self {
+        let category = format!("lint/{group}/{}", metadata.name);
+        let link = category
+            .parse::<&'static Category>()
+            .ok()
+            .and_then(|category| category.link());
+
+        Self {
+            link,
+            group,
+            name: metadata.name,
+            version: metadata.version,
+            recommended: metadata.recommended,
+            deprecated: metadata.deprecated,
+            // mirrors the fallback of `Rules::get_severity_from_code`
+            default_severity: if metadata.recommended {
+                Severity::Error
+            } else {
+                Severity::Warning
+            },
+            sources: metadata
+                .sources
+                .iter()
+                .map(|source| RuleSourceLink {
+                    name: source.to_namespaced_rule_name(),
+                    url: source.to_rule_url(),
+                })
+                .collect(),
+            // doc comments start with a space that is not part of the markdown
+            docs: metadata
+                .docs
+                .lines()
+                .map(|line| line.strip_prefix(' ').unwrap_or(line))
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim()
+                .to_string(),
+            options_schema: options_schema(group, metadata.name),
+            category,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RuleSourceLink {
+    name: String,
+    url: String,
+}
+
+/// The rules printed by `rules list`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleList {
+    path: Option<String>,
+    /// Whether the linter runs on `path`, or at all if no path was given
+    linted: bool,
+    rules: Vec<RuleState>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleState {
+    category: String,
+    recommended: bool,
+    enabled: bool,
+    /// The configured severity, `null` if the rule is disabled
+    severity: Option<Severity>,
+}
+
+/// Finds a rule by its name, e.g. `banDropColumn`, or by its category with or without the
+/// `lint/` prefix, e.g. `safety/banDropColumn`.
+fn find_rule(rule: &str) -> Option<(&'static str, &'static RuleMetadata)> {
+    let rule = rule.strip_prefix("lint/").unwrap_or(rule);
+    match rule.split_once('/') {
+        Some((group, name)) => pgt_analyser::METADATA
+            .iter()
+            .find(|(candidate, metadata)| *candidate == group && metadata.name == name),
+        None => pgt_analyser::METADATA
+            .iter()
+            .find(|(_, metadata)| metadata.name == rule),
+    }
+}
+
+/// Whether the linter runs on `path`, according to the `include` and `ignore` settings of
+/// `files` and `linter`.
+fn is_linted(settings: &Settings, path: &Path) -> bool {
+    let files = &settings.files;
+    let linter = &settings.linter;
+
+    (files.included_files.is_empty() || files.included_files.matches_path(path))
+        && !files.ignored_files.matches_path(path)
+        && (linter.included_files.is_empty() || linter.included_files.matches_path(path))
+        && !linter.ignored_files.matches_path(path)
+}
+
+/// Prints the markdown documentation of a rule. Headings are emphasized and code blocks are
+/// indented, everything else is printed as is.
+fn print_docs(console: &mut dyn pgt_console::Console, docs: &str) {
+    let mut in_code_block = false;
+    for line in docs.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            console.log(markup! { "    "<Dim>{line}</Dim> });
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            console.log(markup! { <Emphasis>{heading}</Emphasis> });
+        } else {
+            console.log(markup! { {line} });
+        }
+    }
+}
+
+/// Extracts the JSON schema of the options of a rule from the schema of the configuration.
+///
+/// The schema of a rule is a `RuleConfiguration<T>`, which is either a plain level or an object
+/// with a `level` and the `options` of type `T`. Rules without options use `T = ()`.
+fn options_schema(group: &str, rule: &str) -> Option<Value> {
+    let root = serde_json::to_value(schemars::schema_for!(Rules)).ok()?;
+    let definitions = root.get("definitions")?.as_object()?;
+
+    let group_schema = *referenced(definitions, root.get("properties")?.get(group)?).first()?;
+    let rule_schema = group_schema.get("properties")?.get(rule)?;
+    let options = referenced(definitions, rule_schema)
+        .into_iter()
+        .flat_map(|configuration| referenced(definitions, configuration))
+        .find_map(|with_options| with_options.get("properties")?.get("options"))?;
+    let mut options = (*referenced(definitions, options).first()?).clone();
+
+    let mut used = BTreeMap::new();
+    collect_definitions(definitions, &options, &mut used);
+    if !used.is_empty() {
+        options.as_object_mut()?.insert(
+            "definitions".to_string(),
+            Value::Object(used.into_iter().collect()),
+        );
+    }
+
+    Some(options)
+}
+
+/// Returns the definitions referenced by `schema`, either directly or through `allOf`, `anyOf`
+/// and `oneOf`.
+fn referenced<'a>(definitions: &'a Map<String, Value>, schema: &'a Value) -> Vec<&'a Value> {
+    if let Some(definition) = schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+    {
+        return definitions.get(definition).into_iter().collect();
+    }
+
+    ["allOf", "anyOf", "oneOf"]
+        .into_iter()
+        .filter_map(|key| schema.get(key)?.as_array())
+        .flatten()
+        .flat_map(|schema| referenced(definitions, schema))
+        .collect()
+}
+
+/// Collects all definitions that `schema` transitively refers to, so that it can be printed on
+/// its own.
+fn collect_definitions(
+    definitions: &Map<String, Value>,
+    schema: &Value,
+    used: &mut BTreeMap<String, Value>,
+) {
+    match schema {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let definition = value
+                    .as_str()
+                    .and_then(|reference| reference.strip_prefix("#/definitions/"));
+                match definition {
+                    Some(name) if key == "$ref" && !used.contains_key(name) => {
+                        if let Some(definition) = definitions.get(name) {
+                            used.insert(name.to_string(), definition.clone());
+                            collect_definitions(definitions, definition, used);
+                        }
+                    }
+                    _ => collect_definitions(definitions, value, used),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_definitions(definitions, item, used);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_rule, options_schema};
+
+    #[test]
+    fn finds_rules_by_name_and_category() {
+        for name in [
+            "banDropColumn",
+            "safety/banDropColumn",
+            "lint/safety/banDropColumn",
+        ] {
+            let (group, metadata) = find_rule(name).unwrap();
+            assert_eq!((group, metadata.name), ("safety", "banDropColumn"));
+        }
+
+        assert!(find_rule("style/banDropColumn").is_none());
+        assert!(find_rule("unknownRule").is_none());
+    }
+
+    #[test]
+    fn extracts_options_schema() {
+        assert!(options_schema("safety", "banDropColumn").is_none());
+
+        let schema = options_schema("style", "namingConvention").unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("tables"));
+        assert!(schema["definitions"]["NameConvention"].is_object());
+    }
+}
@@ -4,6 +4,7 @@ use pgt_diagnostics::{
     Advices, Category, Diagnostic, Error, LogCategory, MessageAndDescription, Severity, Visit,
 };
 use pgt_workspace::WorkspaceError;
+use std::path::Path;
 use std::process::{ExitCode, Termination};
 use std::{env::current_exe, fmt::Debug};
 
@@ -55,6 +56,12 @@ pub enum CliDiagnostic {
     Report(ReportDiagnostic),
     /// Emitted when there's an error emitted when using stdin mode
     Stdin(StdinDiagnostic),
+    /// Emitted when the file passed to `--baseline` can't be used
+    InvalidBaseline(InvalidBaseline),
+    /// Emitted when the snapshot passed to `diff` can't be used
+    InvalidSnapshot(InvalidSnapshot),
+    /// Emitted when a command is called with a rule that doesn't exist
+    UnknownRule(UnknownRule),
 }
 
 #[derive(Debug, Diagnostic)]
@@ -230,6 +237,89 @@ pub struct IncompatibleEndConfiguration {
 )]
 pub struct NoFilesWereProcessed;
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Error,
+    message(
+        description = "The baseline file {path} is invalid: {reason}",
+        message("The baseline file "<Emphasis>{self.path}</Emphasis>" is invalid: "{{&self.reason}})
+    )
+)]
+pub struct InvalidBaseline {
+    path: String,
+    reason: String,
+}
+
+impl InvalidBaseline {
+    pub(crate) fn new(path: &Path, reason: impl Into<String>) -> Self {
+        Self {
+            path: path.display().to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "diff",
+    severity = Error,
+    message(
+        description = "The snapshot file {path} is invalid: {reason}",
+        message("The snapshot file "<Emphasis>{self.path}</Emphasis>" is invalid: "{{&self.reason}})
+    )
+)]
+pub struct InvalidSnapshot {
+    path: String,
+    reason: String,
+}
+
+impl InvalidSnapshot {
+    pub(crate) fn new(path: &Path, reason: impl Into<String>) -> Self {
+        Self {
+            path: path.display().to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "Unknown rule {rule}",
+        message("Unknown rule "<Emphasis>{self.rule}</Emphasis>)
+    )
+)]
+pub struct UnknownRule {
+    rule: String,
+    #[advice]
+    advice: UnknownRuleAdvice,
+}
+
+impl UnknownRule {
+    pub(crate) fn new(rule: impl Into<String>) -> Self {
+        Self {
+            rule: rule.into(),
+            advice: UnknownRuleAdvice,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct UnknownRuleAdvice;
+
+impl Advices for UnknownRuleAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! { "Type the following command to list all rules" },
+        )?;
+        visitor.record_command(&format!("{} rules list", command_name()))
+    }
+}
+
 #[derive(Debug, Diagnostic)]
 pub enum ReportDiagnostic {
     /// Emitted when trying to serialise the report
@@ -422,6 +512,24 @@ impl From<WorkspaceError> for CliDiagnostic {
     }
 }
 
+impl From<InvalidBaseline> for CliDiagnostic {
+    fn from(error: InvalidBaseline) -> Self {
+        CliDiagnostic::InvalidBaseline(error)
+    }
+}
+
+impl From<InvalidSnapshot> for CliDiagnostic {
+    fn from(error: InvalidSnapshot) -> Self {
+        CliDiagnostic::InvalidSnapshot(error)
+    }
+}
+
+impl From<UnknownRule> for CliDiagnostic {
+    fn from(error: UnknownRule) -> Self {
+        CliDiagnostic::UnknownRule(error)
+    }
+}
+
 impl From<std::io::Error> for CliDiagnostic {
     fn from(error: std::io::Error) -> Self {
         CliDiagnostic::io_error(error)
@@ -1,5 +1,8 @@
+use pgt_console::markup;
 use pgt_diagnostics::adapters::{IoError, StdError};
-use pgt_diagnostics::{Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error};
+use pgt_diagnostics::{
+    Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, LogCategory, Visit,
+};
 use std::io;
 
 #[derive(Debug, Diagnostic)]
@@ -10,6 +13,38 @@ pub(crate) struct PanicDiagnostic {
     pub(crate) message: String,
 }
 
+/// Emitted for baseline entries that no longer match any diagnostic
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "baseline",
+    severity = Warning,
+    message(
+        description = "The baseline entry for {rule} no longer matches any diagnostic.",
+        message("The baseline entry for "<Emphasis>{self.rule}</Emphasis>" no longer matches any diagnostic.")
+    )
+)]
+pub(crate) struct StaleBaselineEntry {
+    pub(crate) rule: String,
+    #[location(resource)]
+    pub(crate) file_path: Option<String>,
+    #[advice]
+    pub(crate) advice: StaleBaselineAdvice,
+}
+
+#[derive(Debug)]
+pub(crate) struct StaleBaselineAdvice;
+
+impl Advices for StaleBaselineAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Info,
+            &markup! {
+                "The diagnostic was fixed or its statement changed. Update the baseline with "<Emphasis>"--write-baseline"</Emphasis>" to remove the entry."
+            },
+        )
+    }
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 pub(crate) trait ResultExt {
     type Result;
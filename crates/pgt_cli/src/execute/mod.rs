@@ -2,12 +2,15 @@ mod diagnostics;
 mod process_file;
 mod std_in;
 pub(crate) mod traverse;
+mod watch;
 
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::execute::traverse::{TraverseResult, traverse};
 use crate::reporter::github::{GithubReporter, GithubReporterVisitor};
 use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
+use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
 use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
 use pgt_diagnostics::{Category, category};
@@ -17,6 +20,8 @@ use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 use tracing::info;
 
+pub(crate) use watch::execute_watch_mode;
+
 /// Useful information during the traversal of files and virtual content
 #[derive(Debug, Clone)]
 pub struct Execution {
@@ -28,6 +33,10 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u32,
+
+    /// Whether the checks of the whole project, i.e. the schema rules and the validation of the
+    /// migrations, run once the files are checked
+    check_project: bool,
 }
 
 impl Execution {
@@ -70,6 +79,16 @@ impl From<(bool, bool)> for VcsTargeted {
     }
 }
 
+/// How `check` uses a baseline file
+#[derive(Debug, Clone)]
+pub enum BaselineMode {
+    /// Only diagnostics that are not recorded in the baseline file are reported.
+    /// Set with `--baseline`.
+    Compare(PathBuf),
+    /// All diagnostics are recorded in the baseline file. Set with `--write-baseline`.
+    Write(PathBuf),
+}
+
 #[derive(Debug, Clone)]
 pub enum TraversalMode {
     /// A dummy mode to be used when the CLI is not running any command
@@ -87,6 +106,13 @@ pub enum TraversalMode {
         stdin: Option<Stdin>,
         /// A flag to know vcs integrated options such as `--staged` or `--changed` are enabled
         vcs_targeted: VcsTargeted,
+        /// The baseline file passed via `--baseline` or `--write-baseline`
+        baseline: Option<BaselineMode>,
+        /// Whether the files are checked again when they change, set with `--watch`
+        watch: bool,
+        /// The resolved configuration as JSON, which the results cached on disk are keyed by.
+        /// It's `None` when the cache is disabled with `--no-cache`.
+        result_cache: Option<String>,
     },
 }
 
@@ -111,6 +137,10 @@ pub enum ReportMode {
     Junit,
     /// Reports information in the [GitLab Code Quality](https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool) format.
     GitLab,
+    /// Reports information as a JSON object, see `docs/reporters.md` for its schema
+    Json { pretty: bool },
+    /// Reports information in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) format.
+    Sarif,
 }
 
 impl Default for ReportMode {
@@ -126,6 +156,9 @@ impl From<CliReporter> for ReportMode {
             CliReporter::GitHub => Self::GitHub,
             CliReporter::Junit => Self::Junit,
             CliReporter::GitLab => Self::GitLab {},
+            CliReporter::Json => Self::Json { pretty: false },
+            CliReporter::JsonPretty => Self::Json { pretty: true },
+            CliReporter::Sarif => Self::Sarif,
         }
     }
 }
@@ -136,6 +169,7 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            check_project: true,
         }
     }
 
@@ -175,6 +209,20 @@ impl Execution {
         }
     }
 
+    pub(crate) fn as_baseline(&self) -> Option<&BaselineMode> {
+        match &self.traversal_mode {
+            TraversalMode::Dummy => None,
+            TraversalMode::Check { baseline, .. } => baseline.as_ref(),
+        }
+    }
+
+    pub(crate) fn as_result_cache(&self) -> Option<&str> {
+        match &self.traversal_mode {
+            TraversalMode::Dummy => None,
+            TraversalMode::Check { result_cache, .. } => result_cache.as_deref(),
+        }
+    }
+
     pub(crate) fn is_vcs_targeted(&self) -> bool {
         match &self.traversal_mode {
             TraversalMode::Dummy => false,
@@ -184,6 +232,30 @@ impl Execution {
         }
     }
 
+    pub(crate) fn is_watch(&self) -> bool {
+        match &self.traversal_mode {
+            TraversalMode::Dummy => false,
+            TraversalMode::Check { watch, .. } => *watch,
+        }
+    }
+
+    /// Whether the checks of the whole project run after the files are checked. They only run
+    /// when all the files are checked.
+    pub(crate) fn checks_project(&self) -> bool {
+        self.check_project
+            && matches!(self.traversal_mode, TraversalMode::Check { .. })
+            && !self.is_vcs_targeted()
+    }
+
+    /// Returns a copy of this execution that doesn't run the checks of the whole project, used
+    /// to check a subset of the files again
+    pub(crate) fn without_project_checks(&self) -> Self {
+        Self {
+            check_project: false,
+            ..self.clone()
+        }
+    }
+
     pub(crate) const fn is_check_apply(&self) -> bool {
         false
     }
@@ -283,6 +355,32 @@ pub fn execute_mode(
                 };
                 reporter.write(&mut JunitReporterVisitor::new(console))?;
             }
+            ReportMode::Json { pretty } => {
+                let reporter = JsonReporter {
+                    summary,
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut JsonReporterVisitor::new(console, pretty))?;
+            }
+            ReportMode::Sarif => {
+                let reporter = SarifReporter {
+                    diagnostics_payload: DiagnosticsPayload {
+                        verbose: cli_options.verbose,
+                        diagnostic_level: cli_options.diagnostic_level,
+                        diagnostics,
+                    },
+                    execution: execution.clone(),
+                };
+                reporter.write(&mut SarifReporterVisitor::new(
+                    console,
+                    session.app.fs.borrow().working_directory(),
+                ))?;
+            }
         }
 
         // Processing emitted error diagnostics, exit with a non-zero code
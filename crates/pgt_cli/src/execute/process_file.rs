@@ -51,6 +51,15 @@ pub(crate) enum Message {
         diagnostics: Vec<Error>,
         skipped_diagnostics: u32,
     },
+    /// Diagnostics of the schema rules, which belong to the database rather than to a file
+    SchemaDiagnostics {
+        diagnostics: Vec<Error>,
+        skipped_diagnostics: u32,
+    },
+    /// Diagnostics of the names and the order of the migrations
+    MigrationDiagnostics {
+        diagnostics: Vec<Error>,
+    },
 }
 
 impl<D> From<D> for Message
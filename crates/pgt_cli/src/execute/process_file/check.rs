@@ -7,18 +7,43 @@ use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraver
 use std::path::Path;
 use std::sync::atomic::Ordering;
 
-/// Lints a single file and returns a [FileResult]
+/// Lints a single file and returns a [FileResult]. If the file didn't change since a previous
+/// run, its diagnostics are read from the result cache instead.
 pub(crate) fn check_file<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     path: &Path,
 ) -> FileResult {
-    let mut workspace_file = WorkspaceFile::new(ctx, path)?;
-    check_with_guard(ctx, &mut workspace_file)
+    let (file, input) = WorkspaceFile::read(ctx, path)?;
+
+    let cache_key = ctx
+        .result_cache
+        .as_ref()
+        .map(|cache| cache.key(path, &input));
+    let cached_diagnostics = ctx
+        .result_cache
+        .as_ref()
+        .zip(cache_key.as_deref())
+        .and_then(|(cache, key)| cache.get(path, key));
+    if let Some(diagnostics) = cached_diagnostics {
+        if !diagnostics.is_empty() {
+            ctx.push_message(Message::Diagnostics {
+                name: path.display().to_string(),
+                content: input,
+                diagnostics: diagnostics.into_iter().map(Error::from).collect(),
+                skipped_diagnostics: 0,
+            });
+        }
+        return Ok(FileStatus::Unchanged);
+    }
+
+    let mut workspace_file = WorkspaceFile::open(ctx, path, file, input)?;
+    check_with_guard(ctx, &mut workspace_file, cache_key)
 }
 
 pub(crate) fn check_with_guard<'ctx>(
     ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
     workspace_file: &mut WorkspaceFile,
+    cache_key: Option<String>,
 ) -> FileResult {
     tracing::info_span!("Processes check", path =? workspace_file.path.display()).in_scope(
         move || {
@@ -42,6 +67,17 @@ pub(crate) fn check_with_guard<'ctx>(
                     category!("check"),
                 )?;
 
+            // results cut short by the diagnostics limit can't be reused
+            if let Some((cache, key)) = ctx.result_cache.as_ref().zip(cache_key) {
+                if pull_diagnostics_result.skipped_diagnostics == 0 {
+                    cache.insert(
+                        &workspace_file.path,
+                        key,
+                        pull_diagnostics_result.diagnostics.clone(),
+                    );
+                }
+            }
+
             let no_diagnostics = pull_diagnostics_result.diagnostics.is_empty()
                 && pull_diagnostics_result.skipped_diagnostics == 0;
 
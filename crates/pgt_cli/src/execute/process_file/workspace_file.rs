@@ -14,13 +14,11 @@ pub(crate) struct WorkspaceFile<'ctx, 'app> {
 }
 
 impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
-    /// It attempts to read the file from disk, creating a [FileGuard] and
-    /// saving these information internally
-    pub(crate) fn new(
+    /// Reads the file from disk, without opening it in the workspace
+    pub(crate) fn read(
         ctx: &SharedTraversalOptions<'ctx, 'app>,
         path: &Path,
-    ) -> Result<Self, Error> {
-        let pgt_path = PgTPath::new(path);
+    ) -> Result<(Box<dyn File>, String), Error> {
         let open_options = OpenOptions::default()
             .read(true)
             .write(ctx.execution.requires_write_access());
@@ -33,12 +31,24 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         file.read_to_string(&mut input)
             .with_file_path(path.display().to_string())?;
 
+        Ok((file, input))
+    }
+
+    /// Opens a file read with [WorkspaceFile::read] in the workspace, creating a [FileGuard] and
+    /// saving these information internally
+    pub(crate) fn open(
+        ctx: &SharedTraversalOptions<'ctx, 'app>,
+        path: &Path,
+        file: Box<dyn File>,
+        input: String,
+    ) -> Result<Self, Error> {
+        let pgt_path = PgTPath::new(path);
         let guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
                 path: pgt_path,
                 version: 0,
-                content: input.clone(),
+                content: input,
             },
         )
         .with_file_path_and_code(path.display().to_string(), category!("internalError/fs"))?;
@@ -1,21 +1,27 @@
 use super::process_file::{FileStatus, Message, process_file};
-use super::{Execution, TraversalMode};
+use super::{BaselineMode, Execution, TraversalMode};
+use crate::baseline::Baseline;
+use crate::cache::ResultCache;
 use crate::cli_options::CliOptions;
-use crate::execute::diagnostics::PanicDiagnostic;
+use crate::execute::diagnostics::{PanicDiagnostic, StaleBaselineAdvice, StaleBaselineEntry};
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
 use crossbeam::channel::{Receiver, Sender, unbounded};
+use pgt_analyse::RuleCategoriesBuilder;
 use pgt_diagnostics::DiagnosticTags;
 use pgt_diagnostics::{DiagnosticExt, Error, Resource, Severity};
 use pgt_fs::{FileSystem, PathInterner, PgTPath};
 use pgt_fs::{TraversalContext, TraversalScope};
 use pgt_workspace::dome::Dome;
-use pgt_workspace::workspace::IsPathIgnoredParams;
+use pgt_workspace::features::diagnostics::{
+    PullMigrationDiagnosticsParams, PullSchemaDiagnosticsParams,
+};
+use pgt_workspace::workspace::{GetSchemaFingerprintParams, IsPathIgnoredParams};
 use pgt_workspace::{Workspace, WorkspaceError};
 use rustc_hash::FxHashSet;
 use std::collections::BTreeSet;
-use std::sync::RwLock;
 use std::sync::atomic::AtomicU32;
+use std::sync::{Mutex, RwLock};
 use std::{
     env::current_dir,
     ffi::OsString,
@@ -28,6 +34,7 @@ use std::{
     thread,
     time::{Duration, Instant},
 };
+use tracing::info;
 
 pub(crate) struct TraverseResult {
     pub(crate) summary: TraversalSummary,
@@ -76,45 +83,103 @@ pub(crate) fn traverse(
     let fs = &*session.app.fs;
     let workspace = &*session.app.workspace;
 
+    let result_cache = execution.as_result_cache().and_then(|configuration| {
+        // the results depend on the schema, so they can't be cached if it can't be loaded
+        let schema_fingerprint = workspace
+            .get_schema_fingerprint(GetSchemaFingerprintParams {})
+            .inspect_err(|err| info!("Not using the result cache: {err}"))
+            .ok()?;
+        Some(ResultCache::load(
+            fs,
+            &session.cache_directory,
+            configuration,
+            schema_fingerprint.as_deref(),
+            fs.working_directory().as_deref(),
+        ))
+    });
+
+    let baseline = match execution.as_baseline() {
+        Some(BaselineMode::Compare(path)) => {
+            Some(Baseline::load(fs, path, fs.working_directory())?)
+        }
+        Some(BaselineMode::Write(_)) => Some(Baseline::recording(fs.working_directory())),
+        None => None,
+    };
+
     let max_diagnostics = execution.get_max_diagnostics();
-    let remaining_diagnostics = AtomicU32::new(max_diagnostics);
+    // the baseline has to see every diagnostic, not only the ones that are printed
+    let remaining_diagnostics = AtomicU32::new(if baseline.is_some() {
+        u32::MAX
+    } else {
+        max_diagnostics
+    });
 
-    let printer = DiagnosticsPrinter::new(execution)
+    let mut printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
-        .with_max_diagnostics(max_diagnostics);
+        .with_max_diagnostics(max_diagnostics)
+        .with_baseline(baseline);
 
-    let (duration, evaluated_paths, diagnostics) = thread::scope(|s| {
+    let (duration, evaluated_paths, mut diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("pgt::console"))
             .spawn_scoped(s, || printer.run(receiver, recv_files))
             .expect("failed to spawn console thread");
 
-        // The traversal context is scoped to ensure all the channels it
-        // contains are properly closed once the traversal finishes
-        let (elapsed, evaluated_paths) = traverse_inputs(
+        let mut ctx = TraversalOptions {
             fs,
-            inputs,
-            &TraversalOptions {
-                fs,
-                workspace,
-                execution,
-                interner,
-                matches: &matches,
-                changed: &changed,
-                unchanged: &unchanged,
-                skipped: &skipped,
-                messages: sender,
-                remaining_diagnostics: &remaining_diagnostics,
-                evaluated_paths: RwLock::default(),
-            },
-        );
+            workspace,
+            execution,
+            interner,
+            matches: &matches,
+            changed: &changed,
+            unchanged: &unchanged,
+            skipped: &skipped,
+            result_cache,
+            messages: sender,
+            remaining_diagnostics: &remaining_diagnostics,
+            evaluated_paths: RwLock::default(),
+        };
+        let (elapsed, evaluated_paths) = traverse_inputs(fs, inputs, &ctx);
+
+        if execution.checks_project() {
+            check_schema(&ctx);
+            check_migrations(&ctx);
+        }
+
+        if let Some(result_cache) = ctx.result_cache.take() {
+            result_cache.persist(fs);
+        }
+
+        // The traversal context is dropped to ensure all the channels it
+        // contains are properly closed once the traversal finishes
+        drop(ctx);
         // wait for the main thread to finish
         let diagnostics = handler.join().unwrap();
 
         (elapsed, evaluated_paths, diagnostics)
     });
 
+    if let Some(baseline) = printer.take_baseline() {
+        match execution.as_baseline() {
+            Some(BaselineMode::Write(path)) => baseline.write(fs, path)?,
+            _ => {
+                for entry in
+                    baseline.into_stale_entries(evaluated_paths.iter().map(|p| p.as_path()))
+                {
+                    let diagnostic = Error::from(StaleBaselineEntry {
+                        rule: entry.category,
+                        file_path: (!entry.path.is_empty()).then_some(entry.path),
+                        advice: StaleBaselineAdvice,
+                    });
+                    if printer.should_print_diagnostic(&diagnostic) {
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+        }
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
     let changed = changed.load(Ordering::Relaxed);
@@ -123,6 +188,7 @@ pub(crate) fn traverse(
     let skipped = skipped.load(Ordering::Relaxed);
     let suggested_fixes_skipped = printer.skipped_fixes();
     let diagnostics_not_printed = printer.not_printed_diagnostics();
+    let baselined = printer.baselined();
 
     Ok(TraverseResult {
         summary: TraversalSummary {
@@ -135,6 +201,7 @@ pub(crate) fn traverse(
             skipped,
             suggested_fixes_skipped,
             diagnostics_not_printed,
+            baselined,
         },
         evaluated_paths,
         diagnostics,
@@ -184,6 +251,49 @@ fn traverse_inputs(
     (start.elapsed(), ctx.evaluated_paths())
 }
 
+/// Runs the schema rules once against the connected database, after all files are checked
+fn check_schema(ctx: &TraversalOptions) {
+    let result = ctx
+        .workspace
+        .pull_schema_diagnostics(PullSchemaDiagnosticsParams {
+            categories: RuleCategoriesBuilder::default().all().build(),
+            max_diagnostics: ctx.remaining_diagnostics.load(Ordering::Relaxed).into(),
+            only: Vec::new(),
+            skip: Vec::new(),
+        });
+
+    match result {
+        Ok(result) => {
+            // the message is sent even without diagnostics, so that the baseline knows the
+            // database was checked
+            if result.checked {
+                ctx.push_message(Message::SchemaDiagnostics {
+                    diagnostics: result.diagnostics.into_iter().map(Error::from).collect(),
+                    skipped_diagnostics: result.skipped_diagnostics as u32,
+                });
+            }
+        }
+        Err(err) => ctx.push_message(err),
+    }
+}
+
+/// Checks that the migrations are named and ordered consistently, after all files are checked
+fn check_migrations(ctx: &TraversalOptions) {
+    match ctx
+        .workspace
+        .pull_migration_diagnostics(PullMigrationDiagnosticsParams {})
+    {
+        Ok(result) => {
+            if result.checked {
+                ctx.push_message(Message::MigrationDiagnostics {
+                    diagnostics: result.diagnostics.into_iter().map(Error::from).collect(),
+                });
+            }
+        }
+        Err(err) => ctx.push_message(err),
+    }
+}
+
 // struct DiagnosticsReporter<'ctx> {}
 
 struct DiagnosticsPrinter<'ctx> {
@@ -209,6 +319,10 @@ struct DiagnosticsPrinter<'ctx> {
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
     total_skipped_suggested_fixes: AtomicU32,
+    /// The baseline diagnostics are matched against, or recorded in
+    baseline: Option<Mutex<Baseline>>,
+    /// The number of diagnostics that were part of the baseline
+    baselined: AtomicU32,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -224,6 +338,8 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             not_printed_diagnostics: AtomicU32::new(0),
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
+            baseline: None,
+            baselined: AtomicU32::new(0),
         }
     }
 
@@ -242,6 +358,17 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self
     }
 
+    fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline.map(Mutex::new);
+        self
+    }
+
+    fn take_baseline(&mut self) -> Option<Baseline> {
+        self.baseline
+            .take()
+            .map(|baseline| baseline.into_inner().unwrap())
+    }
+
     fn errors(&self) -> u32 {
         self.errors.load(Ordering::Relaxed)
     }
@@ -258,6 +385,29 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         self.total_skipped_suggested_fixes.load(Ordering::Relaxed)
     }
 
+    fn baselined(&self) -> u32 {
+        self.baselined.load(Ordering::Relaxed)
+    }
+
+    /// Removes the diagnostics that are part of the baseline, if there is one. Returns the
+    /// remaining diagnostics.
+    fn filter_baseline(
+        &self,
+        diagnostics: Vec<Error>,
+        filter: impl FnOnce(&mut Baseline, Vec<Error>) -> Vec<Error>,
+    ) -> Vec<Error> {
+        let Some(baseline) = &self.baseline else {
+            return diagnostics;
+        };
+
+        let count = diagnostics.len();
+        let diagnostics = filter(&mut baseline.lock().unwrap(), diagnostics);
+        self.baselined
+            .fetch_add((count - diagnostics.len()) as u32, Ordering::Relaxed);
+
+        diagnostics
+    }
+
     /// Checks if the diagnostic we received from the thread should be considered or not. Logic:
     /// - it should not be considered if its severity level is lower than the one provided via CLI;
     /// - it should not be considered if it's a verbose diagnostic and the CLI **didn't** request a `--verbose` option.
@@ -273,6 +423,24 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
         false
     }
 
+    /// Counts a diagnostic of a [Message::Diagnostics], [Message::SchemaDiagnostics] or
+    /// [Message::MigrationDiagnostics] message, and then returns a boolean that tells if it
+    /// should be printed
+    fn should_print_diagnostic(&self, diag: &Error) -> bool {
+        let severity = diag.severity();
+        if self.should_skip_diagnostic(severity, diag.tags()) {
+            return false;
+        }
+        if severity == Severity::Error {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        if severity == Severity::Warning {
+            self.warnings.fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.should_print()
+    }
+
     /// Count the diagnostic, and then returns a boolean that tells if it should be printed
     fn should_print(&self) -> bool {
         let printed_diagnostics = self.printed_diagnostics.load(Ordering::Relaxed);
@@ -360,23 +528,41 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     self.not_printed_diagnostics
                         .fetch_add(skipped_diagnostics, Ordering::Relaxed);
 
+                    let diagnostics = self.filter_baseline(diagnostics, |baseline, diagnostics| {
+                        baseline.filter_file(&name, &content, diagnostics)
+                    });
+
                     // is CI mode we want to print all the diagnostics
                     for diag in diagnostics {
-                        let severity = diag.severity();
-                        if self.should_skip_diagnostic(severity, diag.tags()) {
-                            continue;
-                        }
-                        if severity == Severity::Error {
-                            self.errors.fetch_add(1, Ordering::Relaxed);
+                        if self.should_print_diagnostic(&diag) {
+                            let diag = diag.with_file_path(&name).with_file_source_code(&content);
+                            diagnostics_to_print.push(diag)
                         }
-                        if severity == Severity::Warning {
-                            self.warnings.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+
+                Message::SchemaDiagnostics {
+                    diagnostics,
+                    skipped_diagnostics,
+                } => {
+                    self.not_printed_diagnostics
+                        .fetch_add(skipped_diagnostics, Ordering::Relaxed);
+
+                    let diagnostics = self.filter_baseline(diagnostics, Baseline::filter_database);
+
+                    for diag in diagnostics {
+                        if self.should_print_diagnostic(&diag) {
+                            diagnostics_to_print.push(diag)
                         }
+                    }
+                }
 
-                        let should_print = self.should_print();
+                Message::MigrationDiagnostics { diagnostics } => {
+                    let diagnostics =
+                        self.filter_baseline(diagnostics, Baseline::filter_migrations);
 
-                        if should_print {
-                            let diag = diag.with_file_path(&name).with_file_source_code(&content);
+                    for diag in diagnostics {
+                        if self.should_print_diagnostic(&diag) {
                             diagnostics_to_print.push(diag)
                         }
                     }
@@ -406,6 +592,8 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     matches: &'ctx AtomicUsize,
     /// Shared atomic counter storing the number of skipped files
     skipped: &'ctx AtomicUsize,
+    /// The cache of the diagnostics of previous runs, unless it's disabled
+    pub(crate) result_cache: Option<ResultCache>,
     /// Channel sending messages to the display thread
     pub(crate) messages: Sender<Message>,
     /// The approximate number of diagnostics the console will print before
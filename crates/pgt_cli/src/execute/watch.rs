@@ -0,0 +1,368 @@
+use super::Execution;
+use super::traverse::{TraverseResult, traverse};
+use crate::cli_options::CliOptions;
+use crate::commands::CommandRunner;
+use crate::reporter::terminal::ConsoleReporterVisitor;
+use crate::{CliDiagnostic, CliSession, ReporterVisitor, TraversalSummary};
+use pgt_console::{Console, ConsoleExt, markup};
+use pgt_diagnostics::{Error, PrintDiagnostic, Resource, Severity};
+use pgt_fs::{PgTPath, PollWatcher};
+use pgt_workspace::Workspace;
+use pgt_workspace::configuration::load_configuration;
+use pgt_workspace::workspace::{IsPathIgnoredParams, RefreshSchemaCacheParams};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tracing::info;
+
+/// How often the watched files are scanned for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Runs `check --watch`: checks all the files once, and then checks the files again whenever
+/// they change, until the process is stopped.
+///
+/// - when the configuration file changes, it's loaded again and all the files are checked;
+/// - when a migration changes, the schema cache is refreshed and all the files are checked,
+///   because every file depends on the schema;
+/// - otherwise, only the changed files are checked again.
+pub(crate) fn execute_watch_mode(
+    runner: &mut impl CommandRunner,
+    mut execution: Execution,
+    mut session: CliSession,
+    cli_options: &CliOptions,
+    mut paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    if !cli_options.reporter.is_default() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--watch",
+            "--reporter",
+        ));
+    }
+    // every diagnostic is kept, the limit is applied when the report is printed
+    execution.max_diagnostics = u32::MAX;
+
+    let mut targets = WatchTargets::load(runner, &mut session, cli_options)?;
+    let mut watcher = targets.watcher(&paths, &*session.app.workspace);
+    let mut report = WatchReport::default();
+
+    let result = traverse(&execution, &mut session, cli_options, paths.clone())?;
+    report.replace(result);
+    report.print(&execution, session.app.console, cli_options)?;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let changed =
+            watcher.poll(&|path, is_dir| targets.watches(&*session.app.workspace, path, is_dir));
+        if changed.is_empty() {
+            continue;
+        }
+        info!("Changed files: {changed:?}");
+
+        if changed
+            .iter()
+            .any(|path| targets.is_configuration_file(path))
+        {
+            match reload_configuration(runner, &mut session, cli_options) {
+                Ok((new_execution, new_paths, new_targets)) => {
+                    execution = new_execution;
+                    paths = new_paths;
+                    targets = new_targets;
+                    watcher = targets.watcher(&paths, &*session.app.workspace);
+                }
+                Err(error) => {
+                    print_error(session.app.console, error);
+                    continue;
+                }
+            }
+        } else if changed.iter().any(|path| targets.is_migration(path)) {
+            let refreshed = session
+                .app
+                .workspace
+                .refresh_schema_cache(RefreshSchemaCacheParams {});
+            if let Err(error) = refreshed {
+                print_error(session.app.console, error.into());
+                continue;
+            }
+        } else {
+            // only the changed files are checked again, the removed ones are dropped
+            report.remove(&changed);
+            let inputs: Vec<OsString> = changed
+                .into_iter()
+                .filter(|path| session.app.fs.path_exists(path))
+                .map(PathBuf::into_os_string)
+                .collect();
+
+            if !inputs.is_empty() {
+                let execution = execution.without_project_checks();
+                match traverse(&execution, &mut session, cli_options, inputs) {
+                    Ok(result) => report.update(result),
+                    Err(error) => {
+                        print_error(session.app.console, error);
+                        continue;
+                    }
+                }
+            }
+            report.print(&execution, session.app.console, cli_options)?;
+            continue;
+        }
+
+        match traverse(&execution, &mut session, cli_options, paths.clone()) {
+            Ok(result) => {
+                report.replace(result);
+                report.print(&execution, session.app.console, cli_options)?;
+            }
+            Err(error) => print_error(session.app.console, error),
+        }
+    }
+}
+
+/// Loads the configuration file again, and updates the settings of the workspace
+fn reload_configuration(
+    runner: &mut impl CommandRunner,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+) -> Result<(Execution, Vec<OsString>, WatchTargets), CliDiagnostic> {
+    let (mut execution, paths) = runner.configure_workspace(
+        &session.app.fs,
+        session.app.console,
+        &*session.app.workspace,
+        cli_options,
+    )?;
+    execution.max_diagnostics = u32::MAX;
+    let targets = WatchTargets::load(runner, session, cli_options)?;
+
+    Ok((execution, paths, targets))
+}
+
+/// Clears the terminal, and prints a diagnostic that stopped the files from being checked
+fn print_error(console: &mut dyn Console, error: CliDiagnostic) {
+    clear_terminal();
+    console.error(markup! {
+        {PrintDiagnostic::simple(&error)}
+    });
+    console.log(markup! {
+        <Dim>"Watching for changes..."</Dim>
+    });
+}
+
+/// Clears the terminal before a new report is printed
+fn clear_terminal() {
+    let mut stdout = io::stdout();
+    if stdout.is_terminal() {
+        // erases the screen, and moves the cursor back to the top left corner
+        let _ = stdout.write_all(b"\x1b[2J\x1b[H");
+        let _ = stdout.flush();
+    }
+}
+
+/// The files, other than the checked ones, that change how the files are checked
+struct WatchTargets {
+    /// The configuration file, loaded again when it changes
+    configuration_file: Option<PathBuf>,
+    /// The migrations directory, the schema cache is refreshed when a migration changes
+    migrations_dir: Option<PathBuf>,
+    /// The working directory, used to resolve relative paths
+    working_directory: Option<PathBuf>,
+}
+
+impl WatchTargets {
+    fn load(
+        runner: &mut impl CommandRunner,
+        session: &mut CliSession,
+        cli_options: &CliOptions,
+    ) -> Result<Self, CliDiagnostic> {
+        let fs = &session.app.fs;
+        let loaded_configuration =
+            load_configuration(fs, cli_options.as_configuration_path_hint())?;
+        let configuration_file = loaded_configuration.file_path.clone();
+        let configuration =
+            runner.merge_configuration(loaded_configuration, fs, session.app.console)?;
+
+        let working_directory = fs.working_directory();
+        let migrations_dir = configuration
+            .migrations
+            .and_then(|migrations| migrations.migrations_dir)
+            .zip(working_directory.clone())
+            .map(|(migrations_dir, working_directory)| working_directory.join(migrations_dir));
+
+        Ok(Self {
+            configuration_file,
+            migrations_dir,
+            working_directory,
+        })
+    }
+
+    /// Creates a watcher for the checked paths, the configuration file and the migrations
+    fn watcher(&self, paths: &[OsString], workspace: &dyn Workspace) -> PollWatcher {
+        let paths: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+        let mut roots = paths.clone();
+        roots.extend(self.configuration_file.clone());
+        if let Some(migrations_dir) = &self.migrations_dir {
+            // the migrations are usually checked too, they are only watched once
+            if !paths
+                .iter()
+                .any(|path| migrations_dir.starts_with(self.resolve(path)))
+            {
+                roots.push(migrations_dir.clone());
+            }
+        }
+
+        PollWatcher::new(roots, &|path, is_dir| self.watches(workspace, path, is_dir))
+    }
+
+    /// Whether the watcher looks at the path. Directories and files the configuration ignores
+    /// aren't watched, since they aren't checked either.
+    fn watches(&self, workspace: &dyn Workspace, path: &Path, is_dir: bool) -> bool {
+        if self.is_configuration_file(path) {
+            return true;
+        }
+        if !is_dir
+            && !path
+                .extension()
+                .is_some_and(|ext| ext == "sql" || ext == "pg")
+        {
+            return false;
+        }
+
+        !workspace
+            .is_path_ignored(IsPathIgnoredParams {
+                pgt_path: PgTPath::new(path),
+            })
+            .unwrap_or(false)
+    }
+
+    fn is_configuration_file(&self, path: &Path) -> bool {
+        self.configuration_file.as_deref() == Some(path)
+    }
+
+    fn is_migration(&self, path: &Path) -> bool {
+        self.migrations_dir
+            .as_ref()
+            .is_some_and(|migrations_dir| self.resolve(path).starts_with(migrations_dir))
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        match &self.working_directory {
+            Some(working_directory) => working_directory.join(path),
+            None => path.to_path_buf(),
+        }
+    }
+}
+
+/// The diagnostics of the last check of every file, printed after each change
+#[derive(Default)]
+struct WatchReport {
+    /// The diagnostics of each checked file, files without diagnostics included
+    files: BTreeMap<PathBuf, Vec<Error>>,
+    /// The diagnostics that don't belong to a file, such as the ones of the schema rules
+    other: Vec<Error>,
+    /// How long the last check took
+    duration: Duration,
+    /// The number of files of the last check
+    checked: usize,
+}
+
+impl WatchReport {
+    /// Replaces the whole report with the result of checking all the files
+    fn replace(&mut self, result: TraverseResult) {
+        self.files.clear();
+        self.other.clear();
+        self.update(result);
+    }
+
+    /// Removes the files from the report, before they are checked again
+    fn remove(&mut self, paths: &[PathBuf]) {
+        for path in paths {
+            self.files.remove(path);
+        }
+    }
+
+    /// Adds the result of checking some of the files to the report
+    fn update(&mut self, result: TraverseResult) {
+        let TraverseResult {
+            summary,
+            evaluated_paths,
+            diagnostics,
+        } = result;
+
+        self.duration = summary.duration;
+        self.checked = summary.changed + summary.unchanged;
+        for path in evaluated_paths {
+            self.files.entry(path.to_path_buf()).or_default();
+        }
+        for diagnostic in diagnostics {
+            let path = match diagnostic.location().resource {
+                Some(Resource::File(path)) => Some(PathBuf::from(path)),
+                _ => None,
+            };
+            match path {
+                Some(path) => self.files.entry(path).or_default().push(diagnostic),
+                None => self.other.push(diagnostic),
+            }
+        }
+    }
+
+    fn print(
+        &self,
+        execution: &Execution,
+        console: &mut dyn Console,
+        cli_options: &CliOptions,
+    ) -> Result<(), CliDiagnostic> {
+        clear_terminal();
+
+        let max_diagnostics = u32::from(cli_options.max_diagnostics) as usize;
+        let mut summary = TraversalSummary {
+            unchanged: self.checked,
+            duration: self.duration,
+            ..TraversalSummary::default()
+        };
+
+        let diagnostics = self.files.values().flatten().chain(&self.other);
+        for (index, diagnostic) in diagnostics.enumerate() {
+            match diagnostic.severity() {
+                Severity::Error => summary.errors += 1,
+                Severity::Warning => summary.warnings += 1,
+                _ => {}
+            }
+
+            if index >= max_diagnostics {
+                summary.diagnostics_not_printed += 1;
+            } else if diagnostic.tags().is_verbose() && cli_options.verbose {
+                console.error(markup! {{PrintDiagnostic::verbose(diagnostic)}});
+            } else {
+                console.error(markup! {{PrintDiagnostic::simple(diagnostic)}});
+            }
+        }
+
+        ConsoleReporterVisitor(console).report_summary(execution, summary)?;
+        console.log(markup! {
+            <Dim>"Watching for changes..."</Dim>
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WatchTargets;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn resolves_relative_paths_of_migrations() {
+        let targets = WatchTargets {
+            configuration_file: Some(PathBuf::from("/project/postgrestools.jsonc")),
+            migrations_dir: Some(PathBuf::from("/project/supabase/migrations")),
+            working_directory: Some(PathBuf::from("/project")),
+        };
+
+        assert!(targets.is_migration(Path::new("./supabase/migrations/1_init.sql")));
+        assert!(targets.is_migration(Path::new("/project/supabase/migrations/1_init.sql")));
+        assert!(!targets.is_migration(Path::new("./supabase/seed.sql")));
+        assert!(targets.is_configuration_file(Path::new("/project/postgrestools.jsonc")));
+        assert!(!targets.is_configuration_file(Path::new("./postgrestools.jsonc")));
+    }
+}
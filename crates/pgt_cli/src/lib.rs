@@ -4,14 +4,19 @@
 //! to parse commands and arguments, redirect the execution of the commands and
 //! execute the traversal of directory and files, based on the command that was passed.
 
+use cache::ResultCache;
 use cli_options::CliOptions;
 use commands::CommandRunner;
 use commands::check::CheckCommandPayload;
+use commands::diff::DiffCommandPayload;
 use pgt_console::{ColorMode, Console};
 use pgt_fs::OsFileSystem;
 use pgt_workspace::{App, DynRef, Workspace, WorkspaceRef};
 use std::env;
+use std::path::PathBuf;
 
+mod baseline;
+mod cache;
 mod changed;
 mod cli_options;
 mod commands;
@@ -24,10 +29,10 @@ mod reporter;
 mod service;
 
 use crate::cli_options::ColorsArg;
-pub use crate::commands::{PgtCommand, pgt_command};
+pub use crate::commands::{PgtCommand, RulesCommand, pgt_command};
 pub use crate::logging::{LoggingLevel, setup_cli_subscriber};
 pub use diagnostics::CliDiagnostic;
-pub use execute::{Execution, TraversalMode, VcsTargeted, execute_mode};
+pub use execute::{BaselineMode, Execution, TraversalMode, VcsTargeted, execute_mode};
 pub use panic::setup_panic_handler;
 pub use reporter::{DiagnosticsPayload, Reporter, ReporterVisitor, TraversalSummary};
 pub use service::{SocketTransport, open_transport};
@@ -41,6 +46,8 @@ pub(crate) const VERSION: &str = match option_env!("PGT_VERSION") {
 pub struct CliSession<'app> {
     /// Instance of [App] used by this run of the CLI
     pub app: App<'app>,
+    /// The directory where `check` caches its results
+    pub cache_directory: PathBuf,
 }
 
 impl<'app> CliSession<'app> {
@@ -54,6 +61,7 @@ impl<'app> CliSession<'app> {
                 console,
                 WorkspaceRef::Borrowed(workspace),
             ),
+            cache_directory: ResultCache::default_directory(),
         })
     }
 
@@ -74,6 +82,10 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                baseline,
+                write_baseline,
+                watch,
+                no_cache,
             } => run_command(
                 self,
                 &cli_options,
@@ -84,8 +96,54 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    baseline,
+                    write_baseline,
+                    watch,
+                    no_cache,
+                    configuration_for_cache: None,
                 },
             ),
+            PgtCommand::MigrateCheck {
+                configuration,
+                cli_options,
+                json,
+            } => commands::migrate_check::migrate_check(self, &cli_options, configuration, json),
+            PgtCommand::Diff {
+                configuration,
+                cli_options,
+                from,
+                to,
+                schemas,
+                ddl,
+                write_snapshot,
+                json,
+            } => commands::diff::diff(
+                self,
+                &cli_options,
+                configuration,
+                DiffCommandPayload {
+                    from,
+                    to,
+                    schemas,
+                    ddl,
+                    write_snapshot,
+                    json,
+                },
+            ),
+            PgtCommand::Doctor {
+                configuration,
+                cli_options,
+                log_path,
+                log_lines,
+            } => commands::doctor::doctor(self, &cli_options, configuration, log_path, log_lines),
+            PgtCommand::ExplainRule { json, rule, .. } => {
+                commands::rules::explain_rule(self, &rule, json)
+            }
+            PgtCommand::Rules(RulesCommand::List {
+                cli_options,
+                json,
+                path,
+            }) => commands::rules::list(self, &cli_options, path, json),
             PgtCommand::Clean => commands::clean::clean(self),
             PgtCommand::Start {
                 config_path,
@@ -93,7 +151,7 @@ impl<'app> CliSession<'app> {
                 log_prefix_name,
             } => commands::daemon::start(self, config_path, Some(log_path), Some(log_prefix_name)),
             PgtCommand::Stop => commands::daemon::stop(self),
-            PgtCommand::Init => commands::init::init(self),
+            PgtCommand::Init { yes, database_url } => commands::init::init(self, yes, database_url),
             PgtCommand::LspProxy {
                 config_path,
                 log_path,
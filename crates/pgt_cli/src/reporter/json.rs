@@ -0,0 +1,89 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use pgt_console::{Console, ConsoleExt, markup};
+use serde::Serialize;
+use std::io;
+
+/// The version of the JSON report, bumped on every breaking change to its shape
+const JSON_REPORT_VERSION: u32 = 1;
+
+pub(crate) struct JsonReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+    pub(crate) summary: TraversalSummary,
+}
+
+impl Reporter for JsonReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_summary(&self.execution, self.summary)?;
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+/// The JSON report, see `docs/reporters.md` for its documented shape.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JsonReport {
+    version: u32,
+    command: String,
+    summary: TraversalSummary,
+    diagnostics: Vec<pgt_diagnostics::serde::Diagnostic>,
+}
+
+pub(crate) struct JsonReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    pretty: bool,
+    report: JsonReport,
+}
+
+impl<'a> JsonReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, pretty: bool) -> Self {
+        Self {
+            console,
+            pretty,
+            report: JsonReport {
+                version: JSON_REPORT_VERSION,
+                command: String::new(),
+                summary: TraversalSummary::default(),
+                diagnostics: vec![],
+            },
+        }
+    }
+}
+
+impl ReporterVisitor for JsonReporterVisitor<'_> {
+    fn report_summary(
+        &mut self,
+        execution: &Execution,
+        summary: TraversalSummary,
+    ) -> io::Result<()> {
+        self.report.command = execution.traversal_mode().to_string();
+        self.report.summary = summary;
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level
+                && (!diagnostic.tags().is_verbose() || payload.verbose)
+            {
+                self.report
+                    .diagnostics
+                    .push(pgt_diagnostics::serde::Diagnostic::new(diagnostic));
+            }
+        }
+
+        let serialized = if self.pretty {
+            serde_json::to_string_pretty(&self.report)?
+        } else {
+            serde_json::to_string(&self.report)?
+        };
+        self.console.log(markup!({ serialized }));
+
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 pub(crate) mod github;
 pub(crate) mod gitlab;
+pub(crate) mod json;
 pub(crate) mod junit;
+pub(crate) mod sarif;
 pub(crate) mod terminal;
 
 use crate::execute::Execution;
@@ -19,6 +21,7 @@ pub struct DiagnosticsPayload {
 
 /// A type that holds the result of the traversal
 #[derive(Debug, Default, Serialize, Copy, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct TraversalSummary {
     pub changed: usize,
     pub unchanged: usize,
@@ -31,6 +34,8 @@ pub struct TraversalSummary {
     pub skipped: usize,
     pub suggested_fixes_skipped: u32,
     pub diagnostics_not_printed: u32,
+    /// Diagnostics that were recorded in, or matched by, the baseline file
+    pub baselined: u32,
 }
 
 /// When using this trait, the type that implements this trait is the one that holds the read-only information to pass around
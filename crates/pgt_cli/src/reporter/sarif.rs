@@ -0,0 +1,307 @@
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary, VERSION};
+use path_absolutize::Absolutize;
+use pgt_analyse::RuleMetadata;
+use pgt_console::{Console, ConsoleExt, markup};
+use pgt_diagnostics::display::SourceFile;
+use pgt_diagnostics::{Category, Error, PrintDescription, Resource, Severity};
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub(crate) struct SarifReporter {
+    pub(crate) execution: Execution,
+    pub(crate) diagnostics_payload: DiagnosticsPayload,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_diagnostics(&self.execution, self.diagnostics_payload)?;
+        Ok(())
+    }
+}
+
+pub(crate) struct SarifReporterVisitor<'a> {
+    console: &'a mut dyn Console,
+    repository_root: Option<PathBuf>,
+}
+
+impl<'a> SarifReporterVisitor<'a> {
+    pub(crate) fn new(console: &'a mut dyn Console, repository_root: Option<PathBuf>) -> Self {
+        Self {
+            console,
+            repository_root,
+        }
+    }
+
+    /// SARIF consumers resolve artifacts relative to the repository, so we
+    /// strip the working directory from absolute paths when we can.
+    fn artifact_uri(&self, path: &str) -> String {
+        let relativized = Path::new(path).absolutize().ok().and_then(|resolved| {
+            resolved
+                .strip_prefix(self.repository_root.as_deref()?)
+                .ok()
+                .map(Path::to_path_buf)
+        });
+
+        match relativized {
+            Some(relative) => relative.to_string_lossy().replace('\\', "/"),
+            None => path.replace('\\', "/"),
+        }
+    }
+
+    /// Diagnostics without a file, such as the ones of the schema lint pass,
+    /// are reported without a location.
+    fn physical_location(&self, diagnostic: &Error) -> Option<SarifLocation> {
+        let location = diagnostic.location();
+        let Some(Resource::File(path)) = location.resource else {
+            return None;
+        };
+
+        let region = match (location.span, location.source_code) {
+            (Some(span), Some(source_code)) => {
+                let source = SourceFile::new(source_code);
+                let start = source.location(span.start()).ok()?;
+                let end = source.location(span.end()).ok()?;
+                Some(SarifRegion {
+                    start_line: start.line_number.get(),
+                    start_column: start.column_number.get(),
+                    end_line: end.line_number.get(),
+                    end_column: end.column_number.get(),
+                })
+            }
+            _ => None,
+        };
+
+        Some(SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation {
+                    uri: self.artifact_uri(path),
+                },
+                region,
+            },
+        })
+    }
+}
+
+impl ReporterVisitor for SarifReporterVisitor<'_> {
+    fn report_summary(&mut self, _: &Execution, _: TraversalSummary) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        let rules: Vec<_> = pgt_analyser::METADATA
+            .iter()
+            .map(|(group, metadata)| SarifRule::from_metadata(group, metadata))
+            .collect();
+
+        let results = payload
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity() >= payload.diagnostic_level)
+            .filter(|d| !d.tags().is_verbose() || payload.verbose)
+            .map(|diagnostic| {
+                let rule_id = diagnostic
+                    .category()
+                    .map(|category| category.name())
+                    .unwrap_or_default();
+                let rule_index = rules.iter().position(|rule| rule.id == rule_id);
+
+                SarifResult {
+                    rule_id,
+                    rule_index,
+                    level: SarifLevel::from(diagnostic.severity()),
+                    message: SarifMessage {
+                        text: PrintDescription(diagnostic).to_string(),
+                    },
+                    locations: self.physical_location(diagnostic).into_iter().collect(),
+                }
+            })
+            .collect();
+
+        let log = SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "postgrestools",
+                        information_uri: "https://pgtools.dev",
+                        version: VERSION,
+                        rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results,
+            }],
+        };
+
+        let serialized = serde_json::to_string_pretty(&log)?;
+        self.console.log(markup!({ serialized }));
+
+        Ok(())
+    }
+}
+
+/// The root of a SARIF 2.1.0 document.
+/// See https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    /// Columns reported by [SourceFile] count characters, not UTF-16 code units
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+/// A `reportingDescriptor` describing a single lint rule.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    /// The category of the rule, e.g. `lint/safety/banDropColumn`
+    id: String,
+    name: &'static str,
+    short_description: SarifMessage,
+    full_description: SarifMessage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+    default_configuration: SarifDefaultConfiguration,
+    properties: SarifRuleProperties,
+}
+
+impl SarifRule {
+    fn from_metadata(group: &str, metadata: &RuleMetadata) -> Self {
+        let id = format!("lint/{group}/{}", metadata.name);
+        let help_uri = id
+            .parse::<&'static Category>()
+            .ok()
+            .and_then(|category| category.link());
+        let summary = metadata
+            .docs
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default();
+
+        Self {
+            id,
+            name: metadata.name,
+            short_description: SarifMessage {
+                text: summary.to_string(),
+            },
+            full_description: SarifMessage {
+                text: metadata.docs.trim().to_string(),
+            },
+            help_uri,
+            default_configuration: SarifDefaultConfiguration {
+                enabled: metadata.recommended,
+            },
+            properties: SarifRuleProperties {
+                recommended: metadata.recommended,
+                deprecated: metadata.deprecated,
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifDefaultConfiguration {
+    enabled: bool,
+}
+
+#[derive(Serialize)]
+struct SarifRuleProperties {
+    recommended: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecated: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: SarifLevel,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum SarifLevel {
+    Note,
+    Warning,
+    Error,
+}
+
+impl From<Severity> for SarifLevel {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Hint | Severity::Information => Self::Note,
+            Severity::Warning => Self::Warning,
+            Severity::Error | Severity::Fatal => Self::Error,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
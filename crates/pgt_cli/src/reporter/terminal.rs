@@ -1,5 +1,5 @@
 use crate::Reporter;
-use crate::execute::{Execution, TraversalMode};
+use crate::execute::{BaselineMode, Execution, TraversalMode};
 use crate::reporter::{DiagnosticsPayload, ReporterVisitor, TraversalSummary};
 use pgt_console::fmt::Formatter;
 use pgt_console::{Console, ConsoleExt, fmt, markup};
@@ -161,6 +161,26 @@ impl fmt::Display for SummaryTotal<'_> {
     }
 }
 
+struct BaselineSummary<'a>(&'a BaselineMode, u32);
+
+impl fmt::Display for BaselineSummary<'_> {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let diagnostics = if self.1 == 1 {
+            "diagnostic"
+        } else {
+            "diagnostics"
+        };
+        match self.0 {
+            BaselineMode::Compare(path) => fmt.write_markup(markup! {
+                <Info>"Skipped "{self.1}" "{diagnostics}" recorded in the baseline "<Emphasis>{path.display().to_string()}</Emphasis>"."</Info>
+            }),
+            BaselineMode::Write(path) => fmt.write_markup(markup! {
+                <Info>"Recorded "{self.1}" "{diagnostics}" in the baseline "<Emphasis>{path.display().to_string()}</Emphasis>"."</Info>
+            }),
+        }
+    }
+}
+
 pub(crate) struct ConsoleTraversalSummary<'a>(
     pub(crate) &'a TraversalMode,
     pub(crate) &'a TraversalSummary,
@@ -185,6 +205,13 @@ impl fmt::Display for ConsoleTraversalSummary<'_> {
                 fmt.write_markup(markup!("\n"<Warn>"Found "{self.1.warnings}" warnings."</Warn>))?;
             }
         }
+        if let TraversalMode::Check {
+            baseline: Some(baseline),
+            ..
+        } = self.0
+        {
+            fmt.write_markup(markup!("\n"{BaselineSummary(baseline, self.1.baselined)}))?;
+        }
         Ok(())
     }
 }
@@ -43,12 +43,12 @@ use tokio::{
 #[cfg(windows)]
 mod windows;
 #[cfg(windows)]
-pub(crate) use self::windows::{ensure_daemon, open_socket, print_socket, run_daemon};
+pub(crate) use self::windows::{ensure_daemon, open_socket, print_socket, run_daemon, socket_name};
 
 #[cfg(unix)]
 mod unix;
 #[cfg(unix)]
-pub(crate) use self::unix::{ensure_daemon, open_socket, print_socket, run_daemon};
+pub(crate) use self::unix::{ensure_daemon, open_socket, print_socket, run_daemon, socket_name};
 
 /// Tries to open a connection to a running daemon instance, returning a
 /// [WorkspaceTransport] instance if the socket is currently active
@@ -24,6 +24,11 @@ fn get_socket_name() -> PathBuf {
     pgt_fs::ensure_cache_dir().join(format!("pgt-socket-{}", pgt_configuration::VERSION))
 }
 
+/// Returns the path of the global socket as it's shown to users
+pub(crate) fn socket_name() -> String {
+    get_socket_name().display().to_string()
+}
+
 #[allow(dead_code)]
 pub(crate) fn enumerate_pipes() -> io::Result<impl Iterator<Item = String>> {
     fs::read_dir(pgt_fs::ensure_cache_dir()).map(|iter| {
@@ -27,6 +27,11 @@ fn get_pipe_name() -> String {
     format!(r"\\.\pipe\pgt-service-{}", pgt_configuration::VERSION)
 }
 
+/// Returns the name of the global named pipe as it's shown to users
+pub(crate) fn socket_name() -> String {
+    get_pipe_name()
+}
+
 #[allow(dead_code)]
 pub(crate) fn enumerate_pipes() -> io::Result<impl Iterator<Item = String>> {
     read_dir(r"\\.\pipe").map(|iter| {
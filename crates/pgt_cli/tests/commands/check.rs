@@ -22,3 +22,180 @@ fn syntax_error() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 }
+
+fn console_output(console: &BufferConsole) -> String {
+    console
+        .out_buffer
+        .iter()
+        .flat_map(|message| message.content.0.iter())
+        .map(|node| node.content.as_str())
+        .collect()
+}
+
+#[test]
+fn json_reporter() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "alter table test drop column id;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--reporter=json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let report: serde_json::Value =
+        serde_json::from_str(&console_output(&console)).expect("the report is valid JSON");
+
+    assert_eq!(report["version"], 1);
+    assert_eq!(report["command"], "check");
+    assert_eq!(report["summary"]["errors"], 1);
+    assert_eq!(
+        report["diagnostics"][0]["category"],
+        "lint/safety/banDropColumn"
+    );
+    assert_eq!(
+        report["diagnostics"][0]["location"]["path"]["file"],
+        "test.sql"
+    );
+}
+
+#[test]
+fn json_reporter_verbose() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "alter table test drop column id;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--reporter=json",
+                "--verbose",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let report: serde_json::Value =
+        serde_json::from_str(&console_output(&console)).expect("the report is valid JSON");
+
+    assert_eq!(report["summary"]["errors"], 1);
+    assert_eq!(
+        report["diagnostics"][0]["category"],
+        "lint/safety/banDropColumn"
+    );
+}
+
+#[test]
+fn sarif_reporter() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "select 1;\nalter table test drop column id;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let log: serde_json::Value =
+        serde_json::from_str(&console_output(&console)).expect("the log is valid JSON");
+
+    assert_eq!(log["version"], "2.1.0");
+
+    let run = &log["runs"][0];
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let rule = rules
+        .iter()
+        .find(|rule| rule["id"] == "lint/safety/banDropColumn")
+        .expect("the rule is part of the driver");
+    assert_eq!(rule["name"], "banDropColumn");
+    assert_eq!(rule["properties"]["recommended"], true);
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "lint/safety/banDropColumn");
+    assert_eq!(result["level"], "error");
+    assert_eq!(
+        rules[result["ruleIndex"].as_u64().unwrap() as usize]["id"],
+        result["ruleId"]
+    );
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "test.sql");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 1);
+}
+
+#[test]
+fn sarif_reporter_verbose() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("test.sql");
+    fs.insert(
+        file_path.into(),
+        "alter table test drop column id;".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--reporter=sarif",
+                "--verbose",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let log: serde_json::Value =
+        serde_json::from_str(&console_output(&console)).expect("the log is valid JSON");
+
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "lint/safety/banDropColumn");
+}
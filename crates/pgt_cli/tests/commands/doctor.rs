@@ -0,0 +1,70 @@
+use bpaf::Args;
+use std::path::Path;
+
+use crate::run_cli;
+use pgt_console::BufferConsole;
+use pgt_fs::MemoryFileSystem;
+use pgt_workspace::DynRef;
+
+fn console_output(console: &BufferConsole) -> String {
+    console
+        .out_buffer
+        .iter()
+        .flat_map(|message| message.content.0.iter())
+        .map(|node| node.content.as_str())
+        .collect()
+}
+
+#[test]
+fn doctor_reports_files_and_logs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("postgrestools.jsonc").into(),
+        r#"{ "files": { "ignore": ["legacy/**"] } }"#.as_bytes(),
+    );
+    fs.insert(Path::new("schema.sql").into(), "select 1;".as_bytes());
+    fs.insert(
+        Path::new("queries/users.sql").into(),
+        "select 1;".as_bytes(),
+    );
+    fs.insert(
+        Path::new("queries/README.md").into(),
+        "# queries".as_bytes(),
+    );
+    fs.insert(Path::new("legacy/old.sql").into(), "select 1;".as_bytes());
+    fs.insert(
+        Path::new("logs/server.log.2025-01-31-09").into(),
+        "older entry".as_bytes(),
+    );
+    fs.insert(
+        Path::new("logs/server.log.2025-01-31-10").into(),
+        "first entry\nlatest entry".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["doctor", "--disable-db", "--log-path=logs", "--log-lines=1"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let output = console_output(&console);
+    assert!(
+        output.contains("2 SQL file(s) are checked, 1 file(s) or directories are ignored"),
+        "{output}"
+    );
+    assert!(
+        output.contains("legacy/old.sql  matched by files.ignore"),
+        "{output}"
+    );
+    assert!(
+        output.contains("File: logs/server.log.2025-01-31-10"),
+        "{output}"
+    );
+    assert!(output.contains("    latest entry"), "{output}");
+    assert!(!output.contains("first entry"), "{output}");
+    assert!(!output.contains("older entry"), "{output}");
+}
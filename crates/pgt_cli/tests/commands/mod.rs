@@ -1 +1,3 @@
 mod check;
+mod doctor;
+mod init;
@@ -5,6 +5,7 @@ use pgt_cli::{CliDiagnostic, CliSession, pgt_command};
 use pgt_console::{Console, ConsoleExt, markup};
 use pgt_fs::FileSystem;
 use pgt_workspace::{App, DynRef};
+use std::path::PathBuf;
 
 /// Create an [App] instance using the provided [FileSystem] and [Console]
 /// instance, and using an in-process "remote" instance of the workspace
@@ -36,7 +37,10 @@ pub(crate) fn run_cli<'app>(
     let workspace = workspace::client(transport).unwrap();
     let app = App::new(fs, console, WorkspaceRef::Owned(workspace));
 
-    let mut session = CliSession { app };
+    let mut session = CliSession {
+        app,
+        cache_directory: PathBuf::from("cache"),
+    };
     let command = pgt_command().run_inner(args);
     match command {
         Ok(command) => session.run(command),
@@ -28,6 +28,8 @@ fn to_params<'a>(
         schema: cache,
         text,
         tree,
+        usage: None,
+        variables: vec![],
     }
 }
 
@@ -65,6 +65,7 @@ impl<'a> CompletionBuilder<'a> {
             .enumerate()
             .map(|(idx, item)| {
                 let preselected = idx == 0 && should_preselect_first_item;
+                let data = item.score.get_relevance_data().get_item_data();
 
                 CompletionItem {
                     description: item.description,
@@ -76,6 +77,7 @@ impl<'a> CompletionBuilder<'a> {
                     // wonderous Rust syntax ftw
                     sort_text: format!("{:0>padding$}", idx, padding = max_padding),
                     completion_text: item.completion_text,
+                    data,
                 }
             })
             .collect()
@@ -5,9 +5,12 @@ use crate::{
     context::CompletionContext,
     item::CompletionItem,
     providers::{
-        complete_columns, complete_functions, complete_policies, complete_schemas, complete_tables,
+        FunctionVariable, complete_columns, complete_functions, complete_policies,
+        complete_privileges, complete_roles, complete_schemas, complete_settings, complete_tables,
+        complete_variables,
     },
     sanitization::SanitizedCompletionParams,
+    usage::UsageStatistics,
 };
 
 pub const LIMIT: usize = 50;
@@ -18,16 +21,26 @@ pub struct CompletionParams<'a> {
     pub schema: &'a pgt_schema_cache::SchemaCache,
     pub text: String,
     pub tree: &'a tree_sitter::Tree,
+
+    /// Workspace usage statistics used to rank frequently and recently used items higher.
+    pub usage: Option<&'a UsageStatistics>,
+
+    /// Variables in scope if the statement is embedded in a function body.
+    pub variables: Vec<FunctionVariable>,
 }
 
 #[tracing::instrument(level = "debug", skip_all, fields(
     text = params.text,
     position = params.position.to_string()
 ))]
-pub fn complete(params: CompletionParams) -> Vec<CompletionItem> {
+pub fn complete(mut params: CompletionParams) -> Vec<CompletionItem> {
+    let usage = params.usage;
+    let variables = std::mem::take(&mut params.variables);
     let sanitized_params = SanitizedCompletionParams::from(params);
 
-    let ctx = CompletionContext::new(&sanitized_params);
+    let mut ctx = CompletionContext::new(&sanitized_params);
+    ctx.usage = usage;
+    ctx.set_variables(variables);
 
     let mut builder = CompletionBuilder::new(&ctx);
 
@@ -36,6 +49,10 @@ pub fn complete(params: CompletionParams) -> Vec<CompletionItem> {
     complete_columns(&ctx, &mut builder);
     complete_schemas(&ctx, &mut builder);
     complete_policies(&ctx, &mut builder);
+    complete_roles(&ctx, &mut builder);
+    complete_privileges(&ctx, &mut builder);
+    complete_settings(&ctx, &mut builder);
+    complete_variables(&ctx, &mut builder);
 
     builder.finish()
 }
@@ -3,6 +3,8 @@ use std::{
     collections::{HashMap, HashSet},
 };
 mod policy_parser;
+mod utility_stmt_parser;
+mod virtual_relations;
 
 use pgt_schema_cache::SchemaCache;
 use pgt_text_size::TextRange;
@@ -12,11 +14,17 @@ use pgt_treesitter_queries::{
 };
 
 use crate::{
-    NodeText,
-    context::policy_parser::{PolicyParser, PolicyStmtKind},
+    FunctionVariable, FunctionVariableKind, NodeText, UsageStatistics,
+    context::{
+        policy_parser::{PolicyParser, PolicyStmtKind},
+        utility_stmt_parser::{UtilityNodeKind, UtilityStmtParser},
+    },
     sanitization::SanitizedCompletionParams,
 };
 
+use virtual_relations::VirtualRelationsParser;
+pub(crate) use virtual_relations::{VirtualColumn, VirtualRelation, VirtualRelationKind};
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum WrappingClause<'a> {
     Select,
@@ -33,6 +41,8 @@ pub enum WrappingClause<'a> {
     DropTable,
     PolicyName,
     ToRoleAssignment,
+    PrivilegeList,
+    SettingName,
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
@@ -164,6 +174,20 @@ pub(crate) struct CompletionContext<'a> {
     pub mentioned_relations: HashMap<Option<String>, HashSet<String>>,
     pub mentioned_table_aliases: HashMap<String, String>,
     pub mentioned_columns: HashMap<Option<WrappingClause<'a>>, HashSet<MentionedColumn>>,
+
+    /// Relations that are defined within the statement itself,
+    /// such as CTEs, subqueries in `FROM` clauses or `VALUES` lists.
+    pub virtual_relations: Vec<VirtualRelation>,
+
+    /// The kind of object privileges are granted on or revoked from,
+    /// e.g. `function` in `grant | on function my_fn() to anon`.
+    pub privilege_object: Option<String>,
+
+    /// How relations and columns are used throughout the project, if learning is enabled.
+    pub usage: Option<&'a UsageStatistics>,
+
+    /// Variables in scope if the statement is embedded in a function body.
+    pub variables: Vec<FunctionVariable>,
 }
 
 impl<'a> CompletionContext<'a> {
@@ -182,6 +206,10 @@ impl<'a> CompletionContext<'a> {
             mentioned_relations: HashMap::new(),
             mentioned_table_aliases: HashMap::new(),
             mentioned_columns: HashMap::new(),
+            virtual_relations: vec![],
+            privilege_object: None,
+            usage: None,
+            variables: vec![],
         };
 
         // policy handling is important to Supabase, but they are a PostgreSQL specific extension,
@@ -189,14 +217,33 @@ impl<'a> CompletionContext<'a> {
         // We infer the context manually.
         if PolicyParser::looks_like_policy_stmt(&params.text) {
             ctx.gather_policy_context();
+        } else if UtilityStmtParser::looks_like_utility_stmt(&params.text) {
+            // Same for utility statements such as `GRANT` or `SET`.
+            ctx.gather_utility_stmt_context();
         } else {
             ctx.gather_tree_context();
             ctx.gather_info_from_ts_queries();
+            ctx.gather_virtual_relations();
         }
 
         ctx
     }
 
+    pub(crate) fn set_variables(&mut self, variables: Vec<FunctionVariable>) {
+        // `new.|` and `old.|` in trigger functions refer to the columns of the trigger's table.
+        for variable in &variables {
+            if let FunctionVariableKind::TriggerRecord {
+                table: Some((_, table)),
+            } = &variable.kind
+            {
+                self.mentioned_table_aliases
+                    .insert(variable.name.clone(), table.clone());
+            }
+        }
+
+        self.variables = variables;
+    }
+
     fn gather_policy_context(&mut self) {
         let policy_context = PolicyParser::get_context(self.text, self.position);
 
@@ -227,6 +274,30 @@ impl<'a> CompletionContext<'a> {
         };
     }
 
+    fn gather_utility_stmt_context(&mut self) {
+        let utility_context = UtilityStmtParser::get_context(self.text, self.position);
+
+        self.node_under_cursor = Some(NodeUnderCursor::CustomNode {
+            text: utility_context.node_text.into(),
+            range: utility_context.node_range,
+            kind: match utility_context.node_kind {
+                Some(UtilityNodeKind::RoleName) => "role_name",
+                Some(UtilityNodeKind::Privilege) => "privilege",
+                Some(UtilityNodeKind::SettingName) => "setting_name",
+                None => "",
+            }
+            .into(),
+        });
+
+        self.privilege_object = utility_context.privilege_object;
+
+        self.wrapping_clause_type = utility_context.node_kind.map(|kind| match kind {
+            UtilityNodeKind::RoleName => WrappingClause::ToRoleAssignment,
+            UtilityNodeKind::Privilege => WrappingClause::PrivilegeList,
+            UtilityNodeKind::SettingName => WrappingClause::SettingName,
+        });
+    }
+
     fn gather_info_from_ts_queries(&mut self) {
         let stmt_range = self.wrapping_statement_range.as_ref();
         let sql = self.text;
@@ -306,6 +377,33 @@ impl<'a> CompletionContext<'a> {
         }
     }
 
+    fn gather_virtual_relations(&mut self) {
+        self.virtual_relations =
+            VirtualRelationsParser::collect(self.tree.root_node(), self.text, self.schema_cache);
+    }
+
+    /// Returns the virtual relations whose columns can be referenced at the cursor position.
+    ///
+    /// Derived tables are always part of a `FROM` clause, but CTEs need to be mentioned
+    /// in the statement to be relevant.
+    pub(crate) fn get_visible_virtual_relations(&self) -> impl Iterator<Item = &VirtualRelation> {
+        self.virtual_relations.iter().filter(|r| {
+            if r.is_defined_at(self.position) {
+                return false;
+            }
+
+            match r.kind {
+                VirtualRelationKind::Cte => {
+                    self.mentioned_relations
+                        .get(&None)
+                        .is_some_and(|tables| tables.contains(&r.name))
+                        || self.mentioned_table_aliases.values().any(|t| t == &r.name)
+                }
+                VirtualRelationKind::Subquery | VirtualRelationKind::Values => true,
+            }
+        })
+    }
+
     fn get_ts_node_content(&self, ts_node: &tree_sitter::Node<'a>) -> Option<NodeText> {
         let source = self.text;
         ts_node.utf8_text(source.as_bytes()).ok().map(|txt| {
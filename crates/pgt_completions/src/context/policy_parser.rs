@@ -12,10 +12,10 @@ pub(crate) enum PolicyStmtKind {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct WordWithIndex {
-    word: String,
-    start: usize,
-    end: usize,
+pub(crate) struct WordWithIndex {
+    pub(crate) word: String,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
 }
 
 impl WordWithIndex {
@@ -23,7 +23,7 @@ impl WordWithIndex {
         self.start <= cursor_pos && self.end > cursor_pos
     }
 
-    fn get_range(&self) -> TextRange {
+    pub(crate) fn get_range(&self) -> TextRange {
         let start: u32 = self.start.try_into().expect("Text too long");
         let end: u32 = self.end.try_into().expect("Text too long");
         TextRange::new(TextSize::from(start), TextSize::from(end))
@@ -31,7 +31,7 @@ impl WordWithIndex {
 }
 
 /// Note: A policy name within quotation marks will be considered a single word.
-fn sql_to_words(sql: &str) -> Result<Vec<WordWithIndex>, String> {
+pub(crate) fn sql_to_words(sql: &str) -> Result<Vec<WordWithIndex>, String> {
     let mut words = vec![];
 
     let mut start_of_word: Option<usize> = None;
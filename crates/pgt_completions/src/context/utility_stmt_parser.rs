@@ -0,0 +1,295 @@
+use pgt_text_size::TextRange;
+
+use crate::context::policy_parser::{WordWithIndex, sql_to_words};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum UtilityNodeKind {
+    /// `grant select on t to |`, `alter table t owner to |`, `set role |`
+    RoleName,
+    /// `grant |`, `revoke select, |`
+    Privilege,
+    /// `set |`, `show |`, `reset |`
+    SettingName,
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+pub(crate) struct UtilityStmtContext {
+    pub node_text: String,
+    pub node_range: TextRange,
+    pub node_kind: Option<UtilityNodeKind>,
+
+    /// The kind of object privileges are granted on, e.g. `table` in `grant | on table users`.
+    pub privilege_object: Option<String>,
+}
+
+/// Simple parser for utility statements that the tree_sitter_sql language does not support:
+/// `GRANT`, `REVOKE`, `SET`, `SHOW`, `RESET` and `ALTER ... OWNER TO`.
+///
+/// Similar to the `PolicyParser`, it works on the words of the statement and only infers
+/// what kind of node is under the cursor.
+pub(crate) struct UtilityStmtParser {
+    words: Vec<WordWithIndex>,
+    cursor_position: usize,
+}
+
+impl UtilityStmtParser {
+    pub(crate) fn looks_like_utility_stmt(sql: &str) -> bool {
+        let lowercased = sql.to_ascii_lowercase();
+        let mut words = lowercased.split_ascii_whitespace();
+
+        match words.next() {
+            Some("grant" | "revoke" | "set" | "show" | "reset") => true,
+            Some("alter") => {
+                let rest: Vec<&str> = words.collect();
+
+                rest.windows(2).any(|w| w == ["owner", "to"])
+                    || (rest
+                        .first()
+                        .is_some_and(|w| ["system", "database", "role", "user"].contains(w))
+                        && rest.iter().any(|w| *w == "set" || *w == "reset"))
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn get_context(sql: &str, cursor_position: usize) -> UtilityStmtContext {
+        assert!(
+            Self::looks_like_utility_stmt(sql),
+            "UtilityStmtParser should only be used for utility statements. Developer error!"
+        );
+
+        match sql_to_words(sql) {
+            Ok(words) => UtilityStmtParser {
+                words,
+                cursor_position,
+            }
+            .parse(),
+            Err(_) => UtilityStmtContext::default(),
+        }
+    }
+
+    fn parse(self) -> UtilityStmtContext {
+        let Some(idx) = self
+            .words
+            .iter()
+            .position(|w| w.start <= self.cursor_position && self.cursor_position <= w.end)
+        else {
+            return UtilityStmtContext::default();
+        };
+
+        let token = &self.words[idx];
+
+        UtilityStmtContext {
+            node_text: token.word.clone(),
+            node_range: token.get_range(),
+            node_kind: self.node_kind(idx),
+            privilege_object: self.privilege_object(idx),
+        }
+    }
+
+    fn node_kind(&self, idx: usize) -> Option<UtilityNodeKind> {
+        let head = self.lowercased(0)?;
+        let keyword_idx = self.keyword_before(idx)?;
+        let keyword = self.lowercased(keyword_idx)?;
+        let previous_keyword = keyword_idx.checked_sub(1).and_then(|i| self.lowercased(i));
+
+        match (head.as_str(), keyword.as_str()) {
+            ("grant", "to") | ("revoke", "from") => Some(UtilityNodeKind::RoleName),
+            ("alter", "to") if previous_keyword.as_deref() == Some("owner") => {
+                Some(UtilityNodeKind::RoleName)
+            }
+            ("set", "role" | "authorization") => Some(UtilityNodeKind::RoleName),
+
+            ("grant" | "revoke", "grant" | "revoke") => Some(UtilityNodeKind::Privilege),
+
+            ("set", "set" | "local" | "session") => Some(UtilityNodeKind::SettingName),
+            ("show", "show") | ("reset", "reset") => Some(UtilityNodeKind::SettingName),
+            ("alter", "set" | "reset") => Some(UtilityNodeKind::SettingName),
+
+            _ => None,
+        }
+    }
+
+    /// `grant select, | on function my_fn to ...` – returns `function`.
+    fn privilege_object(&self, idx: usize) -> Option<String> {
+        let on_idx =
+            (idx + 1..self.words.len()).find(|i| self.lowercased(*i).as_deref() == Some("on"))?;
+
+        let object = self.lowercased(on_idx + 1)?;
+
+        match object.as_str() {
+            "table" | "sequence" | "database" | "domain" | "function" | "procedure" | "routine"
+            | "language" | "schema" | "tablespace" | "type" => Some(object),
+            "all" => match self.lowercased(on_idx + 2)?.as_str() {
+                "tables" => Some("table".into()),
+                "sequences" => Some("sequence".into()),
+                "functions" => Some("function".into()),
+                "procedures" => Some("procedure".into()),
+                "routines" => Some("routine".into()),
+                _ => None,
+            },
+            // `grant select on users to ...` – the object kind defaults to `table`.
+            _ => Some("table".into()),
+        }
+    }
+
+    /// Returns the index of the closest keyword in front of the word at `idx`,
+    /// skipping over comma-separated lists such as `to anon, authenticated, |`.
+    fn keyword_before(&self, idx: usize) -> Option<usize> {
+        let mut i = idx;
+
+        loop {
+            i = i.checked_sub(1)?;
+
+            let word = self.words[i].word.as_str();
+
+            if word == "," {
+                // skip the list item in front of the standalone comma
+                i = i.checked_sub(1)?;
+                continue;
+            }
+
+            if word.ends_with(',') {
+                continue;
+            }
+
+            return Some(i);
+        }
+    }
+
+    fn lowercased(&self, idx: usize) -> Option<String> {
+        self.words
+            .get(idx)
+            .map(|w| w.word.trim_end_matches(',').to_ascii_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_helper::CURSOR_POS;
+
+    use super::{UtilityNodeKind, UtilityStmtParser};
+
+    fn with_pos(query: String) -> (usize, String) {
+        let pos = query.find(CURSOR_POS).expect("Please add cursor position!");
+
+        (pos, query.replace(CURSOR_POS, "REPLACED_TOKEN"))
+    }
+
+    fn node_kind_of(query: String) -> Option<UtilityNodeKind> {
+        let (pos, query) = with_pos(query);
+        UtilityStmtParser::get_context(query.as_str(), pos).node_kind
+    }
+
+    #[test]
+    fn recognizes_utility_statements() {
+        assert!(UtilityStmtParser::looks_like_utility_stmt(
+            "grant select on users to anon"
+        ));
+        assert!(UtilityStmtParser::looks_like_utility_stmt("  SET ROLE "));
+        assert!(UtilityStmtParser::looks_like_utility_stmt(
+            "alter table users owner to "
+        ));
+        assert!(UtilityStmtParser::looks_like_utility_stmt(
+            "alter system set work_mem = '4MB'"
+        ));
+
+        assert!(!UtilityStmtParser::looks_like_utility_stmt(
+            "alter table users add column x text"
+        ));
+        assert!(!UtilityStmtParser::looks_like_utility_stmt(
+            "update users set name = 'x'"
+        ));
+        assert!(!UtilityStmtParser::looks_like_utility_stmt("settings"));
+    }
+
+    #[test]
+    fn determines_role_names() {
+        let queries = vec![
+            format!("grant select on users to {}", CURSOR_POS),
+            format!("grant select on users to anon, {}", CURSOR_POS),
+            format!("revoke all on users from {}", CURSOR_POS),
+            format!("alter table users owner to {}", CURSOR_POS),
+            format!("alter function my_fn() owner to {}", CURSOR_POS),
+            format!("set role {}", CURSOR_POS),
+            format!("set session authorization {}", CURSOR_POS),
+        ];
+
+        for query in queries {
+            assert_eq!(
+                node_kind_of(query.clone()),
+                Some(UtilityNodeKind::RoleName),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn determines_privileges() {
+        let queries = vec![
+            format!("grant {}", CURSOR_POS),
+            format!("grant select, {} on users to anon", CURSOR_POS),
+            format!("grant select , {}", CURSOR_POS),
+            format!("revoke {}", CURSOR_POS),
+        ];
+
+        for query in queries {
+            assert_eq!(
+                node_kind_of(query.clone()),
+                Some(UtilityNodeKind::Privilege),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn determines_setting_names() {
+        let queries = vec![
+            format!("set {}", CURSOR_POS),
+            format!("set local {}", CURSOR_POS),
+            format!("show {}", CURSOR_POS),
+            format!("reset {}", CURSOR_POS),
+            format!("alter system set {}", CURSOR_POS),
+            format!("alter role authenticated set {}", CURSOR_POS),
+        ];
+
+        for query in queries {
+            assert_eq!(
+                node_kind_of(query.clone()),
+                Some(UtilityNodeKind::SettingName),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn does_not_complete_elsewhere() {
+        let queries = vec![
+            format!("grant select on {}", CURSOR_POS),
+            format!("alter system set work_mem to {}", CURSOR_POS),
+            format!("set search_path = {}", CURSOR_POS),
+        ];
+
+        for query in queries {
+            assert_eq!(node_kind_of(query.clone()), None, "{}", query);
+        }
+    }
+
+    #[test]
+    fn determines_privilege_object() {
+        let (pos, query) = with_pos(format!("grant {} on function my_fn() to anon", CURSOR_POS));
+        let context = UtilityStmtParser::get_context(query.as_str(), pos);
+        assert_eq!(context.privilege_object.as_deref(), Some("function"));
+
+        let (pos, query) = with_pos(format!("grant {} on users to anon", CURSOR_POS));
+        let context = UtilityStmtParser::get_context(query.as_str(), pos);
+        assert_eq!(context.privilege_object.as_deref(), Some("table"));
+
+        let (pos, query) = with_pos(format!("grant {}", CURSOR_POS));
+        let context = UtilityStmtParser::get_context(query.as_str(), pos);
+        assert_eq!(context.privilege_object, None);
+    }
+}
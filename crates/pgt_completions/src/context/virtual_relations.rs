@@ -0,0 +1,743 @@
+use pgt_schema_cache::SchemaCache;
+use pgt_text_size::{TextRange, TextSize};
+
+/// Where a `VirtualRelation` was defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VirtualRelationKind {
+    /// `with recent as (select ...)`, including data-modifying CTEs with a `RETURNING` clause.
+    Cte,
+    /// `from (select ...) as sub`
+    Subquery,
+    /// `from (values (1, 'a')) as v(id, name)`
+    Values,
+}
+
+/// A column that is produced by a `VirtualRelation`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VirtualColumn {
+    pub name: String,
+    pub relation_name: String,
+    /// The inferred type, using the names from `pg_type.typname`.
+    pub type_name: Option<String>,
+}
+
+/// A relation that does not exist in the database, but is defined within the statement,
+/// such as a CTE or a derived table in a `FROM` clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VirtualRelation {
+    pub name: String,
+    pub kind: VirtualRelationKind,
+    pub columns: Vec<VirtualColumn>,
+    /// The range of the query that defines the relation.
+    /// Its columns are not visible within that query.
+    pub definition_range: TextRange,
+}
+
+impl VirtualRelation {
+    pub fn is_defined_at(&self, position: usize) -> bool {
+        TextSize::try_from(position).is_ok_and(|p| self.definition_range.contains_inclusive(p))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OutputColumn {
+    name: String,
+    type_name: Option<String>,
+}
+
+#[derive(Debug)]
+enum SourceColumns {
+    Table {
+        schema: Option<String>,
+        name: String,
+    },
+    Virtual(Vec<OutputColumn>),
+    Unknown,
+}
+
+/// A relation that is available in the `FROM` clause of a query.
+#[derive(Debug)]
+struct Source {
+    /// The alias, or the name of the table if there is none.
+    qualifier: String,
+    columns: SourceColumns,
+}
+
+/// Walks the tree-sitter CST of a statement and derives the output columns
+/// of CTEs, subqueries and `VALUES` lists.
+///
+/// Column types are inferred where possible: from the schema cache for
+/// referenced table columns, from explicit casts, from literals and from
+/// function return types.
+pub(crate) struct VirtualRelationsParser<'a> {
+    sql: &'a str,
+    schema_cache: &'a SchemaCache,
+    relations: Vec<VirtualRelation>,
+}
+
+impl<'a> VirtualRelationsParser<'a> {
+    pub fn collect(
+        root: tree_sitter::Node<'a>,
+        sql: &'a str,
+        schema_cache: &'a SchemaCache,
+    ) -> Vec<VirtualRelation> {
+        let mut parser = Self {
+            sql,
+            schema_cache,
+            relations: vec![],
+        };
+
+        parser.visit(root);
+
+        parser.relations
+    }
+
+    fn visit(&mut self, node: tree_sitter::Node<'a>) {
+        match node.kind() {
+            "cte" => self.add_cte(node),
+            "relation" => self.add_derived_table(node),
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child);
+        }
+    }
+
+    fn add_cte(&mut self, node: tree_sitter::Node<'a>) {
+        let mut name: Option<String> = None;
+        let mut column_names = vec![];
+        let mut body = None;
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                // `with recent(id, total) as (...)`: the first identifier is the name,
+                // all following identifiers before the body are column names.
+                "identifier" if body.is_none() => match name {
+                    None => name = Some(self.identifier_text(child)),
+                    Some(_) => column_names.push(self.identifier_text(child)),
+                },
+                "statement" => body = Some(child),
+                _ => {}
+            }
+        }
+
+        let (Some(name), Some(body)) = (name, body) else {
+            return;
+        };
+
+        let mut columns = self.columns_of_query(body);
+        rename_columns(&mut columns, column_names);
+
+        self.push_relation(name, VirtualRelationKind::Cte, columns, body);
+    }
+
+    fn add_derived_table(&mut self, node: tree_sitter::Node<'a>) {
+        let Some(alias) = self.relation_alias(node) else {
+            return;
+        };
+
+        let mut cursor = node.walk();
+        let children: Vec<tree_sitter::Node<'a>> = node.children(&mut cursor).collect();
+
+        let (kind, definition, mut columns) =
+            if let Some(subquery) = children.iter().find(|c| c.kind() == "subquery") {
+                (
+                    VirtualRelationKind::Subquery,
+                    *subquery,
+                    self.columns_of_query(*subquery),
+                )
+            } else if let Some(values) = children.iter().find(|c| c.kind() == "values") {
+                (
+                    VirtualRelationKind::Values,
+                    *values,
+                    self.columns_of_values(*values),
+                )
+            } else {
+                return;
+            };
+
+        rename_columns(&mut columns, self.relation_column_aliases(node));
+
+        self.push_relation(alias, kind, columns, definition);
+    }
+
+    fn push_relation(
+        &mut self,
+        name: String,
+        kind: VirtualRelationKind,
+        columns: Vec<OutputColumn>,
+        definition: tree_sitter::Node<'a>,
+    ) {
+        let definition_range = TextRange::new(
+            TextSize::try_from(definition.start_byte()).unwrap(),
+            TextSize::try_from(definition.end_byte()).unwrap(),
+        );
+
+        self.relations.push(VirtualRelation {
+            columns: columns
+                .into_iter()
+                .map(|c| VirtualColumn {
+                    name: c.name,
+                    relation_name: name.clone(),
+                    type_name: c.type_name,
+                })
+                .collect(),
+            name,
+            kind,
+            definition_range,
+        });
+    }
+
+    /// Returns the output columns of a `statement` or `subquery` node.
+    /// Data-modifying statements produce the columns of their `RETURNING` clause.
+    fn columns_of_query(&self, node: tree_sitter::Node<'a>) -> Vec<OutputColumn> {
+        let mut cursor = node.walk();
+        let is_dml = node
+            .children(&mut cursor)
+            .any(|c| ["insert", "update", "delete"].contains(&c.kind()));
+
+        if is_dml {
+            let Some(returning) = find_in_query(node, "returning") else {
+                return vec![];
+            };
+
+            let sources = find_in_query(node, "object_reference")
+                .map(
+                    |table| match table.parent().filter(|p| p.kind() == "relation") {
+                        Some(relation) => self.source_from_relation(relation),
+                        None => self.source_from_object_reference(table, None),
+                    },
+                )
+                .into_iter()
+                .collect::<Vec<Source>>();
+
+            return find_in_query(returning, "select_expression")
+                .map(|expr| self.columns_of_select_expression(expr, &sources))
+                .unwrap_or_default();
+        }
+
+        let Some(select) = find_in_query(node, "select") else {
+            return vec![];
+        };
+
+        let sources = select
+            .next_named_sibling()
+            .filter(|n| n.kind() == "from")
+            .map(|from| self.sources_of_from_clause(from))
+            .unwrap_or_default();
+
+        find_in_query(select, "select_expression")
+            .map(|expr| self.columns_of_select_expression(expr, &sources))
+            .unwrap_or_default()
+    }
+
+    fn columns_of_values(&self, node: tree_sitter::Node<'a>) -> Vec<OutputColumn> {
+        let mut cursor = node.walk();
+        let Some(first_row) = node.children(&mut cursor).find(|c| c.kind() == "list") else {
+            return vec![];
+        };
+
+        let mut cursor = first_row.walk();
+        first_row
+            .named_children(&mut cursor)
+            .enumerate()
+            .map(|(idx, value)| OutputColumn {
+                // Postgres names the columns of a `VALUES` list `column1`, `column2`, ...
+                name: format!("column{}", idx + 1),
+                type_name: self.infer_expression_type(value, &[]),
+            })
+            .collect()
+    }
+
+    fn columns_of_select_expression(
+        &self,
+        node: tree_sitter::Node<'a>,
+        sources: &[Source],
+    ) -> Vec<OutputColumn> {
+        let mut columns = vec![];
+
+        let mut cursor = node.walk();
+        for term in node.children(&mut cursor).filter(|c| c.kind() == "term") {
+            let Some(value) = term.named_child(0) else {
+                continue;
+            };
+
+            if value.kind() == "all_fields" {
+                let qualifier = value
+                    .named_child(0)
+                    .filter(|n| n.kind() == "object_reference")
+                    .map(|n| self.object_reference_parts(n).1);
+
+                for source in sources
+                    .iter()
+                    .filter(|s| qualifier.as_ref().is_none_or(|q| q == &s.qualifier))
+                {
+                    columns.extend(self.columns_of_source(source));
+                }
+
+                continue;
+            }
+
+            let alias = term
+                .named_child(term.named_child_count().saturating_sub(1))
+                .filter(|n| n.kind() == "identifier" && n.id() != value.id())
+                .map(|n| self.identifier_text(n));
+
+            // Postgres would name such a column `?column?`, which can't be referenced anyway.
+            let Some(name) = alias.or_else(|| self.implicit_column_name(value)) else {
+                continue;
+            };
+
+            columns.push(OutputColumn {
+                name,
+                type_name: self.infer_expression_type(value, sources),
+            });
+        }
+
+        columns
+    }
+
+    fn sources_of_from_clause(&self, node: tree_sitter::Node<'a>) -> Vec<Source> {
+        let mut relations = vec![];
+        collect_relations(node, &mut relations);
+
+        relations
+            .into_iter()
+            .map(|r| self.source_from_relation(r))
+            .collect()
+    }
+
+    fn source_from_relation(&self, node: tree_sitter::Node<'a>) -> Source {
+        let alias = self.relation_alias(node);
+        let column_aliases = self.relation_column_aliases(node);
+
+        let mut source = match node.named_child(0) {
+            Some(n) if n.kind() == "object_reference" => {
+                self.source_from_object_reference(n, alias)
+            }
+            Some(n) if n.kind() == "subquery" => Source {
+                qualifier: alias.unwrap_or_default(),
+                columns: SourceColumns::Virtual(self.columns_of_query(n)),
+            },
+            Some(n) if n.kind() == "values" => Source {
+                qualifier: alias.unwrap_or_default(),
+                columns: SourceColumns::Virtual(self.columns_of_values(n)),
+            },
+            _ => Source {
+                qualifier: alias.unwrap_or_default(),
+                columns: SourceColumns::Unknown,
+            },
+        };
+
+        if !column_aliases.is_empty() {
+            let mut columns = self.columns_of_source(&source);
+            rename_columns(&mut columns, column_aliases);
+            source.columns = SourceColumns::Virtual(columns);
+        }
+
+        source
+    }
+
+    fn source_from_object_reference(
+        &self,
+        node: tree_sitter::Node<'a>,
+        alias: Option<String>,
+    ) -> Source {
+        let (schema, name) = self.object_reference_parts(node);
+
+        // CTEs shadow tables of the same name, but they can't be schema-qualified.
+        let cte = schema
+            .is_none()
+            .then(|| {
+                self.relations
+                    .iter()
+                    .rev()
+                    .find(|r| r.kind == VirtualRelationKind::Cte && r.name == name)
+            })
+            .flatten();
+
+        let columns = match cte {
+            Some(cte) => SourceColumns::Virtual(
+                cte.columns
+                    .iter()
+                    .map(|c| OutputColumn {
+                        name: c.name.clone(),
+                        type_name: c.type_name.clone(),
+                    })
+                    .collect(),
+            ),
+            None => SourceColumns::Table {
+                schema,
+                name: name.clone(),
+            },
+        };
+
+        Source {
+            qualifier: alias.unwrap_or(name),
+            columns,
+        }
+    }
+
+    fn columns_of_source(&self, source: &Source) -> Vec<OutputColumn> {
+        match &source.columns {
+            SourceColumns::Table { schema, name } => self
+                .schema_cache
+                .columns
+                .iter()
+                .filter(|c| {
+                    &c.table_name == name && schema.as_ref().is_none_or(|s| s == &c.schema_name)
+                })
+                .map(|c| OutputColumn {
+                    name: c.name.clone(),
+                    type_name: c.type_name.clone(),
+                })
+                .collect(),
+            SourceColumns::Virtual(cols) => cols.clone(),
+            SourceColumns::Unknown => vec![],
+        }
+    }
+
+    fn find_column_type(
+        &self,
+        column: &str,
+        qualifier: Option<&str>,
+        sources: &[Source],
+    ) -> Option<String> {
+        sources
+            .iter()
+            .filter(|s| qualifier.is_none_or(|q| q == s.qualifier))
+            .find_map(|s| {
+                self.columns_of_source(s)
+                    .into_iter()
+                    .find(|c| c.name == column)
+            })
+            .and_then(|c| c.type_name)
+    }
+
+    fn infer_expression_type(
+        &self,
+        node: tree_sitter::Node<'a>,
+        sources: &[Source],
+    ) -> Option<String> {
+        match node.kind() {
+            "field" => {
+                let (qualifier, column) = self.field_parts(node);
+                self.find_column_type(column.as_str(), qualifier.as_deref(), sources)
+            }
+            "literal" => infer_literal_type(self.text(node)).map(|t| t.to_string()),
+            "cast" => self.cast_type(node),
+            "invocation" => {
+                let (schema, name) = node
+                    .named_child(0)
+                    .filter(|n| n.kind() == "object_reference")
+                    .map(|n| self.object_reference_parts(n))?;
+
+                self.schema_cache
+                    .functions
+                    .iter()
+                    .find(|f| f.name == name && schema.as_ref().is_none_or(|s| s == &f.schema))
+                    .map(|f| f.return_type.clone())
+            }
+            "parenthesized_expression" => node
+                .named_child(0)
+                .and_then(|n| self.infer_expression_type(n, sources)),
+            _ => None,
+        }
+    }
+
+    /// Handles both `value::type` and `cast(value as type)`.
+    fn cast_type(&self, node: tree_sitter::Node<'a>) -> Option<String> {
+        let mut cursor = node.walk();
+        let children: Vec<tree_sitter::Node<'a>> = node.children(&mut cursor).collect();
+
+        let type_start = children
+            .iter()
+            .position(|c| c.kind() == "::" || c.kind() == "keyword_as")?
+            + 1;
+
+        let type_nodes: Vec<&tree_sitter::Node<'a>> = children[type_start..]
+            .iter()
+            .filter(|c| c.kind() != ")")
+            .collect();
+
+        let start = type_nodes.first()?.start_byte();
+        let end = type_nodes.last()?.end_byte();
+
+        self.sql
+            .get(start..end)
+            .map(|t| t.trim().to_ascii_lowercase())
+    }
+
+    /// Postgres names an unaliased output column after the referenced column
+    /// or the invoked function.
+    fn implicit_column_name(&self, node: tree_sitter::Node<'a>) -> Option<String> {
+        match node.kind() {
+            "field" => Some(self.field_parts(node).1),
+            "invocation" => node
+                .named_child(0)
+                .filter(|n| n.kind() == "object_reference")
+                .map(|n| self.object_reference_parts(n).1),
+            "cast" => {
+                let mut cursor = node.walk();
+                node.named_children(&mut cursor)
+                    .find(|n| !n.kind().starts_with("keyword_"))
+                    .and_then(|n| self.implicit_column_name(n))
+            }
+            _ => None,
+        }
+    }
+
+    fn relation_alias(&self, node: tree_sitter::Node<'a>) -> Option<String> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .find(|c| c.kind() == "identifier")
+            .map(|c| self.identifier_text(c))
+    }
+
+    /// `from (values (1, 'a')) as v(id, name)` – returns `["id", "name"]`.
+    fn relation_column_aliases(&self, node: tree_sitter::Node<'a>) -> Vec<String> {
+        let mut cursor = node.walk();
+        let Some(list) = node
+            .children(&mut cursor)
+            .skip_while(|c| c.kind() != "identifier")
+            .find(|c| c.kind() == "list")
+        else {
+            return vec![];
+        };
+
+        let mut identifiers = vec![];
+        collect_identifiers(list, &mut identifiers);
+
+        identifiers
+            .into_iter()
+            .map(|n| self.identifier_text(n))
+            .collect()
+    }
+
+    /// Returns `(qualifier, column)` of a `field` node.
+    fn field_parts(&self, node: tree_sitter::Node<'a>) -> (Option<String>, String) {
+        let mut cursor = node.walk();
+        let children: Vec<tree_sitter::Node<'a>> = node.named_children(&mut cursor).collect();
+
+        let qualifier = children
+            .iter()
+            .find(|c| c.kind() == "object_reference")
+            .map(|n| self.object_reference_parts(*n).1);
+
+        let column = children
+            .iter()
+            .rev()
+            .find(|c| c.kind() == "identifier")
+            .map(|n| self.identifier_text(*n))
+            .unwrap_or_default();
+
+        (qualifier, column)
+    }
+
+    /// Returns `(schema, name)` of an `object_reference` node.
+    fn object_reference_parts(&self, node: tree_sitter::Node<'a>) -> (Option<String>, String) {
+        let mut cursor = node.walk();
+        let mut parts: Vec<String> = node
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() == "identifier")
+            .map(|c| self.identifier_text(c))
+            .collect();
+
+        let name = parts.pop().unwrap_or_default();
+        let schema = parts.pop();
+
+        (schema, name)
+    }
+
+    fn identifier_text(&self, node: tree_sitter::Node<'a>) -> String {
+        self.text(node).replace('"', "")
+    }
+
+    fn text(&self, node: tree_sitter::Node<'a>) -> &'a str {
+        node.utf8_text(self.sql.as_bytes()).unwrap_or_default()
+    }
+}
+
+fn rename_columns(columns: &mut [OutputColumn], names: Vec<String>) {
+    for (column, name) in columns.iter_mut().zip(names) {
+        column.name = name;
+    }
+}
+
+/// Breadth-first search for a node of the given kind that belongs to the query itself,
+/// not to a nested subquery or CTE.
+fn find_in_query<'a>(node: tree_sitter::Node<'a>, kind: &str) -> Option<tree_sitter::Node<'a>> {
+    let mut queue = std::collections::VecDeque::from([node]);
+
+    while let Some(current) = queue.pop_front() {
+        let mut cursor = current.walk();
+        for child in current.children(&mut cursor) {
+            if child.kind() == kind {
+                return Some(child);
+            }
+
+            if !["subquery", "cte", "statement"].contains(&child.kind()) {
+                queue.push_back(child);
+            }
+        }
+    }
+
+    None
+}
+
+fn collect_relations<'a>(node: tree_sitter::Node<'a>, relations: &mut Vec<tree_sitter::Node<'a>>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "relation" => relations.push(child),
+            "subquery" | "statement" => {}
+            _ => collect_relations(child, relations),
+        }
+    }
+}
+
+fn collect_identifiers<'a>(
+    node: tree_sitter::Node<'a>,
+    identifiers: &mut Vec<tree_sitter::Node<'a>>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "identifier" || child.kind() == "column" {
+            identifiers.push(child);
+        } else {
+            collect_identifiers(child, identifiers);
+        }
+    }
+}
+
+fn infer_literal_type(literal: &str) -> Option<&'static str> {
+    let literal = literal.trim();
+
+    if literal.starts_with('\'') {
+        Some("text")
+    } else if literal.eq_ignore_ascii_case("true") || literal.eq_ignore_ascii_case("false") {
+        Some("bool")
+    } else if literal.parse::<i32>().is_ok() {
+        Some("int4")
+    } else if literal.parse::<i64>().is_ok() {
+        Some("int8")
+    } else if literal.parse::<f64>().is_ok() {
+        Some("numeric")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_schema_cache::SchemaCache;
+
+    use super::{VirtualRelationKind, VirtualRelationsParser};
+
+    fn get_tree(input: &str) -> tree_sitter::Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(tree_sitter_sql::language())
+            .expect("Couldn't set language");
+
+        parser.parse(input, None).expect("Unable to parse tree")
+    }
+
+    fn columns_of(sql: &str, relation: &str) -> Vec<(String, Option<String>)> {
+        let tree = get_tree(sql);
+        let cache = SchemaCache::default();
+
+        let relations = VirtualRelationsParser::collect(tree.root_node(), sql, &cache);
+
+        relations
+            .into_iter()
+            .find(|r| r.name == relation)
+            .unwrap_or_else(|| panic!("Relation {} was not found", relation))
+            .columns
+            .into_iter()
+            .map(|c| (c.name, c.type_name))
+            .collect()
+    }
+
+    #[test]
+    fn finds_cte_columns_with_aliases() {
+        let sql = "with recent as (select id, total as amount, 1 as one, 'x'::varchar as tag from orders) select * from recent;";
+
+        assert_eq!(
+            columns_of(sql, "recent"),
+            vec![
+                ("id".into(), None),
+                ("amount".into(), None),
+                ("one".into(), Some("int4".into())),
+                ("tag".into(), Some("varchar".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn prefers_explicit_cte_column_names() {
+        let sql = "with recent(a, b) as (select id, total from orders) select * from recent;";
+
+        let names: Vec<String> = columns_of(sql, "recent")
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect();
+
+        assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn finds_subquery_columns() {
+        let sql = "select * from (select id, true as active from users) as sub;";
+
+        assert_eq!(
+            columns_of(sql, "sub"),
+            vec![("id".into(), None), ("active".into(), Some("bool".into()))]
+        );
+    }
+
+    #[test]
+    fn finds_values_columns() {
+        let sql = "select * from (values (1, 'one'), (2, 'two')) as v(num, word);";
+
+        assert_eq!(
+            columns_of(sql, "v"),
+            vec![
+                ("num".into(), Some("int4".into())),
+                ("word".into(), Some("text".into()))
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_returning_columns() {
+        let sql = "with inserted as (insert into users (name) values ('x') returning id, name as user_name) select * from inserted;";
+
+        let tree = get_tree(sql);
+        let cache = SchemaCache::default();
+        let relations = VirtualRelationsParser::collect(tree.root_node(), sql, &cache);
+
+        let inserted = relations
+            .iter()
+            .find(|r| r.name == "inserted")
+            .expect("Relation inserted was not found");
+
+        assert_eq!(inserted.kind, VirtualRelationKind::Cte);
+
+        let names: Vec<&str> = inserted.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "user_name"]);
+    }
+
+    #[test]
+    fn resolves_columns_of_previous_ctes() {
+        let sql = "with a as (select 1 as x), b as (select a.x from a) select * from b;";
+
+        assert_eq!(
+            columns_of(sql, "b"),
+            vec![("x".into(), Some("int4".into()))]
+        );
+    }
+}
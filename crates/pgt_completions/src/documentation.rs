@@ -0,0 +1,372 @@
+use std::fmt::Write;
+
+use pgt_schema_cache::{
+    Behavior, Column, Function, Policy, PolicyCommand, Role, Schema, SchemaCache, Setting, Table,
+    TableKind,
+};
+
+use crate::CompletionItemData;
+
+/// Builds the markdown documentation for a completion item.
+///
+/// Returns `None` if the referenced database object no longer exists in the schema cache.
+pub fn resolve_documentation(
+    data: &CompletionItemData,
+    schema_cache: &SchemaCache,
+) -> Option<String> {
+    match data {
+        CompletionItemData::Table { id } => schema_cache
+            .tables
+            .iter()
+            .find(|t| t.id == *id)
+            .map(|t| table_documentation(t, schema_cache)),
+
+        CompletionItemData::Function { id } => schema_cache
+            .functions
+            .iter()
+            .find(|f| f.id == *id)
+            .map(function_documentation),
+
+        CompletionItemData::Column { table_id, name } => schema_cache
+            .columns
+            .iter()
+            .find(|c| c.table_oid == *table_id && c.name == *name)
+            .map(column_documentation),
+
+        CompletionItemData::Schema { id } => schema_cache
+            .schemas
+            .iter()
+            .find(|s| s.id == *id)
+            .map(|s| schema_documentation(s, schema_cache)),
+
+        CompletionItemData::Policy {
+            schema,
+            table,
+            name,
+        } => schema_cache
+            .policies
+            .iter()
+            .find(|p| p.schema_name == *schema && p.table_name == *table && p.name == *name)
+            .map(policy_documentation),
+
+        CompletionItemData::Role { name } => schema_cache
+            .roles
+            .iter()
+            .find(|r| r.name == *name)
+            .map(role_documentation),
+
+        CompletionItemData::Setting { name } => schema_cache
+            .settings
+            .iter()
+            .find(|s| s.name == *name)
+            .map(setting_documentation),
+    }
+}
+
+fn table_documentation(table: &Table, schema_cache: &SchemaCache) -> String {
+    let kind = match table.table_kind {
+        TableKind::Ordinary => "Table",
+        TableKind::Partitioned => "Partitioned Table",
+        TableKind::View => "View",
+        TableKind::MaterializedView => "Materialized View",
+    };
+
+    let mut doc = format!("**{}** `{}.{}`\n", kind, table.schema, table.name);
+
+    if let Some(comment) = table.comment.as_ref() {
+        let _ = write!(doc, "\n{}\n", comment);
+    }
+
+    let columns: Vec<&Column> = schema_cache
+        .columns
+        .iter()
+        .filter(|c| c.table_oid == table.id)
+        .collect();
+
+    if !columns.is_empty() {
+        doc.push_str("\n| Column | Type | Nullable |\n| --- | --- | --- |\n");
+        for col in columns {
+            let _ = writeln!(
+                doc,
+                "| {}{} | {} | {} |",
+                col.name,
+                if col.is_primary_key { " (PK)" } else { "" },
+                col.type_name.as_deref().unwrap_or("unknown"),
+                if col.is_nullable { "yes" } else { "no" }
+            );
+        }
+    }
+
+    if matches!(
+        table.table_kind,
+        TableKind::Ordinary | TableKind::Partitioned
+    ) {
+        let _ = write!(doc, "\n~{} rows, {}", table.live_rows_estimate, table.size);
+
+        if table.rls_enabled {
+            doc.push_str(", row level security enabled");
+        }
+
+        doc.push('\n');
+    }
+
+    doc
+}
+
+fn function_documentation(func: &Function) -> String {
+    let mut doc = format!(
+        "**Function** `{}.{}({})` → `{}`\n",
+        func.schema,
+        func.name,
+        func.argument_types.as_deref().unwrap_or(""),
+        func.return_type
+    );
+
+    let behavior = match func.behavior {
+        Behavior::Immutable => "immutable",
+        Behavior::Stable => "stable",
+        Behavior::Volatile => "volatile",
+    };
+
+    let _ = write!(doc, "\nLanguage `{}`, {}", func.language, behavior);
+
+    if func.security_definer {
+        doc.push_str(", security definer");
+    }
+
+    doc.push('\n');
+
+    if let Some(definition) = func.definition.as_ref() {
+        let _ = write!(doc, "\n```sql\n{}\n```\n", definition.trim_end());
+    }
+
+    doc
+}
+
+fn column_documentation(col: &Column) -> String {
+    let mut doc = format!(
+        "**Column** `{}.{}.{}`\n\nType `{}`{}",
+        col.schema_name,
+        col.table_name,
+        col.name,
+        col.type_name.as_deref().unwrap_or("unknown"),
+        if col.is_nullable { "" } else { ", not null" }
+    );
+
+    if col.is_primary_key {
+        doc.push_str(", primary key");
+    } else if col.is_unique {
+        doc.push_str(", unique");
+    }
+
+    doc.push('\n');
+
+    if let Some(default) = col.default_expr.as_ref() {
+        let _ = write!(doc, "\nDefault `{}`\n", default);
+    }
+
+    if let Some(comment) = col.comment.as_ref() {
+        let _ = write!(doc, "\n{}\n", comment);
+    }
+
+    doc
+}
+
+fn schema_documentation(schema: &Schema, schema_cache: &SchemaCache) -> String {
+    let tables = schema_cache
+        .tables
+        .iter()
+        .filter(|t| t.schema == schema.name)
+        .count();
+
+    let functions = schema_cache
+        .functions
+        .iter()
+        .filter(|f| f.schema == schema.name)
+        .count();
+
+    format!(
+        "**Schema** `{}`\n\nOwned by `{}`, {} tables, {} functions\n",
+        schema.name, schema.owner, tables, functions
+    )
+}
+
+fn policy_documentation(policy: &Policy) -> String {
+    let command = match policy.command {
+        PolicyCommand::Select => "SELECT",
+        PolicyCommand::Insert => "INSERT",
+        PolicyCommand::Update => "UPDATE",
+        PolicyCommand::Delete => "DELETE",
+        PolicyCommand::All => "ALL",
+    };
+
+    let mut doc = format!(
+        "**Policy** `{}` on `{}.{}`\n\n{} for {} to {}\n",
+        policy.name,
+        policy.schema_name,
+        policy.table_name,
+        if policy.is_permissive {
+            "Permissive"
+        } else {
+            "Restrictive"
+        },
+        command,
+        policy.role_names.join(", ")
+    );
+
+    if let Some(using) = policy.security_qualification.as_ref() {
+        let _ = write!(doc, "\n```sql\nusing ({})\n```\n", using);
+    }
+
+    if let Some(check) = policy.with_check.as_ref() {
+        let _ = write!(doc, "\n```sql\nwith check ({})\n```\n", check);
+    }
+
+    doc
+}
+
+fn role_documentation(role: &Role) -> String {
+    let mut attributes = vec![];
+
+    if role.is_super_user {
+        attributes.push("superuser");
+    }
+    if role.can_login {
+        attributes.push("login");
+    }
+    if role.can_create_db {
+        attributes.push("create database");
+    }
+    if role.can_bypass_rls {
+        attributes.push("bypass row level security");
+    }
+
+    let mut doc = format!("**Role** `{}`\n", role.name);
+
+    if !attributes.is_empty() {
+        let _ = write!(doc, "\n{}\n", attributes.join(", "));
+    }
+
+    doc
+}
+
+fn setting_documentation(setting: &Setting) -> String {
+    let mut doc = format!(
+        "**Setting** `{}`\n\n{}\n\nCategory: {}\n",
+        setting.name, setting.short_description, setting.category
+    );
+
+    if let Some(value) = setting.setting.as_ref() {
+        let _ = write!(
+            doc,
+            "\nCurrent value `{}`{}\n",
+            value,
+            setting
+                .unit
+                .as_ref()
+                .map(|u| format!(" ({})", u))
+                .unwrap_or_default()
+        );
+    }
+
+    if let Some(values) = setting.enum_values.as_ref() {
+        let _ = write!(doc, "\nAllowed values: {}\n", values.join(", "));
+    }
+
+    doc
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_schema_cache::SchemaCache;
+    use sqlx::{Executor, PgPool};
+
+    use crate::{
+        CompletionItemData,
+        test_helper::{CURSOR_POS, get_test_deps, get_test_params},
+    };
+
+    use super::resolve_documentation;
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn documents_tables_and_columns(pool: PgPool) {
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                name text not null default 'anonymous'
+            );
+
+            comment on table public.users is 'All registered users.';
+        "#;
+
+        let query = format!("select * from u{}", CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(Some(setup), query.as_str().into(), &pool).await;
+        let params = get_test_params(&tree, &cache, query.as_str().into());
+        let items = crate::complete(params);
+
+        let data = items
+            .iter()
+            .find(|i| i.label == "users")
+            .and_then(|i| i.data.clone())
+            .expect("users should carry item data");
+
+        let doc = resolve_documentation(&data, &cache).expect("users should be documented");
+
+        assert!(doc.contains("`public.users`"));
+        assert!(doc.contains("All registered users."));
+        assert!(doc.contains("| id (PK) | int4 | no |"));
+
+        let table_id = cache.find_table("users", Some("public")).unwrap().id;
+        let doc = resolve_documentation(
+            &CompletionItemData::Column {
+                table_id,
+                name: "name".into(),
+            },
+            &cache,
+        )
+        .expect("column should be documented");
+
+        assert!(doc.contains("Type `text`, not null"));
+        assert!(doc.contains("Default `'anonymous'::text`"));
+    }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn documents_functions(pool: PgPool) {
+        let setup = r#"
+            create function public.add_one(i int4)
+            returns int4
+            language sql
+            immutable
+            as $$ select i + 1; $$;
+        "#;
+
+        pool.execute(setup).await.unwrap();
+
+        let cache = SchemaCache::load(&pool).await.unwrap();
+
+        let id = cache
+            .functions
+            .iter()
+            .find(|f| f.name == "add_one")
+            .unwrap()
+            .id;
+
+        let doc = resolve_documentation(&CompletionItemData::Function { id }, &cache)
+            .expect("function should be documented");
+
+        assert!(doc.contains("`public.add_one(i integer)` → `integer`"));
+        assert!(doc.contains("Language `sql`, immutable"));
+        assert!(doc.contains("```sql\nCREATE OR REPLACE FUNCTION public.add_one"));
+    }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn returns_none_for_unknown_objects(pool: PgPool) {
+        let cache = SchemaCache::load(&pool).await.unwrap();
+
+        assert_eq!(
+            resolve_documentation(&CompletionItemData::Table { id: -1 }, &cache),
+            None
+        );
+    }
+}
@@ -12,6 +12,10 @@ pub enum CompletionItemKind {
     Column,
     Schema,
     Policy,
+    Role,
+    Privilege,
+    Setting,
+    Variable,
 }
 
 impl Display for CompletionItemKind {
@@ -22,6 +26,10 @@ impl Display for CompletionItemKind {
             CompletionItemKind::Column => "Column",
             CompletionItemKind::Schema => "Schema",
             CompletionItemKind::Policy => "Policy",
+            CompletionItemKind::Role => "Role",
+            CompletionItemKind::Privilege => "Privilege",
+            CompletionItemKind::Setting => "Setting",
+            CompletionItemKind::Variable => "Variable",
         };
 
         write!(f, "{txt}")
@@ -45,6 +53,39 @@ pub struct CompletionText {
     pub is_snippet: bool,
 }
 
+/// Identifies the schema cache entry a `CompletionItem` was created from.
+/// Clients send it back when resolving an item, so that its documentation
+/// can be computed lazily via [crate::resolve_documentation].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CompletionItemData {
+    Table {
+        id: i64,
+    },
+    Function {
+        id: i64,
+    },
+    Column {
+        table_id: i64,
+        name: String,
+    },
+    Schema {
+        id: i64,
+    },
+    Policy {
+        schema: String,
+        table: String,
+        name: String,
+    },
+    Role {
+        name: String,
+    },
+    Setting {
+        name: String,
+    },
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CompletionItem {
@@ -57,4 +98,7 @@ pub struct CompletionItem {
     pub detail: Option<String>,
 
     pub completion_text: Option<CompletionText>,
+
+    /// `None` if there is no additional documentation for the item.
+    pub data: Option<CompletionItemData>,
 }
@@ -1,14 +1,19 @@
 mod builder;
 mod complete;
 mod context;
+mod documentation;
 mod item;
 mod providers;
 mod relevance;
 mod sanitization;
+mod usage;
 
 #[cfg(test)]
 mod test_helper;
 
 pub use complete::*;
+pub use documentation::resolve_documentation;
 pub use item::*;
+pub use providers::{FunctionVariable, FunctionVariableKind};
 pub use sanitization::*;
+pub use usage::{FileUsage, UsageStatistics};
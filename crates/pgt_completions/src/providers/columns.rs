@@ -7,7 +7,7 @@ use crate::{
 
 use super::helper::{find_matching_alias_for_table, get_completion_text_with_schema_or_alias};
 
-pub fn complete_columns<'a>(ctx: &CompletionContext<'a>, builder: &mut CompletionBuilder<'a>) {
+pub fn complete_columns<'a>(ctx: &'a CompletionContext<'a>, builder: &mut CompletionBuilder<'a>) {
     let available_columns = &ctx.schema_cache.columns;
 
     for col in available_columns {
@@ -38,6 +38,37 @@ pub fn complete_columns<'a>(ctx: &CompletionContext<'a>, builder: &mut Completio
 
         builder.add_item(item);
     }
+
+    for col in ctx
+        .get_visible_virtual_relations()
+        .flat_map(|r| r.columns.iter())
+    {
+        let relevance = CompletionRelevanceData::VirtualColumn(col);
+
+        let mut item = PossibleCompletionItem {
+            label: col.name.clone(),
+            score: CompletionScore::from(relevance.clone()),
+            filter: CompletionFilter::from(relevance),
+            description: col.relation_name.clone(),
+            kind: CompletionItemKind::Column,
+            completion_text: None,
+            detail: col.type_name.clone(),
+        };
+
+        if matches!(
+            ctx.wrapping_clause_type,
+            Some(WrappingClause::Join { .. })
+                | Some(WrappingClause::Where)
+                | Some(WrappingClause::Select)
+        ) {
+            item.completion_text = find_matching_alias_for_table(ctx, col.relation_name.as_str())
+                .and_then(|alias| {
+                    get_completion_text_with_schema_or_alias(ctx, col.name.as_str(), alias.as_str())
+                });
+        }
+
+        builder.add_item(item);
+    }
 }
 
 #[cfg(test)]
@@ -762,4 +793,96 @@ mod tests {
         )
         .await;
     }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn completes_columns_of_virtual_relations(pool: PgPool) {
+        let setup = r#"
+            create table orders (
+                id serial primary key,
+                total numeric,
+                customer_id int
+            );
+        "#;
+
+        pool.execute(setup).await.unwrap();
+
+        assert_complete_results(
+            format!(
+                "with recent as (select id, total as amount from orders) select recent.{} from recent",
+                CURSOR_POS
+            )
+            .as_str(),
+            vec![
+                CompletionAssertion::LabelAndDesc("amount".into(), "recent".into()),
+                CompletionAssertion::LabelAndDesc("id".into(), "recent".into()),
+                CompletionAssertion::LabelNotExists("customer_id".into()),
+            ],
+            None,
+            &pool,
+        )
+        .await;
+
+        assert_complete_results(
+            format!(
+                "select s.{} from (select customer_id, count(*) as cnt from orders group by customer_id) as s",
+                CURSOR_POS
+            )
+            .as_str(),
+            vec![
+                CompletionAssertion::LabelAndDesc("cnt".into(), "s".into()),
+                CompletionAssertion::LabelAndDesc("customer_id".into(), "s".into()),
+                CompletionAssertion::LabelNotExists("total".into()),
+            ],
+            None,
+            &pool,
+        )
+        .await;
+
+        assert_complete_results(
+            format!(
+                "select v.{} from (values (1, 'one')) as v(num, word)",
+                CURSOR_POS
+            )
+            .as_str(),
+            vec![
+                CompletionAssertion::LabelAndDesc("num".into(), "v".into()),
+                CompletionAssertion::LabelAndDesc("word".into(), "v".into()),
+            ],
+            None,
+            &pool,
+        )
+        .await;
+    }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn infers_types_of_virtual_columns(pool: PgPool) {
+        let setup = r#"
+            create table orders (
+                id serial primary key,
+                total numeric
+            );
+        "#;
+
+        pool.execute(setup).await.unwrap();
+
+        let query = format!(
+            "with recent as (select id, total, now()::date as day from orders) select recent.{} from recent",
+            CURSOR_POS
+        );
+
+        let (tree, cache) = get_test_deps(None, query.as_str().into(), &pool).await;
+        let params = get_test_params(&tree, &cache, query.as_str().into());
+        let items = complete(params);
+
+        let detail_of = |label: &str| {
+            items
+                .iter()
+                .find(|i| i.label == label)
+                .and_then(|i| i.detail.clone())
+        };
+
+        assert_eq!(detail_of("id"), Some("int4".into()));
+        assert_eq!(detail_of("total"), Some("numeric".into()));
+        assert_eq!(detail_of("day"), Some("date".into()));
+    }
 }
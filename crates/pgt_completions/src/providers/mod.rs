@@ -2,11 +2,19 @@ mod columns;
 mod functions;
 mod helper;
 mod policies;
+mod privileges;
+mod roles;
 mod schemas;
+mod settings;
 mod tables;
+mod variables;
 
 pub use columns::*;
 pub use functions::*;
 pub use policies::*;
+pub use privileges::*;
+pub use roles::*;
 pub use schemas::*;
+pub use settings::*;
 pub use tables::*;
+pub use variables::*;
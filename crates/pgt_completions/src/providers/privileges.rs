@@ -0,0 +1,140 @@
+use crate::{
+    CompletionItemKind,
+    builder::{CompletionBuilder, PossibleCompletionItem},
+    context::CompletionContext,
+    relevance::{CompletionRelevanceData, filtering::CompletionFilter, scoring::CompletionScore},
+};
+
+/// A privilege that can be granted with `GRANT`.
+#[derive(Debug)]
+pub(crate) struct Privilege {
+    pub name: &'static str,
+    /// The kinds of objects the privilege applies to, as written after `GRANT ... ON`.
+    pub objects: &'static [&'static str],
+}
+
+/// See the "Privileges" chapter of the Postgres docs.
+pub(crate) static PRIVILEGES: &[Privilege] = &[
+    Privilege {
+        name: "select",
+        objects: &["table", "sequence"],
+    },
+    Privilege {
+        name: "insert",
+        objects: &["table"],
+    },
+    Privilege {
+        name: "update",
+        objects: &["table", "sequence"],
+    },
+    Privilege {
+        name: "delete",
+        objects: &["table"],
+    },
+    Privilege {
+        name: "truncate",
+        objects: &["table"],
+    },
+    Privilege {
+        name: "references",
+        objects: &["table"],
+    },
+    Privilege {
+        name: "trigger",
+        objects: &["table"],
+    },
+    Privilege {
+        name: "maintain",
+        objects: &["table"],
+    },
+    Privilege {
+        name: "create",
+        objects: &["database", "schema", "tablespace"],
+    },
+    Privilege {
+        name: "connect",
+        objects: &["database"],
+    },
+    Privilege {
+        name: "temporary",
+        objects: &["database"],
+    },
+    Privilege {
+        name: "execute",
+        objects: &["function", "procedure", "routine"],
+    },
+    Privilege {
+        name: "usage",
+        objects: &["sequence", "schema", "domain", "language", "type"],
+    },
+    Privilege {
+        name: "all privileges",
+        objects: &[
+            "table",
+            "sequence",
+            "database",
+            "domain",
+            "function",
+            "procedure",
+            "routine",
+            "language",
+            "schema",
+            "tablespace",
+            "type",
+        ],
+    },
+];
+
+pub fn complete_privileges<'a>(_ctx: &CompletionContext<'a>, builder: &mut CompletionBuilder<'a>) {
+    for privilege in PRIVILEGES {
+        let relevance = CompletionRelevanceData::Privilege(privilege);
+
+        let item = PossibleCompletionItem {
+            label: privilege.name.into(),
+            score: CompletionScore::from(relevance.clone()),
+            filter: CompletionFilter::from(relevance),
+            description: format!("On: {}", privilege.objects.join(", ")),
+            kind: CompletionItemKind::Privilege,
+            completion_text: None,
+            detail: None,
+        };
+
+        builder.add_item(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        CompletionItemKind,
+        test_helper::{CURSOR_POS, CompletionAssertion, assert_complete_results},
+    };
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn completes_privileges(pool: PgPool) {
+        assert_complete_results(
+            format!("grant sel{}", CURSOR_POS).as_str(),
+            vec![CompletionAssertion::LabelAndKind(
+                "select".into(),
+                CompletionItemKind::Privilege,
+            )],
+            None,
+            &pool,
+        )
+        .await;
+
+        assert_complete_results(
+            format!("grant {} on function my_fn() to anon", CURSOR_POS).as_str(),
+            vec![
+                CompletionAssertion::Label("all privileges".into()),
+                CompletionAssertion::Label("execute".into()),
+                CompletionAssertion::LabelNotExists("select".into()),
+            ],
+            None,
+            &pool,
+        )
+        .await;
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    CompletionItemKind,
+    builder::{CompletionBuilder, PossibleCompletionItem},
+    context::CompletionContext,
+    relevance::{CompletionRelevanceData, filtering::CompletionFilter, scoring::CompletionScore},
+};
+
+pub fn complete_roles<'a>(ctx: &CompletionContext<'a>, builder: &mut CompletionBuilder<'a>) {
+    let available_roles = &ctx.schema_cache.roles;
+
+    for role in available_roles {
+        let relevance = CompletionRelevanceData::Role(role);
+
+        let description = if role.is_super_user {
+            "Superuser"
+        } else if role.can_login {
+            "Login Role"
+        } else {
+            "Role"
+        };
+
+        let item = PossibleCompletionItem {
+            label: role.name.clone(),
+            score: CompletionScore::from(relevance.clone()),
+            filter: CompletionFilter::from(relevance),
+            description: description.into(),
+            kind: CompletionItemKind::Role,
+            completion_text: None,
+            detail: None,
+        };
+
+        builder.add_item(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        CompletionItemKind,
+        test_helper::{CURSOR_POS, CompletionAssertion, assert_complete_results},
+    };
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn completes_roles_in_grant_and_owner_statements(pool: PgPool) {
+        let setup = r#"
+            create table users (
+                id serial primary key,
+                email text
+            );
+        "#;
+
+        let queries = vec![
+            format!("grant select on users to test_l{}", CURSOR_POS),
+            format!("alter table users owner to test_l{}", CURSOR_POS),
+            format!("set role test_l{}", CURSOR_POS),
+            format!(
+                "create policy \"my_pol\" on users for select to test_l{}",
+                CURSOR_POS
+            ),
+        ];
+
+        for query in queries {
+            assert_complete_results(
+                query.as_str(),
+                vec![
+                    CompletionAssertion::LabelAndKind(
+                        "test_login".into(),
+                        CompletionItemKind::Role,
+                    ),
+                    CompletionAssertion::KindNotExists(CompletionItemKind::Table),
+                    CompletionAssertion::KindNotExists(CompletionItemKind::Column),
+                ],
+                Some(setup),
+                &pool,
+            )
+            .await;
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    CompletionItemKind,
+    builder::{CompletionBuilder, PossibleCompletionItem},
+    context::CompletionContext,
+    relevance::{CompletionRelevanceData, filtering::CompletionFilter, scoring::CompletionScore},
+};
+
+pub fn complete_settings<'a>(ctx: &CompletionContext<'a>, builder: &mut CompletionBuilder<'a>) {
+    let available_settings = &ctx.schema_cache.settings;
+
+    for setting in available_settings {
+        let relevance = CompletionRelevanceData::Setting(setting);
+
+        let current_value = setting
+            .setting
+            .as_ref()
+            .map(|value| match setting.unit.as_ref() {
+                Some(unit) => format!("= {} ({})", value, unit),
+                None => format!("= {}", value),
+            });
+
+        let item = PossibleCompletionItem {
+            label: setting.name.clone(),
+            score: CompletionScore::from(relevance.clone()),
+            filter: CompletionFilter::from(relevance),
+            description: setting.short_description.clone(),
+            kind: CompletionItemKind::Setting,
+            completion_text: None,
+            detail: current_value,
+        };
+
+        builder.add_item(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::{
+        CompletionItemKind, complete,
+        test_helper::{
+            CURSOR_POS, CompletionAssertion, assert_complete_results, get_test_deps,
+            get_test_params,
+        },
+    };
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn completes_settings(pool: PgPool) {
+        let queries = vec![
+            format!("set statement_tim{}", CURSOR_POS),
+            format!("set local statement_tim{}", CURSOR_POS),
+            format!("show statement_tim{}", CURSOR_POS),
+            format!("reset statement_tim{}", CURSOR_POS),
+        ];
+
+        for query in queries {
+            assert_complete_results(
+                query.as_str(),
+                vec![
+                    CompletionAssertion::LabelAndKind(
+                        "statement_timeout".into(),
+                        CompletionItemKind::Setting,
+                    ),
+                    CompletionAssertion::KindNotExists(CompletionItemKind::Role),
+                ],
+                None,
+                &pool,
+            )
+            .await;
+        }
+    }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn shows_current_value_and_unit(pool: PgPool) {
+        let query = format!("set lock_timeo{}", CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(None, query.as_str().into(), &pool).await;
+        let params = get_test_params(&tree, &cache, query.as_str().into());
+        let items = complete(params);
+
+        let lock_timeout = items
+            .into_iter()
+            .find(|i| i.label == "lock_timeout")
+            .expect("lock_timeout should be suggested");
+
+        assert_eq!(lock_timeout.detail, Some("= 0 (ms)".into()));
+    }
+}
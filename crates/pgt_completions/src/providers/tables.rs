@@ -45,7 +45,7 @@ mod tests {
     use sqlx::{Executor, PgPool};
 
     use crate::{
-        CompletionItem, CompletionItemKind, complete,
+        CompletionItem, CompletionItemKind, FileUsage, UsageStatistics, complete,
         test_helper::{
             CURSOR_POS, CompletionAssertion, assert_complete_results, assert_no_complete_results,
             get_test_deps, get_test_params,
@@ -120,6 +120,61 @@ mod tests {
         }
     }
 
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn prefers_tables_used_in_workspace(pool: PgPool) {
+        let setup = r#"
+            create table user_accounts (
+                id serial primary key
+            );
+
+            create table user_sessions (
+                id serial primary key
+            );
+        "#;
+
+        pool.execute(setup).await.unwrap();
+
+        let query = format!("select * from user{}", CURSOR_POS);
+        let (tree, cache) = get_test_deps(None, query.as_str().into(), &pool).await;
+
+        let items = complete(get_test_params(&tree, &cache, query.as_str().into()));
+        assert_eq!(items[0].label, "user_accounts");
+
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_sql::language()).unwrap();
+
+        let mut usage = UsageStatistics::default();
+        for (idx, sql) in [
+            "select * from user_sessions",
+            "select id from user_sessions",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let mut file_usage = FileUsage::default();
+            file_usage.add_statement(&parser.parse(sql, None).unwrap(), sql);
+            usage.set_file_usage(format!("{}.sql", idx), file_usage);
+        }
+
+        let mut params = get_test_params(&tree, &cache, query.as_str().into());
+        params.usage = Some(&usage);
+        let items = complete(params);
+        assert_eq!(items[0].label, "user_sessions");
+
+        // recently accepted items win over frequently used ones
+        let accounts = items
+            .iter()
+            .find(|i| i.label == "user_accounts")
+            .and_then(|i| i.data.clone())
+            .unwrap();
+        usage.record_accepted(accounts);
+
+        let mut params = get_test_params(&tree, &cache, query.as_str().into());
+        params.usage = Some(&usage);
+        let items = complete(params);
+        assert_eq!(items[0].label, "user_accounts");
+    }
+
     #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
     async fn autocompletes_table_with_schema(pool: PgPool) {
         let setup = r#"
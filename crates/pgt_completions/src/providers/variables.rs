@@ -0,0 +1,142 @@
+use crate::{
+    CompletionItemKind,
+    builder::{CompletionBuilder, PossibleCompletionItem},
+    context::CompletionContext,
+    relevance::{CompletionRelevanceData, filtering::CompletionFilter, scoring::CompletionScore},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FunctionVariableKind {
+    Parameter,
+    Declared,
+    /// `NEW` and `OLD` in trigger functions.
+    /// Contains the table of the trigger as (schema, name), if it is known.
+    TriggerRecord {
+        table: Option<(String, String)>,
+    },
+}
+
+/// A variable that is in scope for a statement embedded in a function body,
+/// such as a parameter or a variable from the `DECLARE` section of a PL/pgSQL function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionVariable {
+    pub name: String,
+    pub type_name: Option<String>,
+    pub kind: FunctionVariableKind,
+}
+
+pub fn complete_variables<'a>(ctx: &'a CompletionContext, builder: &mut CompletionBuilder<'a>) {
+    for variable in &ctx.variables {
+        let relevance = CompletionRelevanceData::Variable(variable);
+
+        let description = match variable.kind {
+            FunctionVariableKind::Parameter => "Parameter",
+            FunctionVariableKind::Declared => "Variable",
+            FunctionVariableKind::TriggerRecord { .. } => "Trigger Record",
+        };
+
+        let item = PossibleCompletionItem {
+            label: variable.name.clone(),
+            score: CompletionScore::from(relevance.clone()),
+            filter: CompletionFilter::from(relevance),
+            description: description.into(),
+            kind: CompletionItemKind::Variable,
+            detail: variable.type_name.clone(),
+            completion_text: None,
+        };
+
+        builder.add_item(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Executor, PgPool};
+
+    use crate::{
+        CompletionItemKind, complete,
+        providers::{FunctionVariable, FunctionVariableKind},
+        test_helper::{CURSOR_POS, get_test_deps, get_test_params},
+    };
+
+    fn variables() -> Vec<FunctionVariable> {
+        vec![
+            FunctionVariable {
+                name: "p_user_id".into(),
+                type_name: Some("int4".into()),
+                kind: FunctionVariableKind::Parameter,
+            },
+            FunctionVariable {
+                name: "v_total".into(),
+                type_name: Some("numeric".into()),
+                kind: FunctionVariableKind::Declared,
+            },
+            FunctionVariable {
+                name: "new".into(),
+                type_name: None,
+                kind: FunctionVariableKind::TriggerRecord {
+                    table: Some(("public".into(), "orders".into())),
+                },
+            },
+        ]
+    }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn completes_function_variables(pool: PgPool) {
+        let setup = r#"
+            create table orders (
+                id serial primary key,
+                user_id int4,
+                total numeric
+            );
+        "#;
+
+        pool.execute(setup).await.unwrap();
+
+        let query = format!("select * from orders where user_id = p_{}", CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(None, query.as_str().into(), &pool).await;
+        let mut params = get_test_params(&tree, &cache, query.as_str().into());
+        params.variables = variables();
+        let items = complete(params);
+
+        let first = items.first().expect("should complete the parameter");
+        assert_eq!(first.label, "p_user_id");
+        assert_eq!(first.kind, CompletionItemKind::Variable);
+        assert_eq!(first.description, "Parameter");
+    }
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn completes_columns_of_trigger_records(pool: PgPool) {
+        let setup = r#"
+            create table orders (
+                id serial primary key,
+                user_id int4,
+                total numeric
+            );
+
+            create table users (
+                id serial primary key,
+                name text
+            );
+        "#;
+
+        pool.execute(setup).await.unwrap();
+
+        let query = format!("select * from users where id = new.{}", CURSOR_POS);
+
+        let (tree, cache) = get_test_deps(None, query.as_str().into(), &pool).await;
+        let mut params = get_test_params(&tree, &cache, query.as_str().into());
+        params.variables = variables();
+        let items = complete(params);
+
+        let mut labels: Vec<&str> = items
+            .iter()
+            .filter(|i| i.kind == CompletionItemKind::Column)
+            .map(|i| i.label.as_str())
+            .collect();
+        labels.sort();
+
+        assert_eq!(labels, vec!["id", "total", "user_id"]);
+    }
+}
@@ -8,4 +8,39 @@ pub(crate) enum CompletionRelevanceData<'a> {
     Column(&'a pgt_schema_cache::Column),
     Schema(&'a pgt_schema_cache::Schema),
     Policy(&'a pgt_schema_cache::Policy),
+    VirtualColumn(&'a crate::context::VirtualColumn),
+    Role(&'a pgt_schema_cache::Role),
+    Privilege(&'a crate::providers::Privilege),
+    Setting(&'a pgt_schema_cache::Setting),
+    Variable(&'a crate::providers::FunctionVariable),
+}
+
+impl CompletionRelevanceData<'_> {
+    pub(crate) fn get_item_data(&self) -> Option<crate::CompletionItemData> {
+        use crate::CompletionItemData;
+
+        match self {
+            CompletionRelevanceData::Table(t) => Some(CompletionItemData::Table { id: t.id }),
+            CompletionRelevanceData::Function(f) => Some(CompletionItemData::Function { id: f.id }),
+            CompletionRelevanceData::Column(c) => Some(CompletionItemData::Column {
+                table_id: c.table_oid,
+                name: c.name.clone(),
+            }),
+            CompletionRelevanceData::Schema(s) => Some(CompletionItemData::Schema { id: s.id }),
+            CompletionRelevanceData::Policy(p) => Some(CompletionItemData::Policy {
+                schema: p.schema_name.clone(),
+                table: p.table_name.clone(),
+                name: p.name.clone(),
+            }),
+            CompletionRelevanceData::Role(r) => Some(CompletionItemData::Role {
+                name: r.name.clone(),
+            }),
+            CompletionRelevanceData::Setting(s) => Some(CompletionItemData::Setting {
+                name: s.name.clone(),
+            }),
+            CompletionRelevanceData::VirtualColumn(_)
+            | CompletionRelevanceData::Privilege(_)
+            | CompletionRelevanceData::Variable(_) => None,
+        }
+    }
 }
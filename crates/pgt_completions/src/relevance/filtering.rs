@@ -76,7 +76,10 @@ impl CompletionFilter<'_> {
                     CompletionRelevanceData::Table(_) => match clause {
                         WrappingClause::Select
                         | WrappingClause::Where
-                        | WrappingClause::ColumnDefinitions => false,
+                        | WrappingClause::ColumnDefinitions
+                        | WrappingClause::ToRoleAssignment
+                        | WrappingClause::PrivilegeList
+                        | WrappingClause::SettingName => false,
 
                         WrappingClause::Insert => {
                             ctx.wrapping_node_kind
@@ -97,12 +100,16 @@ impl CompletionFilter<'_> {
                         _ => true,
                     },
 
-                    CompletionRelevanceData::Column(_) => {
+                    CompletionRelevanceData::Column(_)
+                    | CompletionRelevanceData::VirtualColumn(_) => {
                         match clause {
                             WrappingClause::From
                             | WrappingClause::ColumnDefinitions
                             | WrappingClause::AlterTable
-                            | WrappingClause::DropTable => false,
+                            | WrappingClause::DropTable
+                            | WrappingClause::ToRoleAssignment
+                            | WrappingClause::PrivilegeList
+                            | WrappingClause::SettingName => false,
 
                             // We can complete columns in JOIN cluases, but only if we are after the
                             // ON node in the "ON u.id = posts.user_id" part.
@@ -169,6 +176,45 @@ impl CompletionFilter<'_> {
                     CompletionRelevanceData::Policy(_) => {
                         matches!(clause, WrappingClause::PolicyName)
                     }
+
+                    CompletionRelevanceData::Role(_) => {
+                        matches!(clause, WrappingClause::ToRoleAssignment)
+                    }
+
+                    CompletionRelevanceData::Privilege(privilege) => {
+                        matches!(clause, WrappingClause::PrivilegeList)
+                            && ctx
+                                .privilege_object
+                                .as_ref()
+                                .is_none_or(|o| privilege.objects.contains(&o.as_str()))
+                    }
+
+                    CompletionRelevanceData::Setting(_) => {
+                        matches!(clause, WrappingClause::SettingName)
+                    }
+
+                    CompletionRelevanceData::Variable(_) => match clause {
+                        WrappingClause::Select | WrappingClause::Where => true,
+
+                        WrappingClause::Join { on_node: Some(on) } => ctx
+                            .node_under_cursor
+                            .as_ref()
+                            .is_some_and(|cn| cn.start_byte() >= on.end_byte()),
+
+                        WrappingClause::Update => {
+                            ctx.wrapping_node_kind.as_ref().is_some_and(|n| {
+                                n == &WrappingNode::Assignment
+                                    || n == &WrappingNode::BinaryExpression
+                            })
+                        }
+
+                        WrappingClause::Insert => ctx
+                            .wrapping_node_kind
+                            .as_ref()
+                            .is_some_and(|n| n == &WrappingNode::List),
+
+                        _ => false,
+                    },
                 }
             })
             .and_then(|is_ok| if is_ok { Some(()) } else { None })
@@ -180,7 +226,9 @@ impl CompletionFilter<'_> {
         }
 
         match self.data {
-            CompletionRelevanceData::Table(_) | CompletionRelevanceData::Column(_) => return None,
+            CompletionRelevanceData::Table(_)
+            | CompletionRelevanceData::Column(_)
+            | CompletionRelevanceData::VirtualColumn(_) => return None,
             _ => {}
         }
 
@@ -201,11 +249,23 @@ impl CompletionFilter<'_> {
                 .mentioned_table_aliases
                 .get(schema_or_alias)
                 .is_some_and(|t| t == &col.table_name),
+            CompletionRelevanceData::VirtualColumn(col) => {
+                &col.relation_name == schema_or_alias
+                    || ctx
+                        .mentioned_table_aliases
+                        .get(schema_or_alias)
+                        .is_some_and(|t| t == &col.relation_name)
+            }
 
             // we should never allow schema suggestions if there already was one.
             CompletionRelevanceData::Schema(_) => false,
             // no policy comletion if user typed a schema node first.
             CompletionRelevanceData::Policy(_) => false,
+            // roles, privileges, settings and variables don't belong to a schema.
+            CompletionRelevanceData::Role(_)
+            | CompletionRelevanceData::Privilege(_)
+            | CompletionRelevanceData::Setting(_)
+            | CompletionRelevanceData::Variable(_) => false,
         };
 
         if !matches {
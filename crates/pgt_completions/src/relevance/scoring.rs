@@ -24,6 +24,10 @@ impl CompletionScore<'_> {
         self.score
     }
 
+    pub fn get_relevance_data(&self) -> &CompletionRelevanceData<'_> {
+        &self.data
+    }
+
     pub fn calc_score(&mut self, ctx: &CompletionContext) {
         self.check_is_user_defined();
         self.check_matches_schema(ctx);
@@ -33,6 +37,7 @@ impl CompletionScore<'_> {
         self.check_matching_wrapping_node(ctx);
         self.check_relations_in_stmt(ctx);
         self.check_columns_in_stmt(ctx);
+        self.check_workspace_usage(ctx);
     }
 
     fn check_matches_query_input(&mut self, ctx: &CompletionContext) {
@@ -47,6 +52,11 @@ impl CompletionScore<'_> {
             CompletionRelevanceData::Column(c) => c.name.as_str().to_ascii_lowercase(),
             CompletionRelevanceData::Schema(s) => s.name.as_str().to_ascii_lowercase(),
             CompletionRelevanceData::Policy(p) => p.name.as_str().to_ascii_lowercase(),
+            CompletionRelevanceData::VirtualColumn(c) => c.name.as_str().to_ascii_lowercase(),
+            CompletionRelevanceData::Role(r) => r.name.as_str().to_ascii_lowercase(),
+            CompletionRelevanceData::Privilege(p) => p.name.to_ascii_lowercase(),
+            CompletionRelevanceData::Setting(s) => s.name.as_str().to_ascii_lowercase(),
+            CompletionRelevanceData::Variable(v) => v.name.as_str().to_ascii_lowercase(),
         };
 
         let fz_matcher = SkimMatcherV2::default();
@@ -115,6 +125,21 @@ impl CompletionScore<'_> {
                 }
                 _ => -15,
             },
+            CompletionRelevanceData::VirtualColumn(_) => match clause_type {
+                WrappingClause::Select if has_mentioned_tables => 10,
+                WrappingClause::Select if !has_mentioned_tables => 0,
+                WrappingClause::Where => 10,
+                WrappingClause::Join { on_node }
+                    if on_node.is_some_and(|on| {
+                        ctx.node_under_cursor
+                            .as_ref()
+                            .is_some_and(|n| n.start_byte() > on.end_byte())
+                    }) =>
+                {
+                    10
+                }
+                _ => -15,
+            },
             CompletionRelevanceData::Schema(_) => match clause_type {
                 WrappingClause::From if !has_mentioned_schema => 15,
                 WrappingClause::Join { .. } if !has_mentioned_schema => 15,
@@ -126,6 +151,24 @@ impl CompletionScore<'_> {
                 WrappingClause::PolicyName => 25,
                 _ => -50,
             },
+            CompletionRelevanceData::Role(_) => match clause_type {
+                WrappingClause::ToRoleAssignment => 25,
+                _ => -50,
+            },
+            CompletionRelevanceData::Privilege(_) => match clause_type {
+                WrappingClause::PrivilegeList => 25,
+                _ => -50,
+            },
+            CompletionRelevanceData::Setting(_) => match clause_type {
+                WrappingClause::SettingName => 25,
+                _ => -50,
+            },
+            CompletionRelevanceData::Variable(_) => match clause_type {
+                WrappingClause::Where | WrappingClause::Update => 10,
+                WrappingClause::Select | WrappingClause::Join { .. } => 5,
+                WrappingClause::Insert => 0,
+                _ => -50,
+            },
         }
     }
 
@@ -149,17 +192,26 @@ impl CompletionScore<'_> {
                 WrappingNode::Relation => 10,
                 _ => -50,
             },
-            CompletionRelevanceData::Column(_) => match wrapping_node {
-                WrappingNode::BinaryExpression => 15,
-                WrappingNode::Assignment => 15,
-                _ => -15,
-            },
+            CompletionRelevanceData::Column(_) | CompletionRelevanceData::VirtualColumn(_) => {
+                match wrapping_node {
+                    WrappingNode::BinaryExpression => 15,
+                    WrappingNode::Assignment => 15,
+                    _ => -15,
+                }
+            }
             CompletionRelevanceData::Schema(_) => match wrapping_node {
                 WrappingNode::Relation if !has_mentioned_schema && !has_node_text => 15,
                 WrappingNode::Relation if !has_mentioned_schema && has_node_text => 0,
                 _ => -50,
             },
-            CompletionRelevanceData::Policy(_) => 0,
+            CompletionRelevanceData::Variable(_) => match wrapping_node {
+                WrappingNode::BinaryExpression | WrappingNode::Assignment => 15,
+                _ => 0,
+            },
+            CompletionRelevanceData::Policy(_)
+            | CompletionRelevanceData::Role(_)
+            | CompletionRelevanceData::Privilege(_)
+            | CompletionRelevanceData::Setting(_) => 0,
         }
     }
 
@@ -178,9 +230,18 @@ impl CompletionScore<'_> {
             Some(n) => n,
         };
 
-        let data_schema = self.get_schema_name();
+        let matches = match self.data {
+            CompletionRelevanceData::VirtualColumn(col) => {
+                &col.relation_name == schema_name
+                    || ctx
+                        .mentioned_table_aliases
+                        .get(schema_name)
+                        .is_some_and(|t| t == &col.relation_name)
+            }
+            _ => schema_name == self.get_schema_name(),
+        };
 
-        if schema_name == data_schema {
+        if matches {
             self.score += 25;
         } else {
             self.score -= 10;
@@ -194,6 +255,11 @@ impl CompletionScore<'_> {
             CompletionRelevanceData::Column(c) => c.name.as_str(),
             CompletionRelevanceData::Schema(s) => s.name.as_str(),
             CompletionRelevanceData::Policy(p) => p.name.as_str(),
+            CompletionRelevanceData::VirtualColumn(c) => c.name.as_str(),
+            CompletionRelevanceData::Role(r) => r.name.as_str(),
+            CompletionRelevanceData::Privilege(p) => p.name,
+            CompletionRelevanceData::Setting(s) => s.name.as_str(),
+            CompletionRelevanceData::Variable(v) => v.name.as_str(),
         }
     }
 
@@ -204,6 +270,12 @@ impl CompletionScore<'_> {
             CompletionRelevanceData::Column(c) => c.schema_name.as_str(),
             CompletionRelevanceData::Schema(s) => s.name.as_str(),
             CompletionRelevanceData::Policy(p) => p.schema_name.as_str(),
+            // Virtual relations are defined within the statement and don't belong to a schema.
+            CompletionRelevanceData::VirtualColumn(_) => "",
+            CompletionRelevanceData::Role(_)
+            | CompletionRelevanceData::Privilege(_)
+            | CompletionRelevanceData::Setting(_)
+            | CompletionRelevanceData::Variable(_) => "",
         }
     }
 
@@ -212,6 +284,7 @@ impl CompletionScore<'_> {
             CompletionRelevanceData::Column(c) => Some(c.table_name.as_str()),
             CompletionRelevanceData::Table(t) => Some(t.name.as_str()),
             CompletionRelevanceData::Policy(p) => Some(p.table_name.as_str()),
+            CompletionRelevanceData::VirtualColumn(c) => Some(c.relation_name.as_str()),
             _ => None,
         }
     }
@@ -219,6 +292,11 @@ impl CompletionScore<'_> {
     fn check_relations_in_stmt(&mut self, ctx: &CompletionContext) {
         match self.data {
             CompletionRelevanceData::Table(_) | CompletionRelevanceData::Function(_) => return,
+            // Only columns of virtual relations that are referenced in the statement are suggested.
+            CompletionRelevanceData::VirtualColumn(_) => {
+                self.score += 45;
+                return;
+            }
             _ => {}
         }
 
@@ -259,6 +337,12 @@ impl CompletionScore<'_> {
         }
 
         let item_name = self.get_item_name().to_string();
+
+        // predefined roles such as `pg_read_all_data` are rarely what users are looking for
+        if matches!(self.data, CompletionRelevanceData::Role(_)) && item_name.starts_with("pg_") {
+            self.score -= 20;
+        }
+
         let table_name = self.get_table_name();
 
         // migrations shouldn't pop up on top
@@ -270,39 +354,76 @@ impl CompletionScore<'_> {
         }
     }
 
+    /// Items that are used often throughout the project or were accepted recently are
+    /// more likely to be what the user is looking for.
+    fn check_workspace_usage(&mut self, ctx: &CompletionContext) {
+        let usage = match ctx.usage {
+            Some(u) => u,
+            None => return,
+        };
+
+        let count = match self.data {
+            CompletionRelevanceData::Table(t) => usage.relation_count(&t.schema, &t.name),
+            CompletionRelevanceData::Column(c) => {
+                usage.column_count(&c.schema_name, &c.table_name, &c.name)
+            }
+            _ => 0,
+        };
+
+        // logarithmic, so that a handful of heavily used tables does not dominate every list
+        if count > 0 {
+            self.score += ((count as f32).ln_1p() * 5.0).min(20.0) as i32;
+        }
+
+        let recency = self
+            .data
+            .get_item_data()
+            .and_then(|data| usage.accepted_recency(&data));
+
+        if let Some(recency) = recency {
+            self.score += 20_i32.saturating_sub(recency as i32 * 2).max(0);
+        }
+    }
+
     fn check_columns_in_stmt(&mut self, ctx: &CompletionContext) {
-        if let CompletionRelevanceData::Column(column) = self.data {
-            /*
-             * Columns can be mentioned in one of two ways:
-             *
-             * 1) With an alias: `select u.id`.
-             * If the currently investigated suggestion item is "id" of the "users" table,
-             * we want to check
-             * a) whether the name of the column matches.
-             * b) whether we know which table is aliased by "u" (if we don't, we ignore the alias).
-             * c) whether the aliased table matches the currently investigated suggestion item's table.
-             *
-             * 2) Without an alias: `select id`.
-             * In that case, we only check whether the mentioned column fits our currently investigated
-             * suggestion item's name.
-             *
-             */
-            if ctx
-                .mentioned_columns
-                .get(&ctx.wrapping_clause_type)
-                .is_some_and(|set| {
-                    set.iter().any(|mentioned| match mentioned.alias.as_ref() {
-                        Some(als) => {
-                            let aliased_table = ctx.mentioned_table_aliases.get(als.as_str());
-                            column.name == mentioned.column
-                                && aliased_table.is_none_or(|t| t == &column.table_name)
-                        }
-                        None => mentioned.column == column.name,
-                    })
-                })
-            {
-                self.score -= 10;
+        let (column_name, table_name) = match self.data {
+            CompletionRelevanceData::Column(c) => (c.name.as_str(), c.table_name.as_str()),
+            CompletionRelevanceData::VirtualColumn(c) => {
+                (c.name.as_str(), c.relation_name.as_str())
             }
+            _ => return,
+        };
+
+        /*
+         * Columns can be mentioned in one of two ways:
+         *
+         * 1) With an alias: `select u.id`.
+         * If the currently investigated suggestion item is "id" of the "users" table,
+         * we want to check
+         * a) whether the name of the column matches.
+         * b) whether we know which table is aliased by "u" (if we don't, we ignore the alias).
+         * c) whether the aliased table matches the currently investigated suggestion item's table.
+         *
+         * 2) Without an alias: `select id`.
+         * In that case, we only check whether the mentioned column fits our currently investigated
+         * suggestion item's name.
+         *
+         */
+        if ctx
+            .mentioned_columns
+            .get(&ctx.wrapping_clause_type)
+            .is_some_and(|set| {
+                set.iter().any(|mentioned| match mentioned.alias.as_ref() {
+                    Some(als) => {
+                        let aliased_table = ctx.mentioned_table_aliases.get(als.as_str());
+                        column_name == mentioned.column
+                            && aliased_table.is_none_or(|t| t == table_name)
+                    }
+                    None => mentioned.column == column_name,
+                })
+            })
+        {
+            self.score -= 10;
         }
     }
 }
@@ -99,6 +99,8 @@ pub(crate) fn get_test_params<'a>(
         schema: schema_cache,
         tree,
         text,
+        usage: None,
+        variables: vec![],
     }
 }
 
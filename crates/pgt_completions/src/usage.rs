@@ -0,0 +1,321 @@
+use std::collections::{HashMap, HashSet};
+
+use pgt_treesitter_queries::{
+    TreeSitterQueriesExecutor,
+    queries::{self, QueryResult},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::CompletionItemData;
+
+/// The number of accepted completion items we remember.
+const MAX_ACCEPTED_ITEMS: usize = 20;
+
+/// How often relations and columns are referenced in a single file.
+///
+/// Relations are keyed by `schema.table` and columns by `schema.table.column`, lowercased.
+/// The schema is left empty if the query doesn't qualify the relation.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileUsage {
+    relations: HashMap<String, u32>,
+    columns: HashMap<String, u32>,
+}
+
+impl FileUsage {
+    /// Counts the relations and columns referenced in a statement.
+    pub fn add_statement(&mut self, tree: &tree_sitter::Tree, sql: &str) {
+        let mut executor = TreeSitterQueriesExecutor::new(tree.root_node(), sql);
+
+        executor.add_query_results::<queries::RelationMatch>();
+        executor.add_query_results::<queries::TableAliasMatch>();
+        executor.add_query_results::<queries::SelectColumnMatch>();
+        executor.add_query_results::<queries::InsertColumnMatch>();
+        executor.add_query_results::<queries::WhereColumnMatch>();
+
+        let mut relations: Vec<(String, String)> = vec![];
+        let mut aliases: HashMap<String, (String, String)> = HashMap::new();
+        let mut columns: Vec<(Option<String>, String)> = vec![];
+
+        for result in executor.get_iter(None) {
+            match result {
+                QueryResult::Relation(r) => relations.push((
+                    r.get_schema(sql)
+                        .as_deref()
+                        .map(normalize)
+                        .unwrap_or_default(),
+                    normalize(&r.get_table(sql)),
+                )),
+                QueryResult::TableAliases(a) => {
+                    aliases.insert(
+                        normalize(&a.get_alias(sql)),
+                        (
+                            a.get_schema(sql)
+                                .as_deref()
+                                .map(normalize)
+                                .unwrap_or_default(),
+                            normalize(&a.get_table(sql)),
+                        ),
+                    );
+                }
+                QueryResult::SelectClauseColumns(c) => {
+                    columns.push((c.get_alias(sql), c.get_column(sql)))
+                }
+                QueryResult::InsertClauseColumns(c) => columns.push((None, c.get_column(sql))),
+                QueryResult::WhereClauseColumns(c) => {
+                    columns.push((c.get_alias(sql), c.get_column(sql)))
+                }
+                _ => {}
+            }
+        }
+
+        for (schema, table) in &relations {
+            *self.relations.entry(key(&[schema, table])).or_default() += 1;
+        }
+
+        for (qualifier, column) in columns {
+            let column = normalize(&column);
+
+            // a qualified column belongs to the relation it names, an unqualified one to any
+            // relation of the statement; the schema cache tells later which one really has it
+            let owners: HashSet<&(String, String)> = match qualifier.as_deref().map(normalize) {
+                Some(qualifier) => aliases
+                    .get(&qualifier)
+                    .or_else(|| relations.iter().find(|(_, table)| *table == qualifier))
+                    .into_iter()
+                    .collect(),
+                None => relations.iter().collect(),
+            };
+
+            for (schema, table) in owners {
+                *self
+                    .columns
+                    .entry(key(&[schema, table, &column]))
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relations.is_empty() && self.columns.is_empty()
+    }
+
+    fn merge(&mut self, other: &FileUsage, add: bool) {
+        for (own, theirs) in [
+            (&mut self.relations, &other.relations),
+            (&mut self.columns, &other.columns),
+        ] {
+            for (name, count) in theirs {
+                let entry = own.entry(name.clone()).or_default();
+                *entry = if add {
+                    entry.saturating_add(*count)
+                } else {
+                    entry.saturating_sub(*count)
+                };
+
+                if *entry == 0 {
+                    own.remove(name);
+                }
+            }
+        }
+    }
+}
+
+/// Statistics about how relations and columns are used throughout a project,
+/// and which completion items were accepted recently.
+///
+/// Used to rank items the user is likely to need above equally matching ones.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "PersistedUsageStatistics")]
+pub struct UsageStatistics {
+    /// The usage per file, keyed by the file's path.
+    files: HashMap<String, FileUsage>,
+
+    /// Recently accepted items, the most recent one last.
+    accepted: Vec<CompletionItemData>,
+
+    /// The sum of all `files`.
+    #[serde(skip)]
+    totals: FileUsage,
+}
+
+#[derive(Deserialize)]
+struct PersistedUsageStatistics {
+    #[serde(default)]
+    files: HashMap<String, FileUsage>,
+    #[serde(default)]
+    accepted: Vec<CompletionItemData>,
+}
+
+impl From<PersistedUsageStatistics> for UsageStatistics {
+    fn from(value: PersistedUsageStatistics) -> Self {
+        let mut totals = FileUsage::default();
+        for usage in value.files.values() {
+            totals.merge(usage, true);
+        }
+
+        Self {
+            files: value.files,
+            accepted: value.accepted,
+            totals,
+        }
+    }
+}
+
+impl UsageStatistics {
+    /// Replaces the usage of a file. Passing an empty `FileUsage` forgets the file.
+    pub fn set_file_usage(&mut self, file: String, usage: FileUsage) {
+        if let Some(previous) = self.files.remove(&file) {
+            self.totals.merge(&previous, false);
+        }
+
+        if !usage.is_empty() {
+            self.totals.merge(&usage, true);
+            self.files.insert(file, usage);
+        }
+    }
+
+    /// Remembers that the user accepted the given completion item.
+    pub fn record_accepted(&mut self, data: CompletionItemData) {
+        self.accepted.retain(|d| d != &data);
+        self.accepted.push(data);
+
+        if self.accepted.len() > MAX_ACCEPTED_ITEMS {
+            self.accepted.remove(0);
+        }
+    }
+
+    /// How often the relation is referenced, qualified with its schema or not.
+    pub fn relation_count(&self, schema: &str, name: &str) -> u32 {
+        count(&self.totals.relations, &[schema, name])
+    }
+
+    /// How often the column of the relation is referenced, qualified with its schema or not.
+    pub fn column_count(&self, schema: &str, table: &str, name: &str) -> u32 {
+        count(&self.totals.columns, &[schema, table, name])
+    }
+
+    /// How many other items were accepted after this one. `None` if it wasn't accepted recently.
+    pub fn accepted_recency(&self, data: &CompletionItemData) -> Option<usize> {
+        self.accepted
+            .iter()
+            .rev()
+            .position(|accepted| accepted == data)
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.replace('"', "").to_ascii_lowercase()
+}
+
+fn key(parts: &[&str]) -> String {
+    parts.join(".")
+}
+
+/// Adds up the references that qualify the relation with the given schema and those that
+/// don't qualify it at all. `parts` starts with the schema.
+fn count(map: &HashMap<String, u32>, parts: &[&str]) -> u32 {
+    let mut parts: Vec<String> = parts.iter().map(|part| normalize(part)).collect();
+    let qualified = map.get(&parts.join(".")).copied().unwrap_or(0);
+
+    parts[0].clear();
+    let unqualified = map.get(&parts.join(".")).copied().unwrap_or(0);
+
+    qualified + unqualified
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CompletionItemData;
+
+    use super::{FileUsage, MAX_ACCEPTED_ITEMS, UsageStatistics};
+
+    fn file_usage(sql: &str) -> FileUsage {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(tree_sitter_sql::language())
+            .expect("Error loading sql language");
+
+        let tree = parser.parse(sql, None).unwrap();
+
+        let mut usage = FileUsage::default();
+        usage.add_statement(&tree, sql);
+        usage
+    }
+
+    #[test]
+    fn counts_relations_and_columns() {
+        let mut stats = UsageStatistics::default();
+
+        stats.set_file_usage(
+            "a.sql".into(),
+            file_usage("select email from public.users where id = 1"),
+        );
+        stats.set_file_usage(
+            "b.sql".into(),
+            file_usage("select email from \"Users\" join orders on true"),
+        );
+
+        assert_eq!(stats.relation_count("public", "users"), 2);
+        assert_eq!(stats.relation_count("public", "orders"), 1);
+        assert_eq!(stats.relation_count("public", "products"), 0);
+        assert_eq!(stats.relation_count("private", "users"), 1);
+        assert_eq!(stats.column_count("public", "users", "email"), 2);
+        assert_eq!(stats.column_count("public", "users", "id"), 1);
+
+        // columns are counted for the relations they belong to, not for every relation that
+        // has a column of that name
+        assert_eq!(stats.column_count("public", "orders", "id"), 0);
+
+        // replacing the usage of a file does not count it twice
+        stats.set_file_usage("b.sql".into(), file_usage("select 1"));
+
+        assert_eq!(stats.relation_count("public", "users"), 1);
+        assert_eq!(stats.relation_count("public", "orders"), 0);
+        assert_eq!(stats.column_count("public", "users", "email"), 1);
+    }
+
+    #[test]
+    fn attributes_qualified_columns_to_their_relation() {
+        let mut stats = UsageStatistics::default();
+
+        stats.set_file_usage(
+            "a.sql".into(),
+            file_usage("select o.id from orders o join public.users on true where users.id = 1"),
+        );
+
+        assert_eq!(stats.column_count("public", "orders", "id"), 1);
+        assert_eq!(stats.column_count("public", "users", "id"), 1);
+        assert_eq!(stats.column_count("public", "orders", "email"), 0);
+    }
+
+    #[test]
+    fn tracks_recently_accepted_items() {
+        let mut stats = UsageStatistics::default();
+
+        stats.record_accepted(CompletionItemData::Table { id: 1 });
+        stats.record_accepted(CompletionItemData::Table { id: 2 });
+        stats.record_accepted(CompletionItemData::Table { id: 1 });
+
+        assert_eq!(
+            stats.accepted_recency(&CompletionItemData::Table { id: 1 }),
+            Some(0)
+        );
+        assert_eq!(
+            stats.accepted_recency(&CompletionItemData::Table { id: 2 }),
+            Some(1)
+        );
+        assert_eq!(
+            stats.accepted_recency(&CompletionItemData::Table { id: 3 }),
+            None
+        );
+
+        for id in 10..(10 + MAX_ACCEPTED_ITEMS as i64) {
+            stats.record_accepted(CompletionItemData::Function { id });
+        }
+
+        assert_eq!(
+            stats.accepted_recency(&CompletionItemData::Table { id: 1 }),
+            None
+        );
+    }
+}
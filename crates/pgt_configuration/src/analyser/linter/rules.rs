@@ -24,12 +24,22 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum RuleGroup {
+    Correctness,
+    Performance,
     Safety,
+    Schema,
+    Security,
+    Style,
 }
 impl RuleGroup {
     pub const fn as_str(self) -> &'static str {
         match self {
+            Self::Correctness => Correctness::GROUP_NAME,
+            Self::Performance => Performance::GROUP_NAME,
             Self::Safety => Safety::GROUP_NAME,
+            Self::Schema => Schema::GROUP_NAME,
+            Self::Security => Security::GROUP_NAME,
+            Self::Style => Style::GROUP_NAME,
         }
     }
 }
@@ -37,7 +47,12 @@ impl std::str::FromStr for RuleGroup {
     type Err = &'static str;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            Correctness::GROUP_NAME => Ok(Self::Correctness),
+            Performance::GROUP_NAME => Ok(Self::Performance),
             Safety::GROUP_NAME => Ok(Self::Safety),
+            Schema::GROUP_NAME => Ok(Self::Schema),
+            Security::GROUP_NAME => Ok(Self::Security),
+            Style::GROUP_NAME => Ok(Self::Style),
             _ => Err("This rule group doesn't exist."),
         }
     }
@@ -53,14 +68,29 @@ pub struct Rules {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub correctness: Option<Correctness>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance: Option<Performance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub safety: Option<Safety>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Schema>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<Security>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<Style>,
 }
 impl Rules {
     #[doc = r" Checks if the code coming from [pgt_diagnostics::Diagnostic] corresponds to a rule."]
     #[doc = r" Usually the code is built like {group}/{rule_name}"]
     pub fn has_rule(group: RuleGroup, rule_name: &str) -> Option<&'static str> {
         match group {
+            RuleGroup::Correctness => Correctness::has_rule(rule_name),
+            RuleGroup::Performance => Performance::has_rule(rule_name),
             RuleGroup::Safety => Safety::has_rule(rule_name),
+            RuleGroup::Schema => Schema::has_rule(rule_name),
+            RuleGroup::Security => Security::has_rule(rule_name),
+            RuleGroup::Style => Style::has_rule(rule_name),
         }
     }
     #[doc = r" Given a category coming from [Diagnostic](pgt_diagnostics::Diagnostic), this function returns"]
@@ -77,6 +107,36 @@ impl Rules {
         let rule_name = split_code.next()?;
         let rule_name = Self::has_rule(group, rule_name)?;
         let severity = match group {
+            RuleGroup::Correctness => self
+                .correctness
+                .as_ref()
+                .and_then(|group| group.get_rule_configuration(rule_name))
+                .filter(|(level, _)| !matches!(level, RulePlainConfiguration::Off))
+                .map_or_else(
+                    || {
+                        if Correctness::is_recommended_rule(rule_name) {
+                            Severity::Error
+                        } else {
+                            Severity::Warning
+                        }
+                    },
+                    |(level, _)| level.into(),
+                ),
+            RuleGroup::Performance => self
+                .performance
+                .as_ref()
+                .and_then(|group| group.get_rule_configuration(rule_name))
+                .filter(|(level, _)| !matches!(level, RulePlainConfiguration::Off))
+                .map_or_else(
+                    || {
+                        if Performance::is_recommended_rule(rule_name) {
+                            Severity::Error
+                        } else {
+                            Severity::Warning
+                        }
+                    },
+                    |(level, _)| level.into(),
+                ),
             RuleGroup::Safety => self
                 .safety
                 .as_ref()
@@ -92,6 +152,51 @@ impl Rules {
                     },
                     |(level, _)| level.into(),
                 ),
+            RuleGroup::Schema => self
+                .schema
+                .as_ref()
+                .and_then(|group| group.get_rule_configuration(rule_name))
+                .filter(|(level, _)| !matches!(level, RulePlainConfiguration::Off))
+                .map_or_else(
+                    || {
+                        if Schema::is_recommended_rule(rule_name) {
+                            Severity::Error
+                        } else {
+                            Severity::Warning
+                        }
+                    },
+                    |(level, _)| level.into(),
+                ),
+            RuleGroup::Security => self
+                .security
+                .as_ref()
+                .and_then(|group| group.get_rule_configuration(rule_name))
+                .filter(|(level, _)| !matches!(level, RulePlainConfiguration::Off))
+                .map_or_else(
+                    || {
+                        if Security::is_recommended_rule(rule_name) {
+                            Severity::Error
+                        } else {
+                            Severity::Warning
+                        }
+                    },
+                    |(level, _)| level.into(),
+                ),
+            RuleGroup::Style => self
+                .style
+                .as_ref()
+                .and_then(|group| group.get_rule_configuration(rule_name))
+                .filter(|(level, _)| !matches!(level, RulePlainConfiguration::Off))
+                .map_or_else(
+                    || {
+                        if Style::is_recommended_rule(rule_name) {
+                            Severity::Error
+                        } else {
+                            Severity::Warning
+                        }
+                    },
+                    |(level, _)| level.into(),
+                ),
         };
         Some(severity)
     }
@@ -100,9 +205,24 @@ impl Rules {
         if self.all != Some(true) && self.recommended == Some(false) {
             self.recommended = Some(true)
         }
+        if let Some(group) = &mut self.correctness {
+            group.recommended = None;
+        }
+        if let Some(group) = &mut self.performance {
+            group.recommended = None;
+        }
         if let Some(group) = &mut self.safety {
             group.recommended = None;
         }
+        if let Some(group) = &mut self.schema {
+            group.recommended = None;
+        }
+        if let Some(group) = &mut self.security {
+            group.recommended = None;
+        }
+        if let Some(group) = &mut self.style {
+            group.recommended = None;
+        }
     }
     pub(crate) const fn is_recommended_false(&self) -> bool {
         matches!(self.recommended, Some(false))
@@ -116,6 +236,32 @@ impl Rules {
     pub fn as_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut enabled_rules = FxHashSet::default();
         let mut disabled_rules = FxHashSet::default();
+        if let Some(group) = self.correctness.as_ref() {
+            group.collect_preset_rules(
+                self.is_all_true(),
+                !self.is_recommended_false(),
+                &mut enabled_rules,
+            );
+            enabled_rules.extend(&group.get_enabled_rules());
+            disabled_rules.extend(&group.get_disabled_rules());
+        } else if self.is_all_true() {
+            enabled_rules.extend(Correctness::all_rules_as_filters());
+        } else if !self.is_recommended_false() {
+            enabled_rules.extend(Correctness::recommended_rules_as_filters());
+        }
+        if let Some(group) = self.performance.as_ref() {
+            group.collect_preset_rules(
+                self.is_all_true(),
+                !self.is_recommended_false(),
+                &mut enabled_rules,
+            );
+            enabled_rules.extend(&group.get_enabled_rules());
+            disabled_rules.extend(&group.get_disabled_rules());
+        } else if self.is_all_true() {
+            enabled_rules.extend(Performance::all_rules_as_filters());
+        } else if !self.is_recommended_false() {
+            enabled_rules.extend(Performance::recommended_rules_as_filters());
+        }
         if let Some(group) = self.safety.as_ref() {
             group.collect_preset_rules(
                 self.is_all_true(),
@@ -129,6 +275,45 @@ impl Rules {
         } else if !self.is_recommended_false() {
             enabled_rules.extend(Safety::recommended_rules_as_filters());
         }
+        if let Some(group) = self.schema.as_ref() {
+            group.collect_preset_rules(
+                self.is_all_true(),
+                !self.is_recommended_false(),
+                &mut enabled_rules,
+            );
+            enabled_rules.extend(&group.get_enabled_rules());
+            disabled_rules.extend(&group.get_disabled_rules());
+        } else if self.is_all_true() {
+            enabled_rules.extend(Schema::all_rules_as_filters());
+        } else if !self.is_recommended_false() {
+            enabled_rules.extend(Schema::recommended_rules_as_filters());
+        }
+        if let Some(group) = self.security.as_ref() {
+            group.collect_preset_rules(
+                self.is_all_true(),
+                !self.is_recommended_false(),
+                &mut enabled_rules,
+            );
+            enabled_rules.extend(&group.get_enabled_rules());
+            disabled_rules.extend(&group.get_disabled_rules());
+        } else if self.is_all_true() {
+            enabled_rules.extend(Security::all_rules_as_filters());
+        } else if !self.is_recommended_false() {
+            enabled_rules.extend(Security::recommended_rules_as_filters());
+        }
+        if let Some(group) = self.style.as_ref() {
+            group.collect_preset_rules(
+                self.is_all_true(),
+                !self.is_recommended_false(),
+                &mut enabled_rules,
+            );
+            enabled_rules.extend(&group.get_enabled_rules());
+            disabled_rules.extend(&group.get_disabled_rules());
+        } else if self.is_all_true() {
+            enabled_rules.extend(Style::all_rules_as_filters());
+        } else if !self.is_recommended_false() {
+            enabled_rules.extend(Style::recommended_rules_as_filters());
+        }
         enabled_rules.difference(&disabled_rules).copied().collect()
     }
 }
@@ -136,47 +321,91 @@ impl Rules {
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", default, deny_unknown_fields)]
 #[doc = r" A list of rules that belong to this group"]
-pub struct Safety {
+pub struct Correctness {
     #[doc = r" It enables the recommended rules for this group"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recommended: Option<bool>,
     #[doc = r" It enables ALL rules for this group."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
-    #[doc = "Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required."]
+    #[doc = "BETWEEN includes both bounds, which is rarely what you want for timestamps."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub adding_required_field:
-        Option<RuleConfiguration<pgt_analyser::options::AddingRequiredField>>,
-    #[doc = "Dropping a column may break existing clients."]
+    pub ban_between_with_timestamps:
+        Option<RuleConfiguration<pgt_analyser::options::BanBetweenWithTimestamps>>,
+    #[doc = "Comparisons with NULL using = or \\<> never evaluate to true."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ban_drop_column: Option<RuleConfiguration<pgt_analyser::options::BanDropColumn>>,
-    #[doc = "Dropping a NOT NULL constraint may break existing clients."]
+    pub ban_equals_null: Option<RuleConfiguration<pgt_analyser::options::BanEqualsNull>>,
+    #[doc = "Migrations should not start or end transactions themselves."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ban_drop_not_null: Option<RuleConfiguration<pgt_analyser::options::BanDropNotNull>>,
-    #[doc = "Dropping a table may break existing clients."]
+    pub ban_explicit_transaction:
+        Option<RuleConfiguration<pgt_analyser::options::BanExplicitTransaction>>,
+    #[doc = "Tables listed in FROM without a condition that relates them produce a cross join."]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ban_drop_table: Option<RuleConfiguration<pgt_analyser::options::BanDropTable>>,
+    pub ban_implicit_cross_join:
+        Option<RuleConfiguration<pgt_analyser::options::BanImplicitCrossJoin>>,
+    #[doc = "NOT IN with a subquery returns no rows at all if the subquery returns a NULL."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_not_in_subquery: Option<RuleConfiguration<pgt_analyser::options::BanNotInSubquery>>,
+    #[doc = "Avoid SELECT * in views and functions."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_select_star: Option<RuleConfiguration<pgt_analyser::options::BanSelectStar>>,
+    #[doc = "PL/pgSQL functions must not assign to undeclared variables."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_undeclared_variables:
+        Option<RuleConfiguration<pgt_analyser::options::BanUndeclaredVariables>>,
+    #[doc = "PL/pgSQL functions should not contain code that never runs."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_unreachable_code: Option<RuleConfiguration<pgt_analyser::options::BanUnreachableCode>>,
+    #[doc = "Variables and parameters of PL/pgSQL functions should be used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_unused_variables: Option<RuleConfiguration<pgt_analyser::options::BanUnusedVariables>>,
+    #[doc = "PL/pgSQL functions that return a value must not reach the end of the function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_return: Option<RuleConfiguration<pgt_analyser::options::RequireReturn>>,
+    #[doc = "SELECT INTO in PL/pgSQL functions should use STRICT when exactly one row is expected."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_strict_select_into:
+        Option<RuleConfiguration<pgt_analyser::options::RequireStrictSelectInto>>,
 }
-impl Safety {
-    const GROUP_NAME: &'static str = "safety";
+impl Correctness {
+    const GROUP_NAME: &'static str = "correctness";
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
-        "addingRequiredField",
-        "banDropColumn",
-        "banDropNotNull",
-        "banDropTable",
+        "banBetweenWithTimestamps",
+        "banEqualsNull",
+        "banExplicitTransaction",
+        "banImplicitCrossJoin",
+        "banNotInSubquery",
+        "banSelectStar",
+        "banUndeclaredVariables",
+        "banUnreachableCode",
+        "banUnusedVariables",
+        "requireReturn",
+        "requireStrictSelectInto",
+    ];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[
+        "banEqualsNull",
+        "banUndeclaredVariables",
+        "banUnreachableCode",
+        "requireReturn",
     ];
-    const RECOMMENDED_RULES: &'static [&'static str] =
-        &["banDropColumn", "banDropNotNull", "banDropTable"];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended_true(&self) -> bool {
@@ -193,50 +422,120 @@ impl Safety {
     }
     pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.adding_required_field.as_ref() {
+        if let Some(rule) = self.ban_between_with_timestamps.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.ban_drop_column.as_ref() {
+        if let Some(rule) = self.ban_equals_null.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+        if let Some(rule) = self.ban_explicit_transaction.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.ban_drop_table.as_ref() {
+        if let Some(rule) = self.ban_implicit_cross_join.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
+        if let Some(rule) = self.ban_not_in_subquery.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.ban_select_star.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.ban_undeclared_variables.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.ban_unreachable_code.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.ban_unused_variables.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.require_return.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.require_strict_select_into.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
         index_set
     }
     pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
         let mut index_set = FxHashSet::default();
-        if let Some(rule) = self.adding_required_field.as_ref() {
+        if let Some(rule) = self.ban_between_with_timestamps.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.ban_drop_column.as_ref() {
+        if let Some(rule) = self.ban_equals_null.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+        if let Some(rule) = self.ban_explicit_transaction.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.ban_drop_table.as_ref() {
+        if let Some(rule) = self.ban_implicit_cross_join.as_ref() {
             if rule.is_disabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
+        if let Some(rule) = self.ban_not_in_subquery.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.ban_select_star.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.ban_undeclared_variables.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.ban_unreachable_code.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.ban_unused_variables.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.require_return.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.require_strict_select_into.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
         index_set
     }
     #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
@@ -273,29 +572,1427 @@ impl Safety {
         rule_name: &str,
     ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
-            "addingRequiredField" => self
-                .adding_required_field
+            "banBetweenWithTimestamps" => self
+                .ban_between_with_timestamps
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
-            "banDropColumn" => self
-                .ban_drop_column
+            "banEqualsNull" => self
+                .ban_equals_null
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
-            "banDropNotNull" => self
-                .ban_drop_not_null
+            "banExplicitTransaction" => self
+                .ban_explicit_transaction
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
-            "banDropTable" => self
-                .ban_drop_table
+            "banImplicitCrossJoin" => self
+                .ban_implicit_cross_join
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banNotInSubquery" => self
+                .ban_not_in_subquery
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banSelectStar" => self
+                .ban_select_star
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banUndeclaredVariables" => self
+                .ban_undeclared_variables
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banUnreachableCode" => self
+                .ban_unreachable_code
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banUnusedVariables" => self
+                .ban_unused_variables
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireReturn" => self
+                .require_return
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireStrictSelectInto" => self
+                .require_strict_select_into
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
 }
-#[test]
-fn test_order() {
-    for items in Safety::GROUP_RULES.windows(2) {
+#[derive(Clone, Debug, Default, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+#[doc = r" A list of rules that belong to this group"]
+pub struct Performance {
+    #[doc = r" It enables the recommended rules for this group"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+    #[doc = r" It enables ALL rules for this group."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    #[doc = "Paginating with a large OFFSET gets slower with every page."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_large_offset: Option<RuleConfiguration<pgt_analyser::options::BanLargeOffset>>,
+    #[doc = "Sorting by random() reads and sorts the whole table."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_order_by_random: Option<RuleConfiguration<pgt_analyser::options::BanOrderByRandom>>,
+}
+impl Performance {
+    const GROUP_NAME: &'static str = "performance";
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &["banLargeOffset", "banOrderByRandom"];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[];
+    const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[];
+    const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
+    ];
+    #[doc = r" Retrieves the recommended rules"]
+    pub(crate) fn is_recommended_true(&self) -> bool {
+        matches!(self.recommended, Some(true))
+    }
+    pub(crate) fn is_recommended_unset(&self) -> bool {
+        self.recommended.is_none()
+    }
+    pub(crate) fn is_all_true(&self) -> bool {
+        matches!(self.all, Some(true))
+    }
+    pub(crate) fn is_all_unset(&self) -> bool {
+        self.all.is_none()
+    }
+    pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.ban_large_offset.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.ban_order_by_random.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.ban_large_offset.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.ban_order_by_random.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        index_set
+    }
+    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
+    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
+        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
+    }
+    #[doc = r" Checks if, given a rule name, it is marked as recommended"]
+    pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
+        Self::RECOMMENDED_RULES.contains(&rule_name)
+    }
+    pub(crate) fn recommended_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::RECOMMENDED_RULES_AS_FILTERS
+    }
+    pub(crate) fn all_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::ALL_RULES_AS_FILTERS
+    }
+    #[doc = r" Select preset rules"]
+    pub(crate) fn collect_preset_rules(
+        &self,
+        parent_is_all: bool,
+        parent_is_recommended: bool,
+        enabled_rules: &mut FxHashSet<RuleFilter<'static>>,
+    ) {
+        if self.is_all_true() || self.is_all_unset() && parent_is_all {
+            enabled_rules.extend(Self::all_rules_as_filters());
+        } else if self.is_recommended_true()
+            || self.is_recommended_unset() && self.is_all_unset() && parent_is_recommended
+        {
+            enabled_rules.extend(Self::recommended_rules_as_filters());
+        }
+    }
+    pub(crate) fn get_rule_configuration(
+        &self,
+        rule_name: &str,
+    ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
+        match rule_name {
+            "banLargeOffset" => self
+                .ban_large_offset
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banOrderByRandom" => self
+                .ban_order_by_random
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+#[doc = r" A list of rules that belong to this group"]
+pub struct Safety {
+    #[doc = r" It enables the recommended rules for this group"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+    #[doc = r" It enables ALL rules for this group."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    #[doc = "Adding a column with a volatile default value rewrites the whole table while holding an ACCESS EXCLUSIVE lock."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adding_field_with_volatile_default:
+        Option<RuleConfiguration<pgt_analyser::options::AddingFieldWithVolatileDefault>>,
+    #[doc = "Adding a primary key constraint to an existing table builds its index while blocking reads and writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adding_primary_key_constraint:
+        Option<RuleConfiguration<pgt_analyser::options::AddingPrimaryKeyConstraint>>,
+    #[doc = "Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adding_required_field:
+        Option<RuleConfiguration<pgt_analyser::options::AddingRequiredField>>,
+    #[doc = "Adding a stored generated column rewrites the whole table while holding an ACCESS EXCLUSIVE lock."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adding_stored_generated_column:
+        Option<RuleConfiguration<pgt_analyser::options::AddingStoredGeneratedColumn>>,
+    #[doc = "CLUSTER rewrites the whole table while holding an ACCESS EXCLUSIVE lock."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_cluster: Option<RuleConfiguration<pgt_analyser::options::BanCluster>>,
+    #[doc = "A DELETE without a WHERE clause deletes every row of the table."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_delete_without_where:
+        Option<RuleConfiguration<pgt_analyser::options::BanDeleteWithoutWhere>>,
+    #[doc = "Dropping a column may break existing clients."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_drop_column: Option<RuleConfiguration<pgt_analyser::options::BanDropColumn>>,
+    #[doc = "Dropping a function that other functions or triggers still use breaks them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_drop_function_in_use:
+        Option<RuleConfiguration<pgt_analyser::options::BanDropFunctionInUse>>,
+    #[doc = "Dropping a NOT NULL constraint may break existing clients."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_drop_not_null: Option<RuleConfiguration<pgt_analyser::options::BanDropNotNull>>,
+    #[doc = "Dropping a column that other objects still use breaks them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_drop_referenced_column:
+        Option<RuleConfiguration<pgt_analyser::options::BanDropReferencedColumn>>,
+    #[doc = "Dropping a table that other objects still use breaks them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_drop_referenced_table:
+        Option<RuleConfiguration<pgt_analyser::options::BanDropReferencedTable>>,
+    #[doc = "Dropping a table may break existing clients."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_drop_table: Option<RuleConfiguration<pgt_analyser::options::BanDropTable>>,
+    #[doc = "Schema changes and backfills of whole tables should not be run in the same migration."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_mixing_ddl_and_backfill:
+        Option<RuleConfiguration<pgt_analyser::options::BanMixingDdlAndBackfill>>,
+    #[doc = "Renaming a table or column that trigger functions use breaks the triggers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_rename_referenced_by_trigger:
+        Option<RuleConfiguration<pgt_analyser::options::BanRenameReferencedByTrigger>>,
+    #[doc = "An UPDATE without a WHERE clause updates every row of the table."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_update_without_where:
+        Option<RuleConfiguration<pgt_analyser::options::BanUpdateWithoutWhere>>,
+    #[doc = "VACUUM FULL rewrites the whole table while holding an ACCESS EXCLUSIVE lock."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_vacuum_full: Option<RuleConfiguration<pgt_analyser::options::BanVacuumFull>>,
+    #[doc = "Changing the type of a column rewrites the whole table while holding an ACCESS EXCLUSIVE lock."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changing_column_type: Option<RuleConfiguration<pgt_analyser::options::ChangingColumnType>>,
+    #[doc = "Changing a table between logged and unlogged rewrites the whole table while holding an ACCESS EXCLUSIVE lock."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changing_table_persistence:
+        Option<RuleConfiguration<pgt_analyser::options::ChangingTablePersistence>>,
+    #[doc = "Adding constraints without NOT VALID blocks all reads and writes while the existing rows are validated."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint_missing_not_valid:
+        Option<RuleConfiguration<pgt_analyser::options::ConstraintMissingNotValid>>,
+    #[doc = "Adding a unique constraint to an existing table builds its index while blocking reads and writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disallow_unique_constraint:
+        Option<RuleConfiguration<pgt_analyser::options::DisallowUniqueConstraint>>,
+    #[doc = "Renaming columns may break existing queries."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renaming_column: Option<RuleConfiguration<pgt_analyser::options::RenamingColumn>>,
+    #[doc = "Renaming tables may break existing queries."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renaming_table: Option<RuleConfiguration<pgt_analyser::options::RenamingTable>>,
+    #[doc = "Creating indexes non-concurrently can lock the table for writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_concurrent_index_creation:
+        Option<RuleConfiguration<pgt_analyser::options::RequireConcurrentIndexCreation>>,
+    #[doc = "Dropping indexes non-concurrently can lock the table for reads and writes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_concurrent_index_deletion:
+        Option<RuleConfiguration<pgt_analyser::options::RequireConcurrentIndexDeletion>>,
+    #[doc = "Set a lock_timeout before running DDL that locks existing tables."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_lock_timeout: Option<RuleConfiguration<pgt_analyser::options::RequireLockTimeout>>,
+}
+impl Safety {
+    const GROUP_NAME: &'static str = "safety";
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "addingFieldWithVolatileDefault",
+        "addingPrimaryKeyConstraint",
+        "addingRequiredField",
+        "addingStoredGeneratedColumn",
+        "banCluster",
+        "banDeleteWithoutWhere",
+        "banDropColumn",
+        "banDropFunctionInUse",
+        "banDropNotNull",
+        "banDropReferencedColumn",
+        "banDropReferencedTable",
+        "banDropTable",
+        "banMixingDdlAndBackfill",
+        "banRenameReferencedByTrigger",
+        "banUpdateWithoutWhere",
+        "banVacuumFull",
+        "changingColumnType",
+        "changingTablePersistence",
+        "constraintMissingNotValid",
+        "disallowUniqueConstraint",
+        "renamingColumn",
+        "renamingTable",
+        "requireConcurrentIndexCreation",
+        "requireConcurrentIndexDeletion",
+        "requireLockTimeout",
+    ];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[
+        "banDeleteWithoutWhere",
+        "banDropColumn",
+        "banDropNotNull",
+        "banDropTable",
+        "banUpdateWithoutWhere",
+    ];
+    const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
+    ];
+    const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
+    ];
+    #[doc = r" Retrieves the recommended rules"]
+    pub(crate) fn is_recommended_true(&self) -> bool {
+        matches!(self.recommended, Some(true))
+    }
+    pub(crate) fn is_recommended_unset(&self) -> bool {
+        self.recommended.is_none()
+    }
+    pub(crate) fn is_all_true(&self) -> bool {
+        matches!(self.all, Some(true))
+    }
+    pub(crate) fn is_all_unset(&self) -> bool {
+        self.all.is_none()
+    }
+    pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.adding_field_with_volatile_default.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.adding_primary_key_constraint.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.adding_required_field.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.adding_stored_generated_column.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.ban_cluster.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.ban_delete_without_where.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_column.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_function_in_use.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_referenced_column.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_referenced_table.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_table.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.ban_mixing_ddl_and_backfill.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
+        if let Some(rule) = self.ban_rename_referenced_by_trigger.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.ban_update_without_where.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.ban_vacuum_full.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.changing_column_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.changing_table_persistence.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.constraint_missing_not_valid.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.disallow_unique_constraint.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.renaming_column.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.renaming_table.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_creation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_deletion.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.require_lock_timeout.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.adding_field_with_volatile_default.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.adding_primary_key_constraint.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.adding_required_field.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.adding_stored_generated_column.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.ban_cluster.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.ban_delete_without_where.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_column.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_function_in_use.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_not_null.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_referenced_column.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_referenced_table.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.ban_drop_table.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.ban_mixing_ddl_and_backfill.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
+        if let Some(rule) = self.ban_rename_referenced_by_trigger.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.ban_update_without_where.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.ban_vacuum_full.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.changing_column_type.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.changing_table_persistence.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.constraint_missing_not_valid.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.disallow_unique_constraint.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.renaming_column.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.renaming_table.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_creation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.require_concurrent_index_deletion.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.require_lock_timeout.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        index_set
+    }
+    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
+    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
+        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
+    }
+    #[doc = r" Checks if, given a rule name, it is marked as recommended"]
+    pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
+        Self::RECOMMENDED_RULES.contains(&rule_name)
+    }
+    pub(crate) fn recommended_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::RECOMMENDED_RULES_AS_FILTERS
+    }
+    pub(crate) fn all_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::ALL_RULES_AS_FILTERS
+    }
+    #[doc = r" Select preset rules"]
+    pub(crate) fn collect_preset_rules(
+        &self,
+        parent_is_all: bool,
+        parent_is_recommended: bool,
+        enabled_rules: &mut FxHashSet<RuleFilter<'static>>,
+    ) {
+        if self.is_all_true() || self.is_all_unset() && parent_is_all {
+            enabled_rules.extend(Self::all_rules_as_filters());
+        } else if self.is_recommended_true()
+            || self.is_recommended_unset() && self.is_all_unset() && parent_is_recommended
+        {
+            enabled_rules.extend(Self::recommended_rules_as_filters());
+        }
+    }
+    pub(crate) fn get_rule_configuration(
+        &self,
+        rule_name: &str,
+    ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
+        match rule_name {
+            "addingFieldWithVolatileDefault" => self
+                .adding_field_with_volatile_default
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "addingPrimaryKeyConstraint" => self
+                .adding_primary_key_constraint
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "addingRequiredField" => self
+                .adding_required_field
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "addingStoredGeneratedColumn" => self
+                .adding_stored_generated_column
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banCluster" => self
+                .ban_cluster
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDeleteWithoutWhere" => self
+                .ban_delete_without_where
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDropColumn" => self
+                .ban_drop_column
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDropFunctionInUse" => self
+                .ban_drop_function_in_use
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDropNotNull" => self
+                .ban_drop_not_null
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDropReferencedColumn" => self
+                .ban_drop_referenced_column
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDropReferencedTable" => self
+                .ban_drop_referenced_table
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banDropTable" => self
+                .ban_drop_table
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banMixingDdlAndBackfill" => self
+                .ban_mixing_ddl_and_backfill
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banRenameReferencedByTrigger" => self
+                .ban_rename_referenced_by_trigger
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banUpdateWithoutWhere" => self
+                .ban_update_without_where
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banVacuumFull" => self
+                .ban_vacuum_full
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "changingColumnType" => self
+                .changing_column_type
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "changingTablePersistence" => self
+                .changing_table_persistence
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "constraintMissingNotValid" => self
+                .constraint_missing_not_valid
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "disallowUniqueConstraint" => self
+                .disallow_unique_constraint
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "renamingColumn" => self
+                .renaming_column
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "renamingTable" => self
+                .renaming_table
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireConcurrentIndexCreation" => self
+                .require_concurrent_index_creation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireConcurrentIndexDeletion" => self
+                .require_concurrent_index_deletion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireLockTimeout" => self
+                .require_lock_timeout
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+#[doc = r" A list of rules that belong to this group"]
+pub struct Schema {
+    #[doc = r" It enables the recommended rules for this group"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+    #[doc = r" It enables ALL rules for this group."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    #[doc = "Existing policies should not call auth functions for every row."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_rls_initplan: Option<RuleConfiguration<pgt_analyser::options::AuthRlsInitplan>>,
+    #[doc = "A table should not have several identical indexes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_index: Option<RuleConfiguration<pgt_analyser::options::DuplicateIndex>>,
+    #[doc = "Extensions should not be installed in the public schema."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_in_public: Option<RuleConfiguration<pgt_analyser::options::ExtensionInPublic>>,
+    #[doc = "A table should not have several permissive policies for the same role and action."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiple_permissive_policies:
+        Option<RuleConfiguration<pgt_analyser::options::MultiplePermissivePolicies>>,
+    #[doc = "Views in exposed schemas should use the permissions of the querying user."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_definer_view:
+        Option<RuleConfiguration<pgt_analyser::options::SecurityDefinerView>>,
+    #[doc = "Foreign keys should be covered by an index."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unindexed_foreign_keys:
+        Option<RuleConfiguration<pgt_analyser::options::UnindexedForeignKeys>>,
+}
+impl Schema {
+    const GROUP_NAME: &'static str = "schema";
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "authRlsInitplan",
+        "duplicateIndex",
+        "extensionInPublic",
+        "multiplePermissivePolicies",
+        "securityDefinerView",
+        "unindexedForeignKeys",
+    ];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[];
+    const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[];
+    const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+    ];
+    #[doc = r" Retrieves the recommended rules"]
+    pub(crate) fn is_recommended_true(&self) -> bool {
+        matches!(self.recommended, Some(true))
+    }
+    pub(crate) fn is_recommended_unset(&self) -> bool {
+        self.recommended.is_none()
+    }
+    pub(crate) fn is_all_true(&self) -> bool {
+        matches!(self.all, Some(true))
+    }
+    pub(crate) fn is_all_unset(&self) -> bool {
+        self.all.is_none()
+    }
+    pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.auth_rls_initplan.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.duplicate_index.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.extension_in_public.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.multiple_permissive_policies.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.security_definer_view.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.unindexed_foreign_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.auth_rls_initplan.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.duplicate_index.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.extension_in_public.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.multiple_permissive_policies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.security_definer_view.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.unindexed_foreign_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        index_set
+    }
+    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
+    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
+        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
+    }
+    #[doc = r" Checks if, given a rule name, it is marked as recommended"]
+    pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
+        Self::RECOMMENDED_RULES.contains(&rule_name)
+    }
+    pub(crate) fn recommended_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::RECOMMENDED_RULES_AS_FILTERS
+    }
+    pub(crate) fn all_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::ALL_RULES_AS_FILTERS
+    }
+    #[doc = r" Select preset rules"]
+    pub(crate) fn collect_preset_rules(
+        &self,
+        parent_is_all: bool,
+        parent_is_recommended: bool,
+        enabled_rules: &mut FxHashSet<RuleFilter<'static>>,
+    ) {
+        if self.is_all_true() || self.is_all_unset() && parent_is_all {
+            enabled_rules.extend(Self::all_rules_as_filters());
+        } else if self.is_recommended_true()
+            || self.is_recommended_unset() && self.is_all_unset() && parent_is_recommended
+        {
+            enabled_rules.extend(Self::recommended_rules_as_filters());
+        }
+    }
+    pub(crate) fn get_rule_configuration(
+        &self,
+        rule_name: &str,
+    ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
+        match rule_name {
+            "authRlsInitplan" => self
+                .auth_rls_initplan
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "duplicateIndex" => self
+                .duplicate_index
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "extensionInPublic" => self
+                .extension_in_public
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "multiplePermissivePolicies" => self
+                .multiple_permissive_policies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "securityDefinerView" => self
+                .security_definer_view
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "unindexedForeignKeys" => self
+                .unindexed_foreign_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+#[doc = r" A list of rules that belong to this group"]
+pub struct Security {
+    #[doc = r" It enables the recommended rules for this group"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+    #[doc = r" It enables ALL rules for this group."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    #[doc = "Dynamic SQL in PL/pgSQL functions must not be built by concatenating variables."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_dynamic_sql_concatenation:
+        Option<RuleConfiguration<pgt_analyser::options::BanDynamicSqlConcatenation>>,
+    #[doc = "Policies for commands other than SELECT must not be unrestricted."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_unrestricted_write_policy:
+        Option<RuleConfiguration<pgt_analyser::options::BanUnrestrictedWritePolicy>>,
+    #[doc = "Tables in exposed schemas must have row level security enabled."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_row_level_security:
+        Option<RuleConfiguration<pgt_analyser::options::RequireRowLevelSecurity>>,
+    #[doc = "SECURITY DEFINER functions must set a search_path."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_search_path_on_security_definer:
+        Option<RuleConfiguration<pgt_analyser::options::RequireSearchPathOnSecurityDefiner>>,
+    #[doc = "UPDATE policies should have a WITH CHECK expression."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_update_policy_with_check:
+        Option<RuleConfiguration<pgt_analyser::options::RequireUpdatePolicyWithCheck>>,
+    #[doc = "Calls to auth functions and current_setting() in policies should be wrapped in a SELECT."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrap_auth_functions_in_select:
+        Option<RuleConfiguration<pgt_analyser::options::WrapAuthFunctionsInSelect>>,
+}
+impl Security {
+    const GROUP_NAME: &'static str = "security";
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "banDynamicSqlConcatenation",
+        "banUnrestrictedWritePolicy",
+        "requireRowLevelSecurity",
+        "requireSearchPathOnSecurityDefiner",
+        "requireUpdatePolicyWithCheck",
+        "wrapAuthFunctionsInSelect",
+    ];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[
+        "banDynamicSqlConcatenation",
+        "banUnrestrictedWritePolicy",
+        "requireSearchPathOnSecurityDefiner",
+        "requireUpdatePolicyWithCheck",
+    ];
+    const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+    ];
+    const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+    ];
+    #[doc = r" Retrieves the recommended rules"]
+    pub(crate) fn is_recommended_true(&self) -> bool {
+        matches!(self.recommended, Some(true))
+    }
+    pub(crate) fn is_recommended_unset(&self) -> bool {
+        self.recommended.is_none()
+    }
+    pub(crate) fn is_all_true(&self) -> bool {
+        matches!(self.all, Some(true))
+    }
+    pub(crate) fn is_all_unset(&self) -> bool {
+        self.all.is_none()
+    }
+    pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.ban_dynamic_sql_concatenation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.ban_unrestricted_write_policy.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.require_row_level_security.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.require_search_path_on_security_definer.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.require_update_policy_with_check.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.wrap_auth_functions_in_select.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.ban_dynamic_sql_concatenation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.ban_unrestricted_write_policy.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.require_row_level_security.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.require_search_path_on_security_definer.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.require_update_policy_with_check.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.wrap_auth_functions_in_select.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        index_set
+    }
+    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
+    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
+        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
+    }
+    #[doc = r" Checks if, given a rule name, it is marked as recommended"]
+    pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
+        Self::RECOMMENDED_RULES.contains(&rule_name)
+    }
+    pub(crate) fn recommended_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::RECOMMENDED_RULES_AS_FILTERS
+    }
+    pub(crate) fn all_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::ALL_RULES_AS_FILTERS
+    }
+    #[doc = r" Select preset rules"]
+    pub(crate) fn collect_preset_rules(
+        &self,
+        parent_is_all: bool,
+        parent_is_recommended: bool,
+        enabled_rules: &mut FxHashSet<RuleFilter<'static>>,
+    ) {
+        if self.is_all_true() || self.is_all_unset() && parent_is_all {
+            enabled_rules.extend(Self::all_rules_as_filters());
+        } else if self.is_recommended_true()
+            || self.is_recommended_unset() && self.is_all_unset() && parent_is_recommended
+        {
+            enabled_rules.extend(Self::recommended_rules_as_filters());
+        }
+    }
+    pub(crate) fn get_rule_configuration(
+        &self,
+        rule_name: &str,
+    ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
+        match rule_name {
+            "banDynamicSqlConcatenation" => self
+                .ban_dynamic_sql_concatenation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banUnrestrictedWritePolicy" => self
+                .ban_unrestricted_write_policy
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireRowLevelSecurity" => self
+                .require_row_level_security
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireSearchPathOnSecurityDefiner" => self
+                .require_search_path_on_security_definer
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireUpdatePolicyWithCheck" => self
+                .require_update_policy_with_check
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "wrapAuthFunctionsInSelect" => self
+                .wrap_auth_functions_in_select
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Debug, Default, Deserialize, Eq, Merge, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+#[doc = r" A list of rules that belong to this group"]
+pub struct Style {
+    #[doc = r" It enables the recommended rules for this group"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+    #[doc = r" It enables ALL rules for this group."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    #[doc = "Prefer jsonb over json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_json_prefer_jsonb: Option<RuleConfiguration<pgt_analyser::options::BanJsonPreferJsonb>>,
+    #[doc = "Do not use the money type."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_money_type: Option<RuleConfiguration<pgt_analyser::options::BanMoneyType>>,
+    #[doc = "Names of database objects should follow a consistent naming convention."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub naming_convention: Option<RuleConfiguration<pgt_analyser::options::NamingConvention>>,
+    #[doc = "Prefer bigint over integer and smallint for primary keys."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_bigint_over_int:
+        Option<RuleConfiguration<pgt_analyser::options::PreferBigintOverInt>>,
+    #[doc = "Prefer identity columns over serial types."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_identity_over_serial:
+        Option<RuleConfiguration<pgt_analyser::options::PreferIdentityOverSerial>>,
+    #[doc = "Prefer text over varchar(n) and char(n)."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_text: Option<RuleConfiguration<pgt_analyser::options::PreferText>>,
+    #[doc = "Prefer timestamptz over timestamp."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_timestamptz: Option<RuleConfiguration<pgt_analyser::options::PreferTimestamptz>>,
+    #[doc = "Migrations should create objects with IF NOT EXISTS or OR REPLACE."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_create_if_not_exists:
+        Option<RuleConfiguration<pgt_analyser::options::RequireCreateIfNotExists>>,
+    #[doc = "Migrations should drop objects with IF EXISTS."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_drop_if_exists:
+        Option<RuleConfiguration<pgt_analyser::options::RequireDropIfExists>>,
+}
+impl Style {
+    const GROUP_NAME: &'static str = "style";
+    pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "banJsonPreferJsonb",
+        "banMoneyType",
+        "namingConvention",
+        "preferBigintOverInt",
+        "preferIdentityOverSerial",
+        "preferText",
+        "preferTimestamptz",
+        "requireCreateIfNotExists",
+        "requireDropIfExists",
+    ];
+    const RECOMMENDED_RULES: &'static [&'static str] = &[];
+    const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[];
+    const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
+    ];
+    #[doc = r" Retrieves the recommended rules"]
+    pub(crate) fn is_recommended_true(&self) -> bool {
+        matches!(self.recommended, Some(true))
+    }
+    pub(crate) fn is_recommended_unset(&self) -> bool {
+        self.recommended.is_none()
+    }
+    pub(crate) fn is_all_true(&self) -> bool {
+        matches!(self.all, Some(true))
+    }
+    pub(crate) fn is_all_unset(&self) -> bool {
+        self.all.is_none()
+    }
+    pub(crate) fn get_enabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.ban_json_prefer_jsonb.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.ban_money_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.prefer_bigint_over_int.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.prefer_identity_over_serial.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.prefer_text.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.prefer_timestamptz.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.require_create_if_not_exists.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.require_drop_if_exists.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> FxHashSet<RuleFilter<'static>> {
+        let mut index_set = FxHashSet::default();
+        if let Some(rule) = self.ban_json_prefer_jsonb.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.ban_money_type.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.naming_convention.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.prefer_bigint_over_int.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.prefer_identity_over_serial.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.prefer_text.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.prefer_timestamptz.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.require_create_if_not_exists.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.require_drop_if_exists.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        index_set
+    }
+    #[doc = r" Checks if, given a rule name, matches one of the rules contained in this category"]
+    pub(crate) fn has_rule(rule_name: &str) -> Option<&'static str> {
+        Some(Self::GROUP_RULES[Self::GROUP_RULES.binary_search(&rule_name).ok()?])
+    }
+    #[doc = r" Checks if, given a rule name, it is marked as recommended"]
+    pub(crate) fn is_recommended_rule(rule_name: &str) -> bool {
+        Self::RECOMMENDED_RULES.contains(&rule_name)
+    }
+    pub(crate) fn recommended_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::RECOMMENDED_RULES_AS_FILTERS
+    }
+    pub(crate) fn all_rules_as_filters() -> &'static [RuleFilter<'static>] {
+        Self::ALL_RULES_AS_FILTERS
+    }
+    #[doc = r" Select preset rules"]
+    pub(crate) fn collect_preset_rules(
+        &self,
+        parent_is_all: bool,
+        parent_is_recommended: bool,
+        enabled_rules: &mut FxHashSet<RuleFilter<'static>>,
+    ) {
+        if self.is_all_true() || self.is_all_unset() && parent_is_all {
+            enabled_rules.extend(Self::all_rules_as_filters());
+        } else if self.is_recommended_true()
+            || self.is_recommended_unset() && self.is_all_unset() && parent_is_recommended
+        {
+            enabled_rules.extend(Self::recommended_rules_as_filters());
+        }
+    }
+    pub(crate) fn get_rule_configuration(
+        &self,
+        rule_name: &str,
+    ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
+        match rule_name {
+            "banJsonPreferJsonb" => self
+                .ban_json_prefer_jsonb
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "banMoneyType" => self
+                .ban_money_type
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "namingConvention" => self
+                .naming_convention
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "preferBigintOverInt" => self
+                .prefer_bigint_over_int
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "preferIdentityOverSerial" => self
+                .prefer_identity_over_serial
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "preferText" => self
+                .prefer_text
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "preferTimestamptz" => self
+                .prefer_timestamptz
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireCreateIfNotExists" => self
+                .require_create_if_not_exists
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "requireDropIfExists" => self
+                .require_drop_if_exists
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            _ => None,
+        }
+    }
+}
+#[test]
+fn test_order() {
+    for items in Correctness::GROUP_RULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in Performance::GROUP_RULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in Safety::GROUP_RULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in Schema::GROUP_RULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in Security::GROUP_RULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in Style::GROUP_RULES.windows(2) {
         assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
     }
 }
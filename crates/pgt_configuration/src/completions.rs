@@ -0,0 +1,24 @@
+use biome_deserialize_macros::{Merge, Partial};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the completions.
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Eq, PartialEq, Merge))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct CompletionsConfiguration {
+    /// Rank relations and columns higher if they are used often in the project's SQL files
+    /// or were accepted recently. The statistics are stored in the cache directory.
+    /// Enabled by default.
+    #[partial(bpaf(long("completions-learn-from-workspace"), argument("true|false")))]
+    pub learn_from_workspace: bool,
+}
+
+impl Default for CompletionsConfiguration {
+    fn default() -> Self {
+        Self {
+            learn_from_workspace: true,
+        }
+    }
+}
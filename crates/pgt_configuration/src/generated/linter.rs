@@ -7,6 +7,24 @@ pub fn push_to_analyser_rules(
     metadata: &MetadataRegistry,
     analyser_rules: &mut AnalyserRules,
 ) {
+    if let Some(rules) = rules.correctness.as_ref() {
+        for rule_name in Correctness::GROUP_RULES {
+            if let Some((_, Some(rule_options))) = rules.get_rule_configuration(rule_name) {
+                if let Some(rule_key) = metadata.find_rule("correctness", rule_name) {
+                    analyser_rules.push_rule(rule_key, rule_options);
+                }
+            }
+        }
+    }
+    if let Some(rules) = rules.performance.as_ref() {
+        for rule_name in Performance::GROUP_RULES {
+            if let Some((_, Some(rule_options))) = rules.get_rule_configuration(rule_name) {
+                if let Some(rule_key) = metadata.find_rule("performance", rule_name) {
+                    analyser_rules.push_rule(rule_key, rule_options);
+                }
+            }
+        }
+    }
     if let Some(rules) = rules.safety.as_ref() {
         for rule_name in Safety::GROUP_RULES {
             if let Some((_, Some(rule_options))) = rules.get_rule_configuration(rule_name) {
@@ -16,4 +34,22 @@ pub fn push_to_analyser_rules(
             }
         }
     }
+    if let Some(rules) = rules.security.as_ref() {
+        for rule_name in Security::GROUP_RULES {
+            if let Some((_, Some(rule_options))) = rules.get_rule_configuration(rule_name) {
+                if let Some(rule_key) = metadata.find_rule("security", rule_name) {
+                    analyser_rules.push_rule(rule_key, rule_options);
+                }
+            }
+        }
+    }
+    if let Some(rules) = rules.style.as_ref() {
+        for rule_name in Style::GROUP_RULES {
+            if let Some((_, Some(rule_options))) = rules.get_rule_configuration(rule_name) {
+                if let Some(rule_key) = metadata.find_rule("style", rule_name) {
+                    analyser_rules.push_rule(rule_key, rule_options);
+                }
+            }
+        }
+    }
 }
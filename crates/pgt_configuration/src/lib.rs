@@ -4,6 +4,7 @@
 //! by language. The language might further options divided by tool.
 
 pub mod analyser;
+pub mod completions;
 pub mod database;
 pub mod diagnostics;
 pub mod files;
@@ -25,6 +26,9 @@ pub use analyser::{
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Merge, Partial};
 use bpaf::Bpaf;
+use completions::{
+    CompletionsConfiguration, PartialCompletionsConfiguration, partial_completions_configuration,
+};
 use database::{
     DatabaseConfiguration, PartialDatabaseConfiguration, partial_database_configuration,
 };
@@ -83,6 +87,13 @@ pub struct Configuration {
         bpaf(external(partial_database_configuration), optional, hide_usage)
     )]
     pub db: DatabaseConfiguration,
+
+    /// The configuration of the completions
+    #[partial(
+        type,
+        bpaf(external(partial_completions_configuration), optional, hide_usage)
+    )]
+    pub completions: CompletionsConfiguration,
 }
 
 impl PartialConfiguration {
@@ -120,6 +131,7 @@ impl PartialConfiguration {
                 conn_timeout_secs: Some(10),
                 disable_connection: Some(false),
             }),
+            completions: None,
         }
     }
 }
@@ -1,3 +1,4 @@
+use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Merge, Partial};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
@@ -15,4 +16,12 @@ pub struct MigrationsConfiguration {
     /// Ignore any migrations before this timestamp
     #[partial(bpaf(long("after")))]
     pub after: u64,
+
+    /// Additional patterns of migration files, relative to the migrations directory, e.g.
+    /// `V{sequence}__{name}.sql` for Flyway. `{sequence}` matches the sequence number, digits or
+    /// a dotted version such as `1.1`, `{name}` the name of the migration and `*` any part of a
+    /// file name. Migrations of patterns without `{sequence}`, e.g. `deploy/{name}.sql` for
+    /// Sqitch, are ordered by the `sqitch.plan` file in the migrations directory.
+    #[partial(bpaf(hide))]
+    pub patterns: StringSet,
 }
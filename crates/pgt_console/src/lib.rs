@@ -1,7 +1,7 @@
 //! # pgt_console
 
 use std::io;
-use std::io::{IsTerminal, Read, Write};
+use std::io::{BufRead, IsTerminal, Read, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
 use write::Termcolor;
@@ -39,6 +39,10 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// Reads a line typed by the user, e.g. the answer to a question. Returns `None` if there's
+    /// nobody to answer, e.g. when the input isn't a terminal.
+    fn read_line(&mut self) -> Option<String>;
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -180,6 +184,15 @@ impl Console for EnvConsole {
         // Skipping the error for now
         if result.is_ok() { Some(buffer) } else { None }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+        let mut line = String::new();
+        self.r#in.lock().read_line(&mut line).ok()?;
+        Some(line)
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -227,4 +240,13 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    /// Answers with the messages of `in_buffer`, in order
+    fn read_line(&mut self) -> Option<String> {
+        if self.in_buffer.is_empty() {
+            None
+        } else {
+            Some(self.in_buffer.remove(0))
+        }
+    }
 }
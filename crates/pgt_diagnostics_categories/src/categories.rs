@@ -13,10 +13,65 @@
 // must be between `define_categories! {\n` and `\n    ;\n`.
 
 define_categories! {
+    "lint/correctness/banBetweenWithTimestamps": "https://pglt.dev/linter/rules/ban-between-with-timestamps",
+    "lint/correctness/banEqualsNull": "https://pglt.dev/linter/rules/ban-equals-null",
+    "lint/correctness/banExplicitTransaction": "https://pglt.dev/linter/rules/ban-explicit-transaction",
+    "lint/correctness/banImplicitCrossJoin": "https://pglt.dev/linter/rules/ban-implicit-cross-join",
+    "lint/correctness/banNotInSubquery": "https://pglt.dev/linter/rules/ban-not-in-subquery",
+    "lint/correctness/banSelectStar": "https://pglt.dev/linter/rules/ban-select-star",
+    "lint/correctness/banUndeclaredVariables": "https://pglt.dev/linter/rules/ban-undeclared-variables",
+    "lint/correctness/banUnreachableCode": "https://pglt.dev/linter/rules/ban-unreachable-code",
+    "lint/correctness/banUnusedVariables": "https://pglt.dev/linter/rules/ban-unused-variables",
+    "lint/correctness/requireReturn": "https://pglt.dev/linter/rules/require-return",
+    "lint/correctness/requireStrictSelectInto": "https://pglt.dev/linter/rules/require-strict-select-into",
+    "lint/performance/banLargeOffset": "https://pglt.dev/linter/rules/ban-large-offset",
+    "lint/performance/banOrderByRandom": "https://pglt.dev/linter/rules/ban-order-by-random",
+    "lint/safety/addingFieldWithVolatileDefault": "https://pglt.dev/linter/rules/adding-field-with-volatile-default",
+    "lint/safety/addingPrimaryKeyConstraint": "https://pglt.dev/linter/rules/adding-primary-key-constraint",
     "lint/safety/addingRequiredField": "https://pglt.dev/linter/rules/adding-required-field",
+    "lint/safety/addingStoredGeneratedColumn": "https://pglt.dev/linter/rules/adding-stored-generated-column",
+    "lint/safety/banCluster": "https://pglt.dev/linter/rules/ban-cluster",
+    "lint/safety/banDeleteWithoutWhere": "https://pglt.dev/linter/rules/ban-delete-without-where",
     "lint/safety/banDropColumn": "https://pglt.dev/linter/rules/ban-drop-column",
+    "lint/safety/banDropFunctionInUse": "https://pglt.dev/linter/rules/ban-drop-function-in-use",
     "lint/safety/banDropNotNull": "https://pglt.dev/linter/rules/ban-drop-not-null",
+    "lint/safety/banDropReferencedColumn": "https://pglt.dev/linter/rules/ban-drop-referenced-column",
+    "lint/safety/banDropReferencedTable": "https://pglt.dev/linter/rules/ban-drop-referenced-table",
     "lint/safety/banDropTable": "https://pglt.dev/linter/rules/ban-drop-table",
+    "lint/safety/banMixingDdlAndBackfill": "https://pglt.dev/linter/rules/ban-mixing-ddl-and-backfill",
+    "lint/safety/banRenameReferencedByTrigger": "https://pglt.dev/linter/rules/ban-rename-referenced-by-trigger",
+    "lint/safety/banUpdateWithoutWhere": "https://pglt.dev/linter/rules/ban-update-without-where",
+    "lint/safety/banVacuumFull": "https://pglt.dev/linter/rules/ban-vacuum-full",
+    "lint/safety/changingColumnType": "https://pglt.dev/linter/rules/changing-column-type",
+    "lint/safety/changingTablePersistence": "https://pglt.dev/linter/rules/changing-table-persistence",
+    "lint/safety/constraintMissingNotValid": "https://pglt.dev/linter/rules/constraint-missing-not-valid",
+    "lint/safety/disallowUniqueConstraint": "https://pglt.dev/linter/rules/disallow-unique-constraint",
+    "lint/safety/renamingColumn": "https://pglt.dev/linter/rules/renaming-column",
+    "lint/safety/renamingTable": "https://pglt.dev/linter/rules/renaming-table",
+    "lint/safety/requireConcurrentIndexCreation": "https://pglt.dev/linter/rules/require-concurrent-index-creation",
+    "lint/safety/requireConcurrentIndexDeletion": "https://pglt.dev/linter/rules/require-concurrent-index-deletion",
+    "lint/safety/requireLockTimeout": "https://pglt.dev/linter/rules/require-lock-timeout",
+    "lint/schema/authRlsInitplan": "https://pglt.dev/linter/rules/auth-rls-initplan",
+    "lint/schema/duplicateIndex": "https://pglt.dev/linter/rules/duplicate-index",
+    "lint/schema/extensionInPublic": "https://pglt.dev/linter/rules/extension-in-public",
+    "lint/schema/multiplePermissivePolicies": "https://pglt.dev/linter/rules/multiple-permissive-policies",
+    "lint/schema/securityDefinerView": "https://pglt.dev/linter/rules/security-definer-view",
+    "lint/schema/unindexedForeignKeys": "https://pglt.dev/linter/rules/unindexed-foreign-keys",
+    "lint/security/banDynamicSqlConcatenation": "https://pglt.dev/linter/rules/ban-dynamic-sql-concatenation",
+    "lint/security/banUnrestrictedWritePolicy": "https://pglt.dev/linter/rules/ban-unrestricted-write-policy",
+    "lint/security/requireRowLevelSecurity": "https://pglt.dev/linter/rules/require-row-level-security",
+    "lint/security/requireSearchPathOnSecurityDefiner": "https://pglt.dev/linter/rules/require-search-path-on-security-definer",
+    "lint/security/requireUpdatePolicyWithCheck": "https://pglt.dev/linter/rules/require-update-policy-with-check",
+    "lint/security/wrapAuthFunctionsInSelect": "https://pglt.dev/linter/rules/wrap-auth-functions-in-select",
+    "lint/style/banJsonPreferJsonb": "https://pglt.dev/linter/rules/ban-json-prefer-jsonb",
+    "lint/style/banMoneyType": "https://pglt.dev/linter/rules/ban-money-type",
+    "lint/style/namingConvention": "https://pglt.dev/linter/rules/naming-convention",
+    "lint/style/preferBigintOverInt": "https://pglt.dev/linter/rules/prefer-bigint-over-int",
+    "lint/style/preferIdentityOverSerial": "https://pglt.dev/linter/rules/prefer-identity-over-serial",
+    "lint/style/preferText": "https://pglt.dev/linter/rules/prefer-text",
+    "lint/style/preferTimestamptz": "https://pglt.dev/linter/rules/prefer-timestamptz",
+    "lint/style/requireCreateIfNotExists": "https://pglt.dev/linter/rules/require-create-if-not-exists",
+    "lint/style/requireDropIfExists": "https://pglt.dev/linter/rules/require-drop-if-exists",
     // end lint rules
     ;
     // General categories
@@ -33,10 +88,22 @@ define_categories! {
     "internalError/panic",
     "syntax",
     "dummy",
+    "baseline",
+    "migrateCheck",
+    "diff",
+    "migrations/duplicateSequenceNumber",
+    "migrations/modified",
+    "migrations/outOfOrder",
+    "migrations/sequenceGap",
+    "migrations/unmatchedFile",
 
     // Lint groups start
     "lint",
+    "lint/correctness",
     "lint/performance",
     "lint/safety",
+    "lint/schema",
+    "lint/security",
+    "lint/style",
     // Lint groups end
 }
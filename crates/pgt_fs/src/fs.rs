@@ -1,5 +1,6 @@
 use crate::{PathInterner, PgTPath};
 pub use memory::{ErrorEntry, MemoryFileSystem};
+pub(crate) use os::DEFAULT_IGNORE;
 pub use os::OsFileSystem;
 use oxc_resolver::{Resolution, ResolveError};
 use pgt_diagnostics::{Advices, Diagnostic, LogCategory, Visit, console};
@@ -9,7 +10,7 @@ use std::collections::BTreeSet;
 use std::fmt::{Debug, Display, Formatter};
 use std::panic::RefUnwindSafe;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::{fmt, io};
 use tracing::{error, info};
 
@@ -166,6 +167,15 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
 
     fn get_staged_files(&self) -> io::Result<Vec<String>>;
 
+    /// Returns the committed files of `directory` with the time they were added, as a UNIX
+    /// timestamp. Paths are relative to `directory`, and the newest additions come first, so a
+    /// file that was added several times is listed several times.
+    fn get_added_files(&self, directory: &Path) -> io::Result<Vec<(String, u64)>>;
+
+    /// Returns the files of `directory` that were modified since the current branch forked from
+    /// `base`, including the uncommitted changes. Paths are relative to `directory`.
+    fn get_modified_files(&self, directory: &Path, base: &str) -> io::Result<Vec<String>>;
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -323,6 +333,57 @@ pub trait TraversalContext: Sync {
     fn evaluated_paths(&self) -> BTreeSet<PgTPath>;
 }
 
+/// Returns the files below `dir`, visiting only the files and directories `visit` accepts.
+///
+/// `visit` is called from the threads of the traversal. Symbolic links are followed at most
+/// once, so links pointing to their own parents don't loop.
+pub fn walk_files(
+    fs: &dyn FileSystem,
+    dir: &Path,
+    visit: &(dyn Fn(&Path) -> bool + Sync),
+) -> BTreeSet<PgTPath> {
+    let (interner, _) = PathInterner::new();
+    let ctx = WalkContext {
+        interner,
+        visit,
+        files: RwLock::default(),
+    };
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        scope.evaluate(&ctx, dir.to_path_buf());
+    }));
+    ctx.evaluated_paths()
+}
+
+struct WalkContext<'a> {
+    interner: PathInterner,
+    visit: &'a (dyn Fn(&Path) -> bool + Sync),
+    files: RwLock<BTreeSet<PgTPath>>,
+}
+
+impl TraversalContext for WalkContext<'_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // unreadable files are left out
+    }
+
+    fn can_handle(&self, path: &PgTPath) -> bool {
+        (self.visit)(path.as_path())
+    }
+
+    fn handle_path(&self, _path: PgTPath) {}
+
+    fn store_path(&self, path: PgTPath) {
+        self.files.write().unwrap().insert(path);
+    }
+
+    fn evaluated_paths(&self) -> BTreeSet<PgTPath> {
+        self.files.read().unwrap().clone()
+    }
+}
+
 impl<T> FileSystem for Arc<T>
 where
     T: FileSystem + Send,
@@ -363,6 +424,14 @@ where
         T::get_staged_files(self)
     }
 
+    fn get_added_files(&self, directory: &Path) -> io::Result<Vec<(String, u64)>> {
+        T::get_added_files(self, directory)
+    }
+
+    fn get_modified_files(&self, directory: &Path, base: &str) -> io::Result<Vec<String>> {
+        T::get_modified_files(self, directory, base)
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
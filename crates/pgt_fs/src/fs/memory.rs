@@ -30,6 +30,10 @@ pub struct MemoryFileSystem {
     allow_write: bool,
     on_get_staged_files: OnGetChangedFiles,
     on_get_changed_files: OnGetChangedFiles,
+    /// The result of [FileSystem::get_added_files], `None` if the files aren't in a repository
+    added_files: Option<Vec<(String, u64)>>,
+    /// The result of [FileSystem::get_modified_files], `None` if the files aren't in a repository
+    modified_files: Option<Vec<String>>,
 }
 
 impl Default for MemoryFileSystem {
@@ -44,6 +48,8 @@ impl Default for MemoryFileSystem {
             on_get_changed_files: Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(Box::new(
                 Vec::new,
             )))))),
+            added_files: None,
+            modified_files: None,
         }
     }
 }
@@ -121,6 +127,14 @@ impl MemoryFileSystem {
     ) {
         self.on_get_staged_files = Some(Arc::new(AssertUnwindSafe(Mutex::new(Some(cfn)))));
     }
+
+    pub fn set_added_files(&mut self, added_files: Vec<(String, u64)>) {
+        self.added_files = Some(added_files);
+    }
+
+    pub fn set_modified_files(&mut self, modified_files: Vec<String>) {
+        self.modified_files = Some(modified_files);
+    }
 }
 
 impl FileSystem for MemoryFileSystem {
@@ -229,6 +243,18 @@ impl FileSystem for MemoryFileSystem {
         Ok(cb())
     }
 
+    fn get_added_files(&self, _directory: &Path) -> io::Result<Vec<(String, u64)>> {
+        self.added_files
+            .clone()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "not a git repository"))
+    }
+
+    fn get_modified_files(&self, _directory: &Path, _base: &str) -> io::Result<Vec<String>> {
+        self.modified_files
+            .clone()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "not a git repository"))
+    }
+
     fn resolve_configuration(
         &self,
         _specifier: &str,
@@ -20,6 +20,28 @@ use std::{
 
 const MAX_SYMLINK_DEPTH: u8 = 3;
 
+/// Runs git in the directory, and returns its output. Fails if git fails, e.g. when the directory
+/// isn't in a repository.
+fn git(directory: &Path, args: &[&str]) -> io::Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(directory)
+        .args(["-c", "core.quotePath=false"])
+        .args(args)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    String::from_utf8(output.stdout)
+        .map_err(|error| io::Error::new(IoErrorKind::InvalidData, error))
+}
+
 /// Implementation of [FileSystem] that directly calls through to the underlying OS
 pub struct OsFileSystem {
     pub working_directory: Option<PathBuf>,
@@ -130,6 +152,57 @@ impl FileSystem for OsFileSystem {
             .collect())
     }
 
+    fn get_added_files(&self, directory: &Path) -> io::Result<Vec<(String, u64)>> {
+        let output = git(
+            directory,
+            &[
+                "log",
+                // A: added
+                "--diff-filter=A",
+                "--format=format:commit %ct",
+                "--name-only",
+                "--relative",
+                "--",
+                ".",
+            ],
+        )?;
+
+        let mut added = Vec::new();
+        let mut time = None;
+        for line in output.lines() {
+            if let Some(timestamp) = line.strip_prefix("commit ") {
+                time = timestamp.trim().parse::<u64>().ok();
+            } else if let (Some(time), false) = (time, line.is_empty()) {
+                added.push((line.to_string(), time));
+            }
+        }
+
+        Ok(added)
+    }
+
+    fn get_modified_files(&self, directory: &Path, base: &str) -> io::Result<Vec<String>> {
+        let merge_base = git(directory, &["merge-base", base, "HEAD"])?;
+        let output = git(
+            directory,
+            &[
+                "diff",
+                "--name-only",
+                "--relative",
+                // M: modified
+                "--diff-filter=M",
+                merge_base.trim(),
+                "--",
+                ".",
+            ],
+        )?;
+
+        Ok(output
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
     fn resolve_configuration(
         &self,
         specifier: &str,
@@ -219,7 +292,7 @@ impl<'scope> TraversalScope<'scope> for OsTraversalScope<'scope> {
 // TODO: remove in 2.0, and directly use `.gitignore`
 /// Default list of ignored directories, in the future will be supplanted by
 /// detecting and parsing .ignore files
-const DEFAULT_IGNORE: &[&[u8]] = &[b".git", b".svn", b".hg", b".yarn", b"node_modules"];
+pub(crate) const DEFAULT_IGNORE: &[&[u8]] = &[b".git", b".svn", b".hg", b".yarn", b"node_modules"];
 
 /// Traverse a single directory
 fn handle_dir<'scope>(
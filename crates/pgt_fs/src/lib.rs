@@ -2,14 +2,19 @@
 
 mod dir;
 mod fs;
+mod hash;
 mod interner;
 mod path;
+mod watch;
 
 pub use dir::ensure_cache_dir;
+pub use hash::Fnv128;
 pub use interner::PathInterner;
 pub use path::PgTPath;
+pub use watch::PollWatcher;
 
 pub use fs::{
     AutoSearchResult, ConfigName, ErrorEntry, File, FileSystem, FileSystemDiagnostic,
     FileSystemExt, MemoryFileSystem, OpenOptions, OsFileSystem, TraversalContext, TraversalScope,
+    walk_files,
 };
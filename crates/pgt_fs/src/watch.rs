@@ -0,0 +1,155 @@
+use crate::fs::DEFAULT_IGNORE;
+use rustc_hash::FxHashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// The state of a file the last time it was seen by the watcher
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// A watcher that detects changes by periodically scanning the file system.
+///
+/// Each call to [PollWatcher::poll] compares the modification time and size
+/// of every file under the watched roots against the previous scan, and
+/// returns the files that were created, modified or removed in between.
+///
+/// Symbolic links aren't followed, so links pointing to their own parents
+/// don't loop.
+pub struct PollWatcher {
+    roots: Vec<PathBuf>,
+    files: FxHashMap<PathBuf, FileStamp>,
+}
+
+impl PollWatcher {
+    /// Creates a watcher for the given files and directories, and takes the
+    /// initial snapshot.
+    ///
+    /// `filter` is called with each path and whether it's a directory. Only
+    /// the files and directories it returns `true` for are visited, so ignored
+    /// directories aren't scanned at all.
+    pub fn new(
+        roots: impl IntoIterator<Item = PathBuf>,
+        filter: &dyn Fn(&Path, bool) -> bool,
+    ) -> Self {
+        let mut watcher = Self {
+            roots: roots.into_iter().collect(),
+            files: FxHashMap::default(),
+        };
+        watcher.files = watcher.scan(filter);
+        watcher
+    }
+
+    /// Scans the roots again, and returns the paths that changed since the
+    /// previous scan, sorted. `filter` is the same as in [PollWatcher::new].
+    pub fn poll(&mut self, filter: &dyn Fn(&Path, bool) -> bool) -> Vec<PathBuf> {
+        let files = self.scan(filter);
+
+        let mut changed: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, stamp)| self.files.get(*path) != Some(*stamp))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.files
+                    .keys()
+                    .filter(|path| !files.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+
+        self.files = files;
+        changed
+    }
+
+    fn scan(&self, filter: &dyn Fn(&Path, bool) -> bool) -> FxHashMap<PathBuf, FileStamp> {
+        let mut files = FxHashMap::default();
+        for root in &self.roots {
+            scan_path(root, filter, &mut files);
+        }
+        files
+    }
+}
+
+fn scan_path(
+    path: &Path,
+    filter: &dyn Fn(&Path, bool) -> bool,
+    files: &mut FxHashMap<PathBuf, FileStamp>,
+) {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        if let Some(file_name) = path.file_name() {
+            if DEFAULT_IGNORE.contains(&file_name.as_encoded_bytes()) {
+                return;
+            }
+        }
+        if !filter(path, true) {
+            return;
+        }
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            scan_path(&entry.path(), filter, files);
+        }
+    } else if metadata.is_file() && filter(path, false) {
+        files.insert(
+            path.to_path_buf(),
+            FileStamp {
+                modified: metadata.modified().ok(),
+                len: metadata.len(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PollWatcher;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn reports_created_modified_and_removed_files() {
+        let root = std::env::temp_dir().join(format!("pgt_fs_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("node_modules")).unwrap();
+        fs::create_dir_all(root.join("ignored")).unwrap();
+        fs::write(root.join("a.sql"), "select 1;").unwrap();
+        fs::write(root.join("notes.txt"), "hello").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&root, root.join("loop")).unwrap();
+
+        let is_sql = |path: &Path, is_dir: bool| {
+            if is_dir {
+                !path.ends_with("ignored")
+            } else {
+                path.extension().is_some_and(|ext| ext == "sql")
+            }
+        };
+        let mut watcher = PollWatcher::new([root.clone()], &is_sql);
+        assert!(watcher.poll(&is_sql).is_empty());
+
+        fs::write(root.join("a.sql"), "select 1;\nselect 2;").unwrap();
+        fs::write(root.join("b.sql"), "select 3;").unwrap();
+        fs::write(root.join("node_modules/c.sql"), "select 4;").unwrap();
+        fs::write(root.join("ignored/d.sql"), "select 5;").unwrap();
+        fs::write(root.join("notes.txt"), "hello again").unwrap();
+        assert_eq!(
+            watcher.poll(&is_sql),
+            vec![root.join("a.sql"), root.join("b.sql")]
+        );
+        assert!(watcher.poll(&is_sql).is_empty());
+
+        fs::remove_file(root.join("b.sql")).unwrap();
+        assert_eq!(watcher.poll(&is_sql), vec![root.join("b.sql")]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
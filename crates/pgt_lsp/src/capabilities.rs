@@ -7,7 +7,7 @@ use tower_lsp::lsp_types::{
     TextDocumentSyncOptions, TextDocumentSyncSaveOptions, WorkDoneProgressOptions,
 };
 
-use crate::handlers::code_actions::command_id;
+use crate::handlers::{code_actions::command_id, completions::COMPLETION_ACCEPTED_COMMAND};
 
 /// The capabilities to send from server as part of [`InitializeResult`]
 ///
@@ -33,9 +33,9 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
             },
         )),
         completion_provider: Some(CompletionOptions {
-            // currently not supporting the completionItem/resolve request.
-            // The request is used to get more information about a simple CompletionItem.
-            resolve_provider: None,
+            // The completionItem/resolve request is used to lazily add the documentation
+            // of the database object to a CompletionItem.
+            resolve_provider: Some(true),
 
             trigger_characters: Some(vec![".".to_owned(), " ".to_owned(), "(".to_owned()]),
 
@@ -53,6 +53,7 @@ pub(crate) fn server_capabilities(capabilities: &ClientCapabilities) -> ServerCa
         execute_command_provider: Some(ExecuteCommandOptions {
             commands: CommandActionCategory::iter()
                 .map(|c| command_id(&c))
+                .chain(std::iter::once(COMPLETION_ACCEPTED_COMMAND.to_string()))
                 .collect::<Vec<String>>(),
 
             ..Default::default()
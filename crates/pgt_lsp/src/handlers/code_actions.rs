@@ -1,4 +1,8 @@
-use crate::{adapters::get_cursor_position, session::Session};
+use crate::{
+    adapters::get_cursor_position,
+    handlers::completions::{COMPLETION_ACCEPTED_COMMAND, record_accepted_completion},
+    session::Session,
+};
 use anyhow::{Result, anyhow};
 use tower_lsp::lsp_types::{
     self, CodeAction, CodeActionDisabled, CodeActionOrCommand, Command, ExecuteCommandParams,
@@ -106,6 +110,12 @@ pub async fn execute_command(
             Ok(None)
         }
 
+        COMPLETION_ACCEPTED_COMMAND => {
+            record_accepted_completion(session, params.arguments)?;
+
+            Ok(None)
+        }
+
         any => Err(anyhow!(format!("Unknown command: {}", any))),
     }
 }
@@ -4,11 +4,20 @@ use crate::{
     session::Session,
 };
 use anyhow::Result;
-use pgt_workspace::{WorkspaceError, features::completions::GetCompletionsParams};
+use pgt_workspace::{
+    WorkspaceError,
+    features::completions::{
+        GetCompletionsParams, RecordAcceptedCompletionParams, ResolveCompletionItemParams,
+    },
+};
 use tower_lsp::lsp_types::{
-    self, CompletionItem, CompletionItemLabelDetails, InsertTextFormat, TextEdit,
+    self, Command, CompletionItem, CompletionItemLabelDetails, Documentation, InsertTextFormat,
+    MarkupContent, MarkupKind, TextEdit,
 };
 
+/// Sent by the client once the user accepts a completion item, so that it ranks higher next time.
+pub const COMPLETION_ACCEPTED_COMMAND: &str = "pgt.completionAccepted";
+
 #[tracing::instrument(level = "debug", skip(session), err)]
 pub fn get_completions(
     session: &Session,
@@ -60,6 +69,14 @@ pub fn get_completions(
                 })
             }),
             kind: Some(to_lsp_types_completion_item_kind(i.kind)),
+            command: i.data.as_ref().and_then(|d| {
+                Some(Command {
+                    title: String::new(),
+                    command: COMPLETION_ACCEPTED_COMMAND.into(),
+                    arguments: Some(vec![serde_json::to_value(d).ok()?]),
+                })
+            }),
+            data: i.data.and_then(|d| serde_json::to_value(d).ok()),
             ..CompletionItem::default()
         })
         .collect();
@@ -67,6 +84,60 @@ pub fn get_completions(
     Ok(lsp_types::CompletionResponse::Array(items))
 }
 
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub fn resolve_completion_item(
+    session: &Session,
+    mut item: CompletionItem,
+) -> Result<CompletionItem, LspError> {
+    let Some(data) = item
+        .data
+        .clone()
+        .and_then(|d| serde_json::from_value(d).ok())
+    else {
+        return Ok(item);
+    };
+
+    let result = match session
+        .workspace
+        .resolve_completion_item(ResolveCompletionItemParams { data })
+    {
+        Ok(result) => result,
+        Err(e) => match e {
+            WorkspaceError::DatabaseConnectionError(_) => return Ok(item),
+            _ => return Err(e.into()),
+        },
+    };
+
+    item.documentation = result.documentation.map(|value| {
+        Documentation::MarkupContent(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        })
+    });
+
+    Ok(item)
+}
+
+#[tracing::instrument(level = "debug", skip(session), err)]
+pub fn record_accepted_completion(
+    session: &Session,
+    arguments: Vec<serde_json::Value>,
+) -> Result<()> {
+    let Some(data) = arguments
+        .into_iter()
+        .next()
+        .and_then(|d| serde_json::from_value(d).ok())
+    else {
+        return Ok(());
+    };
+
+    session
+        .workspace
+        .record_accepted_completion(RecordAcceptedCompletionParams { data })?;
+
+    Ok(())
+}
+
 fn to_lsp_types_completion_item_kind(
     pg_comp_kind: pgt_completions::CompletionItemKind,
 ) -> lsp_types::CompletionItemKind {
@@ -76,5 +147,9 @@ fn to_lsp_types_completion_item_kind(
         pgt_completions::CompletionItemKind::Column => lsp_types::CompletionItemKind::FIELD,
         pgt_completions::CompletionItemKind::Schema => lsp_types::CompletionItemKind::CLASS,
         pgt_completions::CompletionItemKind::Policy => lsp_types::CompletionItemKind::CONSTANT,
+        pgt_completions::CompletionItemKind::Role => lsp_types::CompletionItemKind::CONSTANT,
+        pgt_completions::CompletionItemKind::Privilege => lsp_types::CompletionItemKind::KEYWORD,
+        pgt_completions::CompletionItemKind::Setting => lsp_types::CompletionItemKind::PROPERTY,
+        pgt_completions::CompletionItemKind::Variable => lsp_types::CompletionItemKind::VARIABLE,
     }
 }
@@ -273,6 +273,14 @@ impl LanguageServer for LSPServer {
         }
     }
 
+    #[tracing::instrument(level = "trace", skip_all)]
+    async fn completion_resolve(&self, item: CompletionItem) -> LspResult<CompletionItem> {
+        match handlers::completions::resolve_completion_item(&self.session, item) {
+            Ok(result) => LspResult::Ok(result),
+            Err(e) => LspResult::Err(into_lsp_error(e)),
+        }
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     async fn code_action(&self, params: CodeActionParams) -> LspResult<Option<CodeActionResponse>> {
         match handlers::code_actions::get_actions(&self.session, params) {
@@ -439,13 +447,23 @@ impl ServerFactory {
         });
 
         workspace_method!(builder, is_path_ignored);
+        workspace_method!(builder, get_ignore_reason);
         workspace_method!(builder, update_settings);
         workspace_method!(builder, get_file_content);
         workspace_method!(builder, open_file);
         workspace_method!(builder, change_file);
         workspace_method!(builder, close_file);
         workspace_method!(builder, pull_diagnostics);
+        workspace_method!(builder, pull_schema_diagnostics);
+        workspace_method!(builder, pull_migration_diagnostics);
+        workspace_method!(builder, refresh_schema_cache);
+        workspace_method!(builder, get_schema_fingerprint);
+        workspace_method!(builder, get_database_info);
+        workspace_method!(builder, migrate_check);
+        workspace_method!(builder, diff_schema);
         workspace_method!(builder, get_completions);
+        workspace_method!(builder, resolve_completion_item);
+        workspace_method!(builder, record_accepted_completion);
         workspace_method!(builder, register_project_folder);
         workspace_method!(builder, unregister_project_folder);
 
@@ -13,7 +13,7 @@ mod codegen;
 pub mod diagnostics;
 
 pub use pg_query::protobuf;
-pub use pg_query::{Error, NodeEnum, Result};
+pub use pg_query::{Error, NodeEnum, Result, parse_plpgsql, scan};
 
 pub use codegen::{
     ChildrenIterator, Node, TokenProperty, get_location, get_node_properties, get_nodes,
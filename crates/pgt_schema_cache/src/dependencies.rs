@@ -0,0 +1,153 @@
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyKind {
+    View,
+    MaterializedView,
+    Policy,
+    ForeignKey,
+}
+
+impl From<&str> for DependencyKind {
+    fn from(value: &str) -> Self {
+        match value {
+            "view" => DependencyKind::View,
+            "materialized_view" => DependencyKind::MaterializedView,
+            "policy" => DependencyKind::Policy,
+            "foreign_key" => DependencyKind::ForeignKey,
+            _ => panic!("Invalid Dependency Kind {}", value),
+        }
+    }
+}
+
+impl From<String> for DependencyKind {
+    fn from(value: String) -> Self {
+        DependencyKind::from(value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DependencyQueried {
+    kind: String,
+    name: String,
+    schema_name: String,
+    table_name: String,
+    referenced_schema: String,
+    referenced_table: String,
+    referenced_column: Option<String>,
+}
+
+impl From<DependencyQueried> for Dependency {
+    fn from(value: DependencyQueried) -> Self {
+        Self {
+            kind: DependencyKind::from(value.kind),
+            name: value.name,
+            schema_name: value.schema_name,
+            table_name: value.table_name,
+            referenced_schema: value.referenced_schema,
+            referenced_table: value.referenced_table,
+            referenced_column: value.referenced_column,
+        }
+    }
+}
+
+/// An object that depends on a table or one of its columns, as recorded in `pg_depend`
+/// and `pg_constraint`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Dependency {
+    pub kind: DependencyKind,
+
+    /// The name of the view, policy or foreign key constraint.
+    pub name: String,
+
+    /// The table the dependent object belongs to. For views, this is the view itself.
+    pub schema_name: String,
+    pub table_name: String,
+
+    pub referenced_schema: String,
+    pub referenced_table: String,
+
+    /// The referenced column, if the dependency is on a single column rather than the whole table.
+    pub referenced_column: Option<String>,
+}
+
+impl SchemaCacheItem for Dependency {
+    type Item = Dependency;
+
+    async fn load(pool: &sqlx::PgPool) -> Result<Vec<Self::Item>, sqlx::Error> {
+        let dependencies = sqlx::query_file_as!(DependencyQueried, "src/queries/dependencies.sql")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(dependencies.into_iter().map(Dependency::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Executor, PgPool};
+
+    use crate::{SchemaCache, dependencies::DependencyKind};
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn loads_dependencies(test_db: PgPool) {
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                email text not null,
+                is_active bool not null default true
+            );
+
+            create table public.orders (
+                id serial primary key,
+                user_id int references public.users (id)
+            );
+
+            create view public.active_users as
+            select id, email from public.users where is_active;
+
+            alter table public.orders enable row level security;
+
+            create policy own_orders
+                on public.orders
+                for select
+                using (user_id = 1);
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let depends_on = |table: &str, column: &str| {
+            cache
+                .dependencies
+                .iter()
+                .filter(|d| {
+                    d.referenced_table == table && d.referenced_column.as_deref() == Some(column)
+                })
+                .map(|d| (d.kind.clone(), d.name.as_str()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            depends_on("users", "id"),
+            vec![
+                (DependencyKind::ForeignKey, "orders_user_id_fkey"),
+                (DependencyKind::View, "active_users"),
+            ]
+        );
+        assert_eq!(
+            depends_on("users", "is_active"),
+            vec![(DependencyKind::View, "active_users")]
+        );
+        assert_eq!(
+            depends_on("orders", "user_id"),
+            vec![(DependencyKind::Policy, "own_orders")]
+        );
+    }
+}
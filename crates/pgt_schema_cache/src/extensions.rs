@@ -0,0 +1,21 @@
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Extension {
+    pub name: String,
+
+    /// The schema the objects of the extension are installed in.
+    pub schema: String,
+
+    pub version: String,
+}
+
+impl SchemaCacheItem for Extension {
+    type Item = Extension;
+
+    async fn load(pool: &sqlx::PgPool) -> Result<Vec<Self::Item>, sqlx::Error> {
+        sqlx::query_file_as!(Extension, "src/queries/extensions.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
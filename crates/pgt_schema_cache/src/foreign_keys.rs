@@ -0,0 +1,69 @@
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ForeignKey {
+    pub schema_name: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// The referencing columns, in the order of the constraint.
+    pub columns: Vec<String>,
+
+    pub referenced_schema: String,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
+impl SchemaCacheItem for ForeignKey {
+    type Item = ForeignKey;
+
+    async fn load(pool: &sqlx::PgPool) -> Result<Vec<Self::Item>, sqlx::Error> {
+        sqlx::query_file_as!(ForeignKey, "src/queries/foreign_keys.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Executor, PgPool};
+
+    use crate::SchemaCache;
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn loads_foreign_keys(test_db: PgPool) {
+        let setup = r#"
+            create table public.tenants (
+                id int,
+                region text,
+                primary key (region, id)
+            );
+
+            create table public.users (
+                id serial primary key,
+                tenant_id int,
+                tenant_region text,
+                foreign key (tenant_region, tenant_id) references public.tenants (region, id)
+            );
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let foreign_key = cache
+            .foreign_keys
+            .iter()
+            .find(|f| f.table_name == "users")
+            .expect("Foreign key not found");
+
+        assert_eq!(foreign_key.columns, vec!["tenant_region", "tenant_id"]);
+        assert_eq!(foreign_key.referenced_table, "tenants");
+        assert_eq!(foreign_key.referenced_columns, vec!["region", "id"]);
+    }
+}
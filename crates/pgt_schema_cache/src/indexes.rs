@@ -0,0 +1,83 @@
+use crate::schema_cache::SchemaCacheItem;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Index {
+    pub schema_name: String,
+    pub table_name: String,
+    pub name: String,
+
+    /// The key columns of the index, in order. Expression columns contain the expression.
+    pub columns: Vec<String>,
+
+    pub is_unique: bool,
+    pub is_primary: bool,
+
+    /// The access method, e.g. `btree` or `gin`.
+    pub method: String,
+
+    /// The `WHERE` clause of a partial index.
+    pub predicate: Option<String>,
+}
+
+impl SchemaCacheItem for Index {
+    type Item = Index;
+
+    async fn load(pool: &sqlx::PgPool) -> Result<Vec<Self::Item>, sqlx::Error> {
+        sqlx::query_file_as!(Index, "src/queries/indexes.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::{Executor, PgPool};
+
+    use crate::SchemaCache;
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn loads_indexes(test_db: PgPool) {
+        let setup = r#"
+            create table public.users (
+                id serial primary key,
+                email text not null,
+                deleted_at timestamptz
+            );
+
+            create unique index users_email_idx on public.users (lower(email), id)
+                where deleted_at is null;
+        "#;
+
+        test_db
+            .execute(setup)
+            .await
+            .expect("Failed to setup test database");
+
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let pkey = cache
+            .indexes
+            .iter()
+            .find(|i| i.name == "users_pkey")
+            .expect("Primary key index not found");
+
+        assert_eq!(pkey.columns, vec!["id".to_string()]);
+        assert!(pkey.is_primary);
+
+        let email = cache
+            .indexes
+            .iter()
+            .find(|i| i.name == "users_email_idx")
+            .expect("Index not found");
+
+        assert_eq!(
+            email.columns,
+            vec!["lower(email)".to_string(), "id".to_string()]
+        );
+        assert!(email.is_unique);
+        assert_eq!(email.method, "btree");
+        assert_eq!(email.predicate.as_deref(), Some("deleted_at IS NULL"));
+    }
+}
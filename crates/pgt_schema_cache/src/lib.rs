@@ -3,22 +3,33 @@
 #![allow(dead_code)]
 
 mod columns;
+mod dependencies;
+mod extensions;
+mod foreign_keys;
 mod functions;
+mod indexes;
 mod policies;
 mod roles;
 mod schema_cache;
 mod schemas;
+mod settings;
 mod tables;
 mod triggers;
 mod types;
 mod versions;
 
 pub use columns::*;
+pub use dependencies::{Dependency, DependencyKind};
+pub use extensions::Extension;
+pub use foreign_keys::ForeignKey;
 pub use functions::{Behavior, Function, FunctionArg, FunctionArgs};
+pub use indexes::Index;
 pub use policies::{Policy, PolicyCommand};
 pub use roles::*;
 pub use schema_cache::SchemaCache;
 pub use schemas::Schema;
+pub use settings::Setting;
 pub use tables::{ReplicaIdentity, Table, TableKind};
-pub use triggers::{Trigger, TriggerAffected, TriggerEvent};
+pub use triggers::{Trigger, TriggerAffected, TriggerEvent, TriggerTiming};
 pub use types::{PostgresType, PostgresTypeAttribute};
+pub use versions::Version;
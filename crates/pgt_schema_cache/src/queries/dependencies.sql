@@ -0,0 +1,74 @@
+-- objects that stop working if a table, or one of its columns, is dropped.
+-- views depend on tables through their rewrite rules, policies through their
+-- expressions and foreign keys through the referenced columns.
+with
+  dependencies as (
+    select
+      case v.relkind
+        when 'm' then 'materialized_view'
+        else 'view'
+      end as kind,
+      v.relname as name,
+      v.oid as table_oid,
+      d.refobjid as referenced_oid,
+      d.refobjsubid :: int4 as referenced_attnum
+    from
+      pg_catalog.pg_depend d
+      join pg_catalog.pg_rewrite r on d.objid = r.oid
+      join pg_catalog.pg_class v on r.ev_class = v.oid
+    where
+      d.classid = 'pg_catalog.pg_rewrite' :: regclass
+      and d.refclassid = 'pg_catalog.pg_class' :: regclass
+      and d.deptype = 'n'
+      -- the rewrite rule of a view also depends on the view itself.
+      and d.refobjid <> r.ev_class
+    union
+    select
+      'policy' as kind,
+      p.polname as name,
+      p.polrelid as table_oid,
+      d.refobjid as referenced_oid,
+      d.refobjsubid :: int4 as referenced_attnum
+    from
+      pg_catalog.pg_depend d
+      join pg_catalog.pg_policy p on d.objid = p.oid
+    where
+      d.classid = 'pg_catalog.pg_policy' :: regclass
+      and d.refclassid = 'pg_catalog.pg_class' :: regclass
+      and d.deptype = 'n'
+    union
+    select
+      'foreign_key' as kind,
+      con.conname as name,
+      con.conrelid as table_oid,
+      con.confrelid as referenced_oid,
+      unnest(con.confkey) :: int4 as referenced_attnum
+    from
+      pg_catalog.pg_constraint con
+    where
+      con.contype = 'f'
+  )
+select
+  dep.kind as "kind!",
+  dep.name as "name!",
+  dn.nspname as "schema_name!",
+  dc.relname as "table_name!",
+  rn.nspname as "referenced_schema!",
+  rc.relname as "referenced_table!",
+  ra.attname as "referenced_column?"
+from
+  dependencies dep
+  join pg_catalog.pg_class dc on dep.table_oid = dc.oid
+  join pg_catalog.pg_namespace dn on dc.relnamespace = dn.oid
+  join pg_catalog.pg_class rc on dep.referenced_oid = rc.oid
+  join pg_catalog.pg_namespace rn on rc.relnamespace = rn.oid
+  left join pg_catalog.pg_attribute ra on ra.attrelid = dep.referenced_oid
+  and ra.attnum = dep.referenced_attnum
+  and dep.referenced_attnum > 0
+where
+  rn.nspname not in ('pg_catalog', 'information_schema')
+order by
+  rn.nspname,
+  rc.relname,
+  dep.kind,
+  dep.name;
\ No newline at end of file
@@ -0,0 +1,9 @@
+select
+  e.extname as "name!",
+  n.nspname as "schema!",
+  e.extversion as "version!"
+from
+  pg_catalog.pg_extension e
+  join pg_catalog.pg_namespace n on e.extnamespace = n.oid
+order by
+  e.extname;
\ No newline at end of file
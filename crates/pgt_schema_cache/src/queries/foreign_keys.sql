@@ -0,0 +1,39 @@
+select
+  n.nspname as "schema_name!",
+  c.relname as "table_name!",
+  con.conname as "name!",
+  array(
+    select
+      a.attname :: text
+    from
+      unnest(con.conkey) with ordinality as k(attnum, ord)
+      join pg_catalog.pg_attribute a on a.attrelid = con.conrelid
+      and a.attnum = k.attnum
+    order by
+      k.ord
+  ) as "columns!",
+  rn.nspname as "referenced_schema!",
+  rc.relname as "referenced_table!",
+  array(
+    select
+      a.attname :: text
+    from
+      unnest(con.confkey) with ordinality as k(attnum, ord)
+      join pg_catalog.pg_attribute a on a.attrelid = con.confrelid
+      and a.attnum = k.attnum
+    order by
+      k.ord
+  ) as "referenced_columns!"
+from
+  pg_catalog.pg_constraint con
+  join pg_catalog.pg_class c on con.conrelid = c.oid
+  join pg_catalog.pg_namespace n on c.relnamespace = n.oid
+  join pg_catalog.pg_class rc on con.confrelid = rc.oid
+  join pg_catalog.pg_namespace rn on rc.relnamespace = rn.oid
+where
+  con.contype = 'f'
+  and n.nspname not in ('pg_catalog', 'information_schema')
+order by
+  n.nspname,
+  c.relname,
+  con.conname;
\ No newline at end of file
@@ -0,0 +1,31 @@
+-- the key columns of an index are rendered by pg_get_indexdef, so expression
+-- columns show up as their expression.
+select
+  n.nspname as "schema_name!",
+  t.relname as "table_name!",
+  i.relname as "name!",
+  array(
+    select
+      pg_catalog.pg_get_indexdef(ix.indexrelid, k.n, true)
+    from
+      generate_series(1, ix.indnkeyatts :: int4) as k(n)
+    order by
+      k.n
+  ) as "columns!",
+  ix.indisunique as "is_unique!",
+  ix.indisprimary as "is_primary!",
+  am.amname as "method!",
+  pg_catalog.pg_get_expr(ix.indpred, ix.indrelid, true) as "predicate"
+from
+  pg_catalog.pg_index ix
+  join pg_catalog.pg_class i on ix.indexrelid = i.oid
+  join pg_catalog.pg_class t on ix.indrelid = t.oid
+  join pg_catalog.pg_namespace n on t.relnamespace = n.oid
+  join pg_catalog.pg_am am on i.relam = am.oid
+where
+  n.nspname not in ('pg_catalog', 'information_schema')
+  and n.nspname not like 'pg_toast%'
+order by
+  n.nspname,
+  t.relname,
+  i.relname;
\ No newline at end of file
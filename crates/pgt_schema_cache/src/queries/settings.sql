@@ -0,0 +1,13 @@
+select
+  name as "name!",
+  setting,
+  unit,
+  category as "category!",
+  short_desc as "short_description!",
+  vartype as "var_type!",
+  context as "context!",
+  enumvals as enum_values
+from
+  pg_catalog.pg_settings
+order by
+  name;
\ No newline at end of file
@@ -17,7 +17,8 @@ select
   ) as "size!",
   pg_stat_get_live_tuples(c.oid) as "live_rows_estimate!",
   pg_stat_get_dead_tuples(c.oid) as "dead_rows_estimate!",
-  obj_description(c.oid) as comment
+  obj_description(c.oid) as comment,
+  coalesce(c.reloptions, '{}') as "options!"
 from
   pg_namespace nc
   join pg_class c on nc.oid = c.relnamespace
@@ -5,7 +5,8 @@ select
   c.relname as "table_name!",
   p.proname as "proc_name!",
   n.nspname as "schema_name!",
-  t.tgtype as "details_bitmask!"
+  t.tgtype as "details_bitmask!",
+  pg_catalog.pg_get_triggerdef(t.oid) as "definition!"
 from 
   pg_catalog.pg_trigger t 
   left join pg_catalog.pg_proc p on t.tgfoid = p.oid
@@ -1,9 +1,15 @@
+use pgt_fs::Fnv128;
 use sqlx::postgres::PgPool;
 
 use crate::columns::Column;
+use crate::dependencies::Dependency;
+use crate::extensions::Extension;
+use crate::foreign_keys::ForeignKey;
 use crate::functions::Function;
+use crate::indexes::Index;
 use crate::policies::Policy;
 use crate::schemas::Schema;
+use crate::settings::Setting;
 use crate::tables::Table;
 use crate::types::PostgresType;
 use crate::versions::Version;
@@ -20,11 +26,31 @@ pub struct SchemaCache {
     pub policies: Vec<Policy>,
     pub triggers: Vec<Trigger>,
     pub roles: Vec<Role>,
+    pub settings: Vec<Setting>,
+    pub dependencies: Vec<Dependency>,
+    pub indexes: Vec<Index>,
+    pub foreign_keys: Vec<ForeignKey>,
+    pub extensions: Vec<Extension>,
 }
 
 impl SchemaCache {
     pub async fn load(pool: &PgPool) -> Result<SchemaCache, sqlx::Error> {
-        let (schemas, tables, functions, types, versions, columns, policies, triggers, roles) = futures_util::try_join!(
+        let (
+            schemas,
+            tables,
+            functions,
+            types,
+            versions,
+            columns,
+            policies,
+            triggers,
+            roles,
+            settings,
+            dependencies,
+            indexes,
+            foreign_keys,
+            extensions,
+        ) = futures_util::try_join!(
             Schema::load(pool),
             Table::load(pool),
             Function::load(pool),
@@ -33,7 +59,12 @@ impl SchemaCache {
             Column::load(pool),
             Policy::load(pool),
             Trigger::load(pool),
-            Role::load(pool)
+            Role::load(pool),
+            Setting::load(pool),
+            Dependency::load(pool),
+            Index::load(pool),
+            ForeignKey::load(pool),
+            Extension::load(pool)
         )?;
 
         Ok(SchemaCache {
@@ -46,6 +77,11 @@ impl SchemaCache {
             policies,
             triggers,
             roles,
+            settings,
+            dependencies,
+            indexes,
+            foreign_keys,
+            extensions,
         })
     }
 
@@ -77,6 +113,152 @@ impl SchemaCache {
         })
     }
 
+    /// Returns a hash of the schema, e.g. to invalidate results computed against it.
+    ///
+    /// Only the definitions of the objects are hashed. Values that change without any change to
+    /// the schema, like the size and row estimates of tables, the number of active connections
+    /// or the values of settings, are left out.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = Fnv128::default();
+        write_all(&mut hasher, &self.schemas, |h, schema| {
+            schema.id.write_to(h);
+            schema.name.write_to(h);
+            schema.owner.write_to(h);
+        });
+        write_all(&mut hasher, &self.tables, |h, table| {
+            table.id.write_to(h);
+            table.schema.write_to(h);
+            table.name.write_to(h);
+            table.rls_enabled.write_to(h);
+            table.rls_forced.write_to(h);
+            format!("{:?}", table.replica_identity).write_to(h);
+            format!("{:?}", table.table_kind).write_to(h);
+            table.comment.write_to(h);
+            table.options.write_to(h);
+        });
+        write_all(&mut hasher, &self.functions, |h, function| {
+            function.id.write_to(h);
+            function.schema.write_to(h);
+            function.name.write_to(h);
+            function.language.write_to(h);
+            function.body.write_to(h);
+            function.definition.write_to(h);
+            for arg in &function.args.args {
+                arg.mode.write_to(h);
+                arg.name.write_to(h);
+                arg.type_id.write_to(h);
+                arg.has_default.write_to(h);
+            }
+            function.argument_types.write_to(h);
+            function.identity_argument_types.write_to(h);
+            function.return_type_id.write_to(h);
+            function.return_type.write_to(h);
+            function.return_type_relation_id.write_to(h);
+            function.is_set_returning_function.write_to(h);
+            format!("{:?}", function.behavior).write_to(h);
+            function.security_definer.write_to(h);
+        });
+        write_all(&mut hasher, &self.types, |h, postgres_type| {
+            postgres_type.id.write_to(h);
+            postgres_type.name.write_to(h);
+            postgres_type.schema.write_to(h);
+            postgres_type.format.write_to(h);
+            postgres_type.enums.values.write_to(h);
+            for attribute in &postgres_type.attributes.attrs {
+                attribute.name.write_to(h);
+                attribute.type_id.write_to(h);
+            }
+            postgres_type.comment.write_to(h);
+        });
+        write_all(&mut hasher, &self.versions, |h, version| {
+            version.version.write_to(h);
+            version.version_num.write_to(h);
+            version.max_connections.write_to(h);
+        });
+        write_all(&mut hasher, &self.columns, |h, column| {
+            column.name.write_to(h);
+            column.table_name.write_to(h);
+            column.table_oid.write_to(h);
+            format!("{:?}", column.class_kind).write_to(h);
+            column.schema_name.write_to(h);
+            column.type_id.write_to(h);
+            column.type_name.write_to(h);
+            column.is_nullable.write_to(h);
+            column.is_primary_key.write_to(h);
+            column.is_unique.write_to(h);
+            column.default_expr.write_to(h);
+            column.varchar_length.map(i64::from).write_to(h);
+            column.comment.write_to(h);
+        });
+        write_all(&mut hasher, &self.policies, |h, policy| {
+            policy.name.write_to(h);
+            policy.table_name.write_to(h);
+            policy.schema_name.write_to(h);
+            policy.is_permissive.write_to(h);
+            format!("{:?}", policy.command).write_to(h);
+            policy.role_names.write_to(h);
+            policy.security_qualification.write_to(h);
+            policy.with_check.write_to(h);
+        });
+        write_all(&mut hasher, &self.triggers, |h, trigger| {
+            trigger.name.write_to(h);
+            trigger.table_name.write_to(h);
+            trigger.schema_name.write_to(h);
+            trigger.proc_name.write_to(h);
+            format!("{:?}", trigger.affected).write_to(h);
+            format!("{:?}", trigger.timing).write_to(h);
+            format!("{:?}", trigger.events).write_to(h);
+            trigger.definition.write_to(h);
+        });
+        write_all(&mut hasher, &self.roles, |h, role| {
+            role.name.write_to(h);
+            role.is_super_user.write_to(h);
+            role.can_create_db.write_to(h);
+            role.can_login.write_to(h);
+            role.can_bypass_rls.write_to(h);
+        });
+        // only which settings exist, their values may change at any time
+        write_all(&mut hasher, &self.settings, |h, setting| {
+            setting.name.write_to(h);
+            setting.var_type.write_to(h);
+            setting.enum_values.write_to(h);
+        });
+        write_all(&mut hasher, &self.dependencies, |h, dependency| {
+            format!("{:?}", dependency.kind).write_to(h);
+            dependency.name.write_to(h);
+            dependency.schema_name.write_to(h);
+            dependency.table_name.write_to(h);
+            dependency.referenced_schema.write_to(h);
+            dependency.referenced_table.write_to(h);
+            dependency.referenced_column.write_to(h);
+        });
+        write_all(&mut hasher, &self.indexes, |h, index| {
+            index.schema_name.write_to(h);
+            index.table_name.write_to(h);
+            index.name.write_to(h);
+            index.columns.write_to(h);
+            index.is_unique.write_to(h);
+            index.is_primary.write_to(h);
+            index.method.write_to(h);
+            index.predicate.write_to(h);
+        });
+        write_all(&mut hasher, &self.foreign_keys, |h, foreign_key| {
+            foreign_key.schema_name.write_to(h);
+            foreign_key.table_name.write_to(h);
+            foreign_key.name.write_to(h);
+            foreign_key.columns.write_to(h);
+            foreign_key.referenced_schema.write_to(h);
+            foreign_key.referenced_table.write_to(h);
+            foreign_key.referenced_columns.write_to(h);
+        });
+        write_all(&mut hasher, &self.extensions, |h, extension| {
+            extension.name.write_to(h);
+            extension.schema.write_to(h);
+            extension.version.write_to(h);
+        });
+        format!("{:032x}", hasher.finish())
+    }
+
     pub fn find_types(&self, name: &str, schema: Option<&str>) -> Vec<&PostgresType> {
         self.types
             .iter()
@@ -85,6 +267,69 @@ impl SchemaCache {
     }
 }
 
+/// Hashes the items of a list of the cache. The rows are loaded in no particular order, so the
+/// hashes of the items are sorted before they are combined.
+fn write_all<T>(hasher: &mut Fnv128, items: &[T], write: impl Fn(&mut Fnv128, &T)) {
+    let mut hashes: Vec<u128> = items
+        .iter()
+        .map(|item| {
+            let mut item_hasher = Fnv128::default();
+            write(&mut item_hasher, item);
+            item_hasher.finish()
+        })
+        .collect();
+    hashes.sort_unstable();
+
+    hasher.write(&(hashes.len() as u64).to_le_bytes());
+    for hash in hashes {
+        hasher.write(&hash.to_le_bytes());
+    }
+}
+
+/// A field that is part of the [SchemaCache::fingerprint]
+trait FingerprintField {
+    fn write_to(&self, hasher: &mut Fnv128);
+}
+
+impl FingerprintField for String {
+    fn write_to(&self, hasher: &mut Fnv128) {
+        hasher.write(self.as_bytes());
+    }
+}
+
+impl FingerprintField for i64 {
+    fn write_to(&self, hasher: &mut Fnv128) {
+        hasher.write(&self.to_le_bytes());
+    }
+}
+
+impl FingerprintField for bool {
+    fn write_to(&self, hasher: &mut Fnv128) {
+        hasher.write(&[u8::from(*self)]);
+    }
+}
+
+impl<T: FingerprintField> FingerprintField for Option<T> {
+    fn write_to(&self, hasher: &mut Fnv128) {
+        match self {
+            Some(value) => {
+                hasher.write(&[1]);
+                value.write_to(hasher);
+            }
+            None => hasher.write(&[0]),
+        }
+    }
+}
+
+impl<T: FingerprintField> FingerprintField for Vec<T> {
+    fn write_to(&self, hasher: &mut Fnv128) {
+        hasher.write(&(self.len() as u64).to_le_bytes());
+        for value in self {
+            value.write_to(hasher);
+        }
+    }
+}
+
 pub trait SchemaCacheItem {
     type Item;
 
@@ -95,7 +340,7 @@ pub trait SchemaCacheItem {
 mod tests {
     use sqlx::PgPool;
 
-    use crate::SchemaCache;
+    use crate::{SchemaCache, Setting, Table};
 
     #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
     async fn it_loads(test_db: PgPool) {
@@ -103,4 +348,53 @@ mod tests {
             .await
             .expect("Couldnt' load Schema Cache");
     }
+
+    fn table(name: &str, bytes: i64) -> Table {
+        Table {
+            schema: "public".to_string(),
+            name: name.to_string(),
+            bytes,
+            size: format!("{bytes} bytes"),
+            live_rows_estimate: bytes / 100,
+            ..Table::default()
+        }
+    }
+
+    fn setting(value: &str) -> Setting {
+        Setting {
+            name: "lock_timeout".to_string(),
+            setting: Some(value.to_string()),
+            unit: Some("ms".to_string()),
+            category: "Client Connection Defaults / Statement Behavior".to_string(),
+            short_description: "Sets the maximum allowed duration of any wait for a lock."
+                .to_string(),
+            var_type: "integer".to_string(),
+            context: "user".to_string(),
+            enum_values: None,
+        }
+    }
+
+    #[test]
+    fn fingerprint_only_depends_on_definitions() {
+        let cache = SchemaCache {
+            tables: vec![table("users", 8192), table("posts", 0)],
+            settings: vec![setting("0")],
+            ..SchemaCache::default()
+        };
+        let fingerprint = cache.fingerprint();
+
+        let grown = SchemaCache {
+            tables: vec![table("posts", 16384), table("users", 8192)],
+            settings: vec![setting("1000")],
+            ..SchemaCache::default()
+        };
+        assert_eq!(fingerprint, grown.fingerprint());
+
+        let altered = SchemaCache {
+            tables: vec![table("users", 8192), table("comments", 0)],
+            settings: vec![setting("0")],
+            ..SchemaCache::default()
+        };
+        assert_ne!(fingerprint, altered.fingerprint());
+    }
 }
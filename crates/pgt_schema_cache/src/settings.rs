@@ -0,0 +1,70 @@
+use crate::schema_cache::SchemaCacheItem;
+
+/// A configuration parameter (GUC), as listed in `pg_settings`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Setting {
+    pub name: String,
+
+    /// The current value of the parameter.
+    pub setting: Option<String>,
+
+    /// The implicit unit of the parameter, e.g. `ms` or `8kB`.
+    pub unit: Option<String>,
+
+    /// Logical group of the parameter, e.g. `Client Connection Defaults / Statement Behavior`.
+    pub category: String,
+
+    pub short_description: String,
+
+    /// `bool`, `enum`, `integer`, `real` or `string`.
+    pub var_type: String,
+
+    /// The context required to set the parameter's value, e.g. `user` or `postmaster`.
+    pub context: String,
+
+    /// Allowed values if `var_type` is `enum`.
+    pub enum_values: Option<Vec<String>>,
+}
+
+impl SchemaCacheItem for Setting {
+    type Item = Setting;
+
+    async fn load(pool: &sqlx::PgPool) -> Result<Vec<Self::Item>, sqlx::Error> {
+        sqlx::query_file_as!(Setting, "src/queries/settings.sql")
+            .fetch_all(pool)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::PgPool;
+
+    use crate::SchemaCache;
+
+    #[sqlx::test(migrator = "pgt_test_utils::MIGRATIONS")]
+    async fn loads_settings(test_db: PgPool) {
+        let cache = SchemaCache::load(&test_db)
+            .await
+            .expect("Failed to load Schema Cache");
+
+        let statement_timeout = cache
+            .settings
+            .iter()
+            .find(|s| s.name == "statement_timeout")
+            .expect("statement_timeout not found");
+
+        assert_eq!(statement_timeout.unit.as_deref(), Some("ms"));
+        assert_eq!(statement_timeout.var_type, "integer");
+        assert_eq!(statement_timeout.context, "user");
+
+        let search_path = cache
+            .settings
+            .iter()
+            .find(|s| s.name == "search_path")
+            .expect("search_path not found");
+
+        assert!(search_path.setting.is_some());
+        assert!(search_path.unit.is_none());
+    }
+}
@@ -65,6 +65,9 @@ pub struct Table {
     pub live_rows_estimate: i64,
     pub dead_rows_estimate: i64,
     pub comment: Option<String>,
+
+    /// The storage parameters of the table, e.g. `security_invoker=true` for a view.
+    pub options: Vec<String>,
 }
 
 impl SchemaCacheItem for Table {
@@ -85,17 +85,20 @@ pub struct TriggerQueried {
     schema_name: String,
     proc_name: String,
     details_bitmask: i16,
+    definition: String,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Trigger {
-    name: String,
-    table_name: String,
-    schema_name: String,
-    proc_name: String,
-    affected: TriggerAffected,
-    timing: TriggerTiming,
-    events: Vec<TriggerEvent>,
+    pub name: String,
+    pub table_name: String,
+    pub schema_name: String,
+    pub proc_name: String,
+    pub affected: TriggerAffected,
+    pub timing: TriggerTiming,
+    pub events: Vec<TriggerEvent>,
+    /// The `create trigger` statement, as returned by `pg_get_triggerdef`
+    pub definition: String,
 }
 
 impl From<TriggerQueried> for Trigger {
@@ -108,6 +111,7 @@ impl From<TriggerQueried> for Trigger {
             affected: value.details_bitmask.into(),
             timing: value.details_bitmask.try_into().unwrap(),
             events: TriggerEvents::from(value.details_bitmask).0,
+            definition: value.definition,
         }
     }
 }
@@ -192,6 +196,10 @@ mod tests {
         assert_eq!(insert_trigger.affected, TriggerAffected::Row);
         assert!(insert_trigger.events.contains(&TriggerEvent::Insert));
         assert_eq!(insert_trigger.proc_name, "log_user_insert");
+        assert_eq!(
+            insert_trigger.definition,
+            "CREATE TRIGGER trg_users_insert BEFORE INSERT ON public.users FOR EACH ROW EXECUTE FUNCTION log_user_insert()"
+        );
 
         let update_trigger = triggers
             .iter()
@@ -26,6 +26,8 @@ pub struct LoadedConfiguration {
     pub directory_path: Option<PathBuf>,
     /// If present, the path of the file where it was found
     pub file_path: Option<PathBuf>,
+    /// The paths of the configuration files in `extends`, in the order they are applied
+    pub extended_files: Vec<PathBuf>,
     /// The Deserialized configuration
     pub configuration: PartialConfiguration,
 }
@@ -45,7 +47,7 @@ impl LoadedConfiguration {
             deserialized: mut partial_configuration,
         } = value;
 
-        partial_configuration.apply_extends(
+        let extended_files = partial_configuration.apply_extends(
             fs,
             &configuration_file_path,
             &external_resolution_base_path,
@@ -53,6 +55,7 @@ impl LoadedConfiguration {
 
         Ok(Self {
             configuration: partial_configuration,
+            extended_files,
             directory_path: configuration_file_path.parent().map(PathBuf::from),
             file_path: Some(configuration_file_path),
         })
@@ -281,14 +284,14 @@ pub trait PartialConfigurationExt {
         fs: &DynRef<'_, dyn FileSystem>,
         file_path: &Path,
         external_resolution_base_path: &Path,
-    ) -> Result<(), WorkspaceError>;
+    ) -> Result<Vec<PathBuf>, WorkspaceError>;
 
     fn deserialize_extends(
         &mut self,
         fs: &DynRef<'_, dyn FileSystem>,
         relative_resolution_base_path: &Path,
         external_resolution_base_path: &Path,
-    ) -> Result<Vec<PartialConfiguration>, WorkspaceError>;
+    ) -> Result<Vec<(PathBuf, PartialConfiguration)>, WorkspaceError>;
 
     fn retrieve_gitignore_matches(
         &self,
@@ -304,17 +307,21 @@ impl PartialConfigurationExt for PartialConfiguration {
     /// The `extends` configs are applied from left to right.
     ///
     /// If a configuration can't be resolved from the file system, the operation will fail.
+    /// Returns the paths of the applied configuration files.
     fn apply_extends(
         &mut self,
         fs: &DynRef<'_, dyn FileSystem>,
         file_path: &Path,
         external_resolution_base_path: &Path,
-    ) -> Result<(), WorkspaceError> {
-        let configurations = self.deserialize_extends(
-            fs,
-            file_path.parent().expect("file path should have a parent"),
-            external_resolution_base_path,
-        )?;
+    ) -> Result<Vec<PathBuf>, WorkspaceError> {
+        let (paths, configurations): (Vec<_>, Vec<_>) = self
+            .deserialize_extends(
+                fs,
+                file_path.parent().expect("file path should have a parent"),
+                external_resolution_base_path,
+            )?
+            .into_iter()
+            .unzip();
 
         let extended_configuration = configurations.into_iter().reduce(
             |mut previous_configuration, current_configuration| {
@@ -328,7 +335,7 @@ impl PartialConfigurationExt for PartialConfiguration {
             self.merge_with(extended_configuration)
         }
 
-        Ok(())
+        Ok(paths)
     }
 
     /// It attempts to deserialize all the configuration files that were specified in the `extends` property
@@ -337,7 +344,7 @@ impl PartialConfigurationExt for PartialConfiguration {
         fs: &DynRef<'_, dyn FileSystem>,
         relative_resolution_base_path: &Path,
         external_resolution_base_path: &Path,
-    ) -> Result<Vec<PartialConfiguration>, WorkspaceError> {
+    ) -> Result<Vec<(PathBuf, PartialConfiguration)>, WorkspaceError> {
         let Some(extends) = &self.extends else {
             return Ok(Vec::new());
         };
@@ -397,7 +404,7 @@ impl PartialConfigurationExt for PartialConfiguration {
 
             let deserialized = serde_json::from_str::<PartialConfiguration>(&content)
                 .map_err(ConfigurationDiagnostic::new_deserialization_error)?;
-            deserialized_configurations.push(deserialized)
+            deserialized_configurations.push((extend_configuration_file_path, deserialized))
         }
         Ok(deserialized_configurations)
     }
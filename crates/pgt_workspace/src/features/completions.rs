@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use pgt_completions::CompletionItem;
+use pgt_completions::{CompletionItem, CompletionItemData};
 use pgt_fs::PgTPath;
 use pgt_text_size::{TextRange, TextSize};
 
@@ -21,6 +21,27 @@ pub struct CompletionsResult {
     pub(crate) items: Vec<CompletionItem>,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolveCompletionItemParams {
+    /// The data of the `CompletionItem` that should be resolved.
+    pub data: CompletionItemData,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolveCompletionItemResult {
+    /// Markdown documentation of the item, if available.
+    pub documentation: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RecordAcceptedCompletionParams {
+    /// The data of the `CompletionItem` the user accepted.
+    pub data: CompletionItemData,
+}
+
 impl IntoIterator for CompletionsResult {
     type Item = CompletionItem;
     type IntoIter = <Vec<CompletionItem> as IntoIterator>::IntoIter;
@@ -12,6 +12,15 @@ pub struct PullDiagnosticsParams {
     pub skip: Vec<RuleSelector>,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullSchemaDiagnosticsParams {
+    pub categories: RuleCategories,
+    pub max_diagnostics: u64,
+    pub only: Vec<RuleSelector>,
+    pub skip: Vec<RuleSelector>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct PullDiagnosticsResult {
@@ -19,3 +28,25 @@ pub struct PullDiagnosticsResult {
     pub errors: usize,
     pub skipped_diagnostics: u64,
 }
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullSchemaDiagnosticsResult {
+    pub diagnostics: Vec<pgt_diagnostics::serde::Diagnostic>,
+    pub errors: usize,
+    pub skipped_diagnostics: u64,
+    /// Whether the schema rules ran. They are skipped without a database connection.
+    pub checked: bool,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullMigrationDiagnosticsParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PullMigrationDiagnosticsResult {
+    pub diagnostics: Vec<pgt_diagnostics::serde::Diagnostic>,
+    /// Whether the migrations were checked. They are skipped without a migrations directory.
+    pub checked: bool,
+}
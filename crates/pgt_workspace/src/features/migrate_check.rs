@@ -0,0 +1,57 @@
+use std::{path::PathBuf, time::Duration};
+
+use pgt_text_size::TextRange;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MigrateCheckParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MigrateCheckResult {
+    /// The name of the scratch database the migrations were applied to. It's dropped once the
+    /// migrations are checked.
+    pub database: String,
+    /// The migrations, in the order they were applied. Migrations after a failing one are not
+    /// applied, and not listed.
+    pub migrations: Vec<MigrationReport>,
+    /// The errors raised while applying the migrations
+    pub diagnostics: Vec<pgt_diagnostics::serde::Diagnostic>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MigrationReport {
+    pub path: PathBuf,
+    /// The sequence number of the migration, e.g. `20240101120000` or `1.1`
+    pub sequence_number: String,
+    pub name: String,
+    /// Whether all statements of the migration were applied, and its transaction committed.
+    /// Migrations with statements that can't run in a transaction run in autocommit mode.
+    pub applied: bool,
+    /// The statements that were executed, including the failing one
+    pub statements: Vec<StatementReport>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct StatementReport {
+    /// The range of the statement in the migration file
+    pub range: TextRange,
+    pub duration: Duration,
+    /// The locks the statement acquired. Locks are held until the end of the transaction of the
+    /// migration, so each lock is only reported for the first statement that acquired it. The
+    /// locks of statements that can't run in a transaction are not reported.
+    pub locks: Vec<AcquiredLock>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AcquiredLock {
+    /// The kind of the locked object, e.g. `relation` or `object`
+    pub locktype: String,
+    /// The lock mode, e.g. `AccessExclusiveLock`
+    pub mode: String,
+    /// The locked relation, if the lock is on a relation
+    pub relation: Option<String>,
+}
@@ -1,3 +1,5 @@
 pub mod code_actions;
 pub mod completions;
 pub mod diagnostics;
+pub mod migrate_check;
+pub mod schema_diff;
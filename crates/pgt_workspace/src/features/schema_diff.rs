@@ -0,0 +1,184 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DiffSchemaParams {
+    /// The schema that is compared
+    pub from: SchemaSource,
+    /// The schema it's compared with
+    pub to: SchemaSource,
+    /// The Postgres schemas to compare, e.g. `public`. All schemas but the system ones are
+    /// compared if it's empty.
+    pub schemas: Vec<String>,
+    /// Whether the result includes the snapshot of `to`, e.g. to write it to a file
+    pub include_snapshot: bool,
+}
+
+/// Where the schema of a comparison comes from
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaSource {
+    /// The schema reconstructed by applying the migrations to a scratch database
+    Migrations,
+    /// The configured database
+    Database,
+    /// Another database, by its connection string
+    Url(String),
+    /// A snapshot of a schema taken earlier
+    Snapshot(SchemaSnapshot),
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DiffSchemaResult {
+    /// The objects that differ, ordered by kind and name
+    pub changes: Vec<SchemaChange>,
+    /// The snapshot of `to`, if it was requested
+    pub snapshot: Option<SchemaSnapshot>,
+    /// The errors raised while applying the migrations. The schemas are not compared if a
+    /// migration fails.
+    pub diagnostics: Vec<pgt_diagnostics::serde::Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SchemaChange {
+    pub kind: SchemaChangeKind,
+    pub object: SchemaObjectKind,
+    /// The qualified name of the object, e.g. `public.users.email` for a column
+    pub name: String,
+    /// What differs between the two versions of a changed object, e.g. `type: integer → bigint`
+    pub details: Vec<String>,
+    /// The statements that turn `from` into `to` for this object. Objects that can't be
+    /// recreated from the schema cache, such as views, get a comment instead.
+    pub ddl: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaChangeKind {
+    /// The object is only in `from`
+    Missing,
+    /// The object is only in `to`
+    Extra,
+    /// The object is in both, but differs
+    Changed,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaObjectKind {
+    Type,
+    Table,
+    Column,
+    Function,
+    Policy,
+    Trigger,
+}
+
+impl std::fmt::Display for SchemaObjectKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SchemaObjectKind::Type => "type",
+            SchemaObjectKind::Table => "table",
+            SchemaObjectKind::Column => "column",
+            SchemaObjectKind::Function => "function",
+            SchemaObjectKind::Policy => "policy",
+            SchemaObjectKind::Trigger => "trigger",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The objects of a schema that are compared, without the details of the database they were
+/// loaded from, such as object ids
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SchemaSnapshot {
+    pub types: Vec<TypeSnapshot>,
+    pub tables: Vec<TableSnapshot>,
+    pub columns: Vec<ColumnSnapshot>,
+    pub functions: Vec<FunctionSnapshot>,
+    pub policies: Vec<PolicySnapshot>,
+    pub triggers: Vec<TriggerSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TypeSnapshot {
+    pub schema: String,
+    pub name: String,
+    /// The labels of an enum, in order
+    pub enum_values: Vec<String>,
+    /// The attributes of a composite type
+    pub attributes: Vec<TypeAttributeSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TypeAttributeSnapshot {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TableSnapshot {
+    pub schema: String,
+    pub name: String,
+    /// `table`, `partitioned table`, `view` or `materialized view`
+    pub kind: String,
+    pub rls_enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ColumnSnapshot {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    /// The type as it's written in DDL, e.g. `character varying(255)`
+    pub data_type: String,
+    pub nullable: bool,
+    /// Whether the column is part of the primary key of the table
+    pub primary_key: bool,
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FunctionSnapshot {
+    pub schema: String,
+    pub name: String,
+    /// The argument types that identify the function among its overloads, e.g. `integer, text`
+    pub identity_arguments: String,
+    /// The `create or replace function` statement. Not set for internal functions.
+    pub definition: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PolicySnapshot {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    pub permissive: bool,
+    /// `select`, `insert`, `update`, `delete` or `all`
+    pub command: String,
+    pub roles: Vec<String>,
+    pub using: Option<String>,
+    pub with_check: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TriggerSnapshot {
+    pub schema: String,
+    pub table: String,
+    pub name: String,
+    /// The `create trigger` statement, as returned by `pg_get_triggerdef`
+    pub definition: String,
+}
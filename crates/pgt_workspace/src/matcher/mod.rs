@@ -18,6 +18,18 @@ pub struct Matcher {
     already_checked: RwLock<HashMap<String, bool>>,
 }
 
+impl Clone for Matcher {
+    fn clone(&self) -> Self {
+        // the cache of checked strings isn't shared
+        Self {
+            root: self.root.clone(),
+            patterns: self.patterns.clone(),
+            options: self.options,
+            already_checked: RwLock::default(),
+        }
+    }
+}
+
 impl Matcher {
     /// Creates a new Matcher with given options.
     ///
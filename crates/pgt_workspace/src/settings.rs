@@ -13,17 +13,19 @@ use tracing::trace;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use pgt_configuration::{
     ConfigurationDiagnostic, LinterConfiguration, PartialConfiguration,
+    completions::PartialCompletionsConfiguration,
     database::PartialDatabaseConfiguration,
     diagnostics::InvalidIgnorePattern,
     files::FilesConfiguration,
     migrations::{MigrationsConfiguration, PartialMigrationsConfiguration},
+    vcs::PartialVcsConfiguration,
 };
 use pgt_fs::PgTPath;
 
 use crate::{
     WorkspaceError,
     matcher::Matcher,
-    workspace::{ProjectKey, WorkspaceData},
+    workspace::{IgnoreReason, MigrationPattern, ProjectKey, WorkspaceData},
 };
 
 #[derive(Debug, Default)]
@@ -212,6 +214,12 @@ pub struct Settings {
 
     /// Migrations settings
     pub migrations: Option<MigrationSettings>,
+
+    /// Completions settings
+    pub completions: CompletionsSettings,
+
+    /// VCS settings
+    pub vcs: VcsSettings,
 }
 
 #[derive(Debug)]
@@ -284,12 +292,22 @@ impl Settings {
                 to_linter_settings(working_directory.clone(), LinterConfiguration::from(linter))?;
         }
 
+        // completions settings
+        if let Some(completions) = configuration.completions {
+            self.completions = completions.into()
+        }
+
         // Migrations settings
         if let Some(migrations) = configuration.migrations {
             self.migrations = to_migration_settings(
                 working_directory.clone(),
                 MigrationsConfiguration::from(migrations),
-            );
+            )?;
+        }
+
+        // VCS settings
+        if let Some(vcs) = configuration.vcs {
+            self.vcs = vcs.into();
         }
 
         Ok(())
@@ -442,6 +460,31 @@ impl Default for LinterSettings {
     }
 }
 
+/// Completions settings for the entire workspace
+#[derive(Debug)]
+pub struct CompletionsSettings {
+    /// Whether usage statistics of the workspace are used to rank completions.
+    pub learn_from_workspace: bool,
+}
+
+impl Default for CompletionsSettings {
+    fn default() -> Self {
+        Self {
+            learn_from_workspace: true,
+        }
+    }
+}
+
+impl From<PartialCompletionsConfiguration> for CompletionsSettings {
+    fn from(value: PartialCompletionsConfiguration) -> Self {
+        Self {
+            learn_from_workspace: value
+                .learn_from_workspace
+                .unwrap_or(Self::default().learn_from_workspace),
+        }
+    }
+}
+
 /// Database settings for the entire workspace
 #[derive(Debug)]
 pub struct DatabaseSettings {
@@ -517,7 +560,7 @@ impl From<PartialDatabaseConfiguration> for DatabaseSettings {
 }
 
 /// Filesystem settings for the entire workspace
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FilesSettings {
     /// File size limit in bytes
     pub max_size: NonZeroU64,
@@ -537,6 +580,8 @@ pub struct FilesSettings {
 pub struct MigrationSettings {
     pub path: Option<PathBuf>,
     pub after: Option<u64>,
+    /// Additional patterns of migration files
+    pub(crate) patterns: Vec<MigrationPattern>,
 }
 
 impl From<PartialMigrationsConfiguration> for MigrationSettings {
@@ -544,6 +589,12 @@ impl From<PartialMigrationsConfiguration> for MigrationSettings {
         Self {
             path: value.migrations_dir.map(PathBuf::from),
             after: value.after,
+            patterns: value
+                .patterns
+                .iter()
+                .flat_map(|patterns| patterns.iter())
+                .filter_map(|pattern| MigrationPattern::parse(pattern).ok())
+                .collect(),
         }
     }
 }
@@ -551,11 +602,44 @@ impl From<PartialMigrationsConfiguration> for MigrationSettings {
 fn to_migration_settings(
     working_directory: Option<PathBuf>,
     conf: MigrationsConfiguration,
-) -> Option<MigrationSettings> {
-    working_directory.map(|working_directory| MigrationSettings {
-        path: Some(working_directory.join(conf.migrations_dir)),
-        after: Some(conf.after),
-    })
+) -> Result<Option<MigrationSettings>, WorkspaceError> {
+    let patterns = conf
+        .patterns
+        .iter()
+        .map(|pattern| {
+            MigrationPattern::parse(pattern).map_err(|message| {
+                ConfigurationDiagnostic::invalid_configuration(format!(
+                    "Invalid migration pattern: {message}"
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(
+        working_directory.map(|working_directory| MigrationSettings {
+            path: Some(working_directory.join(conf.migrations_dir)),
+            after: Some(conf.after),
+            patterns,
+        }),
+    )
+}
+
+/// VCS settings
+#[derive(Debug, Default, Clone)]
+pub struct VcsSettings {
+    /// Whether the VCS integration is enabled
+    pub enabled: bool,
+    /// The main branch of the project
+    pub default_branch: Option<String>,
+}
+
+impl From<PartialVcsConfiguration> for VcsSettings {
+    fn from(value: PartialVcsConfiguration) -> Self {
+        Self {
+            enabled: value.is_enabled(),
+            default_branch: value.default_branch.filter(|branch| !branch.is_empty()),
+        }
+    }
 }
 
 /// Limit the size of files to 1.0 MiB by default
@@ -563,6 +647,33 @@ pub(crate) const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
     // SAFETY: This constant is initialized with a non-zero value
     NonZeroU64::new(1024 * 1024).unwrap();
 
+impl FilesSettings {
+    /// Returns why `files.ignore`, `files.include` or the VCS ignore file ignore the path
+    pub fn ignore_reason(&self, path: &Path, is_dir: bool) -> Option<IgnoreReason> {
+        let is_included =
+            self.included_files.is_empty() || is_dir || self.included_files.matches_path(path);
+        if !is_included {
+            return Some(IgnoreReason::NotIncluded);
+        }
+        if self.ignored_files.matches_path(path) {
+            return Some(IgnoreReason::Ignored);
+        }
+
+        let is_vcs_ignored = self.git_ignore.as_ref().is_some_and(|ignore| {
+            // `matched_path_or_any_parents` panics if `source` is not under the gitignore root.
+            // This checks excludes absolute paths that are not a prefix of the base root.
+            if !path.has_root() || path.starts_with(ignore.path()) {
+                // Because Postgres Tools passes a list of paths,
+                // we use `matched_path_or_any_parents` instead of `matched`.
+                ignore.matched_path_or_any_parents(path, is_dir).is_ignore()
+            } else {
+                false
+            }
+        });
+        is_vcs_ignored.then_some(IgnoreReason::VcsIgnored)
+    }
+}
+
 impl Default for FilesSettings {
     fn default() -> Self {
         Self {
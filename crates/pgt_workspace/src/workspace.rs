@@ -16,8 +16,17 @@ use crate::{
         code_actions::{
             CodeActionsParams, CodeActionsResult, ExecuteStatementParams, ExecuteStatementResult,
         },
-        completions::{CompletionsResult, GetCompletionsParams},
-        diagnostics::{PullDiagnosticsParams, PullDiagnosticsResult},
+        completions::{
+            CompletionsResult, GetCompletionsParams, RecordAcceptedCompletionParams,
+            ResolveCompletionItemParams, ResolveCompletionItemResult,
+        },
+        diagnostics::{
+            PullDiagnosticsParams, PullDiagnosticsResult, PullMigrationDiagnosticsParams,
+            PullMigrationDiagnosticsResult, PullSchemaDiagnosticsParams,
+            PullSchemaDiagnosticsResult,
+        },
+        migrate_check::{MigrateCheckParams, MigrateCheckResult},
+        schema_diff::{DiffSchemaParams, DiffSchemaResult},
     },
 };
 
@@ -25,6 +34,7 @@ mod client;
 mod server;
 
 pub use server::StatementId;
+pub(crate) use server::migration::MigrationPattern;
 pub(crate) use server::parsed_document::*;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -69,6 +79,21 @@ pub struct IsPathIgnoredParams {
     pub pgt_path: PgTPath,
 }
 
+/// Why a path is ignored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum IgnoreReason {
+    /// The path doesn't match `files.include`
+    NotIncluded,
+    /// The path matches `files.ignore`
+    Ignored,
+    /// The path is ignored by the ignore file of the VCS
+    VcsIgnored,
+    /// The path is a migration that isn't after `migrations.after`
+    BeforeMigrationsAfter,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateSettingsParams {
@@ -78,6 +103,47 @@ pub struct UpdateSettingsParams {
     pub workspace_directory: Option<PathBuf>,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RefreshSchemaCacheParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetSchemaFingerprintParams {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GetDatabaseInfoParams {}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DatabaseInfo {
+    /// Whether the connection is disabled, in the configuration or with `--disable-db`
+    pub disabled: bool,
+    /// The error raised while connecting to the database or loading its schema
+    pub error: Option<String>,
+    /// The version of Postgres, e.g. `PostgreSQL 15.7 on x86_64-pc-linux-gnu, ...`
+    pub version: Option<String>,
+    /// The number of objects of each kind in the schema cache
+    pub schema_cache: Option<SchemaCacheCounts>,
+}
+
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SchemaCacheCounts {
+    pub schemas: usize,
+    pub tables: usize,
+    pub columns: usize,
+    pub functions: usize,
+    pub types: usize,
+    pub policies: usize,
+    pub triggers: usize,
+    pub roles: usize,
+    pub indexes: usize,
+    pub foreign_keys: usize,
+    pub extensions: usize,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct GetFileContentParams {
@@ -117,6 +183,48 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError>;
 
+    /// Runs the schema rules once against the connected database. Returns no diagnostics if
+    /// there is no connection.
+    fn pull_schema_diagnostics(
+        &self,
+        params: PullSchemaDiagnosticsParams,
+    ) -> Result<PullSchemaDiagnosticsResult, WorkspaceError>;
+
+    /// Checks that the migrations are named and ordered consistently. Returns no diagnostics if
+    /// there is no migrations directory.
+    fn pull_migration_diagnostics(
+        &self,
+        params: PullMigrationDiagnosticsParams,
+    ) -> Result<PullMigrationDiagnosticsResult, WorkspaceError>;
+
+    /// Drops the cached schemas, so that the next request loads them again from the database
+    fn refresh_schema_cache(&self, params: RefreshSchemaCacheParams) -> Result<(), WorkspaceError>;
+
+    /// Returns a hash of the schema of the connected database, which changes whenever the
+    /// schema changes. Returns `None` if there is no connection.
+    fn get_schema_fingerprint(
+        &self,
+        params: GetSchemaFingerprintParams,
+    ) -> Result<Option<String>, WorkspaceError>;
+
+    /// Returns the version of the connected database and what its schema cache contains, or
+    /// why it couldn't be loaded.
+    fn get_database_info(
+        &self,
+        params: GetDatabaseInfoParams,
+    ) -> Result<DatabaseInfo, WorkspaceError>;
+
+    /// Applies the migrations to a scratch database created next to the connected one, and
+    /// reports the failing statement, how long each statement took and the locks it acquired.
+    fn migrate_check(
+        &self,
+        params: MigrateCheckParams,
+    ) -> Result<MigrateCheckResult, WorkspaceError>;
+
+    /// Compares two schemas, e.g. the one the migrations result in with the connected
+    /// database, and returns the objects that differ.
+    fn diff_schema(&self, params: DiffSchemaParams) -> Result<DiffSchemaResult, WorkspaceError>;
+
     /// Retrieves a list of available code_actions for a file/cursor_position
     fn pull_code_actions(
         &self,
@@ -128,6 +236,18 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: GetCompletionsParams,
     ) -> Result<CompletionsResult, WorkspaceError>;
 
+    /// Remembers that the user accepted a completion item, so that it is ranked higher next time
+    fn record_accepted_completion(
+        &self,
+        params: RecordAcceptedCompletionParams,
+    ) -> Result<(), WorkspaceError>;
+
+    /// Computes the documentation of a completion item returned by `get_completions`
+    fn resolve_completion_item(
+        &self,
+        params: ResolveCompletionItemParams,
+    ) -> Result<ResolveCompletionItemResult, WorkspaceError>;
+
     /// Register a possible workspace project folder. Returns the key of said project. Use this key when you want to switch to different projects.
     fn register_project_folder(
         &self,
@@ -166,6 +286,12 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
     /// If the file path matches, then `true` is returned, and it should be considered ignored.
     fn is_path_ignored(&self, params: IsPathIgnoredParams) -> Result<bool, WorkspaceError>;
 
+    /// Returns why the path is ignored, or `None` if it isn't.
+    fn get_ignore_reason(
+        &self,
+        params: IsPathIgnoredParams,
+    ) -> Result<Option<IgnoreReason>, WorkspaceError>;
+
     fn execute_statement(
         &self,
         params: ExecuteStatementParams,
@@ -8,8 +8,9 @@ use std::{
 };
 
 use super::{
-    CloseFileParams, GetFileContentParams, IsPathIgnoredParams, OpenFileParams, ProjectKey,
-    RegisterProjectFolderParams, UnregisterProjectFolderParams,
+    CloseFileParams, DatabaseInfo, GetDatabaseInfoParams, GetFileContentParams, IgnoreReason,
+    IsPathIgnoredParams, OpenFileParams, ProjectKey, RegisterProjectFolderParams,
+    UnregisterProjectFolderParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -140,6 +141,13 @@ where
         self.request("pgt/is_path_ignored", params)
     }
 
+    fn get_ignore_reason(
+        &self,
+        params: IsPathIgnoredParams,
+    ) -> Result<Option<IgnoreReason>, WorkspaceError> {
+        self.request("pgt/get_ignore_reason", params)
+    }
+
     fn server_info(&self) -> Option<&ServerInfo> {
         self.server_info.as_ref()
     }
@@ -155,10 +163,73 @@ where
         self.request("pgt/pull_diagnostics", params)
     }
 
+    fn pull_schema_diagnostics(
+        &self,
+        params: crate::features::diagnostics::PullSchemaDiagnosticsParams,
+    ) -> Result<crate::features::diagnostics::PullSchemaDiagnosticsResult, WorkspaceError> {
+        self.request("pgt/pull_schema_diagnostics", params)
+    }
+
+    fn pull_migration_diagnostics(
+        &self,
+        params: crate::features::diagnostics::PullMigrationDiagnosticsParams,
+    ) -> Result<crate::features::diagnostics::PullMigrationDiagnosticsResult, WorkspaceError> {
+        self.request("pgt/pull_migration_diagnostics", params)
+    }
+
+    fn refresh_schema_cache(
+        &self,
+        params: super::RefreshSchemaCacheParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("pgt/refresh_schema_cache", params)
+    }
+
+    fn get_schema_fingerprint(
+        &self,
+        params: super::GetSchemaFingerprintParams,
+    ) -> Result<Option<String>, WorkspaceError> {
+        self.request("pgt/get_schema_fingerprint", params)
+    }
+
+    fn get_database_info(
+        &self,
+        params: GetDatabaseInfoParams,
+    ) -> Result<DatabaseInfo, WorkspaceError> {
+        self.request("pgt/get_database_info", params)
+    }
+
+    fn migrate_check(
+        &self,
+        params: crate::features::migrate_check::MigrateCheckParams,
+    ) -> Result<crate::features::migrate_check::MigrateCheckResult, WorkspaceError> {
+        self.request("pgt/migrate_check", params)
+    }
+
+    fn diff_schema(
+        &self,
+        params: crate::features::schema_diff::DiffSchemaParams,
+    ) -> Result<crate::features::schema_diff::DiffSchemaResult, WorkspaceError> {
+        self.request("pgt/diff_schema", params)
+    }
+
     fn get_completions(
         &self,
         params: super::GetCompletionsParams,
     ) -> Result<crate::features::completions::CompletionsResult, WorkspaceError> {
         self.request("pgt/get_completions", params)
     }
+
+    fn record_accepted_completion(
+        &self,
+        params: crate::features::completions::RecordAcceptedCompletionParams,
+    ) -> Result<(), WorkspaceError> {
+        self.request("pgt/record_accepted_completion", params)
+    }
+
+    fn resolve_completion_item(
+        &self,
+        params: crate::features::completions::ResolveCompletionItemParams,
+    ) -> Result<crate::features::completions::ResolveCompletionItemResult, WorkspaceError> {
+        self.request("pgt/resolve_completion_item", params)
+    }
 }
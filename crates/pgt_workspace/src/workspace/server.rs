@@ -7,20 +7,25 @@ use std::{
 
 use analyser::AnalyserVisitorBuilder;
 use async_helper::run_async;
-use connection_manager::ConnectionManager;
+use completion_usage_manager::CompletionUsageManager;
+use connection_manager::{ConnectionManager, connect_options};
 use dashmap::DashMap;
 use document::Document;
 use futures::{StreamExt, stream};
+use migrate_check::MigrationFile;
+use migration::SequenceNumber;
 use parsed_document::{
     AsyncDiagnosticsMapper, CursorPositionFilter, DefaultMapper, ExecuteStatementMapper,
     ParsedDocument, SyncDiagnosticsMapper,
 };
-use pgt_analyse::{AnalyserOptions, AnalysisFilter};
+use pgt_analyse::{AnalyserOptions, AnalysisFilter, context::FileContext};
 use pgt_analyser::{Analyser, AnalyserConfig, AnalyserContext};
+use pgt_configuration::ConfigurationDiagnostic;
 use pgt_diagnostics::{
     Diagnostic, DiagnosticExt, Error, Severity, serde::Diagnostic as SDiagnostic,
 };
-use pgt_fs::{ConfigName, PgTPath};
+use pgt_fs::{ConfigName, OsFileSystem, PgTPath};
+use pgt_schema_cache::SchemaCache;
 use pgt_typecheck::{IdentifierType, TypecheckParams, TypedIdentifier};
 use schema_cache_manager::SchemaCacheManager;
 use sqlx::{Executor, PgPool};
@@ -34,16 +39,27 @@ use crate::{
             self, CodeAction, CodeActionKind, CodeActionsResult, CommandAction,
             CommandActionCategory, ExecuteStatementParams, ExecuteStatementResult,
         },
-        completions::{CompletionsResult, GetCompletionsParams, get_statement_for_completions},
-        diagnostics::{PullDiagnosticsParams, PullDiagnosticsResult},
+        completions::{
+            CompletionsResult, GetCompletionsParams, RecordAcceptedCompletionParams,
+            ResolveCompletionItemParams, ResolveCompletionItemResult,
+            get_statement_for_completions,
+        },
+        diagnostics::{
+            PullDiagnosticsParams, PullDiagnosticsResult, PullMigrationDiagnosticsParams,
+            PullMigrationDiagnosticsResult, PullSchemaDiagnosticsParams,
+            PullSchemaDiagnosticsResult,
+        },
+        migrate_check::{MigrateCheckParams, MigrateCheckResult},
+        schema_diff::{DiffSchemaParams, DiffSchemaResult, SchemaSnapshot, SchemaSource},
     },
     settings::{WorkspaceSettings, WorkspaceSettingsHandle, WorkspaceSettingsHandleMut},
 };
 
 use super::{
-    GetFileContentParams, IsPathIgnoredParams, OpenFileParams, ProjectKey,
-    RegisterProjectFolderParams, ServerInfo, UnregisterProjectFolderParams, UpdateSettingsParams,
-    Workspace,
+    DatabaseInfo, GetDatabaseInfoParams, GetFileContentParams, GetSchemaFingerprintParams,
+    IgnoreReason, IsPathIgnoredParams, OpenFileParams, ProjectKey, RefreshSchemaCacheParams,
+    RegisterProjectFolderParams, SchemaCacheCounts, ServerInfo, UnregisterProjectFolderParams,
+    UpdateSettingsParams, Workspace,
 };
 
 pub use statement_identifier::StatementId;
@@ -52,13 +68,18 @@ mod analyser;
 mod annotation;
 mod async_helper;
 mod change;
+mod completion_usage_manager;
 mod connection_key;
 mod connection_manager;
 pub(crate) mod document;
-mod migration;
+mod migrate_check;
+pub(crate) mod migration;
+mod migration_validation;
 pub(crate) mod parsed_document;
 mod pg_query;
+mod plpgsql;
 mod schema_cache_manager;
+mod schema_diff;
 mod sql_function;
 mod statement_identifier;
 mod tree_sitter;
@@ -73,6 +94,9 @@ pub(super) struct WorkspaceServer {
     parsed_documents: DashMap<PgTPath, ParsedDocument>,
 
     connection: ConnectionManager,
+
+    /// Stores how relations and columns are used per project
+    completion_usage: CompletionUsageManager,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -95,6 +119,7 @@ impl WorkspaceServer {
             parsed_documents: DashMap::default(),
             schema_cache: SchemaCacheManager::new(),
             connection: ConnectionManager::new(),
+            completion_usage: CompletionUsageManager::new(),
         }
     }
 
@@ -113,6 +138,80 @@ impl WorkspaceServer {
         self.connection.get_pool(&settings.db)
     }
 
+    /// Loads the snapshot of a schema that is compared. Returns `None` if the migrations
+    /// couldn't be applied, the errors are added to `diagnostics`.
+    fn load_schema_snapshot(
+        &self,
+        source: SchemaSource,
+        schemas: &[String],
+        diagnostics: &mut Vec<SDiagnostic>,
+    ) -> Result<Option<SchemaSnapshot>, WorkspaceError> {
+        let cache = match source {
+            SchemaSource::Snapshot(mut snapshot) => {
+                schema_diff::retain_schemas(&mut snapshot, schemas);
+                return Ok(Some(snapshot));
+            }
+            SchemaSource::Database => {
+                let pool = self.get_current_connection().ok_or_else(|| {
+                    ConfigurationDiagnostic::invalid_configuration(
+                        "Comparing with the database requires a database connection, but it's disabled.",
+                    )
+                })?;
+                run_async(async move { SchemaCache::load(&pool).await })??
+            }
+            SchemaSource::Url(url) => run_async(async move {
+                let pool = PgPool::connect(&url).await?;
+                let cache = SchemaCache::load(&pool).await;
+                pool.close().await;
+                cache
+            })??,
+            SchemaSource::Migrations => {
+                let (migrations_dir, patterns, options) = {
+                    let settings = self.workspaces();
+                    let settings = settings.settings().ok_or(WorkspaceError::not_found())?;
+                    let migrations = settings.migrations.as_ref();
+                    let migrations_dir = migrations
+                        .and_then(|migrations| migrations.path.clone())
+                        .ok_or_else(|| {
+                            ConfigurationDiagnostic::invalid_configuration(
+                                "Comparing with the migrations requires `migrations.migrationsDir` to be set.",
+                            )
+                        })?;
+                    let patterns = migrations
+                        .map(|migrations| migrations.patterns.clone())
+                        .unwrap_or_default();
+                    (migrations_dir, patterns, connect_options(&settings.db))
+                };
+                let pool = self.get_current_connection().ok_or_else(|| {
+                    ConfigurationDiagnostic::invalid_configuration(
+                        "Comparing with the migrations requires a database connection to apply them, but it's disabled.",
+                    )
+                })?;
+                let migrations = read_migrations(&migrations_dir, &patterns)?;
+
+                info!("Replaying {} migration(s)", migrations.len());
+                let (cache, errors) =
+                    run_async(migrate_check::replay_schema(pool, options, migrations))??;
+                diagnostics.extend(errors);
+                match cache {
+                    Some(cache) => cache,
+                    None => return Ok(None),
+                }
+            }
+        };
+
+        Ok(Some(schema_diff::snapshot(&cache, schemas)))
+    }
+
+    /// Returns the path of the current project if completions should learn from it.
+    fn get_completion_usage_project(&self) -> Option<PathBuf> {
+        let workspaces = self.workspaces();
+        if !workspaces.settings()?.completions.learn_from_workspace {
+            return None;
+        }
+        workspaces.path().map(|p| p.to_path_buf())
+    }
+
     /// Register a new project in the current workspace
     fn register_project(&self, path: PathBuf) -> ProjectKey {
         let mut workspace = self.workspaces_mut();
@@ -152,51 +251,62 @@ impl WorkspaceServer {
             .and_then(|migration_settings| {
                 let ignore_before = migration_settings.after.as_ref()?;
                 let migrations_dir = migration_settings.path.as_ref()?;
-                let migration = migration::get_migration(path, migrations_dir)?;
+                let migration =
+                    migration::get_migration(path, migrations_dir, &migration_settings.patterns)?;
 
-                Some(&migration.sequence_number <= ignore_before)
+                Some(migration.sequence_number <= SequenceNumber::from(*ignore_before))
             })
             .unwrap_or(false)
     }
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
     fn is_ignored(&self, path: &Path) -> bool {
+        self.ignore_reason(path).is_some()
+    }
+
+    /// Returns why a file is ignored, by the top-level config or the migrations config
+    fn ignore_reason(&self, path: &Path) -> Option<IgnoreReason> {
         let file_name = path.file_name().and_then(|s| s.to_str());
         // Never ignore Postgres Tools's config file regardless `include`/`ignore`
-        (file_name != Some(ConfigName::pgt_jsonc())) &&
-            // Apply top-level `include`/`ignore
-            (self.is_ignored_by_top_level_config(path) || self.is_ignored_by_migration_config(path))
+        if file_name == Some(ConfigName::pgt_jsonc()) {
+            return None;
+        }
+
+        // Apply top-level `include`/`ignore
+        self.top_level_ignore_reason(path).or_else(|| {
+            self.is_ignored_by_migration_config(path)
+                .then_some(IgnoreReason::BeforeMigrationsAfter)
+        })
     }
 
     /// Check whether a file is ignored in the top-level config `files.ignore`/`files.include`
-    fn is_ignored_by_top_level_config(&self, path: &Path) -> bool {
+    fn top_level_ignore_reason(&self, path: &Path) -> Option<IgnoreReason> {
         let settings = self.workspaces();
-        let settings = settings.settings();
-        let Some(settings) = settings else {
-            return false;
-        };
+        let settings = settings.settings()?;
 
-        let is_included = settings.files.included_files.is_empty()
-            || is_dir(path)
-            || settings.files.included_files.matches_path(path);
-        !is_included
-            || settings.files.ignored_files.matches_path(path)
-            || settings.files.git_ignore.as_ref().is_some_and(|ignore| {
-                // `matched_path_or_any_parents` panics if `source` is not under the gitignore root.
-                // This checks excludes absolute paths that are not a prefix of the base root.
-                if !path.has_root() || path.starts_with(ignore.path()) {
-                    // Because Postgres Tools passes a list of paths,
-                    // we use `matched_path_or_any_parents` instead of `matched`.
-                    ignore
-                        .matched_path_or_any_parents(path, path.is_dir())
-                        .is_ignore()
-                } else {
-                    false
-                }
-            })
+        settings.files.ignore_reason(path, is_dir(path))
     }
 }
 
+/// Reads the migrations of the directory, in the order they are applied
+fn read_migrations(
+    migrations_dir: &Path,
+    patterns: &[migration::MigrationPattern],
+) -> Result<Vec<MigrationFile>, WorkspaceError> {
+    migration::list_migrations(migrations_dir, patterns)
+        .into_iter()
+        .map(|(path, migration)| {
+            let content = fs::read_to_string(&path)
+                .map_err(|_| WorkspaceError::cant_read_file(path.display().to_string()))?;
+            Ok(MigrationFile {
+                path,
+                migration,
+                content,
+            })
+        })
+        .collect()
+}
+
 impl Workspace for WorkspaceServer {
     fn register_project_folder(
         &self,
@@ -323,6 +433,13 @@ impl Workspace for WorkspaceServer {
         Ok(self.is_ignored(params.pgt_path.as_path()))
     }
 
+    fn get_ignore_reason(
+        &self,
+        params: IsPathIgnoredParams,
+    ) -> Result<Option<IgnoreReason>, WorkspaceError> {
+        Ok(self.ignore_reason(params.pgt_path.as_path()))
+    }
+
     fn pull_code_actions(
         &self,
         params: code_actions::CodeActionsParams,
@@ -455,9 +572,14 @@ impl Workspace for WorkspaceServer {
 
         let mut diagnostics: Vec<SDiagnostic> = parser.document_diagnostics().to_vec();
 
-        if let Some(pool) = self.get_current_connection() {
+        let pool = self.get_current_connection();
+        let schema_cache = pool
+            .as_ref()
+            .map(|pool| self.schema_cache.load(pool.clone()))
+            .transpose()?;
+
+        if let Some((pool, schema_cache)) = pool.zip(schema_cache.clone()) {
             let path_clone = params.path.clone();
-            let schema_cache = self.schema_cache.load(pool.clone())?;
             let input = parser.iter(AsyncDiagnosticsMapper).collect::<Vec<_>>();
             // sorry for the ugly code :(
             let async_results = run_async(async move {
@@ -518,48 +640,97 @@ impl Workspace for WorkspaceServer {
             }
         }
 
-        diagnostics.extend(parser.iter(SyncDiagnosticsMapper).flat_map(
-            |(_id, range, ast, diag)| {
-                let mut errors: Vec<Error> = vec![];
-
-                if let Some(diag) = diag {
-                    errors.push(diag.into());
-                }
-
-                if let Some(ast) = ast {
-                    errors.extend(
-                        analyser
-                            .run(AnalyserContext { root: &ast })
-                            .into_iter()
-                            .map(Error::from)
-                            .collect::<Vec<pgt_diagnostics::Error>>(),
-                    );
-                }
-
-                errors
-                    .into_iter()
-                    .map(|d| {
-                        let severity = d
-                            .category()
-                            .filter(|category| category.name().starts_with("lint/"))
-                            .map_or_else(
-                                || d.severity(),
-                                |category| {
-                                    settings
-                                        .get_severity_from_rule_code(category)
-                                        .unwrap_or(Severity::Warning)
-                                },
-                            );
-
-                        SDiagnostic::new(
-                            d.with_file_path(params.path.as_path().display().to_string())
-                                .with_file_span(range)
-                                .with_severity(severity),
+        // rules for migrations only apply to files within the migrations directory
+        let is_migration = settings
+            .migrations
+            .as_ref()
+            .is_some_and(|migration_settings| {
+                migration_settings
+                    .path
+                    .as_ref()
+                    .is_some_and(|migrations_dir| {
+                        migration::get_migration(
+                            params.path.as_path(),
+                            migrations_dir,
+                            &migration_settings.patterns,
                         )
+                        .is_some()
                     })
-                    .collect::<Vec<_>>()
-            },
-        ));
+            });
+
+        let stmts = parser.iter(SyncDiagnosticsMapper).collect::<Vec<_>>();
+        let file_stmts = stmts
+            .iter()
+            .filter_map(|(_id, _range, _content, ast, _diag)| ast.clone())
+            .collect::<Vec<_>>();
+        let mut stmt_index = 0;
+
+        diagnostics.extend(
+            stmts
+                .into_iter()
+                .flat_map(|(_id, range, content, ast, diag)| {
+                    // the severity of a lint diagnostic can be overridden by the rule itself, e.g. if
+                    // it knows that the affected table is small
+                    let mut errors: Vec<(Error, Option<Severity>)> = vec![];
+
+                    if let Some(diag) = diag {
+                        errors.push((diag.into(), None));
+                    }
+
+                    if let Some(ast) = ast {
+                        let file = FileContext {
+                            stmts: &file_stmts,
+                            stmt_index,
+                            is_migration,
+                        };
+                        stmt_index += 1;
+
+                        errors.extend(
+                            analyser
+                                .run(AnalyserContext {
+                                    root: &ast,
+                                    sql: &content,
+                                    schema_cache: schema_cache.as_deref(),
+                                    file,
+                                })
+                                .into_iter()
+                                .map(|d| {
+                                    let severity_override = d.get_severity_override();
+                                    (Error::from(d), severity_override)
+                                })
+                                .collect::<Vec<_>>(),
+                        );
+                    }
+
+                    errors
+                        .into_iter()
+                        .map(|(d, severity_override)| {
+                            let severity = d
+                                .category()
+                                .filter(|category| category.name().starts_with("lint/"))
+                                .map_or_else(
+                                    || d.severity(),
+                                    |category| {
+                                        severity_override.unwrap_or_else(|| {
+                                            settings
+                                                .get_severity_from_rule_code(category)
+                                                .unwrap_or(Severity::Warning)
+                                        })
+                                    },
+                                );
+
+                            // lint diagnostics may point at a node within the statement
+                            let span = d.location().span.map(|span| span + range.start());
+
+                            SDiagnostic::new(
+                                d.with_file_path(params.path.as_path().display().to_string())
+                                    .with_file_span(span.unwrap_or(range))
+                                    .with_severity(severity),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                }),
+        );
 
         let errors = diagnostics
             .iter()
@@ -574,6 +745,235 @@ impl Workspace for WorkspaceServer {
         })
     }
 
+    fn pull_schema_diagnostics(
+        &self,
+        params: PullSchemaDiagnosticsParams,
+    ) -> Result<PullSchemaDiagnosticsResult, WorkspaceError> {
+        let settings = self.workspaces();
+
+        let (Some(settings), Some(pool)) = (settings.settings(), self.get_current_connection())
+        else {
+            // schema rules need both the settings and a database to check
+            return Ok(PullSchemaDiagnosticsResult {
+                diagnostics: Vec::new(),
+                errors: 0,
+                skipped_diagnostics: 0,
+                checked: false,
+            });
+        };
+
+        let (enabled_rules, disabled_rules) = AnalyserVisitorBuilder::new(settings)
+            .with_linter_rules(&params.only, &params.skip)
+            .finish();
+        let options = AnalyserOptions {
+            rules: to_analyser_rules(settings),
+        };
+        let filter = AnalysisFilter {
+            categories: params.categories,
+            enabled_rules: Some(enabled_rules.as_slice()),
+            disabled_rules: &disabled_rules,
+        };
+        let analyser = Analyser::new(AnalyserConfig {
+            options: &options,
+            filter,
+        });
+
+        let schema_cache = self.schema_cache.load(pool)?;
+
+        let diagnostics = analyser
+            .run_schema(&schema_cache)
+            .into_iter()
+            .map(|d| {
+                let severity_override = d.get_severity_override();
+                let d = Error::from(d);
+                let severity = severity_override.unwrap_or_else(|| {
+                    d.category()
+                        .and_then(|category| settings.get_severity_from_rule_code(category))
+                        .unwrap_or(Severity::Warning)
+                });
+
+                SDiagnostic::new(d.with_severity(severity))
+            })
+            .collect::<Vec<_>>();
+
+        let errors = diagnostics
+            .iter()
+            .filter(|d| d.severity() == Severity::Error || d.severity() == Severity::Fatal)
+            .count();
+
+        info!("Pulled {:?} schema diagnostic(s)", diagnostics.len());
+        Ok(PullSchemaDiagnosticsResult {
+            diagnostics,
+            errors,
+            skipped_diagnostics: 0,
+            checked: true,
+        })
+    }
+
+    fn pull_migration_diagnostics(
+        &self,
+        _params: PullMigrationDiagnosticsParams,
+    ) -> Result<PullMigrationDiagnosticsResult, WorkspaceError> {
+        let (migrations_dir, patterns, vcs) = {
+            let settings = self.workspaces();
+            let Some(settings) = settings.settings() else {
+                return Ok(PullMigrationDiagnosticsResult {
+                    diagnostics: Vec::new(),
+                    checked: false,
+                });
+            };
+            let Some((migrations_dir, patterns)) =
+                settings.migrations.as_ref().and_then(|migrations| {
+                    Some((migrations.path.clone()?, migrations.patterns.clone()))
+                })
+            else {
+                return Ok(PullMigrationDiagnosticsResult {
+                    diagnostics: Vec::new(),
+                    checked: false,
+                });
+            };
+            (migrations_dir, patterns, settings.vcs.clone())
+        };
+
+        // the migrations are reported relative to the project, like the checked files
+        let project_path = self.get_current_project_path();
+        let diagnostics = migration_validation::validate_migrations(
+            &OsFileSystem::default(),
+            &migrations_dir,
+            &patterns,
+            &vcs,
+            |path| self.is_ignored(path),
+        )
+        .into_iter()
+        .map(|diagnostic| match &project_path {
+            Some(project_path) => diagnostic.relative_to(project_path),
+            None => diagnostic,
+        })
+        .map(SDiagnostic::new)
+        .collect::<Vec<_>>();
+
+        info!("Pulled {:?} migration diagnostic(s)", diagnostics.len());
+        Ok(PullMigrationDiagnosticsResult {
+            diagnostics,
+            checked: true,
+        })
+    }
+
+    fn refresh_schema_cache(
+        &self,
+        _params: RefreshSchemaCacheParams,
+    ) -> Result<(), WorkspaceError> {
+        info!("Refreshing the schema cache");
+        self.schema_cache.clear();
+        Ok(())
+    }
+
+    fn get_schema_fingerprint(
+        &self,
+        _params: GetSchemaFingerprintParams,
+    ) -> Result<Option<String>, WorkspaceError> {
+        let Some(pool) = self.get_current_connection() else {
+            return Ok(None);
+        };
+
+        let schema_cache = self.schema_cache.load(pool)?;
+        Ok(Some(schema_cache.fingerprint()))
+    }
+
+    fn get_database_info(
+        &self,
+        _params: GetDatabaseInfoParams,
+    ) -> Result<DatabaseInfo, WorkspaceError> {
+        let Some(pool) = self.get_current_connection() else {
+            return Ok(DatabaseInfo {
+                disabled: true,
+                ..Default::default()
+            });
+        };
+
+        let schema_cache = match self.schema_cache.load(pool) {
+            Ok(schema_cache) => schema_cache,
+            Err(error) => {
+                return Ok(DatabaseInfo {
+                    error: Some(error.to_string()),
+                    ..Default::default()
+                });
+            }
+        };
+
+        Ok(DatabaseInfo {
+            disabled: false,
+            error: None,
+            version: schema_cache
+                .versions
+                .first()
+                .and_then(|version| version.version.clone()),
+            schema_cache: Some(SchemaCacheCounts {
+                schemas: schema_cache.schemas.len(),
+                tables: schema_cache.tables.len(),
+                columns: schema_cache.columns.len(),
+                functions: schema_cache.functions.len(),
+                types: schema_cache.types.len(),
+                policies: schema_cache.policies.len(),
+                triggers: schema_cache.triggers.len(),
+                roles: schema_cache.roles.len(),
+                indexes: schema_cache.indexes.len(),
+                foreign_keys: schema_cache.foreign_keys.len(),
+                extensions: schema_cache.extensions.len(),
+            }),
+        })
+    }
+
+    fn migrate_check(
+        &self,
+        _params: MigrateCheckParams,
+    ) -> Result<MigrateCheckResult, WorkspaceError> {
+        let (migrations_dir, patterns, options) = {
+            let settings = self.workspaces();
+            let settings = settings.settings().ok_or(WorkspaceError::not_found())?;
+            let migrations = settings.migrations.as_ref();
+            let migrations_dir = migrations
+                .and_then(|migrations| migrations.path.clone())
+                .ok_or_else(|| {
+                    ConfigurationDiagnostic::invalid_configuration(
+                        "Checking migrations requires `migrations.migrationsDir` to be set.",
+                    )
+                })?;
+            let patterns = migrations
+                .map(|migrations| migrations.patterns.clone())
+                .unwrap_or_default();
+            (migrations_dir, patterns, connect_options(&settings.db))
+        };
+        let pool = self.get_current_connection().ok_or_else(|| {
+            ConfigurationDiagnostic::invalid_configuration(
+                "Checking migrations requires a database connection, but it's disabled.",
+            )
+        })?;
+
+        let migrations = read_migrations(&migrations_dir, &patterns)?;
+
+        info!("Checking {} migration(s)", migrations.len());
+        let result = run_async(migrate_check::migrate_check(pool, options, migrations))??;
+        Ok(result)
+    }
+
+    fn diff_schema(&self, params: DiffSchemaParams) -> Result<DiffSchemaResult, WorkspaceError> {
+        let mut diagnostics = Vec::new();
+        let from = self.load_schema_snapshot(params.from, &params.schemas, &mut diagnostics)?;
+        let to = self.load_schema_snapshot(params.to, &params.schemas, &mut diagnostics)?;
+
+        let changes = match (&from, &to) {
+            (Some(from), Some(to)) => schema_diff::diff(from, to),
+            _ => Vec::new(),
+        };
+
+        Ok(DiffSchemaResult {
+            changes,
+            snapshot: to.filter(|_| params.include_snapshot),
+            diagnostics,
+        })
+    }
+
     #[tracing::instrument(level = "debug", skip_all, fields(
         path = params.path.as_os_str().to_str(),
         position = params.position.to_string()
@@ -587,6 +987,19 @@ impl Workspace for WorkspaceServer {
             .get(&params.path)
             .ok_or(WorkspaceError::not_found())?;
 
+        // only editors ask for completions, so the usage is only recorded in their sessions
+        let usage = self.get_completion_usage_project().map(|project| {
+            self.completion_usage.scan_project(&project, || {
+                self.workspaces()
+                    .settings()
+                    .map(|settings| settings.files.clone())
+                    .unwrap_or_default()
+            });
+            self.completion_usage
+                .update_document(&project, &parsed_doc, &params.path);
+            self.completion_usage.get_stats(&project)
+        });
+
         let pool = self.get_current_connection();
         if pool.is_none() {
             tracing::debug!("No database connection available. Skipping completions.");
@@ -604,11 +1017,36 @@ impl Workspace for WorkspaceServer {
             Some((id, range, content, cst)) => {
                 let position = params.position - range.start();
 
+                // statements within PL/pgSQL bodies are completed on their own
+                let plpgsql_target = parsed_doc
+                    .find(id.clone(), ExecuteStatementMapper)
+                    .and_then(|(_, _, _, ast)| ast)
+                    .and_then(|ast| {
+                        plpgsql::get_completion_target(
+                            &ast,
+                            &content,
+                            position,
+                            schema_cache.as_ref(),
+                        )
+                    });
+
+                let (position, content, cst, variables) = match plpgsql_target {
+                    Some(target) => (
+                        position - target.range.start(),
+                        target.content,
+                        Arc::new(target.tree),
+                        target.variables,
+                    ),
+                    None => (position, content, cst, vec![]),
+                };
+
                 let items = pgt_completions::complete(pgt_completions::CompletionParams {
                     position,
                     schema: schema_cache.as_ref(),
                     tree: &cst,
                     text: content,
+                    usage: usage.as_deref(),
+                    variables,
                 });
 
                 tracing::debug!(
@@ -621,6 +1059,39 @@ impl Workspace for WorkspaceServer {
             }
         }
     }
+
+    fn record_accepted_completion(
+        &self,
+        params: RecordAcceptedCompletionParams,
+    ) -> Result<(), WorkspaceError> {
+        if let Some(project) = self.get_completion_usage_project() {
+            self.completion_usage.record_accepted(&project, params.data);
+        }
+
+        Ok(())
+    }
+
+    fn resolve_completion_item(
+        &self,
+        params: ResolveCompletionItemParams,
+    ) -> Result<ResolveCompletionItemResult, WorkspaceError> {
+        let pool = match self.get_current_connection() {
+            Some(pool) => pool,
+            None => {
+                tracing::debug!("No database connection available. Skipping resolve.");
+                return Ok(ResolveCompletionItemResult::default());
+            }
+        };
+
+        let schema_cache = self.schema_cache.load(pool)?;
+
+        Ok(ResolveCompletionItemResult {
+            documentation: pgt_completions::resolve_documentation(
+                &params.data,
+                schema_cache.as_ref(),
+            ),
+        })
+    }
 }
 
 /// Returns `true` if `path` is a directory or
@@ -1,4 +1,6 @@
-use pgt_analyse::{GroupCategory, RegistryVisitor, Rule, RuleCategory, RuleFilter, RuleGroup};
+use pgt_analyse::{
+    GroupCategory, RegistryVisitor, Rule, RuleCategory, RuleFilter, RuleGroup, RuleMeta, SchemaRule,
+};
 use pgt_configuration::RuleSelector;
 use rustc_hash::FxHashSet;
 
@@ -81,7 +83,7 @@ impl<'a, 'b> LintVisitor<'a, 'b> {
 
     fn push_rule<R>(&mut self)
     where
-        R: Rule<Options: Default> + 'static,
+        R: RuleMeta + 'static,
     {
         // Do not report unused suppression comment diagnostics if a single rule is run.
         for selector in self.only {
@@ -126,4 +128,11 @@ impl RegistryVisitor for LintVisitor<'_, '_> {
     {
         self.push_rule::<R>()
     }
+
+    fn record_schema_rule<R>(&mut self)
+    where
+        R: SchemaRule + 'static,
+    {
+        self.push_rule::<R>()
+    }
 }
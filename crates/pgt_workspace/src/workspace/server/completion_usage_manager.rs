@@ -0,0 +1,336 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use dashmap::{DashMap, DashSet};
+use pgt_completions::{CompletionItemData, FileUsage, UsageStatistics};
+use pgt_fs::{FileSystem, Fnv128, OpenOptions, OsFileSystem, PgTPath, walk_files};
+
+use super::parsed_document::{GetCompletionsMapper, ParsedDocument};
+use crate::settings::FilesSettings;
+
+/// How long changes are collected before the statistics are written to disk
+const PERSIST_DELAY: Duration = Duration::from_secs(5);
+
+/// Keeps track of how relations and columns are used per project, so that completions
+/// can rank them accordingly.
+///
+/// Usage is only recorded while completing, i.e. in editor sessions. The statistics are
+/// persisted in the cache directory a few seconds after they change, and when the manager is
+/// dropped, so they survive restarts.
+pub struct CompletionUsageManager {
+    store: Arc<Store>,
+    /// The projects whose SQL files were counted since the server started
+    scanned: DashSet<PathBuf>,
+}
+
+/// The state shared with the threads that count the SQL files of a project and write the
+/// statistics
+struct Store {
+    fs: Box<dyn FileSystem>,
+    /// Where the statistics are written. The cache directory of the user if not set.
+    cache_dir: Option<PathBuf>,
+    /// The statistics of every project, handed out as snapshots while completing
+    projects: DashMap<PathBuf, Arc<UsageStatistics>>,
+    /// The versions of the documents whose usage was recorded from the editor. Their content
+    /// on disk may be outdated, so the scan leaves them alone.
+    documents: DashMap<PgTPath, i32>,
+    /// The projects whose statistics changed since they were last written
+    dirty: DashSet<PathBuf>,
+    /// Whether a write of the dirty projects is scheduled
+    write_scheduled: AtomicBool,
+}
+
+impl CompletionUsageManager {
+    pub fn new() -> Self {
+        Self::with_fs(Box::new(OsFileSystem::default()), None)
+    }
+
+    /// Creates a manager that reads the SQL files from `fs` and keeps the statistics in
+    /// `cache_dir`
+    pub fn with_fs(fs: Box<dyn FileSystem>, cache_dir: Option<PathBuf>) -> Self {
+        Self {
+            store: Arc::new(Store {
+                fs,
+                cache_dir,
+                projects: DashMap::new(),
+                documents: DashMap::new(),
+                dirty: DashSet::new(),
+                write_scheduled: AtomicBool::new(false),
+            }),
+            scanned: DashSet::new(),
+        }
+    }
+
+    /// Recounts the references within a document, unless its version was counted already.
+    pub fn update_document(&self, project: &Path, doc: &ParsedDocument, path: &PgTPath) {
+        if self.store.documents.insert(path.clone(), doc.version()) == Some(doc.version()) {
+            return;
+        }
+
+        self.store.update_document(project, doc, path);
+        schedule_persist(&self.store, project);
+    }
+
+    /// Counts the references within the SQL files of the project in the background, once per
+    /// project. `files` is only called if the project wasn't counted yet, the files it ignores
+    /// aren't counted.
+    pub fn scan_project(&self, project: &Path, files: impl FnOnce() -> FilesSettings) {
+        if !self.scanned.insert(project.to_path_buf()) {
+            return;
+        }
+
+        let files = files();
+        let store = self.store.clone();
+        let project = project.to_path_buf();
+        std::thread::spawn(move || {
+            store.count_files(&project, &files);
+            schedule_persist(&store, &project);
+        });
+    }
+
+    pub fn record_accepted(&self, project: &Path, data: CompletionItemData) {
+        self.store
+            .update(project, |stats| stats.record_accepted(data));
+        schedule_persist(&self.store, project);
+    }
+
+    /// Returns a snapshot of the statistics of the project, loading them from disk if
+    /// necessary.
+    pub fn get_stats(&self, project: &Path) -> Arc<UsageStatistics> {
+        self.store
+            .projects
+            .entry(project.to_path_buf())
+            .or_insert_with(|| Arc::new(self.store.load(project)))
+            .clone()
+    }
+}
+
+impl Default for CompletionUsageManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for CompletionUsageManager {
+    fn drop(&mut self) {
+        self.store.persist_dirty();
+    }
+}
+
+/// Writes the statistics of the project after [PERSIST_DELAY], together with the other
+/// changes made until then.
+fn schedule_persist(store: &Arc<Store>, project: &Path) {
+    store.dirty.insert(project.to_path_buf());
+    if store.write_scheduled.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    let store = store.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(PERSIST_DELAY);
+        store.write_scheduled.store(false, Ordering::Release);
+        store.persist_dirty();
+    });
+}
+
+impl Store {
+    fn update(&self, project: &Path, f: impl FnOnce(&mut UsageStatistics)) {
+        let mut stats = self
+            .projects
+            .entry(project.to_path_buf())
+            .or_insert_with(|| Arc::new(self.load(project)));
+
+        // only clones the statistics if a completion is using a snapshot of them right now
+        f(Arc::make_mut(&mut stats));
+    }
+
+    fn update_document(&self, project: &Path, doc: &ParsedDocument, path: &PgTPath) {
+        let mut usage = FileUsage::default();
+
+        for (_, _, content, tree) in doc.iter(GetCompletionsMapper) {
+            usage.add_statement(&tree, &content);
+        }
+
+        self.update(project, |stats| {
+            stats.set_file_usage(path.to_string_lossy().to_string(), usage)
+        });
+    }
+
+    fn count_files(&self, project: &Path, files: &FilesSettings) {
+        for path in self.sql_files(project, files) {
+            if self.documents.contains_key(&path) {
+                continue;
+            }
+            let Ok(content) = self.fs.read_file_from_path(&path.to_path_buf()) else {
+                continue;
+            };
+
+            let doc = ParsedDocument::new(path.clone(), content, 0);
+            self.update_document(project, &doc, &path);
+        }
+    }
+
+    /// Lists the SQL files of the project that aren't ignored. Hidden directories, such as
+    /// `.git`, are skipped.
+    fn sql_files(&self, project: &Path, files: &FilesSettings) -> BTreeSet<PgTPath> {
+        let visit = |path: &Path| {
+            let is_dir = self.fs.path_is_dir(path);
+            if is_dir
+                && path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+            {
+                return false;
+            }
+            if !is_dir && path.extension().is_none_or(|ext| ext != "sql") {
+                return false;
+            }
+            files.ignore_reason(path, is_dir).is_none()
+        };
+
+        walk_files(&*self.fs, project, &visit)
+    }
+
+    fn persist_dirty(&self) {
+        let projects: Vec<PathBuf> = self.dirty.iter().map(|p| p.clone()).collect();
+        for project in projects {
+            self.dirty.remove(&project);
+            self.persist(&project);
+        }
+    }
+
+    fn persist(&self, project: &Path) {
+        let Some(stats) = self.projects.get(project).map(|stats| stats.clone()) else {
+            return;
+        };
+
+        let result = serde_json::to_string(&*stats)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                self.fs
+                    .open_with_options(
+                        &self.stats_file(project),
+                        OpenOptions::default()
+                            .write(true)
+                            .create(true)
+                            .truncate(true),
+                    )
+                    .and_then(|mut file| file.set_content(json.as_bytes()))
+                    .map_err(|e| e.to_string())
+            });
+
+        if let Err(err) = result {
+            tracing::warn!("Failed to persist completion usage statistics: {}", err);
+        }
+    }
+
+    fn load(&self, project: &Path) -> UsageStatistics {
+        self.fs
+            .read_file_from_path(&self.stats_file(project))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Every project gets its own file, named after the hash of the project's path.
+    fn stats_file(&self, project: &Path) -> PathBuf {
+        let mut hasher = Fnv128::default();
+        hasher.write(project.as_os_str().as_encoded_bytes());
+
+        self.cache_dir
+            .clone()
+            .unwrap_or_else(pgt_fs::ensure_cache_dir)
+            .join(format!("completion-usage-{:032x}.json", hasher.finish()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use pgt_fs::{FileSystemExt, MemoryFileSystem};
+
+    use super::CompletionUsageManager;
+    use crate::settings::FilesSettings;
+
+    fn manager(fs: MemoryFileSystem) -> CompletionUsageManager {
+        CompletionUsageManager::with_fs(Box::new(fs), Some(PathBuf::from("cache")))
+    }
+
+    #[test]
+    fn stores_projects_in_separate_files() {
+        let manager = manager(MemoryFileSystem::default());
+        let a = manager.store.stats_file(Path::new("/home/me/project-a"));
+        let b = manager.store.stats_file(Path::new("/home/me/project_a"));
+
+        assert_ne!(a, b);
+        assert!(a.starts_with("cache"));
+    }
+
+    fn project_fs() -> MemoryFileSystem {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("project/a.sql"),
+            "select email from public.users;",
+        );
+        fs.insert(
+            PathBuf::from("project/nested/b.sql"),
+            "select email from public.users;\nselect id from orders;",
+        );
+        fs.insert(
+            PathBuf::from("project/legacy/c.sql"),
+            "select email from public.users;",
+        );
+        fs.insert(PathBuf::from("project/notes.md"), "select id from orders;");
+        fs
+    }
+
+    fn ignoring_legacy() -> FilesSettings {
+        let mut files = FilesSettings::default();
+        files.ignored_files.add_pattern("**/legacy/**").unwrap();
+        files
+    }
+
+    #[test]
+    fn lists_the_sql_files_that_are_not_ignored() {
+        let manager = manager(project_fs());
+        let files: Vec<PathBuf> = manager
+            .store
+            .sql_files(Path::new("project"), &ignoring_legacy())
+            .into_iter()
+            .map(|path| path.to_path_buf())
+            .collect();
+
+        assert_eq!(
+            files,
+            [
+                PathBuf::from("project/a.sql"),
+                PathBuf::from("project/nested/b.sql")
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_the_sql_files_of_a_project() {
+        let files = ignoring_legacy();
+        let manager = manager(project_fs());
+        let project = Path::new("project");
+        manager.store.count_files(project, &files);
+        manager.store.persist(project);
+
+        let stats = manager.get_stats(project);
+        assert_eq!(stats.relation_count("public", "users"), 2);
+        assert_eq!(stats.column_count("public", "users", "email"), 2);
+        assert_eq!(stats.column_count("public", "orders", "id"), 1);
+
+        let file = manager.store.stats_file(project);
+        assert!(manager.store.fs.open(&file).is_ok());
+    }
+}
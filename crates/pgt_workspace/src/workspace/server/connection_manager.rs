@@ -48,12 +48,7 @@ impl ConnectionManager {
         }
 
         // Create a new pool
-        let config = PgConnectOptions::new()
-            .host(&settings.host)
-            .port(settings.port)
-            .username(&settings.username)
-            .password(&settings.password)
-            .database(&settings.database);
+        let config = connect_options(settings);
 
         let timeout = settings.conn_timeout_secs;
 
@@ -93,3 +88,13 @@ impl ConnectionManager {
         });
     }
 }
+
+/// The options to connect to the database of the settings
+pub(crate) fn connect_options(settings: &DatabaseSettings) -> PgConnectOptions {
+    PgConnectOptions::new()
+        .host(&settings.host)
+        .port(settings.port)
+        .username(&settings.username)
+        .password(&settings.password)
+        .database(&settings.database)
+}
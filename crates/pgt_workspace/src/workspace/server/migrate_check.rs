@@ -0,0 +1,444 @@
+use std::{
+    collections::BTreeSet,
+    io,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use pgt_console::markup;
+use pgt_diagnostics::{
+    Advices, Diagnostic, DiagnosticExt, LogCategory, MessageAndDescription, Visit,
+    serde::Diagnostic as SDiagnostic,
+};
+use pgt_schema_cache::SchemaCache;
+use pgt_text_size::{TextRange, TextSize};
+use sqlx::{
+    Connection, Executor, PgConnection, PgPool,
+    postgres::{PgConnectOptions, PgDatabaseError},
+};
+use tracing::error;
+
+use crate::features::migrate_check::{
+    AcquiredLock, MigrateCheckResult, MigrationReport, StatementReport,
+};
+
+use super::migration::Migration;
+
+/// A migration file, read from disk
+pub(crate) struct MigrationFile {
+    pub(crate) path: PathBuf,
+    pub(crate) migration: Migration,
+    pub(crate) content: String,
+}
+
+/// The locks held by the current session, besides the ones every query holds. Locks on system
+/// catalogs and TOAST tables are left out, DDL statements lock a lot of them.
+const HELD_LOCKS_QUERY: &str = "select locktype, mode, relation::regclass::text as relation
+from pg_catalog.pg_locks
+where pid = pg_backend_pid()
+  and granted
+  and locktype not in ('virtualxid', 'transactionid')
+  and (relation is null or (relation >= 16384 and relation::regclass::text not like 'pg_toast.%'))";
+
+/// The number of checks started by this process, to name their databases
+static CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+/// Creates a scratch database, applies the migrations to it and drops it again.
+///
+/// `pool` is connected to the configured database, and `options` are used to connect to the
+/// scratch database.
+pub(crate) async fn migrate_check(
+    pool: PgPool,
+    options: PgConnectOptions,
+    migrations: Vec<MigrationFile>,
+) -> Result<MigrateCheckResult, sqlx::Error> {
+    let database = create_scratch_database(&pool).await?;
+
+    let result = match PgConnection::connect_with(&options.database(&database)).await {
+        Ok(mut connection) => {
+            let result = apply_migrations(&mut connection, migrations).await;
+            let _ = connection.close().await;
+            result
+        }
+        Err(error) => Err(error),
+    };
+
+    let (migrations, diagnostics) = drop_scratch_database(&pool, &database, result).await?;
+    Ok(MigrateCheckResult {
+        database,
+        migrations,
+        diagnostics,
+    })
+}
+
+/// Reconstructs the schema the migrations result in, by applying them to a scratch database and
+/// loading its schema cache. The schema isn't loaded if one of the migrations fails.
+pub(crate) async fn replay_schema(
+    pool: PgPool,
+    options: PgConnectOptions,
+    migrations: Vec<MigrationFile>,
+) -> Result<(Option<SchemaCache>, Vec<SDiagnostic>), sqlx::Error> {
+    let database = create_scratch_database(&pool).await?;
+
+    let result = match PgPool::connect_with(options.database(&database)).await {
+        Ok(scratch) => {
+            let result = replay(&scratch, migrations).await;
+            scratch.close().await;
+            result
+        }
+        Err(error) => Err(error),
+    };
+
+    drop_scratch_database(&pool, &database, result).await
+}
+
+async fn replay(
+    pool: &PgPool,
+    migrations: Vec<MigrationFile>,
+) -> Result<(Option<SchemaCache>, Vec<SDiagnostic>), sqlx::Error> {
+    let (_, diagnostics) = {
+        let mut connection = pool.acquire().await?;
+        apply_migrations(&mut connection, migrations).await?
+    };
+    if !diagnostics.is_empty() {
+        return Ok((None, diagnostics));
+    }
+
+    Ok((Some(SchemaCache::load(pool).await?), diagnostics))
+}
+
+async fn create_scratch_database(pool: &PgPool) -> Result<String, sqlx::Error> {
+    let database = format!(
+        "pgt_migrate_check_{}_{}",
+        std::process::id(),
+        CHECKS.fetch_add(1, Ordering::Relaxed)
+    );
+
+    pool.execute(format!(r#"create database "{database}""#).as_str())
+        .await?;
+
+    Ok(database)
+}
+
+/// Drops the scratch database, even if the migrations couldn't be checked, and passes on their
+/// `result`. If they couldn't, an error dropping the database is only logged, so that it doesn't
+/// hide why.
+async fn drop_scratch_database<T>(
+    pool: &PgPool,
+    database: &str,
+    result: Result<T, sqlx::Error>,
+) -> Result<T, sqlx::Error> {
+    let dropped = pool
+        .execute(format!(r#"drop database if exists "{database}""#).as_str())
+        .await;
+
+    match (result, dropped) {
+        (Ok(_), Err(error)) => Err(error),
+        (Err(error), Err(drop_error)) => {
+            error!("Failed to drop the scratch database {database}: {drop_error}");
+            Err(error)
+        }
+        (result, Ok(_)) => result,
+    }
+}
+
+/// Applies each migration in its own transaction, until one of them fails. Migrations with
+/// statements that can't run in a transaction are applied in autocommit mode instead.
+async fn apply_migrations(
+    connection: &mut PgConnection,
+    migrations: Vec<MigrationFile>,
+) -> Result<(Vec<MigrationReport>, Vec<SDiagnostic>), sqlx::Error> {
+    let mut reports = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    for file in migrations {
+        // each migration already runs in its own transaction, or in none
+        let ranges: Vec<TextRange> = statement_ranges(&file.content)
+            .into_iter()
+            .filter(|range| !is_transaction_control(&file.content[*range]))
+            .collect();
+        let in_transaction = !ranges
+            .iter()
+            .any(|range| is_non_transactional(&file.content[*range]));
+
+        let (statements, failure) = if in_transaction {
+            apply_in_transaction(connection, &file.content, ranges).await?
+        } else {
+            apply_in_autocommit(connection, &file.content, ranges).await?
+        };
+
+        let applied = failure.is_none();
+        if let Some(mut failure) = failure {
+            failure.advices.rolled_back = in_transaction;
+
+            let range = statements.last().map(|statement| statement.range);
+            diagnostics.push(SDiagnostic::new(
+                failure
+                    .with_file_path(file.path.display().to_string())
+                    .with_file_span(range.unwrap_or_default())
+                    .with_file_source_code(&file.content),
+            ));
+        }
+
+        reports.push(MigrationReport {
+            path: file.path,
+            sequence_number: file.migration.sequence_number.to_string(),
+            name: file.migration.name,
+            applied,
+            statements,
+        });
+
+        if !applied {
+            break;
+        }
+    }
+
+    Ok((reports, diagnostics))
+}
+
+/// The statements that were executed, including the failing one, and why it failed
+type AppliedStatements = (Vec<StatementReport>, Option<MigrationFailed>);
+
+/// Executes the statements in a transaction, which is rolled back if one of them fails
+async fn apply_in_transaction(
+    connection: &mut PgConnection,
+    content: &str,
+    ranges: Vec<TextRange>,
+) -> Result<AppliedStatements, sqlx::Error> {
+    let mut statements = Vec::new();
+    let mut transaction = connection.begin().await?;
+    let mut held = held_locks(&mut transaction).await?;
+
+    for range in ranges {
+        let (duration, failure) = execute(&mut transaction, &content[range]).await?;
+        if failure.is_some() {
+            statements.push(StatementReport {
+                range,
+                duration,
+                locks: Vec::new(),
+            });
+            transaction.rollback().await?;
+            return Ok((statements, failure));
+        }
+
+        let locks = held_locks(&mut transaction).await?;
+        statements.push(StatementReport {
+            range,
+            duration,
+            locks: locks.difference(&held).cloned().collect(),
+        });
+        held = locks;
+    }
+
+    transaction.commit().await?;
+    Ok((statements, None))
+}
+
+/// Executes the statements one by one, stopping at the first one that fails. The statements that
+/// can run in a transaction still run in one each, to report the locks they acquire.
+async fn apply_in_autocommit(
+    connection: &mut PgConnection,
+    content: &str,
+    ranges: Vec<TextRange>,
+) -> Result<AppliedStatements, sqlx::Error> {
+    let mut statements = Vec::new();
+
+    for range in ranges {
+        let sql = &content[range];
+        let failure = if is_non_transactional(sql) {
+            let (duration, failure) = execute(connection, sql).await?;
+            statements.push(StatementReport {
+                range,
+                duration,
+                locks: Vec::new(),
+            });
+            failure
+        } else {
+            let (mut applied, failure) =
+                apply_in_transaction(connection, content, vec![range]).await?;
+            statements.append(&mut applied);
+            failure
+        };
+
+        if failure.is_some() {
+            return Ok((statements, failure));
+        }
+    }
+
+    Ok((statements, None))
+}
+
+/// Executes a statement, and returns how long it took and why it failed, if it did
+async fn execute(
+    connection: &mut PgConnection,
+    sql: &str,
+) -> Result<(Duration, Option<MigrationFailed>), sqlx::Error> {
+    let start = Instant::now();
+    let result = connection.execute(sql).await;
+    let duration = start.elapsed();
+
+    match result {
+        Ok(_) => Ok((duration, None)),
+        Err(sqlx::Error::Database(error)) => {
+            Ok((duration, Some(MigrationFailed::new(error.downcast_ref()))))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns the locks the session holds. Only meaningful within a transaction, in autocommit mode
+/// the locks of a statement are released as soon as it finishes.
+async fn held_locks(connection: &mut PgConnection) -> Result<BTreeSet<AcquiredLock>, sqlx::Error> {
+    let locks: Vec<(String, String, Option<String>)> = sqlx::query_as(HELD_LOCKS_QUERY)
+        .fetch_all(connection)
+        .await?;
+
+    Ok(locks
+        .into_iter()
+        .map(|(locktype, mode, relation)| AcquiredLock {
+            locktype,
+            mode,
+            relation,
+        })
+        .collect())
+}
+
+/// Splits the migration into statements. If it can't be split, it's executed as a whole.
+fn statement_ranges(content: &str) -> Vec<TextRange> {
+    match pgt_statement_splitter::split(content) {
+        Ok(result) => result.ranges,
+        Err(_) => vec![TextRange::new(
+            TextSize::from(0),
+            TextSize::of(content.trim_end()),
+        )],
+    }
+}
+
+/// Whether the statement starts, commits or aborts a transaction
+fn is_transaction_control(sql: &str) -> bool {
+    let keyword = sql
+        .split(|c: char| c.is_whitespace() || c == ';')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    matches!(
+        keyword.as_str(),
+        "begin" | "start" | "commit" | "end" | "rollback" | "abort"
+    )
+}
+
+/// Whether the statement can't run in a transaction block, e.g. `create index concurrently`
+fn is_non_transactional(sql: &str) -> bool {
+    match pgt_query_ext::parse(sql) {
+        Ok(pgt_query_ext::NodeEnum::IndexStmt(stmt)) => stmt.concurrent,
+        Ok(pgt_query_ext::NodeEnum::DropStmt(stmt)) => stmt.concurrent,
+        // `alter type ... add value`, the new value can't be used in the same transaction
+        Ok(pgt_query_ext::NodeEnum::AlterEnumStmt(stmt)) => stmt.old_val.is_empty(),
+        _ => false,
+    }
+}
+
+/// Emitted when a statement of a migration fails to apply
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "migrateCheck", severity = Error)]
+struct MigrationFailed {
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+    #[advice]
+    advices: MigrationFailedAdvices,
+}
+
+#[derive(Debug)]
+struct MigrationFailedAdvices {
+    code: String,
+    detail: Option<String>,
+    hint: Option<String>,
+    /// Whether the migration ran in a transaction, which was rolled back
+    rolled_back: bool,
+}
+
+impl MigrationFailed {
+    fn new(error: &PgDatabaseError) -> Self {
+        let non_empty = |value: Option<&str>| {
+            value
+                .filter(|value| !value.is_empty())
+                .map(ToString::to_string)
+        };
+
+        Self {
+            message: error.message().to_string().into(),
+            advices: MigrationFailedAdvices {
+                code: error.code().to_string(),
+                detail: non_empty(error.detail()),
+                hint: non_empty(error.hint()),
+                rolled_back: true,
+            },
+        }
+    }
+}
+
+impl Advices for MigrationFailedAdvices {
+    fn record(&self, visitor: &mut dyn Visit) -> io::Result<()> {
+        visitor.record_log(
+            LogCategory::Error,
+            &markup! { "Error Code: " <Emphasis>{&self.code}</Emphasis> },
+        )?;
+        if let Some(detail) = &self.detail {
+            visitor.record_log(LogCategory::Info, &detail)?;
+        }
+        if let Some(hint) = &self.hint {
+            visitor.record_log(LogCategory::Info, &markup! { "Hint: "{hint}"" })?;
+        }
+        if self.rolled_back {
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The migration was rolled back, and the following migrations were not applied." },
+            )?;
+        } else {
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "The migration ran outside of a transaction, so the statements before this one remain applied. The following migrations were not applied." },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_non_transactional, is_transaction_control};
+
+    #[test]
+    fn detects_transaction_control_statements() {
+        assert!(is_transaction_control("begin;"));
+        assert!(is_transaction_control("BEGIN TRANSACTION"));
+        assert!(is_transaction_control("commit;"));
+        assert!(is_transaction_control("start transaction;"));
+        assert!(!is_transaction_control("create table ending (id int);"));
+        assert!(!is_transaction_control(
+            "create function f() returns int begin atomic select 1; end;"
+        ));
+    }
+
+    #[test]
+    fn detects_non_transactional_statements() {
+        assert!(is_non_transactional(
+            "create index concurrently users_email_idx on users (email);"
+        ));
+        assert!(is_non_transactional(
+            "drop index concurrently users_email_idx;"
+        ));
+        assert!(is_non_transactional("alter type mood add value 'ok';"));
+        assert!(!is_non_transactional(
+            "create index users_email_idx on users (email);"
+        ));
+        assert!(!is_non_transactional(
+            "alter type mood rename value 'ok' to 'fine';"
+        ));
+        assert!(!is_non_transactional("alter type mood owner to admin;"));
+    }
+}
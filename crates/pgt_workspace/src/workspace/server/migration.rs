@@ -1,29 +1,83 @@
-use std::path::Path;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub(crate) struct Migration {
-    pub(crate) sequence_number: u64,
-    #[allow(unused)]
+    pub(crate) sequence_number: SequenceNumber,
     pub(crate) name: String,
 }
 
-/// Get the migration associated with a path, if it is a migration file
-pub(crate) fn get_migration(path: &Path, migrations_dir: &Path) -> Option<Migration> {
-    // Check if path is a child of the migration directory
-    let is_child = path
-        .canonicalize()
-        .ok()
-        .and_then(|canonical_child| {
-            migrations_dir
-                .canonicalize()
-                .ok()
-                .map(|canonical_dir| canonical_child.starts_with(&canonical_dir))
-        })
-        .unwrap_or(false);
+/// The sequence number of a migration, which orders the migrations. Either a number, like a
+/// counter or a timestamp, or a dotted version, like the `1.1` of Flyway's `V1.1__name.sql`.
+/// Versions are compared component by component, so `1.2` comes before `1.10`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct SequenceNumber(Vec<u64>);
+
+impl SequenceNumber {
+    /// Parses one or more numbers separated by dots
+    pub(crate) fn parse(sequence: &str) -> Option<Self> {
+        sequence
+            .split('.')
+            .map(|component| {
+                if component.bytes().all(|b| b.is_ascii_digit()) {
+                    component.parse().ok()
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<u64>>>()
+            .map(Self)
+    }
+
+    /// Returns the number, unless it's a dotted version
+    pub(crate) fn as_number(&self) -> Option<u64> {
+        match self.0.as_slice() {
+            [number] => Some(*number),
+            _ => None,
+        }
+    }
+}
+
+impl From<u64> for SequenceNumber {
+    fn from(number: u64) -> Self {
+        Self(vec![number])
+    }
+}
 
-    if !is_child {
-        return None;
+impl fmt::Display for SequenceNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, component) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{component}")?;
+        }
+        Ok(())
     }
+}
+
+/// The plan of Sqitch, which lists the changes in the order they are deployed
+const SQITCH_PLAN: &str = "sqitch.plan";
+
+/// Get the migration associated with a path, if it is a migration file
+pub(crate) fn get_migration(
+    path: &Path,
+    migrations_dir: &Path,
+    patterns: &[MigrationPattern],
+) -> Option<Migration> {
+    // Check if path is a child of the migration directory
+    let relative_path = path.canonicalize().ok().and_then(|canonical_child| {
+        migrations_dir
+            .canonicalize()
+            .ok()
+            .and_then(|canonical_dir| {
+                canonical_child
+                    .strip_prefix(&canonical_dir)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+    })?;
 
     // we are trying to match patterns used by popular migration tools
 
@@ -41,16 +95,230 @@ pub(crate) fn get_migration(path: &Path, migrations_dir: &Path) -> Option<Migrat
 
     // in the "subdirectory" pattern, each migration is in a subdirectory named <timestamp>_<name>
     // this is used by prisma and drizzle
-    path.parent()
+    let subdirectory_migration = path
+        .parent()
         .and_then(|parent| parent.file_name())
         .and_then(|os_str| os_str.to_str())
-        .and_then(parse_migration_name)
+        .and_then(parse_migration_name);
+
+    if subdirectory_migration.is_some() {
+        return subdirectory_migration;
+    }
+
+    // the patterns of other tools can be configured
+    let relative_path = relative_path
+        .components()
+        .map(|component| component.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()?
+        .join("/");
+    patterns.iter().find_map(|pattern| {
+        let (sequence_number, name) = pattern.matches(&relative_path)?;
+        let sequence_number = match sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => plan_position(migrations_dir, &name)?.into(),
+        };
+        Some(Migration {
+            sequence_number,
+            name,
+        })
+    })
+}
+
+/// Lists the SQL files of the migrations directory, including the ones in subdirectories
+pub(crate) fn list_sql_files(migrations_dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(migrations_dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if path.is_dir() {
+            if !is_hidden {
+                files.extend(list_sql_files(&path));
+            }
+        } else if path.extension().is_some_and(|ext| ext == "sql") {
+            files.push(path);
+        }
+    }
+    files
+}
+
+/// Lists the migration files of the migrations directory, in the order they are applied
+pub(crate) fn list_migrations(
+    migrations_dir: &Path,
+    patterns: &[MigrationPattern],
+) -> Vec<(PathBuf, Migration)> {
+    let mut migrations: Vec<(PathBuf, Migration)> = list_sql_files(migrations_dir)
+        .into_iter()
+        .filter_map(|path| {
+            let migration = get_migration(&path, migrations_dir, patterns)?;
+            Some((path, migration))
+        })
+        .collect();
+    migrations.sort_by(|(a_path, a), (b_path, b)| {
+        a.sequence_number
+            .cmp(&b.sequence_number)
+            .then_with(|| a_path.cmp(b_path))
+    });
+    migrations
+}
+
+/// Returns the position of a change in the Sqitch plan of the migrations directory, which
+/// orders the migrations of patterns without `{sequence}`
+fn plan_position(migrations_dir: &Path, name: &str) -> Option<u64> {
+    let plan = fs::read_to_string(migrations_dir.join(SQITCH_PLAN)).ok()?;
+    plan.lines()
+        .map(str::trim)
+        // skip pragmas, tags and comments
+        .filter(|line| !line.is_empty() && !line.starts_with(['%', '@', '#']))
+        .filter_map(|line| line.split_whitespace().next())
+        .position(|change| change.strip_prefix('+').unwrap_or(change) == name)
+        .map(|position| position as u64 + 1)
+}
+
+/// A configured pattern of migration files, relative to the migrations directory, e.g.
+/// `V{sequence}__{name}.sql`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MigrationPattern {
+    parts: Vec<PatternPart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PatternPart {
+    Literal(String),
+    /// `{sequence}`, one or more digits, or numbers separated by dots
+    Sequence,
+    /// `{name}`, one or more characters other than `/`
+    Name,
+    /// `*`, any number of characters other than `/`
+    Wildcard,
+}
+
+impl MigrationPattern {
+    pub(crate) fn parse(pattern: &str) -> Result<Self, String> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = pattern;
+
+        while let Some(c) = rest.chars().next() {
+            let part = match c {
+                '{' => {
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| format!("A placeholder of `{pattern}` isn't closed."))?;
+                    let part = match &rest[1..end] {
+                        "sequence" => PatternPart::Sequence,
+                        "name" => PatternPart::Name,
+                        other => {
+                            return Err(format!(
+                                "Unknown placeholder `{{{other}}}` in `{pattern}`, expected `{{sequence}}` or `{{name}}`."
+                            ));
+                        }
+                    };
+                    rest = &rest[end + 1..];
+                    part
+                }
+                '*' => {
+                    rest = &rest[1..];
+                    PatternPart::Wildcard
+                }
+                _ => {
+                    literal.push(c);
+                    rest = &rest[c.len_utf8()..];
+                    continue;
+                }
+            };
+
+            if !literal.is_empty() {
+                parts.push(PatternPart::Literal(std::mem::take(&mut literal)));
+            }
+            parts.push(part);
+        }
+        if !literal.is_empty() {
+            parts.push(PatternPart::Literal(literal));
+        }
+
+        if !parts.contains(&PatternPart::Name) {
+            return Err(format!(
+                "The pattern `{pattern}` has no `{{name}}` placeholder."
+            ));
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// Returns the sequence number, if the pattern has one, and the name of the migration
+    fn matches(&self, path: &str) -> Option<(Option<SequenceNumber>, String)> {
+        let mut captures = Captures::default();
+        if !match_parts(&self.parts, path, &mut captures) {
+            return None;
+        }
+
+        let sequence_number = match captures.sequence {
+            Some(sequence) => Some(SequenceNumber::parse(sequence)?),
+            None => None,
+        };
+        Some((sequence_number, captures.name?.to_string()))
+    }
+}
+
+#[derive(Default)]
+struct Captures<'a> {
+    sequence: Option<&'a str>,
+    name: Option<&'a str>,
+}
+
+/// Matches the parts against the input, placeholders match as few characters as possible
+fn match_parts<'a>(parts: &[PatternPart], input: &'a str, captures: &mut Captures<'a>) -> bool {
+    let Some((part, rest)) = parts.split_first() else {
+        return input.is_empty();
+    };
+
+    if let PatternPart::Literal(literal) = part {
+        return input
+            .strip_prefix(literal.as_str())
+            .is_some_and(|input| match_parts(rest, input, captures));
+    }
+
+    let ends = input
+        .char_indices()
+        .map(|(index, _)| index)
+        .skip(1)
+        .chain(std::iter::once(input.len()));
+    let ends = std::iter::once(0).chain(ends);
+
+    for end in ends {
+        let captured = &input[..end];
+        if captured.contains('/')
+            || (*part == PatternPart::Sequence
+                && !captured.bytes().all(|b| b.is_ascii_digit() || b == b'.'))
+        {
+            break;
+        }
+        if captured.is_empty() && *part != PatternPart::Wildcard {
+            continue;
+        }
+        // e.g. the `1.` of `1.1`
+        if *part == PatternPart::Sequence && SequenceNumber::parse(captured).is_none() {
+            continue;
+        }
+
+        if match_parts(rest, &input[end..], captures) {
+            match part {
+                PatternPart::Sequence => captures.sequence = Some(captured),
+                PatternPart::Name => captures.name = Some(captured),
+                _ => {}
+            }
+            return true;
+        }
+    }
+
+    false
 }
 
 fn parse_migration_name(name: &str) -> Option<Migration> {
     let mut parts = name.splitn(2, '_');
     // remove leading zeros to support numeric
     let sequence_number: u64 = parts.next()?.trim_start_matches('0').parse().ok()?;
+    let sequence_number = SequenceNumber::from(sequence_number);
     let full_name = parts.next()?;
     let name = full_name
         .strip_suffix(".sql")
@@ -80,11 +348,11 @@ mod tests {
         let path = migrations_dir.join("1234567890_create_users.sql");
         fs::write(&path, "").unwrap();
 
-        let migration = get_migration(&path, &migrations_dir);
+        let migration = get_migration(&path, &migrations_dir, &[]);
 
         assert!(migration.is_some());
         let migration = migration.unwrap();
-        assert_eq!(migration.sequence_number, 1234567890);
+        assert_eq!(migration.sequence_number, SequenceNumber::from(1234567890));
         assert_eq!(migration.name, "create_users");
     }
 
@@ -97,11 +365,11 @@ mod tests {
         let path = subdir.join("up.sql");
         fs::write(&path, "").unwrap();
 
-        let migration = get_migration(&path, &migrations_dir);
+        let migration = get_migration(&path, &migrations_dir, &[]);
 
         assert!(migration.is_some());
         let migration = migration.unwrap();
-        assert_eq!(migration.sequence_number, 1234567890);
+        assert_eq!(migration.sequence_number, SequenceNumber::from(1234567890));
         assert_eq!(migration.name, "create_users");
     }
 
@@ -112,11 +380,11 @@ mod tests {
         let path = migrations_dir.join("000201_a_migration.sql");
         fs::write(&path, "").unwrap();
 
-        let migration = get_migration(&path, &migrations_dir);
+        let migration = get_migration(&path, &migrations_dir, &[]);
 
         assert!(migration.is_some());
         let migration = migration.unwrap();
-        assert_eq!(migration.sequence_number, 201);
+        assert_eq!(migration.sequence_number, SequenceNumber::from(201));
         assert_eq!(migration.name, "a_migration");
     }
 
@@ -125,17 +393,127 @@ mod tests {
         let migrations_dir = PathBuf::from("/tmp/migrations");
         let path = migrations_dir.join("not_a_migration.sql");
 
-        let migration = get_migration(&path, &migrations_dir);
+        let migration = get_migration(&path, &migrations_dir, &[]);
 
         assert!(migration.is_none());
     }
 
+    #[test]
+    fn test_list_migrations() {
+        let temp_dir = setup();
+        let migrations_dir = temp_dir.path().to_path_buf();
+        fs::write(migrations_dir.join("20_b.sql"), "").unwrap();
+        fs::write(migrations_dir.join("3_a.sql"), "").unwrap();
+        fs::write(migrations_dir.join("seed.sql"), "").unwrap();
+        fs::write(migrations_dir.join("4_notes.md"), "").unwrap();
+        fs::create_dir(migrations_dir.join("10_c")).unwrap();
+        fs::write(migrations_dir.join("10_c").join("migration.sql"), "").unwrap();
+
+        let migrations = list_migrations(&migrations_dir, &[])
+            .into_iter()
+            .map(|(_, migration)| (migration.sequence_number.to_string(), migration.name))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            migrations,
+            vec![
+                ("3".to_string(), "a".to_string()),
+                ("10".to_string(), "c".to_string()),
+                ("20".to_string(), "b".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_migration_configured_pattern() {
+        let temp_dir = setup();
+        let migrations_dir = temp_dir.path().to_path_buf();
+        let path = migrations_dir.join("V12__create_users.sql");
+        fs::write(&path, "").unwrap();
+        let patterns = [MigrationPattern::parse("V{sequence}__{name}.sql").unwrap()];
+
+        let migration = get_migration(&path, &migrations_dir, &patterns).unwrap();
+
+        assert_eq!(migration.sequence_number, SequenceNumber::from(12));
+        assert_eq!(migration.name, "create_users");
+        assert!(get_migration(&path, &migrations_dir, &[]).is_none());
+    }
+
+    #[test]
+    fn test_get_migration_dotted_version() {
+        let temp_dir = setup();
+        let migrations_dir = temp_dir.path().to_path_buf();
+        for name in [
+            "V1.1__create_users.sql",
+            "V1.10__add_email.sql",
+            "V1.2__create_posts.sql",
+        ] {
+            fs::write(migrations_dir.join(name), "").unwrap();
+        }
+        let patterns = [MigrationPattern::parse("V{sequence}__{name}.sql").unwrap()];
+
+        let migrations = list_migrations(&migrations_dir, &patterns)
+            .into_iter()
+            .map(|(_, migration)| (migration.sequence_number.to_string(), migration.name))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            migrations,
+            vec![
+                ("1.1".to_string(), "create_users".to_string()),
+                ("1.2".to_string(), "create_posts".to_string()),
+                ("1.10".to_string(), "add_email".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_migration_sqitch_plan() {
+        let temp_dir = setup();
+        let migrations_dir = temp_dir.path().to_path_buf();
+        fs::create_dir(migrations_dir.join("deploy")).unwrap();
+        fs::write(
+            migrations_dir.join("sqitch.plan"),
+            "%project=app\n\nusers 2024-01-01T00:00:00Z Jane <jane@example.com> # users\n@v1 2024-01-01T00:00:00Z Jane <jane@example.com>\nposts [users] 2024-01-02T00:00:00Z Jane <jane@example.com>\n",
+        )
+        .unwrap();
+        let posts = migrations_dir.join("deploy").join("posts.sql");
+        let unplanned = migrations_dir.join("deploy").join("comments.sql");
+        fs::write(&posts, "").unwrap();
+        fs::write(&unplanned, "").unwrap();
+        let patterns = [MigrationPattern::parse("deploy/{name}.sql").unwrap()];
+
+        let migration = get_migration(&posts, &migrations_dir, &patterns).unwrap();
+
+        assert_eq!(migration.sequence_number, SequenceNumber::from(2));
+        assert_eq!(migration.name, "posts");
+        assert!(get_migration(&unplanned, &migrations_dir, &patterns).is_none());
+    }
+
+    #[test]
+    fn test_parse_migration_pattern() {
+        let pattern = MigrationPattern::parse("*/V{sequence}__{name}.sql").unwrap();
+
+        assert_eq!(
+            pattern.matches("sql/V2__add_email.sql"),
+            Some((Some(SequenceNumber::from(2)), "add_email".to_string()))
+        );
+        assert_eq!(pattern.matches("V2__add_email.sql"), None);
+        assert_eq!(pattern.matches("sql/nested/V2__add_email.sql"), None);
+        assert_eq!(pattern.matches("sql/Vx__add_email.sql"), None);
+        assert_eq!(pattern.matches("sql/V1.__add_email.sql"), None);
+
+        assert!(MigrationPattern::parse("V{sequence}.sql").is_err());
+        assert!(MigrationPattern::parse("V{version}__{name}.sql").is_err());
+        assert!(MigrationPattern::parse("V{sequence__{name}.sql").is_err());
+    }
+
     #[test]
     fn test_get_migration_outside_migrations_dir() {
         let migrations_dir = PathBuf::from("/tmp/migrations");
         let path = PathBuf::from("/tmp/other/1234567890_create_users.sql");
 
-        let migration = get_migration(&path, &migrations_dir);
+        let migration = get_migration(&path, &migrations_dir, &[]);
 
         assert!(migration.is_none());
     }
@@ -0,0 +1,404 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use pgt_diagnostics::{Category, Diagnostic, MessageAndDescription, Severity, category};
+use pgt_fs::FileSystem;
+use tracing::debug;
+
+use crate::settings::VcsSettings;
+
+use super::migration::{
+    Migration, MigrationPattern, SequenceNumber, list_migrations, list_sql_files,
+};
+
+/// Checks that the migrations are named and ordered consistently:
+///
+/// - no two migrations share a sequence number;
+/// - sequence numbers that count up from 0 or 1 have no gaps;
+/// - every SQL file of the migrations directory matches a pattern of migration files;
+/// - with the VCS integration, migrations are added in the order of their sequence numbers, and
+///   the ones already on the default branch aren't modified.
+///
+/// Ignored files aren't reported, but are still taken into account to check the other ones.
+pub(crate) fn validate_migrations(
+    fs: &dyn FileSystem,
+    migrations_dir: &Path,
+    patterns: &[MigrationPattern],
+    vcs: &VcsSettings,
+    is_ignored: impl Fn(&Path) -> bool,
+) -> Vec<MigrationDiagnostic> {
+    // files are checked by their path relative to the migrations directory, which is also how
+    // the messages refer to them
+    let relative = |path: PathBuf| Some(path.strip_prefix(migrations_dir).ok()?.to_path_buf());
+    let migrations: Vec<(PathBuf, Migration)> = list_migrations(migrations_dir, patterns)
+        .into_iter()
+        .filter_map(|(path, migration)| Some((relative(path)?, migration)))
+        .collect();
+    let files: Vec<PathBuf> = list_sql_files(migrations_dir)
+        .into_iter()
+        .filter_map(relative)
+        .collect();
+
+    let mut diagnostics = duplicate_sequence_numbers(&migrations);
+    diagnostics.extend(sequence_gaps(&migrations));
+    diagnostics.extend(unmatched_files(&files, &migrations));
+
+    if vcs.enabled {
+        if let Some(added) = added_times(fs, migrations_dir) {
+            diagnostics.extend(out_of_order(&migrations, &added));
+        }
+        if let Some(modified) = vcs
+            .default_branch
+            .as_deref()
+            .and_then(|default_branch| modified_files(fs, migrations_dir, default_branch))
+        {
+            diagnostics.extend(modified_migrations(&migrations, &modified));
+        }
+    }
+
+    for diagnostic in &mut diagnostics {
+        diagnostic.path = migrations_dir.join(&diagnostic.path).display().to_string();
+    }
+    diagnostics.retain(|diagnostic| !is_ignored(Path::new(&diagnostic.path)));
+    diagnostics.sort_by(|a, b| a.path.cmp(&b.path));
+    diagnostics
+}
+
+/// Reports the migrations whose sequence number is already used by another migration. Files of
+/// the same migration, such as the ones in its subdirectory, share their sequence number.
+fn duplicate_sequence_numbers(migrations: &[(PathBuf, Migration)]) -> Vec<MigrationDiagnostic> {
+    let mut first_of_sequence: HashMap<&SequenceNumber, &(PathBuf, Migration)> = HashMap::new();
+    let mut diagnostics = Vec::new();
+
+    for entry @ (path, migration) in migrations {
+        let (first_path, first) = first_of_sequence
+            .entry(&migration.sequence_number)
+            .or_insert(entry);
+        if first.name != migration.name {
+            diagnostics.push(MigrationDiagnostic::new(
+                category!("migrations/duplicateSequenceNumber"),
+                Severity::Error,
+                path,
+                format!(
+                    "The sequence number {} is already used by {}.",
+                    migration.sequence_number,
+                    first_path.display()
+                ),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+/// Reports the migrations that follow a gap in the sequence numbers. Only sequence numbers that
+/// count up from 0 or 1 are checked, timestamps and versions are expected to have gaps.
+fn sequence_gaps(migrations: &[(PathBuf, Migration)]) -> Vec<MigrationDiagnostic> {
+    let counts_up = migrations.first().is_some_and(|(_, migration)| {
+        migration
+            .sequence_number
+            .as_number()
+            .is_some_and(|number| number <= 1)
+    });
+    if !counts_up {
+        return Vec::new();
+    }
+
+    migrations
+        .windows(2)
+        .filter_map(|pair| {
+            let [(_, previous), (path, migration)] = pair else {
+                return None;
+            };
+            let previous_number = previous.sequence_number.as_number()?;
+            let number = migration.sequence_number.as_number()?;
+            (number > previous_number + 1).then(|| {
+                MigrationDiagnostic::new(
+                    category!("migrations/sequenceGap"),
+                    Severity::Warning,
+                    path,
+                    format!(
+                        "The sequence number {number} follows {previous_number}, the migrations in between are missing."
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Reports the SQL files of the migrations directory that aren't migrations
+fn unmatched_files(
+    files: &[PathBuf],
+    migrations: &[(PathBuf, Migration)],
+) -> Vec<MigrationDiagnostic> {
+    let migrations: HashSet<&PathBuf> = migrations.iter().map(|(path, _)| path).collect();
+
+    files
+        .iter()
+        .filter(|path| !migrations.contains(path))
+        .map(|path| {
+            MigrationDiagnostic::new(
+                category!("migrations/unmatchedFile"),
+                Severity::Warning,
+                path,
+                "This file doesn't match any pattern of migration files, it's not applied as a migration."
+                    .to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Reports the migrations that were added after a migration with a higher sequence number, and
+/// would be applied out of order to databases that are already migrated. `added` maps the
+/// committed migrations to the time they were added, the other ones are added last.
+fn out_of_order(
+    migrations: &[(PathBuf, Migration)],
+    added: &HashMap<PathBuf, u64>,
+) -> Vec<MigrationDiagnostic> {
+    let added_at = |path: &PathBuf| added.get(path).copied().unwrap_or(u64::MAX);
+
+    migrations
+        .iter()
+        .filter_map(|(path, migration)| {
+            let (later_path, _) = migrations.iter().find(|(other_path, other)| {
+                other.sequence_number > migration.sequence_number
+                    && added_at(other_path) < added_at(path)
+            })?;
+            Some(MigrationDiagnostic::new(
+                category!("migrations/outOfOrder"),
+                Severity::Error,
+                path,
+                format!(
+                    "This migration was added after {}, but comes before it. Databases that are already migrated would apply it out of order.",
+                    later_path.display()
+                ),
+            ))
+        })
+        .collect()
+}
+
+/// Reports the migrations that were modified since they were committed to the default branch
+fn modified_migrations(
+    migrations: &[(PathBuf, Migration)],
+    modified: &HashSet<PathBuf>,
+) -> Vec<MigrationDiagnostic> {
+    migrations
+        .iter()
+        .filter(|(path, _)| modified.contains(path))
+        .map(|(path, _)| {
+            MigrationDiagnostic::new(
+                category!("migrations/modified"),
+                Severity::Error,
+                path,
+                "This migration was modified after it was committed to the default branch. Databases that are already migrated won't apply the changes, add a new migration instead."
+                    .to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Returns when each committed file of the migrations directory was added, as a UNIX timestamp,
+/// by its path relative to the migrations directory. `None` if the VCS can't tell, e.g. when the
+/// directory isn't in a repository.
+fn added_times(fs: &dyn FileSystem, migrations_dir: &Path) -> Option<HashMap<PathBuf, u64>> {
+    let added_files = fs
+        .get_added_files(migrations_dir)
+        .inspect_err(|error| debug!("Not checking the order the migrations were added: {error}"))
+        .ok()?;
+
+    let mut added = HashMap::new();
+    for (path, time) in added_files {
+        // the newest additions come first, so a file that was added again keeps the time it was
+        // last added
+        added.entry(PathBuf::from(path)).or_insert(time);
+    }
+
+    Some(added)
+}
+
+/// Returns the files of the migrations directory that were modified since the current branch
+/// forked from the default branch, including the uncommitted changes, by their path relative to
+/// the migrations directory. `None` if the VCS can't tell.
+fn modified_files(
+    fs: &dyn FileSystem,
+    migrations_dir: &Path,
+    default_branch: &str,
+) -> Option<HashSet<PathBuf>> {
+    let modified_files = fs
+        .get_modified_files(migrations_dir, default_branch)
+        .inspect_err(|error| debug!("Not checking for modified migrations: {error}"))
+        .ok()?;
+
+    Some(modified_files.into_iter().map(PathBuf::from).collect())
+}
+
+/// Emitted when a migration is misnamed or out of order
+#[derive(Debug, Diagnostic)]
+pub(crate) struct MigrationDiagnostic {
+    #[category]
+    category: &'static Category,
+    #[severity]
+    severity: Severity,
+    #[location(resource)]
+    path: String,
+    #[message]
+    #[description]
+    message: MessageAndDescription,
+}
+
+impl MigrationDiagnostic {
+    fn new(category: &'static Category, severity: Severity, path: &Path, message: String) -> Self {
+        Self {
+            category,
+            severity,
+            path: path.display().to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Makes the path of the diagnostic relative to `directory`, if it's inside of it
+    pub(crate) fn relative_to(mut self, directory: &Path) -> Self {
+        if let Ok(path) = Path::new(&self.path).strip_prefix(directory) {
+            self.path = path.display().to_string();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use pgt_diagnostics::Diagnostic;
+    use pgt_fs::MemoryFileSystem;
+
+    use super::{
+        Migration, MigrationDiagnostic, SequenceNumber, added_times, duplicate_sequence_numbers,
+        modified_files, out_of_order, sequence_gaps, unmatched_files,
+    };
+
+    fn migrations(names: &[&str]) -> Vec<(PathBuf, Migration)> {
+        names
+            .iter()
+            .map(|name| {
+                let (sequence_number, rest) = name.split_once('_').unwrap();
+                (
+                    PathBuf::from(format!("{name}.sql")),
+                    Migration {
+                        sequence_number: SequenceNumber::parse(sequence_number).unwrap(),
+                        name: rest.to_string(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn reported(diagnostics: Vec<MigrationDiagnostic>) -> Vec<(String, String)> {
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                let category = diagnostic.category().unwrap().name().to_string();
+                (category, diagnostic.path)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_duplicate_sequence_numbers() {
+        let mut migrations = migrations(&["1_users", "2_posts", "2_comments"]);
+        // another file of the same migration
+        migrations.push((
+            PathBuf::from("2_posts/down.sql"),
+            Migration {
+                sequence_number: SequenceNumber::from(2),
+                name: "posts".to_string(),
+            },
+        ));
+
+        assert_eq!(
+            reported(duplicate_sequence_numbers(&migrations)),
+            vec![(
+                "migrations/duplicateSequenceNumber".to_string(),
+                "2_comments.sql".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn reports_gaps_in_counters_only() {
+        assert_eq!(
+            reported(sequence_gaps(&migrations(&[
+                "1_users", "2_posts", "5_tags"
+            ]))),
+            vec![(
+                "migrations/sequenceGap".to_string(),
+                "5_tags.sql".to_string()
+            )]
+        );
+        assert!(sequence_gaps(&migrations(&["20240101_users", "20240301_posts"])).is_empty());
+        assert!(sequence_gaps(&migrations(&["1_users", "1.1_posts", "3_tags"])).is_empty());
+    }
+
+    #[test]
+    fn reports_unmatched_files() {
+        let migrations = migrations(&["1_users"]);
+        let files = vec![PathBuf::from("1_users.sql"), PathBuf::from("seed.sql")];
+
+        assert_eq!(
+            reported(unmatched_files(&files, &migrations)),
+            vec![(
+                "migrations/unmatchedFile".to_string(),
+                "seed.sql".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn reports_migrations_added_out_of_order() {
+        let migrations = migrations(&["1_users", "2_posts", "3_tags", "4_comments"]);
+        let added = HashMap::from([
+            (PathBuf::from("1_users.sql"), 100),
+            (PathBuf::from("2_posts.sql"), 300),
+            (PathBuf::from("3_tags.sql"), 200),
+        ]);
+
+        // 2_posts was added after 3_tags, and 4_comments isn't committed yet
+        assert_eq!(
+            reported(out_of_order(&migrations, &added)),
+            vec![(
+                "migrations/outOfOrder".to_string(),
+                "2_posts.sql".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn reads_added_and_modified_files_from_the_vcs() {
+        let migrations_dir = PathBuf::from("migrations");
+        let mut fs = MemoryFileSystem::default();
+        assert!(added_times(&fs, &migrations_dir).is_none());
+        assert!(modified_files(&fs, &migrations_dir, "main").is_none());
+
+        fs.set_added_files(vec![
+            ("2_posts.sql".to_string(), 300),
+            ("1_users.sql".to_string(), 200),
+            // added, removed and added again
+            ("2_posts.sql".to_string(), 100),
+        ]);
+        fs.set_modified_files(vec!["1_users.sql".to_string()]);
+
+        assert_eq!(
+            added_times(&fs, &migrations_dir),
+            Some(HashMap::from([
+                (PathBuf::from("1_users.sql"), 200),
+                (PathBuf::from("2_posts.sql"), 300),
+            ]))
+        );
+        assert_eq!(
+            modified_files(&fs, &migrations_dir, "main"),
+            Some([PathBuf::from("1_users.sql")].into())
+        );
+    }
+}
@@ -90,6 +90,10 @@ impl ParsedDocument {
         }
     }
 
+    pub fn version(&self) -> i32 {
+        self.doc.version
+    }
+
     pub fn get_document_content(&self) -> &str {
         &self.doc.content
     }
@@ -313,6 +317,7 @@ impl<'a> StatementMapper<'a> for SyncDiagnosticsMapper {
     type Output = (
         StatementId,
         TextRange,
+        String,
         Option<pgt_query_ext::NodeEnum>,
         Option<SyntaxDiagnostic>,
     );
@@ -331,7 +336,7 @@ impl<'a> StatementMapper<'a> for SyncDiagnosticsMapper {
             Err(diag) => (None, Some(diag.clone())),
         };
 
-        (id, range, ast_option, diagnostics)
+        (id, range, content.to_string(), ast_option, diagnostics)
     }
 }
 
@@ -0,0 +1,724 @@
+use pgt_completions::{FunctionVariable, FunctionVariableKind};
+use pgt_lexer::{SyntaxKind, Token, TokenType, WHITESPACE_TOKENS};
+use pgt_schema_cache::SchemaCache;
+use pgt_text_size::{TextRange, TextSize};
+
+use super::sql_function::{get_fn_body, parse_name};
+
+/// Keywords that start a SQL statement within a PL/pgSQL block.
+const STATEMENT_KEYWORDS: &[&str] = &["select", "insert", "update", "delete", "with", "perform"];
+
+/// A SQL statement embedded in the body of a PL/pgSQL function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedStatement {
+    /// The range of the statement within the function body.
+    pub range: TextRange,
+
+    /// The text of the statement, rewritten so that it can be parsed as plain SQL:
+    /// `PERFORM` becomes `SELECT` and `INTO` targets are blanked out.
+    /// Has the same length as `range`.
+    pub content: String,
+}
+
+/// The embedded statement the cursor is in, together with the variables that are in scope.
+#[derive(Debug)]
+pub struct PlPgSqlCompletionTarget {
+    /// The range of the embedded statement within the `CREATE FUNCTION` statement.
+    pub range: TextRange,
+    pub content: String,
+    pub tree: tree_sitter::Tree,
+    pub variables: Vec<FunctionVariable>,
+}
+
+/// Returns the embedded statement at `position` if `ast` is a PL/pgSQL function.
+///
+/// `position` is relative to the start of the `CREATE FUNCTION` statement.
+pub fn get_completion_target(
+    ast: &pgt_query_ext::NodeEnum,
+    content: &str,
+    position: TextSize,
+    schema_cache: &SchemaCache,
+) -> Option<PlPgSqlCompletionTarget> {
+    let pgt_query_ext::NodeEnum::CreateFunctionStmt(create_fn) = ast else {
+        return None;
+    };
+
+    let body = get_fn_body(ast, content, "plpgsql")?;
+
+    if !body.range.contains_inclusive(position) {
+        return None;
+    }
+
+    // the statement at the cursor is usually still being typed, in which case the function
+    // doesn't parse and we have to split the body ourselves
+    let statement = parsed_statements(content, &body.body)
+        .unwrap_or_else(|| split_statements(&body.body))
+        .into_iter()
+        .find(|s| s.range.contains_inclusive(position - body.range.start()))?;
+
+    let range = statement.range + body.range.start();
+
+    let mut variables = parameters(create_fn);
+
+    for variable in declared_variables(content, body.range, range) {
+        if !variables.iter().any(|v| v.name == variable.name) {
+            variables.push(variable);
+        }
+    }
+
+    if returns_trigger(create_fn) {
+        variables.extend(trigger_records(create_fn, schema_cache));
+    }
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(tree_sitter_sql::language())
+        .expect("Error loading sql language");
+
+    let tree = parser.parse(&statement.content, None)?;
+
+    Some(PlPgSqlCompletionTarget {
+        range,
+        content: statement.content,
+        tree,
+        variables,
+    })
+}
+
+/// Finds the SQL statements within the body of a PL/pgSQL function with the PL/pgSQL parser.
+///
+/// `content` is the `CREATE FUNCTION` statement and `body` its body. Returns `None` if the
+/// function doesn't parse.
+fn parsed_statements(content: &str, body: &str) -> Option<Vec<EmbeddedStatement>> {
+    let parsed = pgt_query_ext::parse_plpgsql(content).ok()?;
+
+    let mut queries = vec![];
+    collect_queries(&parsed, None, false, &mut queries);
+
+    let tokens = significant_tokens(body);
+
+    let mut statements = queries
+        .into_iter()
+        .filter_map(|(query, line, perform)| {
+            let (start, end) = locate_query(body, query, line, perform)?;
+            let first = tokens.iter().position(|t| t.span.start() >= start)?;
+            let count = tokens[first..]
+                .iter()
+                .take_while(|t| t.span.end() <= end)
+                .count();
+
+            (count > 0).then(|| embedded_statement(body, &tokens[first..first + count], end))
+        })
+        .collect::<Vec<_>>();
+
+    statements.sort_by_key(|s| s.range.start());
+
+    Some(statements)
+}
+
+/// Collects the SQL statements of the parsed function, e.g. the query of an `execsql` or a
+/// `FOR ... IN <query> LOOP`, with the line they're on and whether they're a `PERFORM`.
+///
+/// Expressions, such as conditions or the string passed to `EXECUTE`, are skipped.
+fn collect_queries<'a>(
+    value: &'a serde_json::Value,
+    line: Option<i64>,
+    perform: bool,
+    queries: &mut Vec<(&'a str, i64, bool)>,
+) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            let line = fields
+                .get("lineno")
+                .and_then(serde_json::Value::as_i64)
+                .or(line);
+
+            if let Some(expr) = fields.get("PLpgSQL_expr") {
+                // parse mode 0 is a complete SQL statement
+                let is_statement =
+                    expr.get("parseMode").and_then(serde_json::Value::as_i64) == Some(0);
+                let query = expr.get("query").and_then(serde_json::Value::as_str);
+
+                if let (true, Some(query), Some(line)) = (is_statement, query, line) {
+                    queries.push((query, line, perform));
+                }
+                return;
+            }
+
+            for (name, field) in fields {
+                collect_queries(
+                    field,
+                    line,
+                    perform || name == "PLpgSQL_stmt_perform",
+                    queries,
+                );
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_queries(item, line, perform, queries);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns where the query of a statement on `line` of the body starts and ends.
+///
+/// The parser copies the query from the body, but blanks out `INTO` clauses and replaces
+/// `PERFORM` with `SELECT `. For the latter, the range starts at `PERFORM`.
+fn locate_query(body: &str, query: &str, line: i64, perform: bool) -> Option<(TextSize, TextSize)> {
+    let query = if perform {
+        query.strip_prefix("SELECT ")?
+    } else {
+        query
+    };
+
+    // lines count from 1
+    let line_start = match usize::try_from(line).ok()?.checked_sub(2) {
+        Some(newlines) => body.match_indices('\n').nth(newlines)?.0 + 1,
+        None => 0,
+    };
+
+    let start = (line_start..body.len()).find(|&start| {
+        body.as_bytes()
+            .get(start..start + query.len())
+            .is_some_and(|text| {
+                text.iter()
+                    .zip(query.as_bytes())
+                    .all(|(text, query)| text == query || *query == b' ')
+            })
+    })?;
+    let end = start + query.len();
+
+    let start = if perform {
+        body[line_start..start]
+            .to_ascii_lowercase()
+            .rfind("perform")?
+            + line_start
+    } else {
+        start
+    };
+
+    Some((
+        TextSize::try_from(start).ok()?,
+        TextSize::try_from(end).ok()?,
+    ))
+}
+
+/// Finds the SQL statements within a PL/pgSQL function body.
+///
+/// Used if the function doesn't parse. A statement starts with one of the `STATEMENT_KEYWORDS` and ends before the next `;`,
+/// the `LOOP` of a `FOR ... IN <query> LOOP`, or the `END` of the surrounding block.
+/// The latter allows us to find statements that are still being typed.
+pub fn split_statements(body: &str) -> Vec<EmbeddedStatement> {
+    let tokens = significant_tokens(body);
+
+    let mut statements = vec![];
+
+    let mut depth = 0usize;
+    let mut case_depth = 0usize;
+    let mut start: Option<usize> = None;
+
+    for (idx, token) in tokens.iter().enumerate() {
+        match token.kind {
+            SyntaxKind::Ascii40 => depth += 1,
+            SyntaxKind::Ascii41 => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        if depth > 0 {
+            continue;
+        }
+
+        let keyword = token.text.to_ascii_lowercase();
+
+        match start {
+            None => {
+                if STATEMENT_KEYWORDS.contains(&keyword.as_str()) {
+                    start = Some(idx);
+                    case_depth = 0;
+                }
+            }
+            Some(first) => {
+                let ends = token.kind == SyntaxKind::Ascii59
+                    || keyword == "loop"
+                    || (keyword == "end" && case_depth == 0);
+
+                if keyword == "case" {
+                    case_depth += 1;
+                } else if keyword == "end" && case_depth > 0 {
+                    case_depth -= 1;
+                }
+
+                if ends {
+                    statements.push(embedded_statement(
+                        body,
+                        &tokens[first..idx],
+                        token.span.start(),
+                    ));
+                    start = None;
+                }
+            }
+        }
+    }
+
+    if let Some(first) = start {
+        statements.push(embedded_statement(
+            body,
+            &tokens[first..],
+            TextSize::of(body),
+        ));
+    }
+
+    statements
+}
+
+fn significant_tokens(text: &str) -> Vec<Token> {
+    pgt_lexer::lex(text)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|t| !WHITESPACE_TOKENS.contains(&t.kind) && t.kind != SyntaxKind::Eof)
+        .collect()
+}
+
+fn embedded_statement(body: &str, tokens: &[Token], end: TextSize) -> EmbeddedStatement {
+    let start = tokens[0].span.start();
+    let range = TextRange::new(start, end);
+
+    let mut content = body[range].to_string();
+
+    let relative = |span: TextRange| -> std::ops::Range<usize> {
+        (span - start).start().into()..(span - start).end().into()
+    };
+
+    // `perform` has the same length as `select `
+    if tokens[0].text.eq_ignore_ascii_case("perform") {
+        content.replace_range(relative(tokens[0].span), "select ");
+    }
+
+    let mut depth = 0usize;
+    for (idx, token) in tokens.iter().enumerate() {
+        match token.kind {
+            SyntaxKind::Ascii40 => depth += 1,
+            SyntaxKind::Ascii41 => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        if depth > 0 || !token.text.eq_ignore_ascii_case("into") {
+            continue;
+        }
+
+        // `insert into` and `merge into` are plain SQL
+        if idx > 0
+            && ["insert", "merge"]
+                .iter()
+                .any(|k| tokens[idx - 1].text.eq_ignore_ascii_case(k))
+        {
+            continue;
+        }
+
+        let end = into_targets_end(&tokens[idx..]);
+        let blanked = relative(TextRange::new(token.span.start(), end));
+        let len = blanked.len();
+        content.replace_range(blanked, &" ".repeat(len));
+    }
+
+    EmbeddedStatement { range, content }
+}
+
+/// Returns the end of an `INTO [STRICT] target [, ...]` clause.
+/// `tokens` starts with the `INTO` keyword.
+fn into_targets_end(tokens: &[Token]) -> TextSize {
+    let mut end = tokens[0].span.end();
+    let mut rest = tokens[1..].iter().peekable();
+
+    if rest
+        .peek()
+        .is_some_and(|t| t.text.eq_ignore_ascii_case("strict"))
+    {
+        end = rest.next().unwrap().span.end();
+    }
+
+    while let Some(target) = rest.next_if(|t| is_identifier(t)) {
+        end = target.span.end();
+
+        if rest
+            .next_if(|t| matches!(t.kind, SyntaxKind::Ascii44 | SyntaxKind::Ascii46))
+            .is_none()
+        {
+            break;
+        }
+    }
+
+    end
+}
+
+fn is_identifier(token: &Token) -> bool {
+    token.kind == SyntaxKind::Ident
+        || matches!(
+            token.token_type,
+            TokenType::UnreservedKeyword
+                | TokenType::ColNameKeyword
+                | TokenType::TypeFuncNameKeyword
+        )
+}
+
+fn parameters(create_fn: &pgt_query_ext::protobuf::CreateFunctionStmt) -> Vec<FunctionVariable> {
+    create_fn
+        .parameters
+        .iter()
+        .filter_map(|param| match &param.node {
+            Some(pgt_query_ext::NodeEnum::FunctionParameter(p)) if !p.name.is_empty() => {
+                Some(FunctionVariable {
+                    name: p.name.clone(),
+                    type_name: p
+                        .arg_type
+                        .as_ref()
+                        .and_then(|t| parse_name(&t.names))
+                        .map(|(_, name)| name),
+                    kind: FunctionVariableKind::Parameter,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collects the variables from the `DECLARE` sections of the function.
+///
+/// The statement at `statement_range` is usually incomplete, so it is replaced with a
+/// valid one before handing the function to the PL/pgSQL parser. If the function still
+/// does not parse, we fall back to reading the `DECLARE` section of the outermost block.
+fn declared_variables(
+    content: &str,
+    body_range: TextRange,
+    statement_range: TextRange,
+) -> Vec<FunctionVariable> {
+    let mut source = content.to_string();
+    let replaced: std::ops::Range<usize> = statement_range.into();
+    let placeholder = format!("{:<width$}", "select", width = replaced.len());
+    source.replace_range(replaced, &placeholder);
+
+    match pgt_query_ext::parse_plpgsql(&source) {
+        Ok(parsed) => variables_from_plpgsql_ast(&parsed),
+        Err(_) => variables_from_declare_section(&content[body_range]),
+    }
+}
+
+fn variables_from_plpgsql_ast(parsed: &serde_json::Value) -> Vec<FunctionVariable> {
+    let datums = parsed
+        .as_array()
+        .and_then(|functions| functions.first())
+        .and_then(|f| f.get("PLpgSQL_function"))
+        .and_then(|f| f.get("datums"))
+        .and_then(|d| d.as_array());
+
+    let Some(datums) = datums else {
+        return vec![];
+    };
+
+    datums
+        .iter()
+        .filter_map(|datum| {
+            let (kind, datum) = ["PLpgSQL_var", "PLpgSQL_rec", "PLpgSQL_row"]
+                .iter()
+                .find_map(|kind| datum.get(kind).map(|d| (*kind, d)))?;
+
+            // implicit variables such as `found` or the function's parameters have no line number
+            datum.get("lineno")?;
+
+            let name = datum.get("refname")?.as_str()?;
+
+            // rows created for `INTO a, b` are unnamed
+            if name.starts_with('(') {
+                return None;
+            }
+
+            let type_name = match kind {
+                "PLpgSQL_var" => datum
+                    .get("datatype")
+                    .and_then(|t| t.get("PLpgSQL_type"))
+                    .and_then(|t| t.get("typname"))
+                    .and_then(|t| t.as_str())
+                    .filter(|t| *t != "UNKNOWN")
+                    .map(|t| t.to_string()),
+                _ => Some("record".to_string()),
+            };
+
+            Some(FunctionVariable {
+                name: name.to_string(),
+                type_name,
+                kind: FunctionVariableKind::Declared,
+            })
+        })
+        .collect()
+}
+
+/// Reads `name [CONSTANT] type [...];` declarations between the first `DECLARE` and `BEGIN`.
+fn variables_from_declare_section(body: &str) -> Vec<FunctionVariable> {
+    let tokens = significant_tokens(body);
+
+    let Some(declare) = tokens
+        .iter()
+        .position(|t| t.text.eq_ignore_ascii_case("declare"))
+    else {
+        return vec![];
+    };
+
+    let section = tokens[declare + 1..]
+        .iter()
+        .take_while(|t| !t.text.eq_ignore_ascii_case("begin"))
+        .collect::<Vec<_>>();
+
+    section
+        .split(|t| t.kind == SyntaxKind::Ascii59)
+        .filter_map(|declaration| {
+            let (name, rest) = declaration.split_first()?;
+
+            let type_tokens = rest
+                .iter()
+                .skip_while(|t| t.text.eq_ignore_ascii_case("constant"))
+                .take_while(|t| {
+                    ![":=", "=", "default", "not", "collate", "for", "alias"]
+                        .iter()
+                        .any(|stop| t.text.eq_ignore_ascii_case(stop))
+                })
+                .collect::<Vec<_>>();
+
+            let type_name = match (type_tokens.first(), type_tokens.last()) {
+                (Some(first), Some(last)) => {
+                    Some(body[TextRange::new(first.span.start(), last.span.end())].to_string())
+                }
+                _ => None,
+            };
+
+            Some(FunctionVariable {
+                name: name.text.clone(),
+                type_name,
+                kind: FunctionVariableKind::Declared,
+            })
+        })
+        .collect()
+}
+
+fn returns_trigger(create_fn: &pgt_query_ext::protobuf::CreateFunctionStmt) -> bool {
+    create_fn
+        .return_type
+        .as_ref()
+        .and_then(|t| parse_name(&t.names))
+        .is_some_and(|(_, name)| name == "trigger")
+}
+
+/// `NEW` and `OLD` are records of the table the trigger is defined on.
+/// We can only resolve their columns if the function is used by triggers on a single table.
+fn trigger_records(
+    create_fn: &pgt_query_ext::protobuf::CreateFunctionStmt,
+    schema_cache: &SchemaCache,
+) -> Vec<FunctionVariable> {
+    let fn_name = parse_name(&create_fn.funcname).map(|(_, name)| name);
+
+    let mut tables = schema_cache
+        .triggers
+        .iter()
+        .filter(|t| fn_name.as_ref().is_some_and(|name| *name == t.proc_name))
+        .map(|t| (t.schema_name.clone(), t.table_name.clone()))
+        .collect::<Vec<_>>();
+
+    tables.sort();
+    tables.dedup();
+
+    let table = match tables.as_slice() {
+        [table] => Some(table.clone()),
+        _ => None,
+    };
+
+    ["new", "old"]
+        .into_iter()
+        .map(|name| FunctionVariable {
+            name: name.to_string(),
+            type_name: None,
+            kind: FunctionVariableKind::TriggerRecord {
+                table: table.clone(),
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pgt_completions::FunctionVariableKind;
+    use pgt_schema_cache::SchemaCache;
+    use pgt_text_size::TextSize;
+
+    use super::{
+        get_completion_target, get_fn_body, parsed_statements, split_statements,
+        variables_from_declare_section,
+    };
+
+    #[test]
+    fn splits_function_body_into_statements() {
+        let body = r#"
+declare
+    v_total numeric;
+begin
+    select sum(total) into strict v_total from orders where user_id = p_user_id;
+
+    for r in select * from users loop
+        perform notify_user(r.id);
+    end loop;
+
+    update users set total = case when v_total > 0 then v_total else 0 end
+    where id = p_user_id;
+end;
+"#;
+
+        let statements = split_statements(body);
+
+        let contents = statements
+            .iter()
+            .map(|s| s.content.trim_end())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contents,
+            vec![
+                "select sum(total)                     from orders where user_id = p_user_id",
+                "select * from users",
+                "select  notify_user(r.id)",
+                "update users set total = case when v_total > 0 then v_total else 0 end\n    where id = p_user_id",
+            ]
+        );
+
+        for statement in statements {
+            assert_eq!(statement.content.len(), usize::from(statement.range.len()));
+        }
+    }
+
+    #[test]
+    fn takes_statements_from_the_plpgsql_parser() {
+        let sql = r#"create function f(p int4) returns int4 language plpgsql as $$
+declare
+    v int4;
+    c cursor for select * from users;
+begin
+    select count(*) into v from orders where user_id = p;
+    begin
+        perform notify(p);
+    exception when others then
+        null;
+    end;
+    execute 'update users set total = 0; ' || 'select 1';
+    return v;
+end;
+$$;"#;
+
+        let ast = pgt_query_ext::parse(sql).unwrap();
+        let body = get_fn_body(&ast, sql, "plpgsql").unwrap();
+
+        let statements = parsed_statements(sql, &body.body).expect("the function parses");
+
+        let contents = statements
+            .iter()
+            .map(|s| s.content.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contents,
+            vec![
+                "select * from users",
+                "select count(*)        from orders where user_id = p",
+                "select  notify(p)",
+            ]
+        );
+
+        for statement in statements {
+            assert_eq!(statement.content.len(), usize::from(statement.range.len()));
+        }
+    }
+
+    #[test]
+    fn finds_incomplete_statements() {
+        let body = "\nbegin\n    select * from \nend;\n";
+
+        let statements = split_statements(body);
+
+        assert_eq!(statements.len(), 1);
+        assert_eq!(statements[0].content, "select * from \n");
+    }
+
+    #[test]
+    fn reads_declare_section() {
+        let body = r#"
+declare
+    v_total constant numeric(10, 2) := 0;
+    v_name text not null default 'x';
+    c_users cursor for select * from users;
+begin
+    return;
+end;
+"#;
+
+        let variables = variables_from_declare_section(body);
+
+        let variables = variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.type_name.as_deref()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            variables,
+            vec![
+                ("v_total", Some("numeric(10, 2)")),
+                ("v_name", Some("text")),
+                ("c_users", Some("cursor")),
+            ]
+        );
+    }
+
+    #[test]
+    fn completion_target_contains_variables() {
+        let sql = r#"create function count_orders(p_user_id int4) returns int4 language plpgsql as $$
+declare
+    v_count int4;
+begin
+    select count(*) into v_count from orders where user_id = ;
+    return v_count;
+end;
+$$;"#;
+
+        let position = TextSize::new(sql.find("= ;").unwrap() as u32 + 2);
+
+        let ast = pgt_query_ext::parse(sql).unwrap();
+
+        let target = get_completion_target(&ast, sql, position, &SchemaCache::default())
+            .expect("should find the embedded statement");
+
+        assert_eq!(&sql[target.range][..6], "select");
+        assert!(target.content.contains("from orders where user_id = "));
+
+        let variables = target
+            .variables
+            .iter()
+            .map(|v| (v.name.as_str(), v.kind.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            variables,
+            vec![
+                ("p_user_id", FunctionVariableKind::Parameter),
+                ("v_count", FunctionVariableKind::Declared),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_sql_functions() {
+        let sql = "create function one() returns int4 language sql as $$ select 1 $$;";
+
+        let ast = pgt_query_ext::parse(sql).unwrap();
+        let position = TextSize::new(sql.find("select").unwrap() as u32);
+
+        assert!(get_completion_target(&ast, sql, position, &SchemaCache::default()).is_none());
+    }
+}
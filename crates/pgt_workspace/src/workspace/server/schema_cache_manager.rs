@@ -44,4 +44,9 @@ impl SchemaCacheManager {
 
         Ok(Arc::clone(&schema_cache))
     }
+
+    /// Drops all cached schemas. They are loaded again on the next call to [Self::load].
+    pub fn clear(&self) {
+        self.schemas.clear();
+    }
 }
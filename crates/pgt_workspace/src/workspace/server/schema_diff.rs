@@ -0,0 +1,945 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use pgt_schema_cache::{ColumnClassKind, PolicyCommand, SchemaCache, TableKind};
+
+use crate::features::schema_diff::{
+    ColumnSnapshot, FunctionSnapshot, PolicySnapshot, SchemaChange, SchemaChangeKind,
+    SchemaObjectKind, SchemaSnapshot, TableSnapshot, TriggerSnapshot, TypeAttributeSnapshot,
+    TypeSnapshot,
+};
+
+/// Takes a snapshot of the objects of the schema cache that are compared. Only the objects of
+/// `schemas` are kept, or of all schemas but the system ones if it's empty.
+pub(crate) fn snapshot(cache: &SchemaCache, schemas: &[String]) -> SchemaSnapshot {
+    let included = |schema: &str| {
+        if schemas.is_empty() {
+            !is_system_schema(schema)
+        } else {
+            schemas.iter().any(|included| included == schema)
+        }
+    };
+    let type_names: HashMap<i64, &str> = cache
+        .types
+        .iter()
+        .map(|t| (t.id, t.format.as_str()))
+        .collect();
+
+    let mut snapshot = SchemaSnapshot {
+        types: cache
+            .types
+            .iter()
+            // array types are created along with their element type
+            .filter(|t| included(&t.schema) && !t.format.ends_with("[]"))
+            .map(|t| TypeSnapshot {
+                schema: t.schema.clone(),
+                name: t.name.clone(),
+                enum_values: t.enums.values.clone(),
+                attributes: t
+                    .attributes
+                    .attrs
+                    .iter()
+                    .map(|attribute| TypeAttributeSnapshot {
+                        name: attribute.name.clone(),
+                        data_type: type_names
+                            .get(&attribute.type_id)
+                            .map(ToString::to_string)
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+        tables: cache
+            .tables
+            .iter()
+            .filter(|table| included(&table.schema))
+            .map(|table| TableSnapshot {
+                schema: table.schema.clone(),
+                name: table.name.clone(),
+                kind: match table.table_kind {
+                    TableKind::Ordinary => "table",
+                    TableKind::Partitioned => "partitioned table",
+                    TableKind::View => "view",
+                    TableKind::MaterializedView => "materialized view",
+                }
+                .to_string(),
+                rls_enabled: table.rls_enabled,
+            })
+            .collect(),
+        columns: cache
+            .columns
+            .iter()
+            // the columns of views follow from their definition
+            .filter(|column| {
+                included(&column.schema_name)
+                    && matches!(
+                        column.class_kind,
+                        ColumnClassKind::OrdinaryTable | ColumnClassKind::PartitionedTable
+                    )
+            })
+            .map(|column| {
+                let mut data_type = type_names
+                    .get(&column.type_id)
+                    .map(ToString::to_string)
+                    .or_else(|| column.type_name.clone())
+                    .unwrap_or_default();
+                if let Some(length) = column.varchar_length {
+                    data_type = format!("{data_type}({length})");
+                }
+
+                ColumnSnapshot {
+                    schema: column.schema_name.clone(),
+                    table: column.table_name.clone(),
+                    name: column.name.clone(),
+                    data_type,
+                    nullable: column.is_nullable,
+                    primary_key: column.is_primary_key,
+                    default: column.default_expr.clone(),
+                }
+            })
+            .collect(),
+        functions: cache
+            .functions
+            .iter()
+            .filter(|function| included(&function.schema))
+            .map(|function| FunctionSnapshot {
+                schema: function.schema.clone(),
+                name: function.name.clone(),
+                identity_arguments: function.identity_argument_types.clone().unwrap_or_default(),
+                definition: function.definition.clone(),
+            })
+            .collect(),
+        policies: cache
+            .policies
+            .iter()
+            .filter(|policy| included(&policy.schema_name))
+            .map(|policy| PolicySnapshot {
+                schema: policy.schema_name.clone(),
+                table: policy.table_name.clone(),
+                name: policy.name.clone(),
+                permissive: policy.is_permissive,
+                command: match policy.command {
+                    PolicyCommand::Select => "select",
+                    PolicyCommand::Insert => "insert",
+                    PolicyCommand::Update => "update",
+                    PolicyCommand::Delete => "delete",
+                    PolicyCommand::All => "all",
+                }
+                .to_string(),
+                roles: policy.role_names.clone(),
+                using: policy.security_qualification.clone(),
+                with_check: policy.with_check.clone(),
+            })
+            .collect(),
+        triggers: cache
+            .triggers
+            .iter()
+            .filter(|trigger| included(&trigger.schema_name))
+            .map(|trigger| TriggerSnapshot {
+                schema: trigger.schema_name.clone(),
+                table: trigger.table_name.clone(),
+                name: trigger.name.clone(),
+                definition: trigger.definition.clone(),
+            })
+            .collect(),
+    };
+
+    snapshot.types.sort_by_key(type_key);
+    snapshot.tables.sort_by_key(table_key);
+    snapshot.columns.sort_by_key(column_key);
+    snapshot.functions.sort_by_key(function_key);
+    snapshot.policies.sort_by_key(policy_key);
+    snapshot.triggers.sort_by_key(trigger_key);
+    snapshot
+}
+
+/// Keeps the objects of `schemas` in a snapshot taken earlier, or all of them if it's empty
+pub(crate) fn retain_schemas(snapshot: &mut SchemaSnapshot, schemas: &[String]) {
+    if schemas.is_empty() {
+        return;
+    }
+    let included = |schema: &String| schemas.contains(schema);
+
+    snapshot.types.retain(|t| included(&t.schema));
+    snapshot.tables.retain(|table| included(&table.schema));
+    snapshot.columns.retain(|column| included(&column.schema));
+    snapshot
+        .functions
+        .retain(|function| included(&function.schema));
+    snapshot.policies.retain(|policy| included(&policy.schema));
+    snapshot
+        .triggers
+        .retain(|trigger| included(&trigger.schema));
+}
+
+fn is_system_schema(schema: &str) -> bool {
+    schema == "pg_catalog"
+        || schema == "information_schema"
+        || schema.starts_with("pg_toast")
+        || schema.starts_with("pg_temp")
+}
+
+/// Compares two snapshots, and returns the objects that differ with the DDL that turns `from`
+/// into `to`
+pub(crate) fn diff(from: &SchemaSnapshot, to: &SchemaSnapshot) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+
+    for (name, from_type, to_type) in differing(&from.types, &to.types, type_key) {
+        changes.push(diff_type(name, from_type, to_type));
+    }
+
+    let tables = differing(&from.tables, &to.tables, table_key);
+    // the columns, policies and triggers of tables that are only in one of the schemas are
+    // created and dropped along with the table
+    let missing_tables: BTreeSet<String> = tables
+        .iter()
+        .filter(|(_, _, to_table)| to_table.is_none())
+        .map(|(name, _, _)| name.clone())
+        .collect();
+    let only_in_one: BTreeSet<String> = tables
+        .iter()
+        .filter(|(_, from_table, to_table)| from_table.is_none() || to_table.is_none())
+        .map(|(name, _, _)| name.clone())
+        .collect();
+    for (name, from_table, to_table) in tables {
+        changes.push(diff_table(name, from_table, to_table, &to.columns));
+    }
+
+    for (name, from_column, to_column) in differing(&from.columns, &to.columns, column_key) {
+        let column = from_column
+            .or(to_column)
+            .expect("one of the columns is set");
+        if !only_in_one.contains(&qualified(&column.schema, &column.table)) {
+            changes.push(diff_column(name, from_column, to_column));
+        }
+    }
+
+    for (name, from_function, to_function) in
+        differing(&from.functions, &to.functions, function_key)
+    {
+        changes.push(diff_function(name, from_function, to_function));
+    }
+
+    for (name, from_policy, to_policy) in differing(&from.policies, &to.policies, policy_key) {
+        let policy = from_policy
+            .or(to_policy)
+            .expect("one of the policies is set");
+        if !missing_tables.contains(&qualified(&policy.schema, &policy.table)) {
+            changes.push(diff_policy(name, from_policy, to_policy));
+        }
+    }
+
+    for (name, from_trigger, to_trigger) in differing(&from.triggers, &to.triggers, trigger_key) {
+        let trigger = from_trigger
+            .or(to_trigger)
+            .expect("one of the triggers is set");
+        if !missing_tables.contains(&qualified(&trigger.schema, &trigger.table)) {
+            changes.push(diff_trigger(name, from_trigger, to_trigger));
+        }
+    }
+
+    changes
+}
+
+/// Returns the objects, by name, that are only in one of the lists or differ between them
+fn differing<'a, T: PartialEq>(
+    from: &'a [T],
+    to: &'a [T],
+    key: impl Fn(&T) -> String,
+) -> Vec<(String, Option<&'a T>, Option<&'a T>)> {
+    let mut objects: BTreeMap<String, (Option<&T>, Option<&T>)> = BTreeMap::new();
+    for object in from {
+        objects.entry(key(object)).or_default().0 = Some(object);
+    }
+    for object in to {
+        objects.entry(key(object)).or_default().1 = Some(object);
+    }
+
+    objects
+        .into_iter()
+        .filter(|(_, (from, to))| from != to)
+        .map(|(name, (from, to))| (name, from, to))
+        .collect()
+}
+
+fn change(
+    object: SchemaObjectKind,
+    name: String,
+    from_exists: bool,
+    to_exists: bool,
+) -> SchemaChange {
+    let kind = match (from_exists, to_exists) {
+        (true, false) => SchemaChangeKind::Missing,
+        (false, true) => SchemaChangeKind::Extra,
+        _ => SchemaChangeKind::Changed,
+    };
+
+    SchemaChange {
+        kind,
+        object,
+        name,
+        details: Vec::new(),
+        ddl: Vec::new(),
+    }
+}
+
+/// Adds a detail for the field if it differs between the two versions of the object
+fn compare<T: PartialEq + std::fmt::Debug>(
+    details: &mut Vec<String>,
+    field: &str,
+    from: &T,
+    to: &T,
+) -> bool {
+    if from != to {
+        details.push(format!("{field}: {from:?} → {to:?}"));
+    }
+    from != to
+}
+
+fn diff_type(name: String, from: Option<&TypeSnapshot>, to: Option<&TypeSnapshot>) -> SchemaChange {
+    let mut change = change(SchemaObjectKind::Type, name, from.is_some(), to.is_some());
+
+    match (from, to) {
+        (Some(t), None) => change
+            .ddl
+            .push(format!("drop type {};", qualified(&t.schema, &t.name))),
+        (None, Some(t)) => change.ddl.push(create_type(t)),
+        (Some(from), Some(to)) => {
+            let name = qualified(&to.schema, &to.name);
+            if compare(
+                &mut change.details,
+                "values",
+                &from.enum_values,
+                &to.enum_values,
+            ) {
+                for value in to
+                    .enum_values
+                    .iter()
+                    .filter(|value| !from.enum_values.contains(value))
+                {
+                    change
+                        .ddl
+                        .push(format!("alter type {name} add value {};", literal(value)));
+                }
+                if from
+                    .enum_values
+                    .iter()
+                    .any(|value| !to.enum_values.contains(value))
+                {
+                    change.ddl.push(format!(
+                        "-- values can't be removed from {name}, the type has to be recreated"
+                    ));
+                }
+            }
+            if compare(
+                &mut change.details,
+                "attributes",
+                &from.attributes,
+                &to.attributes,
+            ) {
+                for attribute in &from.attributes {
+                    if !to.attributes.iter().any(|a| a.name == attribute.name) {
+                        change.ddl.push(format!(
+                            "alter type {name} drop attribute {};",
+                            ident(&attribute.name)
+                        ));
+                    }
+                }
+                for attribute in &to.attributes {
+                    match from.attributes.iter().find(|a| a.name == attribute.name) {
+                        None => change.ddl.push(format!(
+                            "alter type {name} add attribute {} {};",
+                            ident(&attribute.name),
+                            attribute.data_type
+                        )),
+                        Some(a) if a.data_type != attribute.data_type => change.ddl.push(format!(
+                            "alter type {name} alter attribute {} type {};",
+                            ident(&attribute.name),
+                            attribute.data_type
+                        )),
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        (None, None) => {}
+    }
+
+    change
+}
+
+fn create_type(t: &TypeSnapshot) -> String {
+    let name = qualified(&t.schema, &t.name);
+    if !t.enum_values.is_empty() {
+        let values: Vec<String> = t.enum_values.iter().map(|value| literal(value)).collect();
+        format!("create type {name} as enum ({});", values.join(", "))
+    } else if !t.attributes.is_empty() {
+        let attributes: Vec<String> = t
+            .attributes
+            .iter()
+            .map(|attribute| format!("{} {}", ident(&attribute.name), attribute.data_type))
+            .collect();
+        format!("create type {name} as ({});", attributes.join(", "))
+    } else {
+        format!("-- the definition of the type {name} isn't known, create it by hand")
+    }
+}
+
+fn diff_table(
+    name: String,
+    from: Option<&TableSnapshot>,
+    to: Option<&TableSnapshot>,
+    to_columns: &[ColumnSnapshot],
+) -> SchemaChange {
+    let mut change = change(SchemaObjectKind::Table, name, from.is_some(), to.is_some());
+
+    match (from, to) {
+        (Some(table), None) => change.ddl.push(format!(
+            "drop {} {};",
+            match table.kind.as_str() {
+                "view" => "view",
+                "materialized view" => "materialized view",
+                _ => "table",
+            },
+            qualified(&table.schema, &table.name)
+        )),
+        (None, Some(table)) => {
+            let name = qualified(&table.schema, &table.name);
+            if table.kind.ends_with("view") {
+                change.ddl.push(format!(
+                    "-- the definition of the {} {name} isn't known, create it by hand",
+                    table.kind
+                ));
+            } else {
+                let columns: Vec<&ColumnSnapshot> = to_columns
+                    .iter()
+                    .filter(|column| column.schema == table.schema && column.table == table.name)
+                    .collect();
+                change.ddl.push(create_table(&name, &columns));
+                if table.rls_enabled {
+                    change
+                        .ddl
+                        .push(format!("alter table {name} enable row level security;"));
+                }
+            }
+        }
+        (Some(from), Some(to)) => {
+            let name = qualified(&to.schema, &to.name);
+            if compare(&mut change.details, "kind", &from.kind, &to.kind) {
+                change.ddl.push(format!(
+                    "-- {name} changed from a {} to a {}, recreate it by hand",
+                    from.kind, to.kind
+                ));
+            }
+            if compare(
+                &mut change.details,
+                "row level security",
+                &from.rls_enabled,
+                &to.rls_enabled,
+            ) {
+                let action = if to.rls_enabled { "enable" } else { "disable" };
+                change
+                    .ddl
+                    .push(format!("alter table {name} {action} row level security;"));
+            }
+        }
+        (None, None) => {}
+    }
+
+    change
+}
+
+fn create_table(name: &str, columns: &[&ColumnSnapshot]) -> String {
+    let mut lines: Vec<String> = columns
+        .iter()
+        .map(|column| format!("  {}", column_definition(column)))
+        .collect();
+    let primary_key: Vec<String> = columns
+        .iter()
+        .filter(|column| column.primary_key)
+        .map(|column| ident(&column.name))
+        .collect();
+    if !primary_key.is_empty() {
+        lines.push(format!("  primary key ({})", primary_key.join(", ")));
+    }
+
+    format!("create table {name} (\n{}\n);", lines.join(",\n"))
+}
+
+fn column_definition(column: &ColumnSnapshot) -> String {
+    let mut definition = format!("{} {}", ident(&column.name), column.data_type);
+    if !column.nullable {
+        definition.push_str(" not null");
+    }
+    if let Some(default) = &column.default {
+        definition.push_str(&format!(" default {default}"));
+    }
+    definition
+}
+
+fn diff_column(
+    name: String,
+    from: Option<&ColumnSnapshot>,
+    to: Option<&ColumnSnapshot>,
+) -> SchemaChange {
+    let mut change = change(SchemaObjectKind::Column, name, from.is_some(), to.is_some());
+
+    match (from, to) {
+        (Some(column), None) => change.ddl.push(format!(
+            "alter table {} drop column {};",
+            qualified(&column.schema, &column.table),
+            ident(&column.name)
+        )),
+        (None, Some(column)) => change.ddl.push(format!(
+            "alter table {} add column {};",
+            qualified(&column.schema, &column.table),
+            column_definition(column)
+        )),
+        (Some(from), Some(to)) => {
+            let alter = format!(
+                "alter table {} alter column {}",
+                qualified(&to.schema, &to.table),
+                ident(&to.name)
+            );
+            if compare(&mut change.details, "type", &from.data_type, &to.data_type) {
+                change.ddl.push(format!("{alter} type {};", to.data_type));
+            }
+            if compare(
+                &mut change.details,
+                "nullable",
+                &from.nullable,
+                &to.nullable,
+            ) {
+                let action = if to.nullable { "drop" } else { "set" };
+                change.ddl.push(format!("{alter} {action} not null;"));
+            }
+            if compare(&mut change.details, "default", &from.default, &to.default) {
+                match &to.default {
+                    Some(default) => change.ddl.push(format!("{alter} set default {default};")),
+                    None => change.ddl.push(format!("{alter} drop default;")),
+                }
+            }
+            if compare(
+                &mut change.details,
+                "primary key",
+                &from.primary_key,
+                &to.primary_key,
+            ) {
+                change.ddl.push(format!(
+                    "-- the primary key of {} changed, update it by hand",
+                    qualified(&to.schema, &to.table)
+                ));
+            }
+        }
+        (None, None) => {}
+    }
+
+    change
+}
+
+fn diff_function(
+    name: String,
+    from: Option<&FunctionSnapshot>,
+    to: Option<&FunctionSnapshot>,
+) -> SchemaChange {
+    let mut change = change(
+        SchemaObjectKind::Function,
+        name,
+        from.is_some(),
+        to.is_some(),
+    );
+
+    match (from, to) {
+        (Some(function), None) => change.ddl.push(format!(
+            "drop function {}({});",
+            qualified(&function.schema, &function.name),
+            function.identity_arguments
+        )),
+        (_, Some(function)) => {
+            if from.is_some() {
+                change.details.push("definition differs".to_string());
+            }
+            match &function.definition {
+                Some(definition) => change.ddl.push(format!("{};", definition.trim_end())),
+                None => change.ddl.push(format!(
+                    "-- the definition of the function {} isn't known, create it by hand",
+                    qualified(&function.schema, &function.name)
+                )),
+            }
+        }
+        (None, None) => {}
+    }
+
+    change
+}
+
+fn diff_policy(
+    name: String,
+    from: Option<&PolicySnapshot>,
+    to: Option<&PolicySnapshot>,
+) -> SchemaChange {
+    let mut change = change(SchemaObjectKind::Policy, name, from.is_some(), to.is_some());
+
+    if let (Some(from), Some(to)) = (from, to) {
+        compare(
+            &mut change.details,
+            "permissive",
+            &from.permissive,
+            &to.permissive,
+        );
+        compare(&mut change.details, "command", &from.command, &to.command);
+        compare(&mut change.details, "roles", &from.roles, &to.roles);
+        compare(&mut change.details, "using", &from.using, &to.using);
+        compare(
+            &mut change.details,
+            "with check",
+            &from.with_check,
+            &to.with_check,
+        );
+    }
+    // policies can only be altered partially, so changed ones are recreated
+    if let Some(policy) = from {
+        change.ddl.push(format!(
+            "drop policy {} on {};",
+            ident(&policy.name),
+            qualified(&policy.schema, &policy.table)
+        ));
+    }
+    if let Some(policy) = to {
+        change.ddl.push(create_policy(policy));
+    }
+
+    change
+}
+
+fn create_policy(policy: &PolicySnapshot) -> String {
+    let mut statement = format!(
+        "create policy {} on {} as {} for {}",
+        ident(&policy.name),
+        qualified(&policy.schema, &policy.table),
+        if policy.permissive {
+            "permissive"
+        } else {
+            "restrictive"
+        },
+        policy.command
+    );
+    if !policy.roles.is_empty() {
+        let roles: Vec<String> = policy.roles.iter().map(|role| ident(role)).collect();
+        statement.push_str(&format!(" to {}", roles.join(", ")));
+    }
+    if let Some(using) = &policy.using {
+        statement.push_str(&format!(" using ({using})"));
+    }
+    if let Some(with_check) = &policy.with_check {
+        statement.push_str(&format!(" with check ({with_check})"));
+    }
+    statement.push(';');
+    statement
+}
+
+fn diff_trigger(
+    name: String,
+    from: Option<&TriggerSnapshot>,
+    to: Option<&TriggerSnapshot>,
+) -> SchemaChange {
+    let mut change = change(
+        SchemaObjectKind::Trigger,
+        name,
+        from.is_some(),
+        to.is_some(),
+    );
+
+    if let (Some(from), Some(to)) = (from, to) {
+        compare(
+            &mut change.details,
+            "definition",
+            &from.definition,
+            &to.definition,
+        );
+    }
+    if let Some(trigger) = from {
+        change.ddl.push(format!(
+            "drop trigger {} on {};",
+            ident(&trigger.name),
+            qualified(&trigger.schema, &trigger.table)
+        ));
+    }
+    if let Some(trigger) = to {
+        change.ddl.push(format!("{};", trigger.definition));
+    }
+
+    change
+}
+
+fn type_key(t: &TypeSnapshot) -> String {
+    qualified(&t.schema, &t.name)
+}
+
+fn table_key(table: &TableSnapshot) -> String {
+    qualified(&table.schema, &table.name)
+}
+
+fn column_key(column: &ColumnSnapshot) -> String {
+    format!(
+        "{}.{}",
+        qualified(&column.schema, &column.table),
+        ident(&column.name)
+    )
+}
+
+fn function_key(function: &FunctionSnapshot) -> String {
+    format!(
+        "{}({})",
+        qualified(&function.schema, &function.name),
+        function.identity_arguments
+    )
+}
+
+fn policy_key(policy: &PolicySnapshot) -> String {
+    format!(
+        "{} on {}",
+        ident(&policy.name),
+        qualified(&policy.schema, &policy.table)
+    )
+}
+
+fn trigger_key(trigger: &TriggerSnapshot) -> String {
+    format!(
+        "{} on {}",
+        ident(&trigger.name),
+        qualified(&trigger.schema, &trigger.table)
+    )
+}
+
+fn qualified(schema: &str, name: &str) -> String {
+    format!("{}.{}", ident(schema), ident(name))
+}
+
+/// Quotes the identifier, unless it only has characters that don't need quoting
+fn ident(name: &str) -> String {
+    let is_plain = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if is_plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+fn literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::features::schema_diff::{
+        ColumnSnapshot, PolicySnapshot, SchemaChangeKind, SchemaObjectKind, SchemaSnapshot,
+        TableSnapshot, TriggerSnapshot, TypeSnapshot,
+    };
+
+    use super::diff;
+
+    fn table(name: &str) -> TableSnapshot {
+        TableSnapshot {
+            schema: "public".to_string(),
+            name: name.to_string(),
+            kind: "table".to_string(),
+            rls_enabled: false,
+        }
+    }
+
+    fn column(table: &str, name: &str, data_type: &str) -> ColumnSnapshot {
+        ColumnSnapshot {
+            schema: "public".to_string(),
+            table: table.to_string(),
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable: true,
+            primary_key: false,
+            default: None,
+        }
+    }
+
+    #[test]
+    fn reports_extra_tables_with_their_ddl() {
+        let from = SchemaSnapshot::default();
+        let to = SchemaSnapshot {
+            tables: vec![table("audit")],
+            columns: vec![
+                ColumnSnapshot {
+                    nullable: false,
+                    primary_key: true,
+                    ..column("audit", "id", "bigint")
+                },
+                column("audit", "Message", "text"),
+            ],
+            ..SchemaSnapshot::default()
+        };
+
+        let changes = diff(&from, &to);
+
+        // the columns are part of the table
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, SchemaChangeKind::Extra);
+        assert_eq!(changes[0].object, SchemaObjectKind::Table);
+        assert_eq!(changes[0].name, "public.audit");
+        assert_eq!(
+            changes[0].ddl,
+            vec![
+                "create table public.audit (\n  id bigint not null,\n  \"Message\" text,\n  primary key (id)\n);"
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_changed_and_missing_columns() {
+        let from = SchemaSnapshot {
+            tables: vec![table("users")],
+            columns: vec![
+                column("users", "id", "integer"),
+                column("users", "email", "text"),
+            ],
+            ..SchemaSnapshot::default()
+        };
+        let to = SchemaSnapshot {
+            tables: vec![table("users")],
+            columns: vec![column("users", "id", "bigint")],
+            ..SchemaSnapshot::default()
+        };
+
+        let changes = diff(&from, &to);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].kind, SchemaChangeKind::Missing);
+        assert_eq!(changes[0].name, "public.users.email");
+        assert_eq!(
+            changes[0].ddl,
+            vec!["alter table public.users drop column email;"]
+        );
+        assert_eq!(changes[1].kind, SchemaChangeKind::Changed);
+        assert_eq!(changes[1].details, vec!["type: \"integer\" → \"bigint\""]);
+        assert_eq!(
+            changes[1].ddl,
+            vec!["alter table public.users alter column id type bigint;"]
+        );
+    }
+
+    #[test]
+    fn recreates_changed_policies_and_adds_enum_values() {
+        let policy = PolicySnapshot {
+            schema: "public".to_string(),
+            table: "users".to_string(),
+            name: "read own".to_string(),
+            permissive: true,
+            command: "select".to_string(),
+            roles: vec!["authenticated".to_string()],
+            using: Some("(id = auth.uid())".to_string()),
+            with_check: None,
+        };
+        let status = TypeSnapshot {
+            schema: "public".to_string(),
+            name: "status".to_string(),
+            enum_values: vec!["active".to_string()],
+            attributes: Vec::new(),
+        };
+        let from = SchemaSnapshot {
+            types: vec![status.clone()],
+            tables: vec![table("users")],
+            policies: vec![policy.clone()],
+            ..SchemaSnapshot::default()
+        };
+        let to = SchemaSnapshot {
+            types: vec![TypeSnapshot {
+                enum_values: vec!["active".to_string(), "banned".to_string()],
+                ..status
+            }],
+            tables: vec![table("users")],
+            policies: vec![PolicySnapshot {
+                using: Some("true".to_string()),
+                ..policy
+            }],
+            ..SchemaSnapshot::default()
+        };
+
+        let changes = diff(&from, &to);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            changes[0].ddl,
+            vec!["alter type public.status add value 'banned';"]
+        );
+        assert_eq!(
+            changes[1].ddl,
+            vec![
+                "drop policy \"read own\" on public.users;",
+                "create policy \"read own\" on public.users as permissive for select to authenticated using (true);"
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_policies_of_dropped_tables() {
+        let from = SchemaSnapshot {
+            tables: vec![table("users")],
+            policies: vec![PolicySnapshot {
+                schema: "public".to_string(),
+                table: "users".to_string(),
+                name: "all".to_string(),
+                permissive: true,
+                command: "all".to_string(),
+                roles: Vec::new(),
+                using: None,
+                with_check: None,
+            }],
+            ..SchemaSnapshot::default()
+        };
+
+        let changes = diff(&from, &SchemaSnapshot::default());
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].ddl, vec!["drop table public.users;"]);
+    }
+
+    #[test]
+    fn recreates_triggers_from_their_definition() {
+        let trigger = |definition: &str| TriggerSnapshot {
+            schema: "public".to_string(),
+            table: "users".to_string(),
+            name: "audit_users".to_string(),
+            definition: definition.to_string(),
+        };
+        let from = SchemaSnapshot {
+            tables: vec![table("users")],
+            triggers: vec![trigger(
+                "CREATE TRIGGER audit_users AFTER UPDATE ON public.users FOR EACH ROW EXECUTE FUNCTION audit.log_change('users')",
+            )],
+            ..SchemaSnapshot::default()
+        };
+        let to = SchemaSnapshot {
+            tables: vec![table("users")],
+            triggers: vec![trigger(
+                "CREATE TRIGGER audit_users AFTER UPDATE OF email ON public.users FOR EACH ROW WHEN ((old.email IS DISTINCT FROM new.email)) EXECUTE FUNCTION audit.log_change('users')",
+            )],
+            ..SchemaSnapshot::default()
+        };
+
+        let changes = diff(&from, &to);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].object, SchemaObjectKind::Trigger);
+        assert_eq!(changes[0].details.len(), 1);
+        assert!(changes[0].details[0].starts_with("definition: "));
+        assert_eq!(
+            changes[0].ddl,
+            vec![
+                "drop trigger audit_users on public.users;",
+                "CREATE TRIGGER audit_users AFTER UPDATE OF email ON public.users FOR EACH ROW WHEN ((old.email IS DISTINCT FROM new.email)) EXECUTE FUNCTION audit.log_change('users');"
+            ]
+        );
+    }
+}
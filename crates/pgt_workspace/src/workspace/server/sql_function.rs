@@ -78,16 +78,24 @@ pub fn get_sql_fn_signature(ast: &pgt_query_ext::NodeEnum) -> Option<SQLFunction
 
 /// Extracts the SQL body from a function definition
 pub fn get_sql_fn_body(ast: &pgt_query_ext::NodeEnum, content: &str) -> Option<SQLFunctionBody> {
+    get_fn_body(ast, content, "sql")
+}
+
+/// Extracts the body from a function definition if the function is written in `language`
+pub fn get_fn_body(
+    ast: &pgt_query_ext::NodeEnum,
+    content: &str,
+    language: &str,
+) -> Option<SQLFunctionBody> {
     let create_fn = match ast {
         pgt_query_ext::NodeEnum::CreateFunctionStmt(cf) => cf,
         _ => return None,
     };
 
     // Extract language from function options
-    let language = find_option_value(create_fn, "language")?;
+    let fn_language = find_option_value(create_fn, "language")?;
 
-    // Only process SQL functions
-    if language != "sql" {
+    if fn_language != language {
         return None;
     }
 
@@ -107,7 +115,7 @@ pub fn get_sql_fn_body(ast: &pgt_query_ext::NodeEnum, content: &str) -> Option<S
 }
 
 /// Helper function to find a specific option value from function options
-fn find_option_value(
+pub fn find_option_value(
     create_fn: &pgt_query_ext::protobuf::CreateFunctionStmt,
     option_name: &str,
 ) -> Option<String> {
@@ -148,7 +156,7 @@ fn find_option_value(
         })
 }
 
-fn parse_name(nodes: &[pgt_query_ext::protobuf::Node]) -> Option<(Option<String>, String)> {
+pub fn parse_name(nodes: &[pgt_query_ext::protobuf::Node]) -> Option<(Option<String>, String)> {
     let names = nodes
         .iter()
         .map(|n| match &n.node {
@@ -457,13 +457,23 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 9] {
+pub fn methods() -> [WorkspaceMethod; 19] {
     [
         workspace_method!(is_path_ignored),
+        workspace_method!(get_ignore_reason),
         workspace_method!(register_project_folder),
         workspace_method!(get_file_content),
         workspace_method!(pull_diagnostics),
+        workspace_method!(pull_schema_diagnostics),
+        workspace_method!(pull_migration_diagnostics),
+        workspace_method!(refresh_schema_cache),
+        workspace_method!(get_schema_fingerprint),
+        workspace_method!(get_database_info),
+        workspace_method!(migrate_check),
+        workspace_method!(diff_schema),
         workspace_method!(get_completions),
+        workspace_method!(resolve_completion_item),
+        workspace_method!(record_accepted_completion),
         workspace_method!(update_settings),
         workspace_method!(open_file),
         workspace_method!(change_file),
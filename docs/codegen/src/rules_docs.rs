@@ -1,6 +1,8 @@
 use anyhow::{Result, bail};
 use biome_string_case::Case;
-use pgt_analyse::{AnalyserOptions, AnalysisFilter, RuleFilter, RuleMetadata};
+use pgt_analyse::{
+    AnalyserOptions, AnalysisFilter, RuleFilter, RuleMetadata, context::FileContext,
+};
 use pgt_analyser::{Analyser, AnalyserConfig};
 use pgt_console::StdDisplay;
 use pgt_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
@@ -443,10 +445,35 @@ fn print_diagnostics(
 
     // split and parse each statement
     let stmts = pgt_statement_splitter::split(code).expect("unexpected parse error");
-    for stmt in stmts.ranges {
-        match pgt_query_ext::parse(&code[stmt]) {
+    let results = stmts
+        .ranges
+        .into_iter()
+        .map(|stmt| (stmt, pgt_query_ext::parse(&code[stmt])))
+        .collect::<Vec<_>>();
+
+    // code blocks are analysed like migration files, so that rules for migrations can be documented
+    let file_stmts = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok().cloned())
+        .collect::<Vec<_>>();
+
+    let mut stmt_index = 0;
+    for (range, result) in results {
+        match result {
             Ok(ast) => {
-                for rule_diag in analyser.run(pgt_analyser::AnalyserContext { root: &ast }) {
+                let file = FileContext {
+                    stmts: &file_stmts,
+                    stmt_index,
+                    is_migration: true,
+                };
+                stmt_index += 1;
+
+                for rule_diag in analyser.run(pgt_analyser::AnalyserContext {
+                    root: &ast,
+                    sql: &code[range],
+                    schema_cache: None,
+                    file,
+                }) {
                     let diag = pgt_diagnostics::serde::Diagnostic::new(rule_diag);
 
                     let category = diag.category().expect("linter diagnostic has no code");
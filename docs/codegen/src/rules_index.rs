@@ -80,12 +80,42 @@ fn generate_group(
 
 fn extract_group_metadata(group: &str) -> (&str, Markup) {
     match group {
+        "correctness" => (
+            "Correctness",
+            markup! {
+                "Rules that detect queries that are most likely wrong or return unexpected results."
+            },
+        ),
+        "performance" => (
+            "Performance",
+            markup! {
+                "Rules that detect queries that are likely to be slow."
+            },
+        ),
         "safety" => (
             "Safety",
             markup! {
                 "Rules that detect potential safety issues in your code."
             },
         ),
+        "schema" => (
+            "Schema",
+            markup! {
+                "Rules that check the whole connected database once, rather than single statements."
+            },
+        ),
+        "security" => (
+            "Security",
+            markup! {
+                "Rules that detect potential security issues, such as missing row level security."
+            },
+        ),
+        "style" => (
+            "Style",
+            markup! {
+                "Rules that enforce best practices for choosing types and writing schemas."
+            },
+        ),
         _ => panic!("Unknown group ID {group:?}"),
     }
 }
@@ -17,7 +17,8 @@ struct SourceSet {
 
 impl Ord for SourceSet {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.source_rule_name.cmp(&other.source_rule_name)
+        // several rules can be ported from the same source rule
+        (&self.source_rule_name, &self.rule_name).cmp(&(&other.source_rule_name, &other.rule_name))
     }
 }
 
@@ -1,4 +1,7 @@
-use pgt_analyse::{GroupCategory, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata};
+use pgt_analyse::{
+    GroupCategory, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMeta, RuleMetadata,
+    SchemaRule,
+};
 use regex::Regex;
 use std::collections::BTreeMap;
 
@@ -33,7 +36,7 @@ pub(crate) struct LintRulesVisitor {
 impl LintRulesVisitor {
     fn push_rule<R>(&mut self)
     where
-        R: Rule<Options: Default> + 'static,
+        R: RuleMeta + 'static,
     {
         let group = self
             .groups
@@ -57,4 +60,11 @@ impl RegistryVisitor for LintRulesVisitor {
     {
         self.push_rule::<R>()
     }
+
+    fn record_schema_rule<R>(&mut self)
+    where
+        R: SchemaRule + 'static,
+    {
+        self.push_rule::<R>()
+    }
 }
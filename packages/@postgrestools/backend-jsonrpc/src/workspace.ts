@@ -19,6 +19,14 @@ export type FileKind = FileKind2[];
  * The priority of the file
  */
 export type FileKind2 = "Config" | "Ignore" | "Inspectable" | "Handleable";
+/**
+ * Why a path is ignored
+ */
+export type IgnoreReason =
+	| "notIncluded"
+	| "ignored"
+	| "vcsIgnored"
+	| "beforeMigrationsAfter";
 export interface RegisterProjectFolderParams {
 	path?: string;
 	setAsCurrentWorkspace: boolean;
@@ -63,10 +71,65 @@ export interface Advices {
 	advices: Advice[];
 }
 export type Category =
+	| "lint/correctness/banBetweenWithTimestamps"
+	| "lint/correctness/banEqualsNull"
+	| "lint/correctness/banExplicitTransaction"
+	| "lint/correctness/banImplicitCrossJoin"
+	| "lint/correctness/banNotInSubquery"
+	| "lint/correctness/banSelectStar"
+	| "lint/correctness/banUndeclaredVariables"
+	| "lint/correctness/banUnreachableCode"
+	| "lint/correctness/banUnusedVariables"
+	| "lint/correctness/requireReturn"
+	| "lint/correctness/requireStrictSelectInto"
+	| "lint/performance/banLargeOffset"
+	| "lint/performance/banOrderByRandom"
+	| "lint/safety/addingFieldWithVolatileDefault"
+	| "lint/safety/addingPrimaryKeyConstraint"
 	| "lint/safety/addingRequiredField"
+	| "lint/safety/addingStoredGeneratedColumn"
+	| "lint/safety/banCluster"
+	| "lint/safety/banDeleteWithoutWhere"
 	| "lint/safety/banDropColumn"
+	| "lint/safety/banDropFunctionInUse"
 	| "lint/safety/banDropNotNull"
+	| "lint/safety/banDropReferencedColumn"
+	| "lint/safety/banDropReferencedTable"
 	| "lint/safety/banDropTable"
+	| "lint/safety/banMixingDdlAndBackfill"
+	| "lint/safety/banRenameReferencedByTrigger"
+	| "lint/safety/banUpdateWithoutWhere"
+	| "lint/safety/banVacuumFull"
+	| "lint/safety/changingColumnType"
+	| "lint/safety/changingTablePersistence"
+	| "lint/safety/constraintMissingNotValid"
+	| "lint/safety/disallowUniqueConstraint"
+	| "lint/safety/renamingColumn"
+	| "lint/safety/renamingTable"
+	| "lint/safety/requireConcurrentIndexCreation"
+	| "lint/safety/requireConcurrentIndexDeletion"
+	| "lint/safety/requireLockTimeout"
+	| "lint/schema/authRlsInitplan"
+	| "lint/schema/duplicateIndex"
+	| "lint/schema/extensionInPublic"
+	| "lint/schema/multiplePermissivePolicies"
+	| "lint/schema/securityDefinerView"
+	| "lint/schema/unindexedForeignKeys"
+	| "lint/security/banDynamicSqlConcatenation"
+	| "lint/security/banUnrestrictedWritePolicy"
+	| "lint/security/requireRowLevelSecurity"
+	| "lint/security/requireSearchPathOnSecurityDefiner"
+	| "lint/security/requireUpdatePolicyWithCheck"
+	| "lint/security/wrapAuthFunctionsInSelect"
+	| "lint/style/banJsonPreferJsonb"
+	| "lint/style/banMoneyType"
+	| "lint/style/namingConvention"
+	| "lint/style/preferBigintOverInt"
+	| "lint/style/preferIdentityOverSerial"
+	| "lint/style/preferText"
+	| "lint/style/preferTimestamptz"
+	| "lint/style/requireCreateIfNotExists"
+	| "lint/style/requireDropIfExists"
 	| "stdin"
 	| "check"
 	| "configuration"
@@ -82,7 +145,11 @@ export type Category =
 	| "dummy"
 	| "lint"
 	| "lint/performance"
-	| "lint/safety";
+	| "lint/correctness"
+	| "lint/safety"
+	| "lint/schema"
+	| "lint/security"
+	| "lint/style";
 export interface Location {
 	path?: Resource_for_String;
 	sourceCode?: string;
@@ -174,6 +241,272 @@ export interface BacktraceSymbol {
 	lineno?: number;
 	name?: string;
 }
+export interface PullSchemaDiagnosticsParams {
+	categories: RuleCategories;
+	max_diagnostics: number;
+	only: RuleCode[];
+	skip: RuleCode[];
+}
+export interface PullSchemaDiagnosticsResult {
+	/**
+	 * Whether the schema rules ran. They are skipped without a database connection.
+	 */
+	checked: boolean;
+	diagnostics: Diagnostic[];
+	errors: number;
+	skipped_diagnostics: number;
+}
+export interface PullMigrationDiagnosticsParams {}
+export interface PullMigrationDiagnosticsResult {
+	/**
+	 * Whether the migrations were checked. They are skipped without a migrations directory.
+	 */
+	checked: boolean;
+	diagnostics: Diagnostic[];
+}
+export interface RefreshSchemaCacheParams {}
+export interface GetSchemaFingerprintParams {}
+export interface GetDatabaseInfoParams {}
+export interface DatabaseInfo {
+	/**
+	 * Whether the connection is disabled, in the configuration or with `--disable-db`
+	 */
+	disabled: boolean;
+	/**
+	 * The error raised while connecting to the database or loading its schema
+	 */
+	error?: string;
+	/**
+	 * The number of objects of each kind in the schema cache
+	 */
+	schema_cache?: SchemaCacheCounts;
+	/**
+	 * The version of Postgres, e.g. `PostgreSQL 15.7 on x86_64-pc-linux-gnu, ...`
+	 */
+	version?: string;
+}
+export interface SchemaCacheCounts {
+	columns: number;
+	extensions: number;
+	foreign_keys: number;
+	functions: number;
+	indexes: number;
+	policies: number;
+	roles: number;
+	schemas: number;
+	tables: number;
+	triggers: number;
+	types: number;
+}
+export interface MigrateCheckParams {}
+export interface MigrateCheckResult {
+	/**
+	 * The name of the scratch database the migrations were applied to. It's dropped once the migrations are checked.
+	 */
+	database: string;
+	/**
+	 * The errors raised while applying the migrations
+	 */
+	diagnostics: Diagnostic[];
+	/**
+	 * The migrations, in the order they were applied. Migrations after a failing one are not applied, and not listed.
+	 */
+	migrations: MigrationReport[];
+}
+export interface MigrationReport {
+	/**
+	 * Whether all statements of the migration were applied, and its transaction committed. Migrations with statements that can't run in a transaction run in autocommit mode.
+	 */
+	applied: boolean;
+	name: string;
+	path: string;
+	/**
+	 * The sequence number of the migration, e.g. `20240101120000` or `1.1`
+	 */
+	sequence_number: string;
+	/**
+	 * The statements that were executed, including the failing one
+	 */
+	statements: StatementReport[];
+}
+export interface StatementReport {
+	duration: Duration;
+	/**
+	 * The locks the statement acquired. Locks are held until the end of the transaction of the migration, so each lock is only reported for the first statement that acquired it. The locks of statements that can't run in a transaction are not reported.
+	 */
+	locks: AcquiredLock[];
+	/**
+	 * The range of the statement in the migration file
+	 */
+	range: TextRange;
+}
+export interface Duration {
+	nanos: number;
+	secs: number;
+}
+export interface AcquiredLock {
+	/**
+	 * The kind of the locked object, e.g. `relation` or `object`
+	 */
+	locktype: string;
+	/**
+	 * The lock mode, e.g. `AccessExclusiveLock`
+	 */
+	mode: string;
+	/**
+	 * The locked relation, if the lock is on a relation
+	 */
+	relation?: string;
+}
+export interface DiffSchemaParams {
+	/**
+	 * The schema that is compared
+	 */
+	from: SchemaSource;
+	/**
+	 * Whether the result includes the snapshot of `to`, e.g. to write it to a file
+	 */
+	include_snapshot: boolean;
+	/**
+	 * The Postgres schemas to compare, e.g. `public`. All schemas but the system ones are compared if it's empty.
+	 */
+	schemas: string[];
+	/**
+	 * The schema it's compared with
+	 */
+	to: SchemaSource;
+}
+/**
+ * Where the schema of a comparison comes from
+ */
+export type SchemaSource =
+	| "migrations"
+	| "database"
+	| { url: string }
+	| { snapshot: SchemaSnapshot };
+/**
+ * The objects of a schema that are compared, without the details of the database they were loaded from, such as object ids
+ */
+export interface SchemaSnapshot {
+	columns: ColumnSnapshot[];
+	functions: FunctionSnapshot[];
+	policies: PolicySnapshot[];
+	tables: TableSnapshot[];
+	triggers: TriggerSnapshot[];
+	types: TypeSnapshot[];
+}
+export interface ColumnSnapshot {
+	/**
+	 * The type as it's written in DDL, e.g. `character varying(255)`
+	 */
+	data_type: string;
+	default?: string;
+	name: string;
+	nullable: boolean;
+	/**
+	 * Whether the column is part of the primary key of the table
+	 */
+	primary_key: boolean;
+	schema: string;
+	table: string;
+}
+export interface FunctionSnapshot {
+	/**
+	 * The `create or replace function` statement. Not set for internal functions.
+	 */
+	definition?: string;
+	/**
+	 * The argument types that identify the function among its overloads, e.g. `integer, text`
+	 */
+	identity_arguments: string;
+	name: string;
+	schema: string;
+}
+export interface PolicySnapshot {
+	/**
+	 * `select`, `insert`, `update`, `delete` or `all`
+	 */
+	command: string;
+	name: string;
+	permissive: boolean;
+	roles: string[];
+	schema: string;
+	table: string;
+	using?: string;
+	with_check?: string;
+}
+export interface TableSnapshot {
+	/**
+	 * `table`, `partitioned table`, `view` or `materialized view`
+	 */
+	kind: string;
+	name: string;
+	rls_enabled: boolean;
+	schema: string;
+}
+export interface TriggerSnapshot {
+	/**
+	 * The `create trigger` statement, as returned by `pg_get_triggerdef`
+	 */
+	definition: string;
+	name: string;
+	schema: string;
+	table: string;
+}
+export interface TypeSnapshot {
+	/**
+	 * The attributes of a composite type
+	 */
+	attributes: TypeAttributeSnapshot[];
+	/**
+	 * The labels of an enum, in order
+	 */
+	enum_values: string[];
+	name: string;
+	schema: string;
+}
+export interface TypeAttributeSnapshot {
+	data_type: string;
+	name: string;
+}
+export interface DiffSchemaResult {
+	/**
+	 * The objects that differ, ordered by kind and name
+	 */
+	changes: SchemaChange[];
+	/**
+	 * The errors raised while applying the migrations. The schemas are not compared if a migration fails.
+	 */
+	diagnostics: Diagnostic[];
+	/**
+	 * The snapshot of `to`, if it was requested
+	 */
+	snapshot?: SchemaSnapshot;
+}
+export interface SchemaChange {
+	/**
+	 * The statements that turn `from` into `to` for this object. Objects that can't be recreated from the schema cache, such as views, get a comment instead.
+	 */
+	ddl: string[];
+	/**
+	 * What differs between the two versions of a changed object, e.g. `type: integer → bigint`
+	 */
+	details: string[];
+	kind: SchemaChangeKind;
+	/**
+	 * The qualified name of the object, e.g. `public.users.email` for a column
+	 */
+	name: string;
+	object: SchemaObjectKind;
+}
+export type SchemaChangeKind = "missing" | "extra" | "changed";
+export type SchemaObjectKind =
+	| "type"
+	| "table"
+	| "column"
+	| "function"
+	| "policy"
+	| "trigger";
 export interface GetCompletionsParams {
 	/**
 	 * The File for which a completion is requested.
@@ -189,6 +522,10 @@ export interface CompletionsResult {
 }
 export interface CompletionItem {
 	completion_text?: CompletionText;
+	/**
+	 * `None` if there is no additional documentation for the item.
+	 */
+	data?: CompletionItemData;
 	description: string;
 	detail?: string;
 	kind: CompletionItemKind;
@@ -212,12 +549,45 @@ export interface CompletionText {
 	range: TextRange;
 	text: string;
 }
+/**
+ * Identifies the schema cache entry a `CompletionItem` was created from. Clients send it back when resolving an item, so that its documentation can be computed lazily via [crate::resolve_documentation].
+ */
+export type CompletionItemData =
+	| { id: number; kind: "table" }
+	| { id: number; kind: "function" }
+	| { kind: "column"; name: string; table_id: number }
+	| { id: number; kind: "schema" }
+	| { kind: "policy"; name: string; schema: string; table: string }
+	| { kind: "role"; name: string }
+	| { kind: "setting"; name: string };
 export type CompletionItemKind =
 	| "table"
 	| "function"
 	| "column"
 	| "schema"
-	| "policy";
+	| "policy"
+	| "role"
+	| "privilege"
+	| "setting"
+	| "variable";
+export interface ResolveCompletionItemParams {
+	/**
+	 * The data of the `CompletionItem` that should be resolved.
+	 */
+	data: CompletionItemData;
+}
+export interface RecordAcceptedCompletionParams {
+	/**
+	 * The data of the `CompletionItem` the user accepted.
+	 */
+	data: CompletionItemData;
+}
+export interface ResolveCompletionItemResult {
+	/**
+	 * Markdown documentation of the item, if available.
+	 */
+	documentation?: string;
+}
 export interface UpdateSettingsParams {
 	configuration: PartialConfiguration;
 	gitignore_matches: string[];
@@ -232,6 +602,10 @@ export interface PartialConfiguration {
 	 * A field for the [JSON schema](https://json-schema.org/) specification
 	 */
 	$schema?: string;
+	/**
+	 * The configuration of the completions
+	 */
+	completions?: PartialCompletionsConfiguration;
 	/**
 	 * The configuration of the database connection
 	 */
@@ -257,6 +631,15 @@ export interface PartialConfiguration {
 	 */
 	vcs?: PartialVcsConfiguration;
 }
+/**
+ * The configuration of the completions.
+ */
+export interface PartialCompletionsConfiguration {
+	/**
+	 * Rank relations and columns higher if they are used often in the project's SQL files or were accepted recently. The statistics are stored in the cache directory. Enabled by default.
+	 */
+	learnFromWorkspace?: boolean;
+}
 /**
  * The configuration of the database connection.
  */
@@ -335,6 +718,10 @@ export interface PartialMigrationsConfiguration {
 	 * The directory where the migration files are stored
 	 */
 	migrationsDir?: string;
+	/**
+	 * Additional patterns of migration files, relative to the migrations directory, e.g. `V{sequence}__{name}.sql` for Flyway. `{sequence}` matches the sequence number, digits or a dotted version such as `1.1`, `{name}` the name of the migration and `*` any part of a file name. Migrations of patterns without `{sequence}`, e.g. `deploy/{name}.sql` for Sqitch, are ordered by the `sqitch.plan` file in the migrations directory.
+	 */
+	patterns?: StringSet;
 }
 /**
  * Set of properties to integrate with a VCS software.
@@ -368,37 +755,327 @@ export interface Rules {
 	 * It enables ALL rules. The rules that belong to `nursery` won't be enabled.
 	 */
 	all?: boolean;
+	correctness?: Correctness;
+	performance?: Performance;
 	/**
 	 * It enables the lint rules recommended by Postgres Tools. `true` by default.
 	 */
 	recommended?: boolean;
 	safety?: Safety;
+	schema?: Schema;
+	security?: Security;
+	style?: Style;
 }
 export type VcsClientKind = "git";
 /**
  * A list of rules that belong to this group
  */
 export interface Safety {
+	/**
+	 * Adding a column with a volatile default value rewrites the whole table while holding an ACCESS EXCLUSIVE lock.
+	 */
+	addingFieldWithVolatileDefault?: RuleConfiguration_for_Null;
+	/**
+	 * Adding a primary key constraint to an existing table builds its index while blocking reads and writes.
+	 */
+	addingPrimaryKeyConstraint?: RuleConfiguration_for_Null;
 	/**
 	 * Adding a new column that is NOT NULL and has no default value to an existing table effectively makes it required.
 	 */
 	addingRequiredField?: RuleConfiguration_for_Null;
+	/**
+	 * Adding a stored generated column rewrites the whole table while holding an ACCESS EXCLUSIVE lock.
+	 */
+	addingStoredGeneratedColumn?: RuleConfiguration_for_Null;
 	/**
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * CLUSTER rewrites the whole table while holding an ACCESS EXCLUSIVE lock.
+	 */
+	banCluster?: RuleConfiguration_for_Null;
+	/**
+	 * A DELETE without a WHERE clause deletes every row of the table.
+	 */
+	banDeleteWithoutWhere?: RuleConfiguration_for_Null;
 	/**
 	 * Dropping a column may break existing clients.
 	 */
 	banDropColumn?: RuleConfiguration_for_Null;
+	/**
+	 * Dropping a function that other functions or triggers still use breaks them.
+	 */
+	banDropFunctionInUse?: RuleConfiguration_for_Null;
 	/**
 	 * Dropping a NOT NULL constraint may break existing clients.
 	 */
 	banDropNotNull?: RuleConfiguration_for_Null;
+	/**
+	 * Dropping a column that other objects still use breaks them.
+	 */
+	banDropReferencedColumn?: RuleConfiguration_for_Null;
+	/**
+	 * Dropping a table that other objects still use breaks them.
+	 */
+	banDropReferencedTable?: RuleConfiguration_for_Null;
 	/**
 	 * Dropping a table may break existing clients.
 	 */
 	banDropTable?: RuleConfiguration_for_Null;
+	/**
+	 * Schema changes and backfills of whole tables should not be run in the same migration.
+	 */
+	banMixingDdlAndBackfill?: RuleConfiguration_for_Null;
+	/**
+	 * Renaming a table or column that trigger functions use breaks the triggers.
+	 */
+	banRenameReferencedByTrigger?: RuleConfiguration_for_Null;
+	/**
+	 * An UPDATE without a WHERE clause updates every row of the table.
+	 */
+	banUpdateWithoutWhere?: RuleConfiguration_for_Null;
+	/**
+	 * VACUUM FULL rewrites the whole table while holding an ACCESS EXCLUSIVE lock.
+	 */
+	banVacuumFull?: RuleConfiguration_for_Null;
+	/**
+	 * Changing the type of a column rewrites the whole table while holding an ACCESS EXCLUSIVE lock.
+	 */
+	changingColumnType?: RuleConfiguration_for_Null;
+	/**
+	 * Changing a table between logged and unlogged rewrites the whole table while holding an ACCESS EXCLUSIVE lock.
+	 */
+	changingTablePersistence?: RuleConfiguration_for_Null;
+	/**
+	 * Adding constraints without NOT VALID blocks all reads and writes while the existing rows are validated.
+	 */
+	constraintMissingNotValid?: RuleConfiguration_for_Null;
+	/**
+	 * Adding a unique constraint to an existing table builds its index while blocking reads and writes.
+	 */
+	disallowUniqueConstraint?: RuleConfiguration_for_Null;
+	/**
+	 * It enables the recommended rules for this group
+	 */
+	recommended?: boolean;
+	/**
+	 * Renaming columns may break existing queries.
+	 */
+	renamingColumn?: RuleConfiguration_for_Null;
+	/**
+	 * Renaming tables may break existing queries.
+	 */
+	renamingTable?: RuleConfiguration_for_Null;
+	/**
+	 * Creating indexes non-concurrently can lock the table for writes.
+	 */
+	requireConcurrentIndexCreation?: RuleConfiguration_for_Null;
+	/**
+	 * Dropping indexes non-concurrently can lock the table for reads and writes.
+	 */
+	requireConcurrentIndexDeletion?: RuleConfiguration_for_Null;
+	/**
+	 * Set a lock_timeout before running DDL that locks existing tables.
+	 */
+	requireLockTimeout?: RuleConfiguration_for_Null;
+}
+/**
+ * A list of rules that belong to this group
+ */
+export interface Style {
+	/**
+	 * It enables ALL rules for this group.
+	 */
+	all?: boolean;
+	/**
+	 * Prefer jsonb over json.
+	 */
+	banJsonPreferJsonb?: RuleConfiguration_for_ColumnTypeOptions;
+	/**
+	 * Do not use the money type.
+	 */
+	banMoneyType?: RuleConfiguration_for_ColumnTypeOptions;
+	/**
+	 * Names of database objects should follow a consistent naming convention.
+	 */
+	namingConvention?: RuleConfiguration_for_NamingConventionOptions;
+	/**
+	 * Prefer bigint over integer and smallint for primary keys.
+	 */
+	preferBigintOverInt?: RuleConfiguration_for_ColumnTypeOptions;
+	/**
+	 * Prefer identity columns over serial types.
+	 */
+	preferIdentityOverSerial?: RuleConfiguration_for_ColumnTypeOptions;
+	/**
+	 * Prefer text over varchar(n) and char(n).
+	 */
+	preferText?: RuleConfiguration_for_ColumnTypeOptions;
+	/**
+	 * Prefer timestamptz over timestamp.
+	 */
+	preferTimestamptz?: RuleConfiguration_for_ColumnTypeOptions;
+	/**
+	 * It enables the recommended rules for this group
+	 */
+	recommended?: boolean;
+	/**
+	 * Migrations should create objects with IF NOT EXISTS or OR REPLACE.
+	 */
+	requireCreateIfNotExists?: RuleConfiguration_for_RequireCreateIfNotExistsOptions;
+	/**
+	 * Migrations should drop objects with IF EXISTS.
+	 */
+	requireDropIfExists?: RuleConfiguration_for_Null;
+}
+/**
+ * A list of rules that belong to this group
+ */
+export interface Schema {
+	/**
+	 * It enables ALL rules for this group.
+	 */
+	all?: boolean;
+	/**
+	 * Existing policies should not call auth functions for every row.
+	 */
+	authRlsInitplan?: RuleConfiguration_for_Null;
+	/**
+	 * A table should not have several identical indexes.
+	 */
+	duplicateIndex?: RuleConfiguration_for_Null;
+	/**
+	 * Extensions should not be installed in the public schema.
+	 */
+	extensionInPublic?: RuleConfiguration_for_Null;
+	/**
+	 * A table should not have several permissive policies for the same role and action.
+	 */
+	multiplePermissivePolicies?: RuleConfiguration_for_Null;
+	/**
+	 * It enables the recommended rules for this group
+	 */
+	recommended?: boolean;
+	/**
+	 * Views in exposed schemas should use the permissions of the querying user.
+	 */
+	securityDefinerView?: RuleConfiguration_for_SecurityDefinerViewOptions;
+	/**
+	 * Foreign keys should be covered by an index.
+	 */
+	unindexedForeignKeys?: RuleConfiguration_for_Null;
+}
+/**
+ * A list of rules that belong to this group
+ */
+export interface Security {
+	/**
+	 * It enables ALL rules for this group.
+	 */
+	all?: boolean;
+	/**
+	 * Dynamic SQL in PL/pgSQL functions must not be built by concatenating variables.
+	 */
+	banDynamicSqlConcatenation?: RuleConfiguration_for_Null;
+	/**
+	 * Policies for commands other than SELECT must not be unrestricted.
+	 */
+	banUnrestrictedWritePolicy?: RuleConfiguration_for_Null;
+	/**
+	 * It enables the recommended rules for this group
+	 */
+	recommended?: boolean;
+	/**
+	 * Tables in exposed schemas must have row level security enabled.
+	 */
+	requireRowLevelSecurity?: RuleConfiguration_for_RequireRowLevelSecurityOptions;
+	/**
+	 * SECURITY DEFINER functions must set a search_path.
+	 */
+	requireSearchPathOnSecurityDefiner?: RuleConfiguration_for_Null;
+	/**
+	 * UPDATE policies should have a WITH CHECK expression.
+	 */
+	requireUpdatePolicyWithCheck?: RuleConfiguration_for_Null;
+	/**
+	 * Calls to auth functions and current_setting() in policies should be wrapped in a SELECT.
+	 */
+	wrapAuthFunctionsInSelect?: RuleConfiguration_for_Null;
+}
+/**
+ * A list of rules that belong to this group
+ */
+export interface Correctness {
+	/**
+	 * It enables ALL rules for this group.
+	 */
+	all?: boolean;
+	/**
+	 * BETWEEN includes both bounds, which is rarely what you want for timestamps.
+	 */
+	banBetweenWithTimestamps?: RuleConfiguration_for_Null;
+	/**
+	 * Comparisons with NULL using = or \<> never evaluate to true.
+	 */
+	banEqualsNull?: RuleConfiguration_for_Null;
+	/**
+	 * Migrations should not start or end transactions themselves.
+	 */
+	banExplicitTransaction?: RuleConfiguration_for_Null;
+	/**
+	 * Tables listed in FROM without a condition that relates them produce a cross join.
+	 */
+	banImplicitCrossJoin?: RuleConfiguration_for_Null;
+	/**
+	 * NOT IN with a subquery returns no rows at all if the subquery returns a NULL.
+	 */
+	banNotInSubquery?: RuleConfiguration_for_Null;
+	/**
+	 * Avoid SELECT * in views and functions.
+	 */
+	banSelectStar?: RuleConfiguration_for_Null;
+	/**
+	 * PL/pgSQL functions must not assign to undeclared variables.
+	 */
+	banUndeclaredVariables?: RuleConfiguration_for_Null;
+	/**
+	 * PL/pgSQL functions should not contain code that never runs.
+	 */
+	banUnreachableCode?: RuleConfiguration_for_Null;
+	/**
+	 * Variables and parameters of PL/pgSQL functions should be used.
+	 */
+	banUnusedVariables?: RuleConfiguration_for_Null;
+	/**
+	 * It enables the recommended rules for this group
+	 */
+	recommended?: boolean;
+	/**
+	 * PL/pgSQL functions that return a value must not reach the end of the function.
+	 */
+	requireReturn?: RuleConfiguration_for_Null;
+	/**
+	 * SELECT INTO in PL/pgSQL functions should use STRICT when exactly one row is expected.
+	 */
+	requireStrictSelectInto?: RuleConfiguration_for_Null;
+}
+/**
+ * A list of rules that belong to this group
+ */
+export interface Performance {
+	/**
+	 * It enables ALL rules for this group.
+	 */
+	all?: boolean;
+	/**
+	 * Paginating with a large OFFSET gets slower with every page.
+	 */
+	banLargeOffset?: RuleConfiguration_for_Null;
+	/**
+	 * Sorting by random() reads and sorts the whole table.
+	 */
+	banOrderByRandom?: RuleConfiguration_for_Null;
 	/**
 	 * It enables the recommended rules for this group
 	 */
@@ -418,7 +1095,157 @@ export interface RuleWithOptions_for_Null {
 	 */
 	options: null;
 }
-export interface OpenFileParams {
+export type RuleConfiguration_for_ColumnTypeOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_ColumnTypeOptions;
+export interface RuleWithOptions_for_ColumnTypeOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ColumnTypeOptions;
+}
+/**
+ * Options of the rules that check the types of columns.
+ */
+export interface ColumnTypeOptions {
+	/**
+	 * Tables that are not checked by the rule, either as `table` or as `schema.table`.
+	 */
+	allowedTables?: string[];
+}
+export type RuleConfiguration_for_RequireRowLevelSecurityOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_RequireRowLevelSecurityOptions;
+export interface RuleWithOptions_for_RequireRowLevelSecurityOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: RequireRowLevelSecurityOptions;
+}
+export interface RequireRowLevelSecurityOptions {
+	/**
+	 * Schemas that are exposed via an API. Defaults to `["public"]`.
+	 */
+	exposedSchemas?: string[];
+}
+export type RuleConfiguration_for_SecurityDefinerViewOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_SecurityDefinerViewOptions;
+export interface RuleWithOptions_for_SecurityDefinerViewOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: SecurityDefinerViewOptions;
+}
+export interface SecurityDefinerViewOptions {
+	/**
+	 * Schemas that are exposed via an API. Defaults to `["public"]`.
+	 */
+	exposedSchemas?: string[];
+}
+export type RuleConfiguration_for_NamingConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NamingConventionOptions;
+export interface RuleWithOptions_for_NamingConventionOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NamingConventionOptions;
+}
+export interface NamingConventionOptions {
+	/**
+	 * The convention for column names.
+	 */
+	columns?: NameConvention;
+	/**
+	 * The convention for constraint names.
+	 */
+	constraints?: NameConvention;
+	/**
+	 * The convention for foreign key names. Defaults to the convention for constraints.
+	 */
+	foreignKeys?: NameConvention;
+	/**
+	 * The convention for function names.
+	 */
+	functions?: NameConvention;
+	/**
+	 * The convention for index names.
+	 */
+	indexes?: NameConvention;
+	/**
+	 * The convention for policy names.
+	 */
+	policies?: NameConvention;
+	/**
+	 * The convention for table names.
+	 */
+	tables?: NameConvention;
+	/**
+	 * The convention for trigger names.
+	 */
+	triggers?: NameConvention;
+}
+export interface NameConvention {
+	/**
+	 * The case the name must be in.
+	 */
+	case?: Case;
+	/**
+	 * A regular expression the name must match. `<table>` is replaced with the name of the table, and `<columns>` with the names of the columns joined by `_`.
+	 */
+	pattern?: string;
+	/**
+	 * Whether the last word of the name must be singular or plural.
+	 */
+	plurality?: Plurality;
+}
+export type Case = "snakeCase" | "camelCase" | "pascalCase";
+export type Plurality = "singular" | "plural";export type RuleConfiguration_for_RequireCreateIfNotExistsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_RequireCreateIfNotExistsOptions;
+export interface RuleWithOptions_for_RequireCreateIfNotExistsOptions {
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: RequireCreateIfNotExistsOptions;
+}
+export interface RequireCreateIfNotExistsOptions {
+	/**
+	 * The kinds of objects that must be created idempotently. Defaults to all.
+	 */
+	objectTypes?: CreateObjectType[];
+}
+export type CreateObjectType =
+	| "table"
+	| "index"
+	| "schema"
+	| "sequence"
+	| "extension"
+	| "view"
+	| "materializedView"
+	| "function"
+	| "trigger";export interface OpenFileParams {
 	content: string;
 	path: PgTPath;
 	version: number;
@@ -441,6 +1268,7 @@ export interface CloseFileParams {
 export type Configuration = PartialConfiguration;
 export interface Workspace {
 	isPathIgnored(params: IsPathIgnoredParams): Promise<boolean>;
+	getIgnoreReason(params: IsPathIgnoredParams): Promise<IgnoreReason | null>;
 	registerProjectFolder(
 		params: RegisterProjectFolderParams,
 	): Promise<ProjectKey>;
@@ -448,7 +1276,26 @@ export interface Workspace {
 	pullDiagnostics(
 		params: PullDiagnosticsParams,
 	): Promise<PullDiagnosticsResult>;
+	pullSchemaDiagnostics(
+		params: PullSchemaDiagnosticsParams,
+	): Promise<PullSchemaDiagnosticsResult>;
+	pullMigrationDiagnostics(
+		params: PullMigrationDiagnosticsParams,
+	): Promise<PullMigrationDiagnosticsResult>;
+	refreshSchemaCache(params: RefreshSchemaCacheParams): Promise<void>;
+	getSchemaFingerprint(
+		params: GetSchemaFingerprintParams,
+	): Promise<string | null>;
+	getDatabaseInfo(params: GetDatabaseInfoParams): Promise<DatabaseInfo>;
+	migrateCheck(params: MigrateCheckParams): Promise<MigrateCheckResult>;
+	diffSchema(params: DiffSchemaParams): Promise<DiffSchemaResult>;
 	getCompletions(params: GetCompletionsParams): Promise<CompletionsResult>;
+	resolveCompletionItem(
+		params: ResolveCompletionItemParams,
+	): Promise<ResolveCompletionItemResult>;
+	recordAcceptedCompletion(
+		params: RecordAcceptedCompletionParams,
+	): Promise<void>;
 	updateSettings(params: UpdateSettingsParams): Promise<void>;
 	openFile(params: OpenFileParams): Promise<void>;
 	changeFile(params: ChangeFileParams): Promise<void>;
@@ -460,6 +1307,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		isPathIgnored(params) {
 			return transport.request("pgt/is_path_ignored", params);
 		},
+		getIgnoreReason(params) {
+			return transport.request("pgt/get_ignore_reason", params);
+		},
 		registerProjectFolder(params) {
 			return transport.request("pgt/register_project_folder", params);
 		},
@@ -469,9 +1319,36 @@ export function createWorkspace(transport: Transport): Workspace {
 		pullDiagnostics(params) {
 			return transport.request("pgt/pull_diagnostics", params);
 		},
+		pullSchemaDiagnostics(params) {
+			return transport.request("pgt/pull_schema_diagnostics", params);
+		},
+		pullMigrationDiagnostics(params) {
+			return transport.request("pgt/pull_migration_diagnostics", params);
+		},
+		refreshSchemaCache(params) {
+			return transport.request("pgt/refresh_schema_cache", params);
+		},
+		getSchemaFingerprint(params) {
+			return transport.request("pgt/get_schema_fingerprint", params);
+		},
+		getDatabaseInfo(params) {
+			return transport.request("pgt/get_database_info", params);
+		},
+		migrateCheck(params) {
+			return transport.request("pgt/migrate_check", params);
+		},
+		diffSchema(params) {
+			return transport.request("pgt/diff_schema", params);
+		},
 		getCompletions(params) {
 			return transport.request("pgt/get_completions", params);
 		},
+		resolveCompletionItem(params) {
+			return transport.request("pgt/resolve_completion_item", params);
+		},
+		recordAcceptedCompletion(params) {
+			return transport.request("pgt/record_accepted_completion", params);
+		},
 		updateSettings(params) {
 			return transport.request("pgt/update_settings", params);
 		},
@@ -38,8 +38,13 @@ fn generate_options(base_path: &Path) -> Result<()> {
                 let rule_name = Case::Pascal.convert(rule_filename);
                 let rule_module_name = format_ident!("{}", rule_filename);
                 let rule_name = format_ident!("{}", rule_name);
+                let rule_trait = if is_schema_rule(&rule_path)? {
+                    quote!(pgt_analyse::SchemaRule)
+                } else {
+                    quote!(pgt_analyse::Rule)
+                };
                 rules_options.insert(rule_filename.to_string(), quote! {
-                    pub type #rule_name = <#category_name::#group_name::#rule_module_name::#rule_name as pgt_analyse::Rule>::Options;
+                    pub type #rule_name = <#category_name::#group_name::#rule_module_name::#rule_name as #rule_trait>::Options;
                 });
             }
         }
@@ -139,6 +144,7 @@ fn generate_group(category: &'static str, group: &str, base_path: &Path) -> Resu
     let path = base_path.join(category).join(group);
 
     let mut rules = BTreeMap::new();
+    let mut schema_rules = BTreeMap::new();
     for entry in fs2::read_dir(path)? {
         let entry = entry?.path();
         let file_name = entry
@@ -153,6 +159,13 @@ fn generate_group(category: &'static str, group: &str, base_path: &Path) -> Resu
         let module_name = format_ident!("{}", file_name);
         let rule_type = format_ident!("{}", rule_type);
 
+        // schema rules run once over the connected database and are recorded separately
+        let rules = if is_schema_rule(&entry)? {
+            &mut schema_rules
+        } else {
+            &mut rules
+        };
+
         rules.insert(
             key,
             (
@@ -169,6 +182,29 @@ fn generate_group(category: &'static str, group: &str, base_path: &Path) -> Resu
     let group_name = format_ident!("{}", Case::Pascal.convert(group));
 
     let (rule_imports, rule_names): (Vec<_>, Vec<_>) = rules.into_values().unzip();
+    let (schema_rule_imports, schema_rule_names): (Vec<_>, Vec<_>) =
+        schema_rules.into_values().unzip();
+
+    let rule_lists = match (rule_names.is_empty(), schema_rule_names.is_empty()) {
+        (_, true) => quote! {
+            rules: [
+                #(#rule_names,)*
+            ]
+        },
+        (true, false) => quote! {
+            schema_rules: [
+                #(#schema_rule_names,)*
+            ]
+        },
+        (false, false) => quote! {
+            rules: [
+                #(#rule_names,)*
+            ],
+            schema_rules: [
+                #(#schema_rule_names,)*
+            ]
+        },
+    };
 
     let (import_macro, use_macro) = match category {
         "lint" => (
@@ -183,13 +219,12 @@ fn generate_group(category: &'static str, group: &str, base_path: &Path) -> Resu
         #import_macro
 
         #(#rule_imports)*
+        #(#schema_rule_imports)*
 
         #use_macro! {
             pub #group_name {
                 name: #group,
-                rules: [
-                    #(#rule_names,)*
-                ]
+                #rule_lists
             }
         }
     })?;
@@ -199,6 +234,11 @@ fn generate_group(category: &'static str, group: &str, base_path: &Path) -> Resu
     Ok(())
 }
 
+/// Returns true if the rule in the file at `path` implements `SchemaRule` instead of `Rule`.
+fn is_schema_rule(path: &Path) -> Result<bool> {
+    Ok(fs2::read_to_string(path)?.contains("impl SchemaRule for"))
+}
+
 fn update_linter_registry_builder(rules: BTreeMap<&'static str, TokenStream>) -> Result<()> {
     let path = project_root().join("crates/pgt_analyser/src/registry.rs");
 
@@ -1,6 +1,9 @@
 use crate::{to_capitalized, update};
 use biome_string_case::Case;
-use pgt_analyse::{GroupCategory, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata};
+use pgt_analyse::{
+    GroupCategory, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMeta, RuleMetadata,
+    SchemaRule,
+};
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use pulldown_cmark::{Event, Parser, Tag, TagEnd};
 use quote::quote;
@@ -13,6 +16,18 @@ struct LintRulesVisitor {
     groups: BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
 }
 
+impl LintRulesVisitor {
+    fn push_rule<R>(&mut self)
+    where
+        R: RuleMeta + 'static,
+    {
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
 impl RegistryVisitor for LintRulesVisitor {
     fn record_category<C: GroupCategory>(&mut self) {
         if matches!(C::CATEGORY, RuleCategory::Lint) {
@@ -24,10 +39,14 @@ impl RegistryVisitor for LintRulesVisitor {
     where
         R: Rule<Options: Default> + 'static,
     {
-        self.groups
-            .entry(<R::Group as RuleGroup>::NAME)
-            .or_default()
-            .insert(R::METADATA.name, R::METADATA);
+        self.push_rule::<R>()
+    }
+
+    fn record_schema_rule<R>(&mut self)
+    where
+        R: SchemaRule + 'static,
+    {
+        self.push_rule::<R>()
     }
 }
 
@@ -4,8 +4,8 @@ use std::{fmt::Write, slice};
 
 use anyhow::bail;
 use pgt_analyse::{
-    AnalyserOptions, AnalysisFilter, GroupCategory, RegistryVisitor, Rule, RuleCategory,
-    RuleFilter, RuleGroup, RuleMetadata,
+    context::FileContext, AnalyserOptions, AnalysisFilter, GroupCategory, RegistryVisitor, Rule,
+    RuleCategory, RuleFilter, RuleGroup, RuleMeta, RuleMetadata, SchemaRule,
 };
 use pgt_analyser::{Analyser, AnalyserConfig};
 use pgt_console::{markup, Console};
@@ -23,7 +23,7 @@ pub fn check_rules() -> anyhow::Result<()> {
     impl LintRulesVisitor {
         fn push_rule<R>(&mut self)
         where
-            R: Rule<Options: Default> + 'static,
+            R: RuleMeta + 'static,
         {
             self.groups
                 .entry(<R::Group as RuleGroup>::NAME)
@@ -45,6 +45,13 @@ pub fn check_rules() -> anyhow::Result<()> {
         {
             self.push_rule::<R>()
         }
+
+        fn record_schema_rule<R>(&mut self)
+        where
+            R: SchemaRule + 'static,
+        {
+            self.push_rule::<R>()
+        }
     }
 
     let mut visitor = LintRulesVisitor::default();
@@ -90,7 +97,9 @@ fn assert_lint(
                     );
                 }
                 has_error = true;
-                bail!("Analysis of '{group}/{rule}' on the following code block returned multiple diagnostics.\n\n{code}");
+                bail!(
+                    "Analysis of '{group}/{rule}' on the following code block returned multiple diagnostics.\n\n{code}"
+                );
             }
         } else {
             // Print all diagnostics to help the user
@@ -104,7 +113,9 @@ fn assert_lint(
                 );
             }
             has_error = true;
-            bail!("Analysis of '{group}/{rule}' on the following code block returned an unexpected diagnostic.\n\n{code}");
+            bail!(
+                "Analysis of '{group}/{rule}' on the following code block returned an unexpected diagnostic.\n\n{code}"
+            );
         }
         diagnostic_count += 1;
         Ok(())
@@ -129,11 +140,35 @@ fn assert_lint(
     // split and parse each statement
     match pgt_statement_splitter::split(code) {
         Ok(stmts) => {
-            for stmt in stmts.ranges {
-                match pgt_query_ext::parse(&code[stmt]) {
+            let results = stmts
+                .ranges
+                .into_iter()
+                .map(|stmt| (stmt, pgt_query_ext::parse(&code[stmt])))
+                .collect::<Vec<_>>();
+
+            // code blocks are checked like migration files, so that rules for migrations can be tested
+            let file_stmts = results
+                .iter()
+                .filter_map(|(_, result)| result.as_ref().ok().cloned())
+                .collect::<Vec<_>>();
+
+            let mut stmt_index = 0;
+            for (range, result) in results {
+                match result {
                     Ok(ast) => {
-                        for rule_diag in analyser.run(pgt_analyser::AnalyserContext { root: &ast })
-                        {
+                        let file = FileContext {
+                            stmts: &file_stmts,
+                            stmt_index,
+                            is_migration: true,
+                        };
+                        stmt_index += 1;
+
+                        for rule_diag in analyser.run(pgt_analyser::AnalyserContext {
+                            root: &ast,
+                            sql: &code[range],
+                            schema_cache: None,
+                            file,
+                        }) {
                             let diag = pgt_diagnostics::serde::Diagnostic::new(rule_diag);
 
                             let category = diag.category().expect("linter diagnostic has no code");
@@ -169,7 +204,9 @@ fn assert_lint(
                     },
                 );
             }
-            bail!("Analysis of '{group}/{rule}' on the following code block returned a scan diagnostic.\n\n{code}");
+            bail!(
+                "Analysis of '{group}/{rule}' on the following code block returned a scan diagnostic.\n\n{code}"
+            );
         }
     };
 